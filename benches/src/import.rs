@@ -14,6 +14,7 @@ use fuel_core_sync::{
     },
     state::State,
     Config,
+    SyncMode,
 };
 use std::{
     sync::Arc,
@@ -50,6 +51,9 @@ pub fn provision_import_test(
     let params = Config {
         header_batch_size: header_batch_size as usize,
         block_stream_buffer_size,
+        mode: SyncMode::Full,
+        checkpoint: None,
+        ..Default::default()
     };
     let p2p = Arc::new(PressurePeerToPeer::new(
         shared_count.clone(),
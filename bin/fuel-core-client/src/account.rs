@@ -0,0 +1,74 @@
+use crate::output::OutputFormat;
+use clap::Subcommand;
+use fuel_core_client::client::{
+    types::primitives::{
+        Address,
+        AssetId,
+    },
+    FuelClient,
+};
+use futures::TryStreamExt;
+use serde_json::json;
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Get the balances of the owner, or the balance of one asset.
+    #[command(arg_required_else_help = true)]
+    Balance {
+        /// The address of the owner.
+        #[clap(long = "owner")]
+        owner: Address,
+        /// Only the balance of the asset.
+        #[clap(long = "asset-id")]
+        asset_id: Option<AssetId>,
+    },
+    /// List the unspent coins of the owner.
+    #[command(arg_required_else_help = true)]
+    Coins {
+        /// The address of the owner.
+        #[clap(long = "owner")]
+        owner: Address,
+        /// Only coins of the asset.
+        #[clap(long = "asset-id")]
+        asset_id: Option<AssetId>,
+    },
+}
+
+impl Command {
+    pub async fn exec(
+        &self,
+        client: &FuelClient,
+        format: OutputFormat,
+    ) -> anyhow::Result<()> {
+        match self {
+            Command::Balance {
+                owner,
+                asset_id: Some(asset_id),
+            } => {
+                let amount = client.balance(owner, Some(asset_id)).await?;
+                format.print(&json!({
+                    "owner": owner,
+                    "asset_id": asset_id,
+                    "amount": amount,
+                }))
+            }
+            Command::Balance {
+                owner,
+                asset_id: None,
+            } => {
+                let balances = client
+                    .balances_stream(owner)
+                    .try_collect::<Vec<_>>()
+                    .await?;
+                format.print(&balances)
+            }
+            Command::Coins { owner, asset_id } => {
+                let coins = client
+                    .coins_stream(owner, asset_id.as_ref())
+                    .try_collect::<Vec<_>>()
+                    .await?;
+                format.print(&coins)
+            }
+        }
+    }
+}
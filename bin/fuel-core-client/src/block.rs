@@ -0,0 +1,118 @@
+use crate::output::OutputFormat;
+use anyhow::Context;
+use clap::Subcommand;
+use fuel_core_client::client::{
+    pagination::{
+        PageDirection,
+        PaginationRequest,
+    },
+    types::{
+        primitives::BlockId,
+        Block,
+    },
+    FuelClient,
+};
+use fuel_core_types::fuel_types::BlockHeight;
+use std::time::Duration;
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Get the block by its height or id.
+    #[command(arg_required_else_help = true)]
+    Get {
+        /// The height of the block.
+        #[clap(long = "height", conflicts_with = "id")]
+        height: Option<u32>,
+        /// The id of the block.
+        #[clap(long = "id")]
+        id: Option<BlockId>,
+    },
+    /// List the latest blocks starting from the newest one.
+    List {
+        /// The number of blocks to list.
+        #[clap(long = "count", default_value = "10")]
+        count: i32,
+    },
+    /// Print new blocks as soon as they are produced.
+    Tail {
+        /// The interval of polling of the node for new blocks, in milliseconds.
+        #[clap(long = "poll-interval-ms", default_value = "1000")]
+        poll_interval_ms: u64,
+    },
+}
+
+impl Command {
+    pub async fn exec(
+        &self,
+        client: &FuelClient,
+        format: OutputFormat,
+    ) -> anyhow::Result<()> {
+        match self {
+            Command::Get { height, id } => {
+                let block = match (height, id) {
+                    (Some(height), _) => {
+                        client.block_by_height(BlockHeight::new(*height)).await?
+                    }
+                    (None, Some(id)) => client.block(id).await?,
+                    (None, None) => {
+                        anyhow::bail!("Either `--height` or `--id` is required")
+                    }
+                };
+                let block = block.context("The block is not found")?;
+                format.print(&block)
+            }
+            Command::List { count } => {
+                let blocks = client
+                    .blocks(PaginationRequest {
+                        cursor: None,
+                        results: *count,
+                        direction: PageDirection::Backward,
+                    })
+                    .await?
+                    .results
+                    .iter()
+                    .map(BlockSummary::from)
+                    .collect::<Vec<_>>();
+                format.print(&blocks)
+            }
+            Command::Tail { poll_interval_ms } => {
+                let poll_interval = Duration::from_millis(*poll_interval_ms);
+                let latest_block = client.chain_info().await?.latest_block;
+                let mut next_height = latest_block.header.height;
+                let mut first = true;
+                loop {
+                    match client.block_by_height(next_height.into()).await? {
+                        Some(block) => {
+                            format.print_row(&BlockSummary::from(&block), first)?;
+                            first = false;
+                            next_height = next_height.saturating_add(1);
+                        }
+                        None => tokio::time::sleep(poll_interval).await,
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The short description of the block for lists of blocks.
+#[derive(serde::Serialize)]
+struct BlockSummary {
+    height: u32,
+    id: BlockId,
+    time: i64,
+    transactions: usize,
+    producer: Option<String>,
+}
+
+impl From<&Block> for BlockSummary {
+    fn from(block: &Block) -> Self {
+        Self {
+            height: block.header.height,
+            id: block.id,
+            time: block.header.time.to_unix(),
+            transactions: block.transactions.len(),
+            producer: block.block_producer().map(ToString::to_string),
+        }
+    }
+}
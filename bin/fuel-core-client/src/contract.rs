@@ -0,0 +1,74 @@
+use crate::output::{
+    hex,
+    OutputFormat,
+};
+use anyhow::Context;
+use clap::Subcommand;
+use fuel_core_client::client::{
+    types::primitives::{
+        AssetId,
+        ContractId,
+    },
+    FuelClient,
+};
+use futures::TryStreamExt;
+use serde_json::json;
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Get the bytecode and the salt of the contract.
+    #[command(arg_required_else_help = true)]
+    Get {
+        /// The id of the contract.
+        id: ContractId,
+    },
+    /// Get the balances of the contract, or the balance of one asset.
+    #[command(arg_required_else_help = true)]
+    Balance {
+        /// The id of the contract.
+        id: ContractId,
+        /// Only the balance of the asset.
+        #[clap(long = "asset-id")]
+        asset_id: Option<AssetId>,
+    },
+}
+
+impl Command {
+    pub async fn exec(
+        &self,
+        client: &FuelClient,
+        format: OutputFormat,
+    ) -> anyhow::Result<()> {
+        match self {
+            Command::Get { id } => {
+                let contract = client
+                    .contract(id)
+                    .await?
+                    .context("The contract is not found")?;
+                format.print(&json!({
+                    "id": contract.id,
+                    "salt": contract.salt,
+                    "bytecode": hex(&contract.bytecode),
+                }))
+            }
+            Command::Balance {
+                id,
+                asset_id: Some(asset_id),
+            } => {
+                let amount = client.contract_balance(id, Some(asset_id)).await?;
+                format.print(&json!({
+                    "contract": id,
+                    "asset_id": asset_id,
+                    "amount": amount,
+                }))
+            }
+            Command::Balance { id, asset_id: None } => {
+                let balances = client
+                    .contract_balances_stream(id)
+                    .try_collect::<Vec<_>>()
+                    .await?;
+                format.print(&balances)
+            }
+        }
+    }
+}
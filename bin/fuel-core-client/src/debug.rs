@@ -0,0 +1,192 @@
+use crate::output::{
+    hex,
+    OutputFormat,
+};
+use anyhow::Context;
+use clap::Subcommand;
+use fuel_core_client::client::{
+    schema::RunResult,
+    types::primitives::ContractId,
+    FuelClient,
+};
+use fuel_core_types::{
+    fuel_asm::Instruction,
+    fuel_tx::Transaction,
+};
+use serde_json::{
+    json,
+    Value,
+};
+
+/// The commands of the debugger. The node should run with the `debug` flag.
+#[derive(Subcommand)]
+pub enum Command {
+    /// Start the new debugging session and print its id.
+    StartSession,
+    /// End the debugging session.
+    #[command(arg_required_else_help = true)]
+    EndSession {
+        /// The id of the session.
+        session_id: String,
+    },
+    /// Reset the VM of the session to the initial state.
+    #[command(arg_required_else_help = true)]
+    Reset {
+        /// The id of the session.
+        session_id: String,
+    },
+    /// Execute one instruction in the VM of the session.
+    #[command(arg_required_else_help = true)]
+    Execute {
+        /// The id of the session.
+        session_id: String,
+        /// The instruction encoded as a `u32`, hex with the `0x` prefix or decimal.
+        instruction: String,
+    },
+    /// Get the value of the register.
+    #[command(arg_required_else_help = true)]
+    Register {
+        /// The id of the session.
+        session_id: String,
+        /// The index of the register.
+        register: u32,
+    },
+    /// Get the hex-encoded range of the memory of the VM.
+    #[command(arg_required_else_help = true)]
+    Memory {
+        /// The id of the session.
+        session_id: String,
+        /// The start of the range.
+        #[clap(long = "start")]
+        start: u32,
+        /// The size of the range.
+        #[clap(long = "size")]
+        size: u32,
+    },
+    /// Set the breakpoint at the instruction of the contract.
+    #[command(arg_required_else_help = true)]
+    SetBreakpoint {
+        /// The id of the session.
+        session_id: String,
+        /// The id of the contract.
+        #[clap(long = "contract")]
+        contract: ContractId,
+        /// The program counter of the instruction, relative to the start of the contract.
+        #[clap(long = "pc")]
+        pc: u64,
+    },
+    /// Enable or disable the execution of one instruction at a time.
+    #[command(arg_required_else_help = true)]
+    SetSingleStepping {
+        /// The id of the session.
+        session_id: String,
+        /// `true` enables the single stepping.
+        #[clap(action = clap::ArgAction::Set)]
+        enable: bool,
+    },
+    /// Start the execution of a JSON encoded transaction in the VM of the session.
+    #[command(arg_required_else_help = true)]
+    StartTx {
+        /// The id of the session.
+        session_id: String,
+        /// The JSON encoded transaction.
+        tx: String,
+    },
+    /// Continue the execution of the transaction until the next breakpoint.
+    #[command(arg_required_else_help = true)]
+    ContinueTx {
+        /// The id of the session.
+        session_id: String,
+    },
+}
+
+impl Command {
+    pub async fn exec(
+        &self,
+        client: &FuelClient,
+        format: OutputFormat,
+    ) -> anyhow::Result<()> {
+        match self {
+            Command::StartSession => {
+                let session_id = client.start_session().await?;
+                format.print(&session_id)
+            }
+            Command::EndSession { session_id } => {
+                let ended = client.end_session(session_id).await?;
+                format.print(&ended)
+            }
+            Command::Reset { session_id } => {
+                let reset = client.reset(session_id).await?;
+                format.print(&reset)
+            }
+            Command::Execute {
+                session_id,
+                instruction,
+            } => {
+                let instruction = parse_instruction(instruction)?;
+                let executed = client.execute(session_id, &instruction).await?;
+                format.print(&executed)
+            }
+            Command::Register {
+                session_id,
+                register,
+            } => {
+                let value = client.register(session_id, *register).await?;
+                format.print(&value)
+            }
+            Command::Memory {
+                session_id,
+                start,
+                size,
+            } => {
+                let memory = client.memory(session_id, *start, *size).await?;
+                format.print(&hex(&memory))
+            }
+            Command::SetBreakpoint {
+                session_id,
+                contract,
+                pc,
+            } => {
+                client.set_breakpoint(session_id, *contract, *pc).await?;
+                Ok(())
+            }
+            Command::SetSingleStepping { session_id, enable } => {
+                client.set_single_stepping(session_id, *enable).await?;
+                Ok(())
+            }
+            Command::StartTx { session_id, tx } => {
+                let tx: Transaction =
+                    serde_json::from_str(tx).context("Invalid transaction json")?;
+                let result = client.start_tx(session_id, &tx).await?;
+                format.print(&run_result_to_json(&result))
+            }
+            Command::ContinueTx { session_id } => {
+                let result = client.continue_tx(session_id).await?;
+                format.print(&run_result_to_json(&result))
+            }
+        }
+    }
+}
+
+fn parse_instruction(instruction: &str) -> anyhow::Result<Instruction> {
+    let raw = match instruction.strip_prefix("0x") {
+        Some(hex) => u32::from_str_radix(hex, 16),
+        None => instruction.parse(),
+    }
+    .with_context(|| format!("Invalid instruction {instruction}"))?;
+    Instruction::try_from(raw)
+        .map_err(|e| anyhow::anyhow!("Invalid instruction {instruction}: {e:?}"))
+}
+
+fn run_result_to_json(result: &RunResult) -> Value {
+    let breakpoint = result.breakpoint.as_ref().map(|breakpoint| {
+        json!({
+            "contract": ContractId::from(breakpoint.contract.clone()),
+            "pc": u64::from(breakpoint.pc.clone()),
+        })
+    });
+    json!({
+        "breakpoint": breakpoint,
+        "receipts": result.receipts().collect::<Vec<_>>(),
+    })
+}
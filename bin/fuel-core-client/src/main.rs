@@ -5,30 +5,50 @@
 
 use clap::Parser;
 use fuel_core_client::client::FuelClient;
-use fuel_core_types::fuel_tx::{
-    Transaction,
-    TxId,
-};
-use serde_json::json;
+use output::OutputFormat;
+
+mod account;
+mod block;
+mod contract;
+mod debug;
+mod message;
+mod node;
+mod output;
+mod transaction;
 
 #[derive(Parser)]
 enum Command {
     #[clap(subcommand)]
-    Transaction(TransactionCommands),
-}
-
-#[derive(Parser)]
-enum TransactionCommands {
-    /// Submit a JSON encoded transaction for inclusion in a block
-    Submit { tx: String },
-    /// Submit a JSON encoded transaction for predicate estimation.
-    EstimatePredicates { tx: String },
-    /// Submit a JSON encoded transaction for a dry-run execution
-    DryRun { txs: Vec<String> },
-    /// Get the transactions associated with a particular transaction id
-    Get { id: String },
-    /// Get the receipts for a particular transaction id
-    Receipts { id: String },
+    Transaction(transaction::Command),
+    /// Get blocks by height or id, list or follow the latest blocks.
+    #[clap(subcommand)]
+    Block(block::Command),
+    /// Get balances and coins of the owner.
+    #[clap(subcommand)]
+    Account(account::Command),
+    /// Get the bytecode and balances of contracts.
+    #[clap(subcommand)]
+    Contract(contract::Command),
+    /// Get messages, their statuses and proofs.
+    #[clap(subcommand)]
+    Message(message::Command),
+    /// Get the information about the node and its peers.
+    #[clap(subcommand)]
+    Node(node::Command),
+    /// Get the chain information and the consensus parameters.
+    Chain,
+    /// Produce blocks. Requires the node to run in the `debug` mode.
+    #[command(arg_required_else_help = true)]
+    ProduceBlocks {
+        /// The number of blocks to produce.
+        count: u32,
+        /// The Unix timestamp of the first block. By default, the current time.
+        #[clap(long = "start-time")]
+        start_time: Option<u64>,
+    },
+    /// Debug transactions in the VM of the node.
+    #[clap(subcommand)]
+    Debug(debug::Command),
 }
 
 #[derive(Parser)]
@@ -36,61 +56,68 @@ enum TransactionCommands {
 struct CliArgs {
     #[clap(name = "endpoint", default_value = "127.0.0.1:4000", long = "endpoint")]
     endpoint: String,
+    /// The format of the output.
+    #[clap(long = "output", value_enum, default_value = "table", global = true)]
+    output: OutputFormat,
     #[clap(subcommand)]
     command: Command,
 }
 
 impl CliArgs {
-    async fn exec(&self) {
-        let client =
-            FuelClient::new(self.endpoint.as_str()).expect("expected valid endpoint");
+    async fn exec(&self) -> anyhow::Result<()> {
+        let client = FuelClient::new(self.endpoint.as_str())?;
+        let format = self.output;
 
         match &self.command {
-            Command::Transaction(sub_cmd) => match sub_cmd {
-                TransactionCommands::Submit { tx } => {
-                    let tx: Transaction =
-                        serde_json::from_str(tx).expect("invalid transaction json");
-
-                    let result = client.submit(&tx).await;
-                    println!("{}", result.unwrap());
-                }
-                TransactionCommands::EstimatePredicates { tx } => {
-                    let mut tx: Transaction =
-                        serde_json::from_str(tx).expect("invalid transaction json");
-
-                    client
-                        .estimate_predicates(&mut tx)
-                        .await
-                        .expect("Should be able to estimate predicates");
-                    println!("{:?}", tx);
-                }
-                TransactionCommands::DryRun { txs } => {
-                    let txs: Vec<Transaction> = txs
-                        .iter()
-                        .map(|tx| {
-                            serde_json::from_str(tx).expect("invalid transaction json")
-                        })
-                        .collect();
-
-                    let result = client.dry_run(&txs).await;
-                    println!("{:?}", result.unwrap());
-                }
-                TransactionCommands::Get { id } => {
-                    let tx_id = id.parse::<TxId>().expect("invalid transaction id");
-                    let tx = client.transaction(&tx_id).await.unwrap();
-                    println!("{:?}", json!(tx).to_string())
-                }
-                TransactionCommands::Receipts { id } => {
-                    let tx_id = id.parse::<TxId>().expect("invalid transaction id");
-                    let receipts = client.receipts(&tx_id).await.unwrap().unwrap();
-                    println!("{:?}", json!(receipts).to_string())
-                }
-            },
+            Command::Transaction(command) => command.exec(&client, format).await,
+            Command::Block(command) => command.exec(&client, format).await,
+            Command::Account(command) => command.exec(&client, format).await,
+            Command::Contract(command) => command.exec(&client, format).await,
+            Command::Message(command) => command.exec(&client, format).await,
+            Command::Node(command) => command.exec(&client, format).await,
+            Command::Chain => {
+                let chain_info = client.chain_info().await?;
+                format.print(&chain_info)
+            }
+            Command::ProduceBlocks { count, start_time } => {
+                let height = client.produce_blocks(*count, *start_time).await?;
+                format.print(&height)
+            }
+            Command::Debug(command) => command.exec(&client, format).await,
         }
     }
 }
 
 #[tokio::main(flavor = "current_thread")]
-async fn main() {
-    CliArgs::parse().exec().await;
+async fn main() -> anyhow::Result<()> {
+    CliArgs::parse().exec().await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+
+    #[test]
+    fn cli_is_valid() {
+        CliArgs::command().debug_assert();
+    }
+
+    #[test]
+    fn output_format_is_global() {
+        // When
+        let args = CliArgs::try_parse_from([
+            "fuel-core-client",
+            "account",
+            "balance",
+            "--owner",
+            "0x0000000000000000000000000000000000000000000000000000000000000001",
+            "--output",
+            "json",
+        ])
+        .unwrap();
+
+        // Then
+        assert_eq!(args.output, OutputFormat::Json);
+    }
 }
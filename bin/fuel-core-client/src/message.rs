@@ -0,0 +1,120 @@
+use crate::output::{
+    hex,
+    OutputFormat,
+};
+use anyhow::Context;
+use clap::Subcommand;
+use fuel_core_client::client::{
+    types::{
+        primitives::{
+            Address,
+            BlockId,
+            Nonce,
+            TransactionId,
+        },
+        Message,
+    },
+    FuelClient,
+};
+use fuel_core_types::fuel_types::BlockHeight;
+use futures::TryStreamExt;
+use serde_json::{
+    json,
+    Value,
+};
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// List the unspent messages, optionally only of the recipient.
+    List {
+        /// The address of the recipient.
+        #[clap(long = "owner")]
+        owner: Option<Address>,
+    },
+    /// Get the message by its nonce.
+    #[command(arg_required_else_help = true)]
+    Get {
+        /// The nonce of the message.
+        nonce: Nonce,
+    },
+    /// Get the status of the message by its nonce.
+    #[command(arg_required_else_help = true)]
+    Status {
+        /// The nonce of the message.
+        nonce: Nonce,
+    },
+    /// Get the merkle proof of the message sent by the transaction.
+    #[command(arg_required_else_help = true)]
+    Proof {
+        /// The id of the transaction that sent the message.
+        #[clap(long = "tx-id")]
+        tx_id: TransactionId,
+        /// The nonce of the message.
+        #[clap(long = "nonce")]
+        nonce: Nonce,
+        /// The id of the committed block used as the root of the block proof.
+        #[clap(long = "commit-block-id", conflicts_with = "commit_block_height")]
+        commit_block_id: Option<BlockId>,
+        /// The height of the committed block used as the root of the block proof.
+        #[clap(long = "commit-block-height")]
+        commit_block_height: Option<u32>,
+    },
+}
+
+impl Command {
+    pub async fn exec(
+        &self,
+        client: &FuelClient,
+        format: OutputFormat,
+    ) -> anyhow::Result<()> {
+        match self {
+            Command::List { owner } => {
+                let messages = client
+                    .messages_stream(owner.as_ref())
+                    .map_ok(|message| message_to_json(&message))
+                    .try_collect::<Vec<_>>()
+                    .await?;
+                format.print(&messages)
+            }
+            Command::Get { nonce } => {
+                let message = client
+                    .message(nonce)
+                    .await?
+                    .context("The message is not found")?;
+                format.print(&message_to_json(&message))
+            }
+            Command::Status { nonce } => {
+                let status = client.message_status(nonce).await?;
+                format.print(&status)
+            }
+            Command::Proof {
+                tx_id,
+                nonce,
+                commit_block_id,
+                commit_block_height,
+            } => {
+                let proof = client
+                    .message_proof(
+                        tx_id,
+                        nonce,
+                        commit_block_id.as_ref(),
+                        commit_block_height.map(BlockHeight::new),
+                    )
+                    .await?
+                    .context("The proof of the message is not found")?;
+                format.print(&proof)
+            }
+        }
+    }
+}
+
+fn message_to_json(message: &Message) -> Value {
+    json!({
+        "nonce": message.nonce,
+        "sender": message.sender,
+        "recipient": message.recipient,
+        "amount": message.amount,
+        "da_height": message.da_height,
+        "data": hex(&message.data),
+    })
+}
@@ -0,0 +1,107 @@
+use crate::output::OutputFormat;
+use clap::Subcommand;
+use fuel_core_client::client::FuelClient;
+use fuel_core_types::services::p2p::PeerId;
+use serde_json::json;
+use std::time::{
+    Duration,
+    SystemTime,
+};
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Get the version and the configuration of the node.
+    Info,
+    /// List the connected peers.
+    Peers,
+    /// List the banned peers.
+    BannedPeers,
+    /// Ban the peer. Requires the node to run in the `debug` mode.
+    #[command(arg_required_else_help = true)]
+    BanPeer {
+        /// The libp2p id of the peer.
+        peer_id: PeerId,
+        /// The duration of the ban in seconds. By default, the node decides.
+        #[clap(long = "duration-secs")]
+        duration_secs: Option<u64>,
+    },
+    /// Unban the peer. Requires the node to run in the `debug` mode.
+    #[command(arg_required_else_help = true)]
+    UnbanPeer {
+        /// The libp2p id of the peer.
+        peer_id: PeerId,
+    },
+    /// Get the latest gas price.
+    GasPrice,
+}
+
+impl Command {
+    pub async fn exec(
+        &self,
+        client: &FuelClient,
+        format: OutputFormat,
+    ) -> anyhow::Result<()> {
+        match self {
+            Command::Info => {
+                let info = client.node_info().await?;
+                format.print(&info)
+            }
+            Command::Peers => {
+                let peers = client
+                    .connected_peers_info()
+                    .await?
+                    .into_iter()
+                    .map(|peer| {
+                        json!({
+                            "id": peer.id.to_string(),
+                            "addresses": peer.peer_addresses,
+                            "client_version": peer.client_version,
+                            "block_height": peer.heartbeat_data.block_height,
+                            "last_heartbeat": unix_time(peer.heartbeat_data.last_heartbeat),
+                            "app_score": peer.app_score,
+                        })
+                    })
+                    .collect::<Vec<_>>();
+                format.print(&peers)
+            }
+            Command::BannedPeers => {
+                let peers = client
+                    .banned_peers_info()
+                    .await?
+                    .into_iter()
+                    .map(|peer| {
+                        json!({
+                            "id": peer.id.to_string(),
+                            "banned_until": unix_time(peer.banned_until),
+                        })
+                    })
+                    .collect::<Vec<_>>();
+                format.print(&peers)
+            }
+            Command::BanPeer {
+                peer_id,
+                duration_secs,
+            } => {
+                let banned = client
+                    .ban_peer(peer_id, duration_secs.map(Duration::from_secs))
+                    .await?;
+                format.print(&banned)
+            }
+            Command::UnbanPeer { peer_id } => {
+                let unbanned = client.unban_peer(peer_id).await?;
+                format.print(&unbanned)
+            }
+            Command::GasPrice => {
+                let gas_price = client.latest_gas_price().await?;
+                format.print(&gas_price)
+            }
+        }
+    }
+}
+
+/// Returns the number of seconds since the Unix epoch.
+fn unix_time(time: SystemTime) -> u64 {
+    time.duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
@@ -0,0 +1,175 @@
+//! Printing of responses from the node as JSON or as human-readable tables.
+
+use clap::ValueEnum;
+use serde::Serialize;
+use serde_json::Value;
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Lists are printed as tables, objects as lists of fields.
+    #[default]
+    Table,
+    /// Pretty-printed JSON.
+    Json,
+}
+
+impl OutputFormat {
+    /// Prints the `value` in the format.
+    pub fn print<T>(&self, value: &T) -> anyhow::Result<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        let value = serde_json::to_value(value)?;
+        match self {
+            OutputFormat::Table => println!("{}", render(&value)),
+            OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&value)?),
+        }
+        Ok(())
+    }
+
+    /// Prints one row of the endless list, for example, of new blocks.
+    /// The header of the table is printed only with the first row.
+    pub fn print_row<T>(&self, value: &T, first: bool) -> anyhow::Result<()>
+    where
+        T: Serialize,
+    {
+        let value = serde_json::to_value(value)?;
+        match self {
+            OutputFormat::Table => {
+                let table = render_rows(&[value]);
+                let skip = if first { 0 } else { 1 };
+                for line in table.lines().skip(skip) {
+                    println!("{line}");
+                }
+            }
+            OutputFormat::Json => println!("{}", serde_json::to_string(&value)?),
+        }
+        Ok(())
+    }
+}
+
+/// Encodes bytes as the hex string with the `0x` prefix, the same way as the node does.
+pub fn hex(bytes: &[u8]) -> String {
+    let mut hex = String::from("0x");
+    for byte in bytes {
+        hex.push_str(&format!("{byte:02x}"));
+    }
+    hex
+}
+
+fn render(value: &Value) -> String {
+    match value {
+        Value::Array(rows) => render_rows(rows),
+        Value::Object(fields) => {
+            let width = fields.keys().map(String::len).max().unwrap_or_default();
+            fields
+                .iter()
+                .map(|(name, value)| format!("{name:width$}  {}", cell(value)))
+                .collect::<Vec<_>>()
+                .join("\n")
+        }
+        value => cell(value),
+    }
+}
+
+/// Renders objects as rows of the table with a column per field.
+fn render_rows(rows: &[Value]) -> String {
+    let mut columns = Vec::<String>::new();
+    for row in rows {
+        if let Value::Object(fields) = row {
+            for name in fields.keys() {
+                if !columns.contains(name) {
+                    columns.push(name.clone());
+                }
+            }
+        }
+    }
+    if columns.is_empty() {
+        return rows.iter().map(cell).collect::<Vec<_>>().join("\n")
+    }
+
+    let cells = rows
+        .iter()
+        .map(|row| {
+            columns
+                .iter()
+                .map(|column| row.get(column).map(cell).unwrap_or_default())
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let widths = columns
+        .iter()
+        .enumerate()
+        .map(|(i, column)| {
+            cells
+                .iter()
+                .filter_map(|row| row.get(i))
+                .map(String::len)
+                .chain([column.len()])
+                .max()
+                .unwrap_or_default()
+        })
+        .collect::<Vec<_>>();
+
+    let mut lines = vec![line(&columns, &widths)];
+    lines.extend(cells.iter().map(|row| line(row, &widths)));
+    lines.join("\n")
+}
+
+fn line(cells: &[String], widths: &[usize]) -> String {
+    cells
+        .iter()
+        .zip(widths)
+        .map(|(cell, width)| format!("{cell:width$}"))
+        .collect::<Vec<_>>()
+        .join("  ")
+        .trim_end()
+        .to_string()
+}
+
+/// Nested lists and objects are rendered as compact JSON.
+fn cell(value: &Value) -> String {
+    match value {
+        Value::Null => "-".to_string(),
+        Value::String(string) => string.clone(),
+        value => value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn objects_are_rendered_as_aligned_table() {
+        // Given
+        let rows = json!([
+            { "amount": 100, "asset_id": "0x01" },
+            { "amount": 5, "asset_id": "0x0202", "owner": null },
+        ]);
+
+        // When
+        let table = render(&rows);
+
+        // Then
+        assert_eq!(
+            table,
+            "amount  asset_id  owner\n\
+             100     0x01\n\
+             5       0x0202    -"
+        );
+    }
+
+    #[test]
+    fn object_is_rendered_as_list_of_fields() {
+        // Given
+        let object = json!({ "height": 10, "transactions": ["0x01"] });
+
+        // When
+        let list = render(&object);
+
+        // Then
+        assert_eq!(list, "height        10\ntransactions  [\"0x01\"]");
+    }
+}
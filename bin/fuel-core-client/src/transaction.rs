@@ -0,0 +1,99 @@
+use crate::output::OutputFormat;
+use anyhow::Context;
+use clap::Subcommand;
+use fuel_core_client::client::FuelClient;
+use fuel_core_types::{
+    fuel_tx::{
+        Transaction,
+        TxId,
+    },
+    services::executor::TransactionExecutionResult,
+};
+use serde_json::json;
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Submit a JSON encoded transaction for inclusion in a block
+    Submit { tx: String },
+    /// Submit a JSON encoded transaction for predicate estimation.
+    EstimatePredicates { tx: String },
+    /// Submit a JSON encoded transaction for a dry-run execution
+    DryRun { txs: Vec<String> },
+    /// Get the transactions associated with a particular transaction id
+    Get { id: String },
+    /// Get the status of a particular transaction id
+    Status { id: String },
+    /// Get the receipts for a particular transaction id
+    Receipts { id: String },
+}
+
+impl Command {
+    pub async fn exec(
+        &self,
+        client: &FuelClient,
+        format: OutputFormat,
+    ) -> anyhow::Result<()> {
+        match self {
+            Command::Submit { tx } => {
+                let tx = parse_tx(tx)?;
+                let id = client.submit(&tx).await?;
+                format.print(&id)
+            }
+            Command::EstimatePredicates { tx } => {
+                let mut tx = parse_tx(tx)?;
+                client.estimate_predicates(&mut tx).await?;
+                format.print(&tx)
+            }
+            Command::DryRun { txs } => {
+                let txs = txs
+                    .iter()
+                    .map(|tx| parse_tx(tx))
+                    .collect::<anyhow::Result<Vec<_>>>()?;
+                let statuses = client
+                    .dry_run(&txs)
+                    .await?
+                    .into_iter()
+                    .map(|status| {
+                        let success = matches!(
+                            status.result,
+                            TransactionExecutionResult::Success { .. }
+                        );
+                        json!({
+                            "id": status.id,
+                            "success": success,
+                            "receipts": status.result.receipts(),
+                        })
+                    })
+                    .collect::<Vec<_>>();
+                format.print(&statuses)
+            }
+            Command::Get { id } => {
+                let tx = client
+                    .transaction(&parse_tx_id(id)?)
+                    .await?
+                    .context("The transaction is not found")?;
+                format.print(&tx)
+            }
+            Command::Status { id } => {
+                let status = client.transaction_status(&parse_tx_id(id)?).await?;
+                format.print(&status)
+            }
+            Command::Receipts { id } => {
+                let receipts = client
+                    .receipts(&parse_tx_id(id)?)
+                    .await?
+                    .context("The transaction doesn't have receipts")?;
+                format.print(&receipts)
+            }
+        }
+    }
+}
+
+fn parse_tx(tx: &str) -> anyhow::Result<Transaction> {
+    serde_json::from_str(tx).context("Invalid transaction json")
+}
+
+fn parse_tx_id(id: &str) -> anyhow::Result<TxId> {
+    id.parse()
+        .map_err(|e| anyhow::anyhow!("Invalid transaction id {id}: {e}"))
+}
@@ -18,8 +18,14 @@ lazy_static::lazy_static! {
     pub static ref DEFAULT_DB_PATH: PathBuf = dirs::home_dir().unwrap().join(".fuel").join("db");
 }
 
+pub mod chain_config;
+pub mod db;
 pub mod fee_contract;
+#[cfg(feature = "relayer")]
+pub mod relayer;
+pub mod rollback;
 pub mod run;
+pub mod signer;
 pub mod snapshot;
 
 #[derive(Parser, Debug)]
@@ -39,7 +45,13 @@ pub struct Opt {
 pub enum Fuel {
     Run(run::Command),
     Snapshot(snapshot::Command),
+    #[cfg(feature = "relayer")]
+    Relayer(relayer::Command),
+    Rollback(rollback::Command),
+    Db(db::Command),
     GenerateFeeContract(fee_contract::Command),
+    Signer(signer::Command),
+    ChainConfig(chain_config::Command),
 }
 
 pub const LOG_FILTER: &str = "RUST_LOG";
@@ -119,7 +131,13 @@ pub async fn run_cli() -> anyhow::Result<()> {
         Ok(opt) => match opt.command {
             Fuel::Run(command) => run::exec(command).await,
             Fuel::Snapshot(command) => snapshot::exec(command).await,
+            #[cfg(feature = "relayer")]
+            Fuel::Relayer(command) => relayer::exec(command).await,
+            Fuel::Rollback(command) => rollback::exec(command).await,
+            Fuel::Db(command) => db::exec(command).await,
             Fuel::GenerateFeeContract(command) => fee_contract::exec(command).await,
+            Fuel::Signer(command) => signer::exec(command).await,
+            Fuel::ChainConfig(command) => chain_config::exec(command).await,
         },
         Err(e) => {
             // Prints the error and exits.
@@ -0,0 +1,85 @@
+use anyhow::{
+    anyhow,
+    Context,
+};
+use clap::{
+    Parser,
+    Subcommand,
+};
+use fuel_core_chain_config::ChainConfig;
+
+mod new;
+
+/// Creates and inspects the chain config files.
+#[derive(Debug, Clone, Parser)]
+pub struct Command {
+    /// The sub-command of the chain config operation.
+    #[command(subcommand)]
+    subcommand: SubCommands,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum SubCommands {
+    /// Builds the chain config for the new network, e.g. the devnet.
+    New(new::NewArgs),
+    /// Checks the invariants of the chain config and prints all violations.
+    /// Fails if the config is invalid.
+    Validate {
+        /// Specify either an alias to a built-in configuration or filepath to a JSON file.
+        chain_config: String,
+    },
+    /// Prints the fields that differ between two chain configs.
+    Diff {
+        /// The old chain config, an alias to a built-in configuration or filepath to a JSON file.
+        old: String,
+        /// The new chain config, an alias to a built-in configuration or filepath to a JSON file.
+        new: String,
+        /// Fails if the configs differ.
+        #[arg(long = "exit-code")]
+        exit_code: bool,
+    },
+}
+
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    match command.subcommand {
+        SubCommands::New(args) => new::exec(args),
+        SubCommands::Validate { chain_config } => {
+            let config = load(&chain_config)?;
+            match config.validate() {
+                Ok(()) => {
+                    println!("{chain_config} is valid");
+                    Ok(())
+                }
+                Err(errors) => {
+                    for error in &errors {
+                        println!("{error}");
+                    }
+                    Err(anyhow!("{chain_config} has {} error(s)", errors.len()))
+                }
+            }
+        }
+        SubCommands::Diff {
+            old,
+            new,
+            exit_code,
+        } => {
+            let changes = fuel_core_chain_config::diff::diff(&load(&old)?, &load(&new)?)?;
+            for change in &changes {
+                println!("{change}");
+            }
+            if exit_code && !changes.is_empty() {
+                return Err(anyhow!(
+                    "{old} and {new} differ in {} field(s)",
+                    changes.len()
+                ))
+            }
+            Ok(())
+        }
+    }
+}
+
+fn load(chain_config: &str) -> anyhow::Result<ChainConfig> {
+    chain_config
+        .parse()
+        .with_context(|| format!("failed to load the chain config {chain_config}"))
+}
@@ -0,0 +1,465 @@
+use anyhow::{
+    anyhow,
+    Context,
+};
+use fuel_core::{
+    chain_config::{
+        ChainConfig,
+        ConsensusConfig,
+        ContractConfig,
+        MessageConfig,
+        StateConfig,
+        TESTNET_INITIAL_BALANCE,
+    },
+    types::{
+        blockchain::primitives::DaBlockHeight,
+        fuel_crypto::{
+            rand::{
+                prelude::StdRng,
+                SeedableRng,
+            },
+            SecretKey,
+        },
+        fuel_tx::{
+            ConsensusParameters,
+            GasCosts,
+        },
+        fuel_types::{
+            Address,
+            Bytes32,
+            ChainId,
+            Nonce,
+        },
+    },
+};
+use std::{
+    path::{
+        Path,
+        PathBuf,
+    },
+    str::FromStr,
+};
+
+/// Builds the chain config of the new network from the arguments.
+#[derive(Debug, Clone, clap::Args)]
+pub struct NewArgs {
+    #[arg(long = "chain-name", default_value = "local")]
+    pub chain_name: String,
+
+    #[arg(long = "chain-id")]
+    pub chain_id: Option<u64>,
+
+    /// The number of accounts funded in the genesis.
+    #[arg(long = "accounts", default_value = "5")]
+    pub accounts: u32,
+
+    /// The seed of the keys of the funded accounts.
+    /// The same seed always produces the same accounts.
+    #[arg(long = "seed", default_value = "0")]
+    pub seed: u64,
+
+    /// The balance of the base asset of each funded account.
+    #[arg(long = "balance", default_value_t = TESTNET_INITIAL_BALANCE)]
+    pub balance: u64,
+
+    /// The contract deployed in the genesis, `<CODE>[:<STORAGE_SLOTS>]`.
+    /// The `CODE` is the path to the binary of the contract. The optional `STORAGE_SLOTS`
+    /// is the path to the JSON file with the initial storage slots, in the format
+    /// of the `*-storage_slots.json` file produced by `forc build`.
+    #[arg(long = "contract", value_parser = parse_contract)]
+    pub contracts: Vec<ContractArg>,
+
+    /// The message from the base layer available in the genesis,
+    /// `<RECIPIENT>:<AMOUNT>[:<DATA>]`. The `DATA` is hex-encoded.
+    #[arg(long = "message", value_parser = parse_message)]
+    pub messages: Vec<MessageArg>,
+
+    /// The address of the PoA block producer.
+    /// If not set, the address of the development consensus key is used.
+    #[arg(long = "poa-signing-key")]
+    pub poa_signing_key: Option<Address>,
+
+    #[arg(long = "block-gas-limit")]
+    pub block_gas_limit: Option<u64>,
+
+    /// The path to the JSON object with the costs of instructions replacing
+    /// the default ones, e.g. `{ "add": 3 }`. Other costs stay default.
+    #[arg(long = "gas-costs")]
+    pub gas_costs: Option<PathBuf>,
+
+    /// The file to write the chain config to. If not set, the config is printed to stdout.
+    #[arg(long = "output")]
+    pub output: Option<PathBuf>,
+
+    /// Writes the chain config as the snapshot directory at the `--output`,
+    /// with the state split into chunks.
+    #[arg(long = "snapshot", requires = "output")]
+    pub snapshot: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContractArg {
+    pub code: PathBuf,
+    pub storage_slots: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MessageArg {
+    pub recipient: Address,
+    pub amount: u64,
+    pub data: Vec<u8>,
+}
+
+fn parse_contract(s: &str) -> anyhow::Result<ContractArg> {
+    let (code, storage_slots) = match s.split_once(':') {
+        Some((code, storage_slots)) => (code, Some(PathBuf::from(storage_slots))),
+        None => (s, None),
+    };
+    Ok(ContractArg {
+        code: PathBuf::from(code),
+        storage_slots,
+    })
+}
+
+fn parse_message(s: &str) -> anyhow::Result<MessageArg> {
+    let mut parts = s.splitn(3, ':');
+    let (recipient, amount) = match (parts.next(), parts.next()) {
+        (Some(recipient), Some(amount)) => (recipient, amount),
+        _ => {
+            return Err(anyhow!(
+                "Expected `<RECIPIENT>:<AMOUNT>[:<DATA>]`, got `{s}`"
+            ))
+        }
+    };
+    let recipient = Address::from_str(recipient).map_err(|e| anyhow!(e))?;
+    let amount = amount.parse()?;
+    let data = match parts.next() {
+        Some(data) => hex::decode(data.trim_start_matches("0x"))?,
+        None => vec![],
+    };
+    Ok(MessageArg {
+        recipient,
+        amount,
+        data,
+    })
+}
+
+pub fn exec(args: NewArgs) -> anyhow::Result<()> {
+    let output = args.output.clone();
+    let snapshot = args.snapshot;
+    let config = build(args)?;
+
+    if let Err(errors) = config.validate() {
+        for error in &errors {
+            eprintln!("{error}");
+        }
+        return Err(anyhow!(
+            "The built chain config has {} error(s)",
+            errors.len()
+        ))
+    }
+
+    match output {
+        Some(dir) if snapshot => config.write_snapshot(&dir)?,
+        Some(path) => {
+            let file = std::fs::File::create(&path)
+                .with_context(|| format!("failed to create {}", path.display()))?;
+            serde_json::to_writer_pretty(file, &config)
+                .context("failed to write the chain config")?;
+        }
+        None => {
+            let stdout = std::io::stdout().lock();
+            serde_json::to_writer_pretty(stdout, &config)
+                .context("failed to write the chain config")?;
+        }
+    }
+    Ok(())
+}
+
+fn build(args: NewArgs) -> anyhow::Result<ChainConfig> {
+    let default = ChainConfig::default();
+
+    tracing::info!("Initial Accounts");
+    let mut rng = StdRng::seed_from_u64(args.seed);
+    let coins = (0..args.accounts)
+        .map(|_| {
+            let secret = SecretKey::random(&mut rng);
+            tracing::info!(
+                "PrivateKey({:#x}), Address({:#x}), Balance({})",
+                secret,
+                Address::from(*secret.public_key().hash()),
+                args.balance
+            );
+            ChainConfig::initial_coin(secret, args.balance, None)
+        })
+        .collect::<Vec<_>>();
+
+    let contracts = args
+        .contracts
+        .iter()
+        .map(contract_config)
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let messages = args
+        .messages
+        .into_iter()
+        .zip(0u64..)
+        .map(|(message, index)| {
+            let mut nonce = [0u8; 32];
+            nonce[24..].copy_from_slice(&index.to_be_bytes());
+            MessageConfig {
+                sender: Address::zeroed(),
+                recipient: message.recipient,
+                nonce: Nonce::from(nonce),
+                amount: message.amount,
+                data: message.data,
+                da_height: DaBlockHeight(0),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let mut consensus_parameters = ConsensusParameters::default();
+    if let Some(chain_id) = args.chain_id {
+        consensus_parameters.chain_id = ChainId::from(chain_id);
+    }
+    if let Some(path) = &args.gas_costs {
+        consensus_parameters.gas_costs = gas_costs(path)?;
+    }
+
+    let consensus = match args.poa_signing_key {
+        Some(signing_key) => ConsensusConfig::PoA { signing_key },
+        None => ConsensusConfig::default_poa(),
+    };
+
+    Ok(ChainConfig {
+        chain_name: args.chain_name,
+        block_gas_limit: args.block_gas_limit.unwrap_or(default.block_gas_limit),
+        initial_state: Some(StateConfig {
+            coins: Some(coins),
+            contracts: Some(contracts),
+            messages: Some(messages),
+            height: None,
+        }),
+        consensus_parameters,
+        consensus,
+    })
+}
+
+fn contract_config(arg: &ContractArg) -> anyhow::Result<ContractConfig> {
+    let code = std::fs::read(&arg.code).with_context(|| {
+        format!("failed to read the contract code {}", arg.code.display())
+    })?;
+    let state = arg
+        .storage_slots
+        .as_ref()
+        .map(|path| storage_slots(path))
+        .transpose()?;
+
+    let mut contract = ContractConfig {
+        contract_id: Default::default(),
+        code,
+        salt: Default::default(),
+        state,
+        balances: None,
+        tx_id: None,
+        output_index: None,
+        tx_pointer_block_height: None,
+        tx_pointer_tx_idx: None,
+    };
+    contract.calculate_contract_id();
+    // The generated UTXO ids of contracts overlap with the generated ids of coins,
+    // so the id of the contract is used as the transaction id of its UTXO.
+    contract.tx_id = Some(Bytes32::new(*contract.contract_id));
+    contract.output_index = Some(0);
+    tracing::info!(
+        "Contract({:#x}) from {}",
+        contract.contract_id,
+        arg.code.display()
+    );
+    Ok(contract)
+}
+
+/// Reads the costs of instructions in the format `{ "<INSTRUCTION>": <COST> }`
+/// and applies them over the default gas costs.
+fn gas_costs(path: &Path) -> anyhow::Result<GasCosts> {
+    let contents = std::fs::read(path)
+        .with_context(|| format!("failed to read {}", path.display()))?;
+    let overrides: serde_json::Map<String, serde_json::Value> =
+        serde_json::from_slice(&contents).with_context(|| {
+            format!("failed to parse the gas costs {}", path.display())
+        })?;
+    let mut gas_costs = serde_json::to_value(GasCosts::default())?;
+    let costs = gas_costs
+        .as_object_mut()
+        .ok_or_else(|| anyhow!("the default gas costs are not a JSON object"))?;
+    for (name, cost) in overrides {
+        if !costs.contains_key(&name) {
+            return Err(anyhow!("unknown gas cost `{name}` in {}", path.display()))
+        }
+        costs.insert(name, cost);
+    }
+    serde_json::from_value(gas_costs)
+        .with_context(|| format!("failed to parse the gas costs {}", path.display()))
+}
+
+/// Reads the storage slots in the format `[{ "key": "<HEX>", "value": "<HEX>" }]`.
+fn storage_slots(path: &Path) -> anyhow::Result<Vec<(Bytes32, Bytes32)>> {
+    let contents = std::fs::read(path).with_context(|| {
+        format!("failed to read the storage slots {}", path.display())
+    })?;
+    let slots: Vec<serde_json::Map<String, serde_json::Value>> =
+        serde_json::from_slice(&contents).with_context(|| {
+            format!("failed to parse the storage slots {}", path.display())
+        })?;
+    slots
+        .iter()
+        .enumerate()
+        .map(|(index, slot)| {
+            let field = |name: &str| {
+                slot.get(name)
+                    .and_then(|value| value.as_str())
+                    .and_then(|value| Bytes32::from_str(value).ok())
+                    .ok_or_else(|| {
+                        anyhow!(
+                            "the slot {index} in {} has no valid `{name}`",
+                            path.display()
+                        )
+                    })
+            };
+            Ok((field("key")?, field("value")?))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Debug, Parser)]
+    struct Command {
+        #[clap(flatten)]
+        args: NewArgs,
+    }
+
+    fn build_from(args: &[&str]) -> ChainConfig {
+        let command = Command::parse_from(["new"].iter().chain(args));
+        build(command.args).unwrap()
+    }
+
+    #[test]
+    fn build_funds_the_same_accounts_for_the_same_seed() {
+        // Given
+        let args = ["--accounts", "3", "--seed", "7"];
+
+        // When
+        let first = build_from(&args);
+        let second = build_from(&args);
+        let other_seed = build_from(&["--accounts", "3", "--seed", "8"]);
+
+        // Then
+        let coins = first
+            .initial_state
+            .as_ref()
+            .unwrap()
+            .coins
+            .as_ref()
+            .unwrap();
+        assert_eq!(coins.len(), 3);
+        assert_eq!(first, second);
+        assert_ne!(first, other_seed);
+        assert_eq!(first.validate(), Ok(()));
+    }
+
+    #[test]
+    fn build_applies_overrides() {
+        // Given
+        let recipient = Address::from([1; 32]);
+        let signing_key = Address::from([2; 32]);
+        let message = format!("{recipient:#x}:100:0x0102");
+
+        // When
+        let config = build_from(&[
+            "--message",
+            &message,
+            "--message",
+            &message,
+            "--poa-signing-key",
+            &format!("{signing_key:#x}"),
+            "--block-gas-limit",
+            "123456789",
+            "--chain-id",
+            "9",
+        ]);
+
+        // Then
+        let messages = config.initial_state.as_ref().unwrap().messages.clone();
+        let messages = messages.unwrap();
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[0].recipient, recipient);
+        assert_eq!(messages[0].amount, 100);
+        assert_eq!(messages[0].data, vec![1, 2]);
+        assert_ne!(messages[0].nonce, messages[1].nonce);
+        assert_eq!(config.consensus, ConsensusConfig::PoA { signing_key });
+        assert_eq!(config.block_gas_limit, 123456789);
+        assert_eq!(config.consensus_parameters.chain_id, ChainId::from(9));
+        assert_eq!(config.validate(), Ok(()));
+    }
+
+    #[test]
+    fn build_creates_valid_config_with_accounts_and_contracts() {
+        // Given
+        let dir = tempfile::tempdir().unwrap();
+        let first = dir.path().join("first.bin");
+        let second = dir.path().join("second.bin");
+        std::fs::write(&first, [1]).unwrap();
+        std::fs::write(&second, [2]).unwrap();
+
+        // When
+        let config = build_from(&[
+            "--accounts",
+            "3",
+            "--contract",
+            first.to_str().unwrap(),
+            "--contract",
+            second.to_str().unwrap(),
+        ]);
+
+        // Then
+        let contracts = config.initial_state.as_ref().unwrap().contracts.clone();
+        assert_eq!(contracts.unwrap().len(), 2);
+        assert_eq!(config.validate(), Ok(()));
+    }
+
+    #[test]
+    fn build_applies_partial_gas_costs_over_defaults() {
+        // Given
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("gas_costs.json");
+        std::fs::write(&path, r#"{ "add": 12345 }"#).unwrap();
+
+        // When
+        let config = build_from(&["--gas-costs", path.to_str().unwrap()]);
+
+        // Then
+        let gas_costs =
+            serde_json::to_value(&config.consensus_parameters.gas_costs).unwrap();
+        let default = serde_json::to_value(GasCosts::default()).unwrap();
+        assert_eq!(gas_costs["add"], 12345);
+        assert_eq!(gas_costs["addi"], default["addi"]);
+    }
+
+    #[test]
+    fn gas_costs_rejects_unknown_instruction() {
+        // Given
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("gas_costs.json");
+        std::fs::write(&path, r#"{ "unknown": 1 }"#).unwrap();
+
+        // When
+        let result = gas_costs(&path);
+
+        // Then
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,334 @@
+use crate::cli::DEFAULT_DB_PATH;
+use clap::{
+    Parser,
+    Subcommand,
+    ValueEnum,
+};
+use fuel_core::service::config::DbType;
+use std::path::PathBuf;
+
+/// Manages the databases of the node.
+/// The node should be stopped while the command is running.
+#[derive(Debug, Clone, Parser)]
+pub struct Command {
+    /// The path to the database.
+    #[clap(
+        name = "DB_PATH",
+        long = "db-path",
+        value_parser,
+        default_value = (*DEFAULT_DB_PATH).to_str().unwrap()
+    )]
+    database_path: PathBuf,
+
+    /// The sub-command of the database operation.
+    #[command(subcommand)]
+    subcommand: SubCommands,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum SubCommands {
+    /// Creates the backup of the on-chain, off-chain and relayer databases
+    /// at the same block height.
+    #[command(arg_required_else_help = true)]
+    Backup {
+        /// The directory for the backup. It should not contain another backup.
+        #[clap(long = "backup-path", value_parser)]
+        backup_path: PathBuf,
+
+        /// The type of the database.
+        #[clap(
+            long = "db-type",
+            default_value = "rocks-db",
+            value_enum,
+            ignore_case = true
+        )]
+        database_type: DbType,
+    },
+    /// Restores the on-chain, off-chain and relayer databases from the backup.
+    /// The database path should not contain these databases.
+    #[command(arg_required_else_help = true)]
+    Restore {
+        /// The directory with the backup.
+        #[clap(long = "backup-path", value_parser)]
+        backup_path: PathBuf,
+
+        /// The type of the database.
+        #[clap(
+            long = "db-type",
+            default_value = "rocks-db",
+            value_enum,
+            ignore_case = true
+        )]
+        database_type: DbType,
+    },
+    /// Prints the number of keys and the size of each column of the database.
+    Columns {
+        /// The database to inspect.
+        #[clap(long = "database", value_enum, default_value = "on-chain")]
+        database: DatabaseKind,
+    },
+    /// Decodes the entry of the table and prints it.
+    #[command(arg_required_else_help = true)]
+    Get {
+        /// The database with the table.
+        #[clap(long = "database", value_enum, default_value = "on-chain")]
+        database: DatabaseKind,
+
+        /// The name of the table, for example, `Coins` or `OwnedCoins`.
+        #[clap(long = "table")]
+        table: String,
+
+        /// The hex-encoded key of the entry as it is stored in the database.
+        #[clap(long = "key")]
+        key: String,
+    },
+    /// Checks invariants between the on-chain and off-chain databases
+    /// and prints all violations. Fails if any violation is found.
+    Check,
+    /// Rebuilds the indexes of the off-chain database from the on-chain state.
+    RebuildOffChain,
+    /// Upgrades the databases created by an older version of the node.
+    /// The node also applies migrations on start.
+    Migrate {
+        /// Only prints the migrations without applying them.
+        #[arg(long = "dry-run")]
+        dry_run: bool,
+    },
+    /// Re-indexes the off-chain database by executing the stored blocks again.
+    /// It restores all off-chain data, including statuses of transactions.
+    Reindex {
+        /// Specify either an alias to a built-in configuration or filepath to a JSON file.
+        #[clap(name = "CHAIN_CONFIG", long = "chain", default_value = "local_testnet")]
+        chain_config: String,
+
+        /// The height of the first block to re-index. The off-chain database is rolled
+        /// back to the previous height. By default, the off-chain database is cleared
+        /// and re-indexed from the genesis block.
+        #[clap(long = "from-height")]
+        from_height: Option<u32>,
+
+        /// Should be the same as the `--utxo-validation` of the node
+        /// that executed the blocks.
+        #[arg(long = "utxo-validation")]
+        utxo_validation: bool,
+
+        /// The type of the database. The blocks are executed again
+        /// on a temporary copy of the database of the same type.
+        #[clap(
+            long = "db-type",
+            default_value = "rocks-db",
+            value_enum,
+            ignore_case = true
+        )]
+        database_type: DbType,
+    },
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum DatabaseKind {
+    OnChain,
+    OffChain,
+    Relayer,
+}
+
+#[cfg(not(any(feature = "rocksdb", feature = "rocksdb-production")))]
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    Err(anyhow::anyhow!(
+        "Rocksdb must be enabled to use the database at {}",
+        command.database_path.display()
+    ))
+}
+
+#[cfg(any(feature = "rocksdb", feature = "rocksdb-production"))]
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    use crate::cli::run::DEFAULT_DATABASE_CACHE_SIZE;
+    use fuel_core::combined_database::CombinedDatabase;
+
+    match command.subcommand {
+        SubCommands::Backup {
+            backup_path,
+            database_type,
+        } => {
+            let db = match database_type {
+                DbType::RocksDb => CombinedDatabase::open(
+                    &command.database_path,
+                    DEFAULT_DATABASE_CACHE_SIZE,
+                )?,
+                #[cfg(feature = "redb")]
+                DbType::Redb => CombinedDatabase::open_redb(
+                    &command.database_path,
+                    DEFAULT_DATABASE_CACHE_SIZE,
+                )?,
+                database_type => {
+                    return Err(anyhow::anyhow!(
+                        "The {database_type} database can't be backed up"
+                    ))
+                }
+            };
+            let height = db.backup(&database_type, &backup_path)?;
+            println!(
+                "Created the backup at {} with the block height {}",
+                backup_path.display(),
+                *height
+            );
+        }
+        SubCommands::Restore {
+            backup_path,
+            database_type,
+        } => {
+            CombinedDatabase::restore(
+                &database_type,
+                &backup_path,
+                &command.database_path,
+            )?;
+            println!(
+                "Restored the database at {} from the backup at {}",
+                command.database_path.display(),
+                backup_path.display()
+            );
+        }
+        SubCommands::Columns { database } => {
+            let db = CombinedDatabase::open(
+                &command.database_path,
+                DEFAULT_DATABASE_CACHE_SIZE,
+            )?;
+            let stats = match database {
+                DatabaseKind::OnChain => db.on_chain().column_stats()?,
+                DatabaseKind::OffChain => db.off_chain().column_stats()?,
+                DatabaseKind::Relayer => db.relayer().column_stats()?,
+            };
+            for column in stats {
+                println!(
+                    "{:<32} keys: {:<12} size: {} bytes",
+                    column.name, column.keys, column.size
+                );
+            }
+        }
+        SubCommands::Get {
+            database,
+            table,
+            key,
+        } => {
+            let key = hex::decode(key.trim_start_matches("0x"))?;
+            let db = CombinedDatabase::open(
+                &command.database_path,
+                DEFAULT_DATABASE_CACHE_SIZE,
+            )?;
+            let entry = match database {
+                DatabaseKind::OnChain => db.on_chain().decode_entry(&table, &key)?,
+                DatabaseKind::OffChain => db.off_chain().decode_entry(&table, &key)?,
+                #[cfg(feature = "relayer")]
+                DatabaseKind::Relayer => db.relayer().decode_entry(&table, &key)?,
+                #[cfg(not(feature = "relayer"))]
+                DatabaseKind::Relayer => {
+                    return Err(anyhow::anyhow!(
+                        "The relayer feature must be enabled to decode its tables"
+                    ))
+                }
+            };
+            match entry {
+                Some(entry) => {
+                    println!("key: {}", entry.key);
+                    println!("value: {}", entry.value);
+                }
+                None => println!("The entry doesn't exist in the {table} table"),
+            }
+        }
+        SubCommands::Check => {
+            let db = CombinedDatabase::open(
+                &command.database_path,
+                DEFAULT_DATABASE_CACHE_SIZE,
+            )?;
+            let inconsistencies = db.check_consistency()?;
+            for inconsistency in inconsistencies.iter() {
+                println!("{inconsistency}");
+            }
+            if !inconsistencies.is_empty() {
+                return Err(anyhow::anyhow!(
+                    "Found {} inconsistencies",
+                    inconsistencies.len()
+                ))
+            }
+            println!("The databases are consistent");
+        }
+        SubCommands::RebuildOffChain => {
+            let db = CombinedDatabase::open(
+                &command.database_path,
+                DEFAULT_DATABASE_CACHE_SIZE,
+            )?;
+            db.rebuild_off_chain_indexes()?;
+            db.flush()?;
+            println!("Rebuilt the indexes of the off-chain database");
+        }
+        SubCommands::Migrate { dry_run } => {
+            let db = CombinedDatabase::open(
+                &command.database_path,
+                DEFAULT_DATABASE_CACHE_SIZE,
+            )?;
+            let reports = db.migrate(dry_run)?;
+            for report in reports.iter() {
+                println!(
+                    "{}: {} -> {}, processed {} items, changed {} keys: {}",
+                    report.database,
+                    report.from_version,
+                    report.to_version,
+                    report.items,
+                    report.changes,
+                    report.description
+                );
+            }
+            if reports.is_empty() {
+                println!("The databases are up to date");
+            } else if dry_run {
+                println!("The dry run didn't change the databases");
+            } else {
+                db.flush()?;
+                println!("Migrated the databases");
+            }
+        }
+        SubCommands::Reindex {
+            chain_config,
+            from_height,
+            utxo_validation,
+            database_type,
+        } => {
+            use fuel_core::{
+                chain_config::ChainConfig,
+                service::{
+                    reindex::rebuild_off_chain,
+                    Config,
+                },
+            };
+
+            let config = Config {
+                chain_conf: chain_config.parse::<ChainConfig>()?,
+                utxo_validation,
+                database_type: database_type.clone(),
+                ..Config::local_node()
+            };
+            let db = match database_type {
+                DbType::RocksDb => CombinedDatabase::open(
+                    &command.database_path,
+                    DEFAULT_DATABASE_CACHE_SIZE,
+                )?,
+                #[cfg(feature = "redb")]
+                DbType::Redb => CombinedDatabase::open_redb(
+                    &command.database_path,
+                    DEFAULT_DATABASE_CACHE_SIZE,
+                )?,
+                database_type => {
+                    return Err(anyhow::anyhow!(
+                        "The {database_type} database can't be re-indexed"
+                    ))
+                }
+            };
+            let height = rebuild_off_chain(&db, &config, from_height.map(Into::into))?;
+            db.flush()?;
+            println!(
+                "Re-indexed the off-chain database up to the block {}",
+                *height
+            );
+        }
+    }
+    Ok(())
+}
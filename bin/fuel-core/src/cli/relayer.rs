@@ -0,0 +1,180 @@
+use crate::cli::{
+    run::relayer::parse_h160,
+    DEFAULT_DB_PATH,
+};
+use clap::{
+    Parser,
+    Subcommand,
+};
+use fuel_core::relayer::{
+    Config,
+    H160,
+};
+use std::path::PathBuf;
+
+/// Checks and repairs the relayer database.
+/// The node should be stopped while the command is running.
+#[derive(Debug, Clone, Parser)]
+pub struct Command {
+    /// The path to the database.
+    #[clap(
+        name = "DB_PATH",
+        long = "db-path",
+        value_parser,
+        default_value = (*DEFAULT_DB_PATH).to_str().unwrap()
+    )]
+    database_path: PathBuf,
+
+    /// The sub-command of the relayer operation.
+    #[command(subcommand)]
+    subcommand: SubCommands,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum SubCommands {
+    /// Re-downloads the logs for the range of DA heights and compares them
+    /// with the events stored in the database.
+    /// Prints missing and extra events for each DA height with a mismatch.
+    #[command(arg_required_else_help = true)]
+    Verify {
+        /// The first DA height of the range.
+        #[clap(long = "from")]
+        from: u64,
+
+        /// The last DA height of the range. It can't be above the finalized DA height
+        /// in the database. By default, it is the finalized DA height.
+        #[clap(long = "to")]
+        to: Option<u64>,
+
+        /// Replaces the stored events with the events from the DA layer
+        /// at each DA height with a mismatch.
+        #[clap(long = "repair", action)]
+        repair: bool,
+
+        #[clap(flatten)]
+        endpoint: EndpointArgs,
+    },
+    /// Lowers the finalized DA height and removes all events above it,
+    /// so the relayer downloads them again on the next start of the node.
+    #[command(arg_required_else_help = true)]
+    Backfill {
+        /// The first DA height that should be downloaded again.
+        /// It should be above the DA height of the contract deployment.
+        #[clap(long = "from")]
+        from: u64,
+
+        /// Number of da block that the contract is deployed at.
+        #[clap(long = "relayer-da-deploy-height", default_value_t = Config::DEFAULT_DA_DEPLOY_HEIGHT, env)]
+        da_deploy_height: u64,
+    },
+}
+
+#[derive(Debug, Clone, clap::Args)]
+pub struct EndpointArgs {
+    /// Uri addresses to ethereum clients. It can be in format of `http://localhost:8545/`.
+    #[arg(long = "relayer", env, value_delimiter = ',', required = true)]
+    relayer: Vec<url::Url>,
+
+    /// The number of ethereum clients that must return the same response to accept it.
+    #[clap(long = "relayer-quorum", default_value_t = Config::DEFAULT_QUORUM, env)]
+    quorum: usize,
+
+    /// Ethereum contract address. By default, the address from the default config is used.
+    #[arg(long = "relayer-v2-listening-contracts", value_parser = parse_h160, env)]
+    eth_v2_listening_contracts: Vec<H160>,
+
+    /// Number of blocks containing logs that should be downloaded in a single call to the da layer.
+    #[clap(long = "relayer-log-page-size", default_value_t = Config::DEFAULT_LOG_PAGE_SIZE, env)]
+    log_page_size: u64,
+}
+
+impl EndpointArgs {
+    fn into_config(self) -> Config {
+        let default = Config::default();
+        let eth_v2_listening_contracts = if self.eth_v2_listening_contracts.is_empty() {
+            default.eth_v2_listening_contracts.clone()
+        } else {
+            self.eth_v2_listening_contracts
+        };
+        Config {
+            relayer: Some(self.relayer),
+            quorum: self.quorum,
+            eth_v2_listening_contracts,
+            log_page_size: self.log_page_size,
+            ..default
+        }
+    }
+}
+
+#[cfg(not(any(feature = "rocksdb", feature = "rocksdb-production")))]
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    Err(anyhow::anyhow!(
+        "Rocksdb must be enabled to use the database at {}",
+        command.database_path.display()
+    ))
+}
+
+#[cfg(any(feature = "rocksdb", feature = "rocksdb-production"))]
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    use fuel_core::{
+        database::{
+            database_description::relayer::Relayer,
+            Database,
+        },
+        relayer::{
+            ports::RelayerDb,
+            repair_events,
+            storage::reset_finalized_da_height,
+            verify_events,
+        },
+    };
+
+    let mut db = Database::<Relayer>::open(&command.database_path, None)?;
+    let finalized = db.get_finalized_da_height()?.as_u64();
+
+    match command.subcommand {
+        SubCommands::Verify {
+            from,
+            to,
+            repair,
+            endpoint,
+        } => {
+            let to = to.unwrap_or(finalized);
+            let config = endpoint.into_config();
+            let mismatches = verify_events(&config, &db, from..=to).await?;
+
+            for mismatch in mismatches.iter() {
+                println!("DA height {}:", mismatch.da_height.as_u64());
+                for event in mismatch.missing() {
+                    println!("  missing: {event:?}");
+                }
+                for event in mismatch.extra() {
+                    println!("  extra: {event:?}");
+                }
+            }
+            println!(
+                "Found {} DA heights with mismatched events in the range {from}..={to}",
+                mismatches.len()
+            );
+
+            if repair && !mismatches.is_empty() {
+                repair_events(&mut db, &mismatches)?;
+                println!("Repaired {} DA heights", mismatches.len());
+            }
+        }
+        SubCommands::Backfill {
+            from,
+            da_deploy_height,
+        } => {
+            let height = from.checked_sub(1).ok_or_else(|| {
+                anyhow::anyhow!("The DA height to backfill from should be above zero")
+            })?;
+            reset_finalized_da_height(&mut db, &height.into(), &da_deploy_height.into())?;
+            println!(
+                "The finalized DA height is reset from {finalized} to {height}, \
+                the relayer will download events starting from {from}"
+            );
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,86 @@
+use crate::cli::{
+    run::DEFAULT_DATABASE_CACHE_SIZE,
+    DEFAULT_DB_PATH,
+};
+use clap::Parser;
+use std::path::PathBuf;
+
+/// Rolls back the on-chain and off-chain databases to the block height.
+/// The node should be stopped while the command is running. The interrupted
+/// rollback is finished before the new one.
+#[derive(Debug, Clone, Parser)]
+pub struct Command {
+    /// The path to the database.
+    #[clap(
+        name = "DB_PATH",
+        long = "db-path",
+        value_parser,
+        default_value = (*DEFAULT_DB_PATH).to_str().unwrap()
+    )]
+    database_path: PathBuf,
+
+    /// The height of the block that becomes the latest block after the rollback.
+    #[clap(long = "to-height")]
+    to_height: u32,
+
+    /// The slashing protection file of the block producer. The last signed block
+    /// in it is lowered to the block at the target height, so the node can produce
+    /// the blocks above it again.
+    #[clap(long = "slashing-protection-file")]
+    slashing_protection_file: Option<PathBuf>,
+}
+
+#[cfg(not(any(feature = "rocksdb", feature = "rocksdb-production")))]
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    Err(anyhow::anyhow!(
+        "Rocksdb must be enabled to use the database at {}",
+        command.database_path.display()
+    ))
+}
+
+#[cfg(any(feature = "rocksdb", feature = "rocksdb-production"))]
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    use fuel_core::{
+        combined_database::CombinedDatabase,
+        poa::signer::{
+            SignedBlock,
+            SlashingProtection,
+        },
+    };
+
+    let db = CombinedDatabase::open(&command.database_path, DEFAULT_DATABASE_CACHE_SIZE)?;
+    db.resume_rollback()?;
+    let latest_height = db.on_chain().latest_height()?;
+    let height = command.to_height.into();
+    if height > latest_height {
+        return Err(anyhow::anyhow!(
+            "The target height {} is above the latest block height {}",
+            command.to_height,
+            *latest_height
+        ))
+    }
+
+    db.rollback_to(&height)?;
+    db.flush()?;
+    println!(
+        "Rolled back the database from {} to {}",
+        *latest_height, command.to_height
+    );
+
+    if let Some(state_file) = command.slashing_protection_file {
+        let block = db
+            .on_chain()
+            .get_sealed_block_by_height(&height)?
+            .ok_or_else(|| anyhow::anyhow!("The block at height {height} is missing"))?;
+        let protection = SlashingProtection::with_state_file(state_file)?;
+        protection.rollback_to(SignedBlock {
+            height,
+            block_id: block.entity.id(),
+        })?;
+        println!(
+            "Lowered the last signed block in the slashing protection file to {}",
+            command.to_height
+        );
+    }
+    Ok(())
+}
@@ -1,7 +1,10 @@
 #![allow(unused_variables)]
 use crate::{
     cli::{
-        run::consensus::PoATriggerArgs,
+        run::consensus::{
+            PoATriggerArgs,
+            RemoteSignerArgs,
+        },
         DEFAULT_DB_PATH,
     },
     FuelService,
@@ -33,6 +36,12 @@ use fuel_core::{
         secrecy::Secret,
     },
 };
+#[cfg(any(feature = "rocksdb", feature = "rocksdb-production"))]
+use fuel_core::{
+    service::backup::BackupConfig,
+    state::rocks_db::RocksDbConfig,
+};
+use fuel_core_keygen::KeyType;
 use pyroscope::{
     pyroscope::PyroscopeAgentRunning,
     PyroscopeAgent,
@@ -55,7 +64,7 @@ use tracing::{
 
 pub const CONSENSUS_KEY_ENV: &str = "CONSENSUS_KEY_SECRET";
 // Default database cache is 1 GB
-const DEFAULT_DATABASE_CACHE_SIZE: usize = 1024 * 1024 * 1024;
+pub const DEFAULT_DATABASE_CACHE_SIZE: usize = 1024 * 1024 * 1024;
 
 #[cfg(feature = "p2p")]
 mod p2p;
@@ -63,7 +72,7 @@ mod p2p;
 mod consensus;
 mod profiling;
 #[cfg(feature = "relayer")]
-mod relayer;
+pub(crate) mod relayer;
 
 /// Run the Fuel client node locally.
 #[derive(Debug, Clone, Parser)]
@@ -104,6 +113,18 @@ pub struct Command {
     )]
     pub database_type: DbType,
 
+    /// The path to the JSON file with the tuning of RocksDB column families,
+    /// like compression, bloom filters, block caches and write buffers.
+    #[cfg(any(feature = "rocksdb", feature = "rocksdb-production"))]
+    #[clap(long = "db-config", value_parser, env)]
+    pub database_config: Option<PathBuf>,
+
+    /// The number of the latest blocks that keep their changes in the database,
+    /// so the node can be rolled back to any of them. Keeping more blocks
+    /// requires more disk space.
+    #[clap(long = "history-retention", default_value = "10000", env)]
+    pub history_retention: std::num::NonZeroU32,
+
     /// Specify either an alias to a built-in configuration or filepath to a JSON file.
     #[arg(
         name = "CHAIN_CONFIG",
@@ -138,10 +159,42 @@ pub struct Command {
     #[arg(long = "consensus-key", env)]
     pub consensus_key: Option<String>,
 
+    /// The path to the encrypted keystore with the signing key used when producing blocks.
+    /// The keystore is created by `fuel-core-keygen new --keystore`.
+    #[arg(
+        long = "consensus-key-keystore",
+        env,
+        conflicts_with = "consensus_key",
+        requires = "consensus_key_password_file"
+    )]
+    pub consensus_key_keystore: Option<PathBuf>,
+
+    /// The path to the file with the password of the `--consensus-key-keystore`.
+    #[arg(
+        long = "consensus-key-password-file",
+        env,
+        requires = "consensus_key_keystore"
+    )]
+    pub consensus_key_password_file: Option<PathBuf>,
+
+    /// The file persisting the last block signed by the consensus key. The node always
+    /// refuses to sign a block below the height of the signed block, but without
+    /// the file, it forgets the signed blocks on restart. The `rollback` command
+    /// lowers the signed block in the file with `--slashing-protection-file`.
+    #[arg(
+        long = "slashing-protection-file",
+        env,
+        conflicts_with = "remote_signer_url"
+    )]
+    pub slashing_protection_file: Option<PathBuf>,
+
     /// A new block is produced instantly when transactions are available.
     #[clap(flatten)]
     pub poa_trigger: PoATriggerArgs,
 
+    #[clap(flatten)]
+    pub remote_signer: RemoteSignerArgs,
+
     /// The block's fee recipient public key.
     ///
     /// If not set, `consensus_key` is used as the provider of the `Address`.
@@ -160,6 +213,12 @@ pub struct Command {
     #[cfg(feature = "p2p")]
     pub sync_args: p2p::SyncArgs,
 
+    /// The state is pinned to serve it to syncing peers only at heights divisible
+    /// by the interval, so checkpoints should be created at such heights.
+    #[cfg(feature = "p2p")]
+    #[arg(long = "state-snapshot-interval", default_value = "10000", env)]
+    pub state_snapshot_interval: std::num::NonZeroU32,
+
     #[arg(long = "metrics", env)]
     pub metrics: bool,
 
@@ -185,6 +244,11 @@ pub struct Command {
     #[clap(long = "tx-number-active-subscriptions", default_value = "4064", env)]
     pub tx_number_active_subscriptions: usize,
 
+    /// The minimum percentage by which the gas price of a transaction must exceed
+    /// the gas price of the transactions it replaces in the `TxPool`.
+    #[clap(long = "tx-min-gas-price-bump-percentage", default_value = "10", env)]
+    pub tx_min_gas_price_bump_percentage: u64,
+
     /// The number of reserved peers to connect to before starting to sync.
     #[clap(long = "min-connected-reserved-peers", default_value = "0", env)]
     pub min_connected_reserved_peers: usize,
@@ -201,6 +265,21 @@ pub struct Command {
     #[clap(long = "api-request-timeout", default_value = "30m", env)]
     pub api_request_timeout: humantime::Duration,
 
+    /// The directory for periodic database backups. Backups are disabled if not set.
+    #[cfg(any(feature = "rocksdb", feature = "rocksdb-production"))]
+    #[clap(long = "db-backup-path", value_parser, env)]
+    pub database_backup_path: Option<PathBuf>,
+
+    /// The backup is created after each block with a height divisible by the interval.
+    #[cfg(any(feature = "rocksdb", feature = "rocksdb-production"))]
+    #[clap(long = "db-backup-interval", default_value = "10000", env)]
+    pub database_backup_interval: std::num::NonZeroU32,
+
+    /// The number of the latest backups to keep. It should be at least one.
+    #[cfg(any(feature = "rocksdb", feature = "rocksdb-production"))]
+    #[clap(long = "db-backup-keep", default_value = "3", value_parser = parse_backup_keep, env)]
+    pub database_backup_keep: usize,
+
     #[clap(flatten)]
     pub profiling: profiling::ProfilingArgs,
 }
@@ -214,13 +293,20 @@ impl Command {
             max_database_cache_size,
             database_path,
             database_type,
+            #[cfg(any(feature = "rocksdb", feature = "rocksdb-production"))]
+            database_config,
+            history_retention,
             chain_config,
             vm_backtrace,
             debug,
             utxo_validation,
             min_gas_price,
             consensus_key,
+            consensus_key_keystore,
+            consensus_key_password_file,
+            slashing_protection_file,
             poa_trigger,
+            remote_signer,
             coinbase_recipient,
             #[cfg(feature = "relayer")]
             relayer_args,
@@ -228,6 +314,8 @@ impl Command {
             p2p_args,
             #[cfg(feature = "p2p")]
             sync_args,
+            #[cfg(feature = "p2p")]
+            state_snapshot_interval,
             metrics,
             max_da_lag,
             max_wait_time,
@@ -235,10 +323,17 @@ impl Command {
             tx_max_number,
             tx_max_depth,
             tx_number_active_subscriptions,
+            tx_min_gas_price_bump_percentage,
             min_connected_reserved_peers,
             time_until_synced,
             query_log_threshold_time,
             api_request_timeout,
+            #[cfg(any(feature = "rocksdb", feature = "rocksdb-production"))]
+            database_backup_path,
+            #[cfg(any(feature = "rocksdb", feature = "rocksdb-production"))]
+            database_backup_interval,
+            #[cfg(any(feature = "rocksdb", feature = "rocksdb-production"))]
+            database_backup_keep,
             profiling: _,
         } = self;
 
@@ -247,7 +342,7 @@ impl Command {
         let chain_conf: ChainConfig = chain_config.as_str().parse()?;
 
         #[cfg(feature = "relayer")]
-        let relayer_cfg = relayer_args.into_config();
+        let relayer_cfg = relayer_args.into_config(metrics);
 
         #[cfg(feature = "p2p")]
         let p2p_cfg = p2p_args.into_config(chain_conf.chain_name.clone(), metrics)?;
@@ -260,7 +355,18 @@ impl Command {
             info!("Block production disabled");
         }
 
-        let consensus_key = load_consensus_key(consensus_key)?;
+        let consensus_key = match (consensus_key_keystore, consensus_key_password_file) {
+            (Some(keystore), Some(password_file)) => {
+                let key = fuel_core_keygen::keystore::load_secret(
+                    &keystore,
+                    &password_file,
+                    KeyType::BlockProduction,
+                )
+                .context("failed to load consensus signing key from the keystore")?;
+                Some(Secret::new(key.into()))
+            }
+            _ => load_consensus_key(consensus_key)?,
+        };
         if consensus_key.is_some() && trigger == Trigger::Never {
             warn!("Consensus key configured but block production is disabled!");
         }
@@ -303,6 +409,12 @@ impl Command {
             max_database_cache_size,
             database_path,
             database_type,
+            #[cfg(any(feature = "rocksdb", feature = "rocksdb-production"))]
+            rocksdb: database_config
+                .map(|path| RocksDbConfig::load(&path))
+                .transpose()?
+                .unwrap_or_default(),
+            history_retention,
             chain_conf: chain_conf.clone(),
             debug,
             utxo_validation,
@@ -319,6 +431,7 @@ impl Command {
                 metrics,
                 tx_pool_ttl.into(),
                 tx_number_active_subscriptions,
+                tx_min_gas_price_bump_percentage,
             ),
             block_producer: ProducerConfig {
                 utxo_validation,
@@ -332,12 +445,22 @@ impl Command {
             p2p: p2p_cfg,
             #[cfg(feature = "p2p")]
             sync: sync_args.into(),
+            #[cfg(feature = "p2p")]
+            state_snapshot_interval,
             consensus_key,
+            remote_signer: remote_signer.into_config(),
+            slashing_protection_file,
             name,
             relayer_consensus_config: verifier,
             min_connected_reserved_peers,
             time_until_synced: time_until_synced.into(),
             query_log_threshold_time: query_log_threshold_time.into(),
+            #[cfg(any(feature = "rocksdb", feature = "rocksdb-production"))]
+            database_backup: database_backup_path.map(|path| BackupConfig {
+                path,
+                interval: database_backup_interval,
+                keep: database_backup_keep,
+            }),
         };
         Ok(config)
     }
@@ -368,6 +491,15 @@ pub async fn exec(command: Command) -> anyhow::Result<()> {
     Ok(())
 }
 
+#[cfg(any(feature = "rocksdb", feature = "rocksdb-production"))]
+fn parse_backup_keep(s: &str) -> anyhow::Result<usize> {
+    let keep = usize::from_str(s)?;
+    if keep == 0 {
+        return Err(anyhow::anyhow!("At least one backup should be kept"))
+    }
+    Ok(keep)
+}
+
 // Attempt to load the consensus key from cli arg first, otherwise check the env.
 fn load_consensus_key(
     cli_arg: Option<String>,
@@ -413,7 +545,7 @@ fn start_pyroscope_agent(
         .transpose()
 }
 
-async fn shutdown_signal() -> anyhow::Result<()> {
+pub(crate) async fn shutdown_signal() -> anyhow::Result<()> {
     #[cfg(unix)]
     {
         let mut sigterm =
@@ -4,8 +4,16 @@ use clap::{
     ArgGroup,
     ValueEnum,
 };
-use fuel_core::service::config::Trigger as PoATrigger;
+use fuel_core::{
+    remote_signer::{
+        RemoteSignerConfig,
+        TlsConfig,
+        Url,
+    },
+    service::config::Trigger as PoATrigger,
+};
 use humantime::Duration;
+use std::path::PathBuf;
 
 #[derive(Debug, Clone, clap::Args)]
 pub struct PoATriggerArgs {
@@ -63,6 +71,69 @@ struct Interval {
     pub period: Option<Duration>,
 }
 
+#[derive(Debug, Clone, clap::Args)]
+pub struct RemoteSignerArgs {
+    /// The URL of the signing daemon holding the consensus key, started with
+    /// `fuel-core signer`. If set, the produced blocks are signed by the daemon
+    /// instead of the local consensus key. Use `unix:///path/to/socket` to connect
+    /// to the daemon listening on the Unix socket.
+    #[arg(
+        long = "remote-signer-url",
+        env,
+        conflicts_with_all = ["consensus_key", "consensus_key_keystore"]
+    )]
+    pub remote_signer_url: Option<Url>,
+
+    /// The PEM-encoded certificate used by the node to authenticate to the signing daemon.
+    /// Enables mutual TLS together with `--remote-signer-tls-key` and `--remote-signer-ca-cert`.
+    #[arg(
+        long = "remote-signer-tls-cert",
+        env,
+        requires_all = ["remote_signer_url", "remote_signer_tls_key", "remote_signer_ca_cert"]
+    )]
+    pub remote_signer_tls_cert: Option<PathBuf>,
+
+    /// The PEM-encoded private key of the `--remote-signer-tls-cert`.
+    #[arg(
+        long = "remote-signer-tls-key",
+        env,
+        requires = "remote_signer_tls_cert"
+    )]
+    pub remote_signer_tls_key: Option<PathBuf>,
+
+    /// The PEM-encoded certificate authority used to verify the signing daemon.
+    #[arg(
+        long = "remote-signer-ca-cert",
+        env,
+        requires = "remote_signer_tls_cert"
+    )]
+    pub remote_signer_ca_cert: Option<PathBuf>,
+
+    /// The timeout of the signing request.
+    #[arg(long = "remote-signer-timeout", default_value = "5s", env)]
+    pub remote_signer_timeout: Duration,
+}
+
+impl RemoteSignerArgs {
+    pub fn into_config(self) -> Option<RemoteSignerConfig> {
+        let tls = match (
+            self.remote_signer_tls_cert,
+            self.remote_signer_tls_key,
+            self.remote_signer_ca_cert,
+        ) {
+            (Some(cert), Some(key), Some(ca_cert)) => {
+                Some(TlsConfig { cert, key, ca_cert })
+            }
+            _ => None,
+        };
+        self.remote_signer_url.map(|url| RemoteSignerConfig {
+            url,
+            tls,
+            timeout: self.remote_signer_timeout.into(),
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
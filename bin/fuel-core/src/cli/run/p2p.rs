@@ -1,9 +1,12 @@
 use anyhow::anyhow;
 use clap::{
     builder::ArgPredicate::IsPresent,
+    ArgGroup,
     Args,
+    ValueEnum,
 };
 use fuel_core::{
+    chain_config::StateCheckpoint,
     p2p::{
         config::{
             convert_to_libp2p_keypair,
@@ -18,8 +21,14 @@ use fuel_core::{
     types::{
         fuel_crypto,
         fuel_crypto::SecretKey,
+        services::p2p::peer_reputation::{
+            AppScore,
+            ReputationConfig,
+            ReputationEvent,
+        },
     },
 };
+use fuel_core_keygen::KeyType;
 use std::{
     net::{
         IpAddr,
@@ -34,18 +43,32 @@ use std::{
 const MAX_RESPONSE_SIZE_STR: &str = const_format::formatcp!("{MAX_RESPONSE_SIZE}");
 
 #[derive(Debug, Clone, Args)]
+#[clap(
+    group = ArgGroup::new("p2p-keypair").args(&["keypair", "keypair_keystore"]).multiple(false),
+)]
 pub struct P2PArgs {
     /// Enable P2P. By default, P2P is disabled, even when the binary is compiled with the "p2p"
     /// feature flag. Providing `--enable-p2p` will enable the P2P service.
-    #[clap(long = "enable-p2p", action)]
+    #[clap(long = "enable-p2p", action, requires = "p2p-keypair")]
     pub enable_p2p: bool,
 
     /// Peering secret key. Supports either a hex encoded secret key inline or a path to bip32 mnemonic encoded secret file.
+    /// Either `--keypair` or `--keypair-keystore` is required when P2P is enabled.
     #[clap(long = "keypair", env, value_parser = KeypairArg::try_from_string)]
-    #[arg(required_if_eq("enable_p2p", "true"))]
     #[arg(requires_if(IsPresent, "enable_p2p"))]
     pub keypair: Option<KeypairArg>,
 
+    /// The path to the encrypted keystore with the peering secret key.
+    /// The keystore is created by `fuel-core-keygen new --key-type peering --keystore`.
+    #[clap(long = "keypair-keystore", env)]
+    #[arg(requires_if(IsPresent, "enable_p2p"))]
+    #[arg(requires = "keypair_password_file")]
+    pub keypair_keystore: Option<PathBuf>,
+
+    /// The path to the file with the password of the `--keypair-keystore`.
+    #[clap(long = "keypair-password-file", env, requires = "keypair_keystore")]
+    pub keypair_password_file: Option<PathBuf>,
+
     /// p2p network's IP Address
     #[clap(long = "address", env)]
     pub address: Option<IpAddr>,
@@ -120,6 +143,43 @@ pub struct P2PArgs {
     #[clap(long = "identify-interval", default_value = "5", env)]
     pub identify_interval: u64,
 
+    /// How long a peer with a bad reputation stays banned, in seconds.
+    /// Bans are persisted and survive restarts of the node.
+    #[clap(long = "peer-ban-duration", default_value = "86400", env)]
+    pub peer_ban_duration: u64,
+
+    /// The peer is banned when its reputation score drops below this value
+    #[clap(
+        long = "min-peer-score",
+        default_value = "-50",
+        allow_hyphen_values = true,
+        env
+    )]
+    pub min_peer_score: AppScore,
+
+    /// The maximum reputation score a peer can reach
+    #[clap(long = "max-peer-score", default_value = "150", env)]
+    pub max_peer_score: AppScore,
+
+    /// The factor by which the reputation score of peers decays on every decay tick
+    #[clap(long = "peer-score-decay", default_value = "0.9", env)]
+    pub peer_score_decay: AppScore,
+
+    /// Overrides the reputation score delta of the event, e.g.
+    /// `--peer-score-deltas request_timeout=-10,duplicate_gossiped_transaction=0`.
+    /// Supported events: `successful_block_import`, `missing_block_headers`,
+    /// `bad_block_header`, `bad_seal`, `missing_transactions`, `invalid_transactions`,
+    /// `invalid_state_chunk`, `invalid_gossiped_transaction`, `duplicate_gossiped_transaction`,
+    /// `oversized_response`, `request_timeout`, `wrong_chain`.
+    #[clap(
+        long = "peer-score-deltas",
+        value_delimiter = ',',
+        value_parser = parse_peer_score_delta,
+        allow_hyphen_values = true,
+        env
+    )]
+    pub peer_score_deltas: Vec<(ReputationEvent, AppScore)>,
+
     /// Choose max mesh size for gossipsub protocol
     #[clap(long = "max-mesh-size", default_value = "12", env)]
     pub max_mesh_size: usize,
@@ -191,6 +251,28 @@ pub struct SyncArgs {
     /// The maximum number of headers to request in a single batch.
     #[clap(long = "sync-header-batch-size", default_value = "10", env)]
     pub header_batch_size: u32,
+    /// The synchronization mode. The `full` mode downloads and executes blocks.
+    /// The `light` mode only downloads and verifies sealed block headers,
+    /// without execution of transactions. The node in the `light` mode can't
+    /// produce blocks, and the database synced in it can't be used in the `full` mode.
+    #[clap(long = "sync-mode", default_value = "full", value_enum, env)]
+    pub mode: SyncModeArg,
+    /// The path to the trusted state checkpoint created by the
+    /// `fuel-core snapshot checkpoint` command. If it is set, a fresh node downloads
+    /// the state at the checkpoint from peers instead of executing all blocks from
+    /// the genesis.
+    #[clap(long = "sync-checkpoint", env)]
+    pub checkpoint: Option<StateCheckpoint>,
+    /// The maximum size of the state downloaded at the checkpoint, in bytes.
+    /// The state is kept in memory until it is verified and committed.
+    #[clap(long = "sync-max-state-size", default_value = "536870912", env)]
+    pub max_state_size: u64,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum SyncModeArg {
+    Full,
+    Light,
 }
 
 #[derive(Clone, Debug)]
@@ -218,11 +300,27 @@ impl KeypairArg {
     }
 }
 
+fn parse_peer_score_delta(s: &str) -> anyhow::Result<(ReputationEvent, AppScore)> {
+    let (event, score) = s
+        .split_once('=')
+        .ok_or_else(|| anyhow!("Expected `<event>=<score>`, got `{s}`"))?;
+    let event = ReputationEvent::from_str(event).map_err(|e| anyhow!(e))?;
+    let score = AppScore::from_str(score)?;
+    Ok((event, score))
+}
+
 impl From<SyncArgs> for fuel_core::sync::Config {
     fn from(value: SyncArgs) -> Self {
         Self {
             block_stream_buffer_size: value.block_stream_buffer_size,
             header_batch_size: value.header_batch_size as usize,
+            mode: match value.mode {
+                SyncModeArg::Full => fuel_core::sync::SyncMode::Full,
+                SyncModeArg::Light => fuel_core::sync::SyncMode::Light,
+            },
+            checkpoint: value.checkpoint,
+            max_state_size: value.max_state_size,
+            ..Default::default()
         }
     }
 }
@@ -238,8 +336,28 @@ impl P2PArgs {
             return Ok(None)
         }
 
+        let keypair = match (self.keypair, self.keypair_keystore) {
+            (Some(keypair), _) => keypair,
+            (None, Some(keystore)) => {
+                let password_file = self
+                    .keypair_password_file
+                    .ok_or_else(|| anyhow!("`--keypair-password-file` is required"))?;
+                let secret_key = fuel_core_keygen::keystore::load_secret(
+                    &keystore,
+                    &password_file,
+                    KeyType::Peering,
+                )?;
+                KeypairArg::InlineSecret(secret_key)
+            }
+            (None, None) => {
+                return Err(anyhow!(
+                    "`--keypair` or `--keypair-keystore` is required when P2P is enabled"
+                ))
+            }
+        };
+
         let local_keypair = {
-            match self.keypair.expect("mandatory value") {
+            match keypair {
                 KeypairArg::Path(path) => {
                     let phrase = std::fs::read_to_string(path)?;
                     let secret_key =
@@ -283,6 +401,16 @@ impl P2PArgs {
             )
         };
 
+        let mut reputation_config = ReputationConfig {
+            min_app_score: self.min_peer_score,
+            max_app_score: self.max_peer_score,
+            decay_app_score: self.peer_score_decay,
+            ..Default::default()
+        };
+        for (event, score) in self.peer_score_deltas {
+            reputation_config.set_score(event, score);
+        }
+
         let config = Config {
             keypair: local_keypair,
             network_name,
@@ -318,9 +446,38 @@ impl P2PArgs {
             ),
             info_interval: Some(Duration::from_secs(self.info_interval)),
             identify_interval: Some(Duration::from_secs(self.identify_interval)),
+            peer_ban_duration: Duration::from_secs(self.peer_ban_duration),
+            reputation_config,
             metrics,
             state: NotInitialized,
         };
         Ok(Some(config))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+    use test_case::test_case;
+
+    #[derive(Debug, Clone, Parser)]
+    pub struct Command {
+        #[clap(flatten)]
+        p2p: P2PArgs,
+    }
+
+    const KEYPAIR: &str =
+        "--keypair=0x0101010101010101010101010101010101010101010101010101010101010101";
+
+    #[test_case(&[""] => Ok(()); "p2p is disabled by default")]
+    #[test_case(&["", "--enable-p2p"] => Err(()); "p2p requires the keypair")]
+    #[test_case(&["", "--enable-p2p", KEYPAIR] => Ok(()); "p2p accepts the inline keypair")]
+    #[test_case(&["", "--enable-p2p", "--keypair-keystore=key.json", "--keypair-password-file=password"] => Ok(()); "p2p accepts the keypair keystore")]
+    #[test_case(&["", "--enable-p2p", KEYPAIR, "--keypair-keystore=key.json", "--keypair-password-file=password"] => Err(()); "keypair and keystore are exclusive")]
+    #[test_case(&["", "--enable-p2p", "--keypair-keystore=key.json"] => Err(()); "keystore requires the password file")]
+    #[test_case(&["", KEYPAIR] => Err(()); "keypair requires p2p")]
+    fn parse(args: &[&str]) -> Result<(), ()> {
+        Command::try_parse_from(args).map(|_| ()).map_err(|_| ())
+    }
+}
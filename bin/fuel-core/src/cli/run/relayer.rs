@@ -20,12 +20,26 @@ pub struct RelayerArgs {
     #[clap(long = "enable-relayer", action)]
     pub enable_relayer: bool,
 
-    /// Uri address to ethereum client. It can be in format of `http://localhost:8545/` or `ws://localhost:8545/`.
+    /// Uri addresses to ethereum clients. It can be in format of `http://localhost:8545/`.
+    /// Several comma-separated endpoints can be provided, the relayer fails over
+    /// to the next endpoint when the previous ones are unhealthy.
     /// If not set relayer will not start.
-    #[arg(long = "relayer", env)]
+    #[arg(long = "relayer", env, value_delimiter = ',')]
     #[arg(required_if_eq("enable_relayer", "true"))]
     #[arg(requires_if(IsPresent, "enable_relayer"))]
-    pub relayer: Option<url::Url>,
+    pub relayer: Option<Vec<url::Url>>,
+
+    /// The number of ethereum clients that must return the same response to accept it.
+    #[clap(long = "relayer-quorum", default_value_t = Config::DEFAULT_QUORUM, env)]
+    pub quorum: usize,
+
+    /// The number of consecutive failures after which the ethereum client is considered unhealthy.
+    #[clap(long = "relayer-endpoint-max-failures", default_value_t = Config::DEFAULT_ENDPOINT_MAX_FAILURES, env)]
+    pub endpoint_max_failures: u32,
+
+    /// The number of seconds during which the unhealthy ethereum client is not used.
+    #[clap(long = "relayer-endpoint-cooldown-s", default_value_t = Config::DEFAULT_ENDPOINT_COOLDOWN.as_secs(), env)]
+    pub endpoint_cooldown_secs: u64,
 
     /// Ethereum contract address. Create EthAddress into fuel_types
     #[arg(long = "relayer-v2-listening-contracts", value_parser = parse_h160, env)]
@@ -58,7 +72,7 @@ pub fn parse_h160(input: &str) -> Result<H160, <H160 as FromStr>::Err> {
 }
 
 impl RelayerArgs {
-    pub fn into_config(self) -> Option<Config> {
+    pub fn into_config(self, metrics: bool) -> Option<Config> {
         if !self.enable_relayer {
             tracing::info!("Relayer service disabled");
             return None
@@ -67,12 +81,15 @@ impl RelayerArgs {
         let config = Config {
             da_deploy_height: DaBlockHeight(self.da_deploy_height),
             relayer: self.relayer,
+            quorum: self.quorum,
+            endpoint_max_failures: self.endpoint_max_failures,
+            endpoint_cooldown: Duration::from_secs(self.endpoint_cooldown_secs),
             eth_v2_listening_contracts: self.eth_v2_listening_contracts,
             log_page_size: self.log_page_size,
             sync_minimum_duration: Duration::from_secs(self.sync_minimum_duration_secs),
             syncing_call_frequency: Duration::from_secs(self.syncing_call_frequency_secs),
             syncing_log_frequency: Duration::from_secs(self.syncing_log_frequency_secs),
-            metrics: false,
+            metrics,
         };
         Some(config)
     }
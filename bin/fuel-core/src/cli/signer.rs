@@ -0,0 +1,129 @@
+use crate::cli::run::shutdown_signal;
+use anyhow::Context;
+use clap::Parser;
+use fuel_core::{
+    remote_signer::{
+        DaemonAddress,
+        SigningDaemonConfig,
+        TlsConfig,
+    },
+    service::ServiceTrait,
+    types::{
+        fuel_tx::Input,
+        secrecy::Secret,
+    },
+};
+use fuel_core_keygen::KeyType;
+use std::{
+    net,
+    path::PathBuf,
+};
+
+/// Runs the signing daemon holding the consensus key.
+/// The block producer started with `--remote-signer-url` sends the produced blocks
+/// to the daemon for signing. The daemon refuses to sign the blocks below
+/// the height of the last signed block.
+#[derive(Debug, Clone, Parser)]
+pub struct Command {
+    /// The IP address to listen on. The non-loopback address requires TLS.
+    #[clap(long = "ip", default_value = "127.0.0.1", value_parser, env)]
+    pub ip: net::IpAddr,
+
+    #[clap(long = "port", default_value = "4001", env)]
+    pub port: u16,
+
+    /// The path to the Unix socket to listen on instead of `--ip` and `--port`.
+    /// The socket is accessible only by the user running the daemon.
+    /// The node connects to it with `--remote-signer-url unix:///path/to/socket`.
+    #[cfg(unix)]
+    #[arg(long = "unix-socket", env, conflicts_with = "tls_cert")]
+    pub unix_socket: Option<PathBuf>,
+
+    /// The path to the encrypted keystore with the consensus key.
+    /// The keystore is created by `fuel-core-keygen new --keystore`.
+    #[arg(long = "keystore", env)]
+    pub keystore: PathBuf,
+
+    /// The path to the file with the password of the keystore.
+    #[arg(long = "password-file", env)]
+    pub password_file: PathBuf,
+
+    /// The file persisting the last signed block, so the daemon doesn't sign
+    /// a conflicting block after the restart. It is created if it doesn't exist.
+    #[arg(long = "slashing-protection-file", env)]
+    pub slashing_protection_file: PathBuf,
+
+    /// The PEM-encoded certificate of the daemon.
+    /// Enables mutual TLS together with `--tls-key` and `--client-ca-cert`.
+    #[arg(long = "tls-cert", env, requires_all = ["tls_key", "client_ca_cert"])]
+    pub tls_cert: Option<PathBuf>,
+
+    /// The PEM-encoded private key of the `--tls-cert`.
+    #[arg(long = "tls-key", env, requires = "tls_cert")]
+    pub tls_key: Option<PathBuf>,
+
+    /// The PEM-encoded certificate authority of the clients.
+    /// Only the clients with the certificate signed by it can request signatures.
+    #[arg(long = "client-ca-cert", env, requires = "tls_cert")]
+    pub client_ca_cert: Option<PathBuf>,
+}
+
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    let Command {
+        ip,
+        port,
+        #[cfg(unix)]
+        unix_socket,
+        keystore,
+        password_file,
+        slashing_protection_file,
+        tls_cert,
+        tls_key,
+        client_ca_cert,
+    } = command;
+
+    let secret = fuel_core_keygen::keystore::load_secret(
+        &keystore,
+        &password_file,
+        KeyType::BlockProduction,
+    )
+    .context("failed to load consensus signing key from the keystore")?;
+    tracing::info!(
+        "Signing blocks with the consensus key of {}",
+        Input::owner(&secret.public_key())
+    );
+
+    let addr = DaemonAddress::Tcp(net::SocketAddr::new(ip, port));
+    #[cfg(unix)]
+    let addr = unix_socket.map(DaemonAddress::Unix).unwrap_or(addr);
+
+    let tls = match (tls_cert, tls_key, client_ca_cert) {
+        (Some(cert), Some(key), Some(ca_cert)) => Some(TlsConfig { cert, key, ca_cert }),
+        #[cfg(unix)]
+        _ if matches!(addr, DaemonAddress::Unix(_)) => None,
+        _ => {
+            tracing::warn!(
+                "The signing daemon accepts unauthenticated requests without TLS"
+            );
+            None
+        }
+    };
+    let config = SigningDaemonConfig {
+        addr,
+        tls,
+        slashing_protection_file,
+    };
+
+    let service =
+        fuel_core::remote_signer::new_service(Secret::new(secret.into()), config)?;
+    service.start_and_await().await?;
+    tokio::select! {
+        result = service.await_stop() => {
+            result?;
+        }
+        _ = shutdown_signal() => {}
+    }
+    service.stop_and_await().await?;
+
+    Ok(())
+}
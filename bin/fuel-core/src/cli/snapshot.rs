@@ -39,6 +39,15 @@ pub enum SubCommands {
         #[clap(long = "id")]
         contract_id: ContractId,
     },
+    /// Creates a trusted checkpoint of the state at the latest height divisible by
+    /// the state snapshot interval. It can be passed to the `--sync-checkpoint`
+    /// argument of another node to download the state from peers instead of
+    /// executing all blocks.
+    Checkpoint {
+        /// The interval used by the peers serving the state.
+        #[clap(long = "state-snapshot-interval", default_value = "10000")]
+        state_snapshot_interval: std::num::NonZeroU32,
+    },
 }
 
 #[cfg(not(any(feature = "rocksdb", feature = "rocksdb-production")))]
@@ -90,6 +99,48 @@ pub async fn exec(command: Command) -> anyhow::Result<()> {
             serde_json::to_writer_pretty(stdout, &config)
                 .context("failed to dump contract snapshot to JSON")?;
         }
+        SubCommands::Checkpoint {
+            state_snapshot_interval,
+        } => {
+            let checkpoint = create_aligned_checkpoint(db, state_snapshot_interval)?;
+            let stdout = std::io::stdout().lock();
+
+            serde_json::to_writer_pretty(stdout, &checkpoint)
+                .context("failed to dump checkpoint to JSON")?;
+        }
     }
     Ok(())
 }
+
+/// Creates the checkpoint at the latest height divisible by the `interval`.
+/// If the latest height is not divisible, a copy of the database is rolled back
+/// to that height, so the node's database is left untouched.
+#[cfg(any(feature = "rocksdb", feature = "rocksdb-production"))]
+fn create_aligned_checkpoint(
+    db: fuel_core::database::Database,
+    interval: std::num::NonZeroU32,
+) -> anyhow::Result<fuel_core::chain_config::StateCheckpoint> {
+    use anyhow::Context;
+    use fuel_core::{
+        database::Database,
+        service::genesis::create_state_checkpoint,
+    };
+
+    let latest = *db.latest_height()?;
+    let aligned = latest - latest % interval.get();
+    if aligned == latest {
+        return create_state_checkpoint(&db, interval)
+    }
+
+    let copy_dir = tempfile::tempdir()?;
+    db.checkpoint(copy_dir.path())?;
+    drop(db);
+    let copy = Database::open(copy_dir.path(), None)?;
+    copy.rollback_to(&aligned.into()).with_context(|| {
+        format!(
+            "failed to roll back the copy of the database from {latest} to \
+            the height {aligned} divisible by the state snapshot interval"
+        )
+    })?;
+    create_state_checkpoint(&copy, interval)
+}
@@ -3,17 +3,42 @@ use atty::Stream;
 use clap::Parser;
 use crossterm::terminal;
 use fuel_core_keygen::{
+    hd::{
+        derive_key,
+        derive_keys,
+        new_mnemonic,
+        vanity_key,
+        DEFAULT_WORD_COUNT,
+    },
+    keystore::{
+        read_password_file,
+        Keystore,
+    },
     new_key,
+    new_keys,
     parse_secret,
+    secret_response,
     KeyType,
 };
-use std::io::{
-    stdin,
-    stdout,
-    Read,
-    Write,
+use fuel_core_types::fuel_crypto::SecretKey;
+use std::{
+    io::{
+        stdin,
+        stdout,
+        Read,
+        Write,
+    },
+    path::{
+        Path,
+        PathBuf,
+    },
+    str::FromStr,
 };
-use termion::screen::IntoAlternateScreen;
+use termion::{
+    input::TermRead,
+    screen::IntoAlternateScreen,
+};
+use zeroize::Zeroizing;
 
 /// Parse a secret key to view the associated public key
 #[derive(Debug, clap::Args)]
@@ -47,6 +72,112 @@ pub struct NewKey {
         default_value = <KeyType as std::convert::Into<&'static str>>::into(KeyType::BlockProduction),
     )]
     pub key_type: KeyType,
+    /// Save the key to the new keystore encrypted with the password instead of
+    /// printing the secret.
+    #[clap(long = "keystore")]
+    pub keystore: Option<PathBuf>,
+    /// The file with the password of the keystore. If not set, the password is prompted.
+    #[clap(long = "password-file", requires = "keystore")]
+    pub password_file: Option<PathBuf>,
+    /// Generate the batch of keys, e.g. for the validators of the test network.
+    #[clap(long = "count", conflicts_with = "keystore")]
+    pub count: Option<usize>,
+}
+
+/// Generate a new random BIP-39 mnemonic phrase
+#[derive(Debug, clap::Args)]
+pub struct NewMnemonic {
+    /// The number of words in the phrase: 12, 15, 18, 21 or 24
+    #[clap(long = "words", short = 'w', default_value_t = DEFAULT_WORD_COUNT)]
+    pub words: usize,
+    /// Print the JSON in pretty format
+    #[clap(long = "pretty", short = 'p')]
+    pub pretty: bool,
+}
+
+/// Derive the keys from the BIP-39 mnemonic phrase.
+/// By default, the keys of Fuel accounts are derived along `m/44'/1179993420'/{index}'/0/0`.
+#[derive(Debug, clap::Args)]
+pub struct DeriveKeys {
+    /// The file with the mnemonic phrase. If not set, the phrase is prompted.
+    #[clap(long = "mnemonic-file")]
+    pub mnemonic_file: Option<PathBuf>,
+    /// The index of the first account
+    #[clap(long = "index", default_value_t = 0)]
+    pub index: u32,
+    /// The number of the derived keys
+    #[clap(long = "count", default_value_t = 1)]
+    pub count: u32,
+    /// The custom derivation path, e.g. `m/44'/60'/0'/0/0`
+    #[clap(long = "path", conflicts_with_all = ["index", "count"])]
+    pub path: Option<String>,
+    /// Print the JSON in pretty format
+    #[clap(long = "pretty", short = 'p')]
+    pub pretty: bool,
+    /// Key type to derive. It can either be `block-production` or `peering`.
+    #[clap(
+        long = "key-type",
+        short = 'k',
+        value_enum,
+        default_value = <KeyType as std::convert::Into<&'static str>>::into(KeyType::BlockProduction),
+    )]
+    pub key_type: KeyType,
+}
+
+/// Search for the random block production key with the address matching the hex pattern
+#[derive(Debug, clap::Args)]
+pub struct VanityKey {
+    /// The hex prefix of the address
+    #[clap(long = "prefix", default_value = "")]
+    pub prefix: String,
+    /// The hex suffix of the address
+    #[clap(long = "suffix", default_value = "")]
+    pub suffix: String,
+    /// The number of attempts before giving up
+    #[clap(long = "max-attempts", default_value_t = 100_000_000)]
+    pub max_attempts: u64,
+    /// Print the JSON in pretty format
+    #[clap(long = "pretty", short = 'p')]
+    pub pretty: bool,
+}
+
+/// Encrypt an existing secret key into the new keystore
+#[derive(Debug, clap::Args)]
+pub struct ImportKey {
+    /// The path of the new keystore
+    #[clap(long = "keystore")]
+    pub keystore: PathBuf,
+    /// The file with the hex-encoded secret key. If not set, the secret is prompted.
+    #[clap(long = "secret-file")]
+    pub secret_file: Option<PathBuf>,
+    /// The file with the password of the keystore. If not set, the password is prompted.
+    #[clap(long = "password-file")]
+    pub password_file: Option<PathBuf>,
+    /// Print the JSON in pretty format
+    #[clap(long = "pretty", short = 'p')]
+    pub pretty: bool,
+    /// Key type to import. It can either be `block-production` or `peering`.
+    #[clap(
+        long = "key-type",
+        short = 'k',
+        value_enum,
+        default_value = <KeyType as std::convert::Into<&'static str>>::into(KeyType::BlockProduction),
+    )]
+    pub key_type: KeyType,
+}
+
+/// Decrypt the keystore and print the secret key
+#[derive(Debug, clap::Args)]
+pub struct ExportKey {
+    /// The path of the keystore
+    #[clap(long = "keystore")]
+    pub keystore: PathBuf,
+    /// The file with the password of the keystore. If not set, the password is prompted.
+    #[clap(long = "password-file")]
+    pub password_file: Option<PathBuf>,
+    /// Print the JSON in pretty format
+    #[clap(long = "pretty", short = 'p')]
+    pub pretty: bool,
 }
 
 /// Key management utilities for configuring fuel-core
@@ -55,26 +186,178 @@ pub struct NewKey {
 pub(crate) enum Command {
     New(NewKey),
     Parse(ParseSecret),
+    Import(ImportKey),
+    Export(ExportKey),
+    Mnemonic(NewMnemonic),
+    Derive(DeriveKeys),
+    Vanity(VanityKey),
+}
+
+/// The result of the command.
+pub(crate) struct Output {
+    value: serde_json::Value,
+    pretty: bool,
+    /// The secret key is printed discreetly.
+    contains_secret: bool,
 }
 
 impl Command {
-    pub(crate) fn exec(&self) -> anyhow::Result<(serde_json::Value, bool)> {
+    pub(crate) fn exec(&self) -> anyhow::Result<Output> {
         match self {
-            Command::New(cmd) => {
-                Ok((serde_json::to_value(new_key(cmd.key_type)?)?, cmd.pretty))
+            Command::New(cmd) => match &cmd.keystore {
+                Some(keystore) => {
+                    let key = new_key(cmd.key_type)?;
+                    let password = new_password(cmd.password_file.as_deref())?;
+                    let value =
+                        save_keystore(keystore, key.secret(), cmd.key_type, &password)?;
+                    Ok(Output {
+                        value,
+                        pretty: cmd.pretty,
+                        contains_secret: false,
+                    })
+                }
+                None => {
+                    let value = match cmd.count {
+                        Some(count) => {
+                            serde_json::to_value(new_keys(cmd.key_type, count)?)?
+                        }
+                        None => serde_json::to_value(new_key(cmd.key_type)?)?,
+                    };
+                    Ok(Output {
+                        value,
+                        pretty: cmd.pretty,
+                        contains_secret: true,
+                    })
+                }
+            },
+            Command::Parse(cmd) => Ok(Output {
+                value: serde_json::to_value(parse_secret(cmd.key_type, &cmd.secret)?)?,
+                pretty: cmd.pretty,
+                contains_secret: false,
+            }),
+            Command::Import(cmd) => {
+                let secret = match &cmd.secret_file {
+                    Some(path) => Zeroizing::new(
+                        std::fs::read_to_string(path)
+                            .map(Zeroizing::new)?
+                            .trim()
+                            .to_string(),
+                    ),
+                    None => prompt_hidden("Secret key: ")?,
+                };
+                let secret = SecretKey::from_str(&secret)
+                    .map_err(|_| anyhow::anyhow!("invalid secret key"))?;
+                let password = new_password(cmd.password_file.as_deref())?;
+                let value =
+                    save_keystore(&cmd.keystore, &secret, cmd.key_type, &password)?;
+                Ok(Output {
+                    value,
+                    pretty: cmd.pretty,
+                    contains_secret: false,
+                })
+            }
+            Command::Export(cmd) => {
+                let keystore = Keystore::load(&cmd.keystore)?;
+                let password = match &cmd.password_file {
+                    Some(path) => read_password_file(path)?,
+                    None => prompt_hidden("Password: ")?,
+                };
+                let secret = keystore.decrypt(password.as_bytes())?;
+                Ok(Output {
+                    value: serde_json::to_value(secret_response(
+                        secret,
+                        keystore.key_type,
+                    ))?,
+                    pretty: cmd.pretty,
+                    contains_secret: true,
+                })
+            }
+            Command::Mnemonic(cmd) => Ok(Output {
+                value: serde_json::json!({ "mnemonic": new_mnemonic(cmd.words)? }),
+                pretty: cmd.pretty,
+                contains_secret: true,
+            }),
+            Command::Derive(cmd) => {
+                let phrase = match &cmd.mnemonic_file {
+                    Some(path) => Zeroizing::new(std::fs::read_to_string(path)?),
+                    None => prompt_hidden("Mnemonic phrase: ")?,
+                };
+                let value = match &cmd.path {
+                    Some(path) => {
+                        serde_json::to_value(derive_key(&phrase, path, cmd.key_type)?)?
+                    }
+                    None => serde_json::to_value(derive_keys(
+                        &phrase,
+                        cmd.key_type,
+                        cmd.index,
+                        cmd.count,
+                    )?)?,
+                };
+                Ok(Output {
+                    value,
+                    pretty: cmd.pretty,
+                    contains_secret: true,
+                })
             }
-            Command::Parse(cmd) => Ok((
-                serde_json::to_value(parse_secret(cmd.key_type, &cmd.secret)?)?,
-                cmd.pretty,
-            )),
+            Command::Vanity(cmd) => Ok(Output {
+                value: serde_json::to_value(vanity_key(
+                    &cmd.prefix,
+                    &cmd.suffix,
+                    cmd.max_attempts,
+                )?)?,
+                pretty: cmd.pretty,
+                contains_secret: true,
+            }),
         }
     }
 }
 
 fn main() -> anyhow::Result<()> {
     let cmd = Command::parse();
-    let (result, is_pretty) = cmd.exec()?;
-    print_value(result, is_pretty)
+    let output = cmd.exec()?;
+    print_value(output)
+}
+
+/// Encrypts the secret into the new keystore and returns the public part of the keystore.
+fn save_keystore(
+    path: &Path,
+    secret: &SecretKey,
+    key_type: KeyType,
+    password: &str,
+) -> anyhow::Result<serde_json::Value> {
+    let keystore = Keystore::encrypt(secret, key_type, password.as_bytes())?;
+    keystore.save(path)?;
+    Ok(serde_json::json!({
+        "keystore": path,
+        "address": keystore.address,
+        "peer_id": keystore.peer_id,
+        "type": keystore.key_type,
+    }))
+}
+
+/// Reads the password of the new keystore from the file, or prompts it twice.
+fn new_password(password_file: Option<&Path>) -> anyhow::Result<Zeroizing<String>> {
+    if let Some(path) = password_file {
+        return read_password_file(path)
+    }
+    let password = prompt_hidden("Password: ")?;
+    let confirmation = prompt_hidden("Repeat password: ")?;
+    if password != confirmation {
+        anyhow::bail!("The passwords don't match");
+    }
+    Ok(password)
+}
+
+/// Prompts the value without echoing it to the terminal.
+fn prompt_hidden(prompt: &str) -> anyhow::Result<Zeroizing<String>> {
+    let mut stdout = stdout();
+    write!(stdout, "{prompt}")?;
+    stdout.flush()?;
+    let value = stdin().read_passwd(&mut stdout)?;
+    writeln!(stdout)?;
+    value
+        .map(Zeroizing::new)
+        .ok_or_else(|| anyhow::anyhow!("No input"))
 }
 
 fn wait_for_keypress() {
@@ -100,14 +383,24 @@ fn display_string_discreetly(
     Ok(())
 }
 
-fn print_value(output: serde_json::Value, pretty: bool) -> anyhow::Result<()> {
+fn print_value(output: Output) -> anyhow::Result<()> {
+    let Output {
+        value,
+        pretty,
+        contains_secret,
+    } = output;
     let output = if pretty {
-        serde_json::to_string_pretty(&output)
+        serde_json::to_string_pretty(&value)
     } else {
-        serde_json::to_string(&output)
+        serde_json::to_string(&value)
     }
     .map_err(anyhow::Error::msg);
 
+    if !contains_secret {
+        println!("{}", output?);
+        return Ok(())
+    }
+
     let _ = display_string_discreetly(
         &output?,
         "### Do not share or lose this private key! Press any key to complete. ###",
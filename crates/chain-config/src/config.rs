@@ -1,15 +1,19 @@
 mod chain;
+mod checkpoint;
 mod coin;
 mod consensus;
 mod contract;
 mod message;
+mod snapshot;
 mod state;
 
 pub use chain::*;
+pub use checkpoint::*;
 pub use coin::*;
 pub use consensus::*;
 pub use contract::*;
 pub use message::*;
+pub use snapshot::*;
 pub use state::*;
 
 #[cfg(test)]
@@ -63,6 +67,27 @@ mod tests {
         assert_eq!(disk_config, load_config);
     }
 
+    #[cfg(feature = "std")]
+    #[test]
+    fn from_str_loads_from_snapshot_directory() {
+        // Given
+        let dir = tmp_path();
+        let mut disk_config = test_config_contract(true, true, true, true);
+        let state = disk_config.initial_state.as_mut().unwrap();
+        state.coins = Some(vec![CoinConfig::default(); 2500]);
+        state.height = Some(10u32.into());
+        disk_config.write_snapshot(&dir).unwrap();
+
+        // When
+        let load_config: ChainConfig =
+            dir.to_string_lossy().into_owned().parse().unwrap();
+
+        // Then
+        assert!(dir.join("state").join("coins_2.json").exists());
+        assert_eq!(disk_config, load_config);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
     #[test]
     fn snapshot_local_testnet_config() {
         let config = ChainConfig::local_testnet();
@@ -178,6 +178,16 @@ impl FromStr for ChainConfig {
             s => {
                 // Attempt to load chain config from path
                 let path = PathBuf::from(s.to_string());
+                if path.is_dir() {
+                    return Self::load_snapshot(&path).map_err(|e| {
+                        std::io::Error::new(
+                            ErrorKind::InvalidData,
+                            e.context(format!(
+                                "an error occurred while loading the chain config snapshot {s}"
+                            )),
+                        )
+                    })
+                }
                 let contents = std::fs::read(path)?;
                 serde_json::from_slice(&contents).map_err(|e| {
                     std::io::Error::new(
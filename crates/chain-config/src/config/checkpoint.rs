@@ -0,0 +1,208 @@
+use crate::serialization::{
+    HexNumber,
+    HexType,
+};
+
+use fuel_core_types::{
+    blockchain::primitives::BlockId,
+    fuel_types::{
+        AssetId,
+        BlockHeight,
+        Bytes32,
+    },
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use serde_with::serde_as;
+#[cfg(feature = "std")]
+use std::{
+    io::ErrorKind,
+    path::PathBuf,
+    str::FromStr,
+};
+
+use super::{
+    coin::CoinConfig,
+    contract::ContractConfig,
+    message::MessageConfig,
+    state::StateConfig,
+};
+
+/// The trusted state of the chain at the `height`. The node can download the state
+/// at this height from peers instead of executing all blocks from the genesis.
+///
+/// The block header doesn't commit to the state, so the checkpoint contains roots of
+/// the state. The roots are calculated in the same way as roots in the genesis block.
+#[serde_as]
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, Eq, PartialEq)]
+pub struct StateCheckpoint {
+    /// The height of the trusted block.
+    #[serde_as(as = "HexNumber")]
+    pub height: BlockHeight,
+    /// The id of the trusted block.
+    pub block_id: BlockId,
+    /// The merkle root of all unspent coins at the `height`.
+    #[serde_as(as = "HexType")]
+    pub coins_root: Bytes32,
+    /// The merkle root of all contracts at the `height`.
+    #[serde_as(as = "HexType")]
+    pub contracts_root: Bytes32,
+    /// The merkle root of all unspent messages at the `height`.
+    #[serde_as(as = "HexType")]
+    pub messages_root: Bytes32,
+}
+
+#[cfg(feature = "std")]
+impl FromStr for StateCheckpoint {
+    type Err = std::io::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Attempt to load the checkpoint from path
+        let path = PathBuf::from(s.to_string());
+        let contents = std::fs::read(path)?;
+        serde_json::from_slice(&contents).map_err(|e| {
+            std::io::Error::new(
+                ErrorKind::InvalidData,
+                anyhow::Error::new(e).context(format!(
+                    "an error occurred while loading the checkpoint file {s}"
+                )),
+            )
+        })
+    }
+}
+
+/// The kind of the on-chain state requested in the chunk.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, Eq, PartialEq, Hash)]
+pub enum StateChunkKind {
+    Coins,
+    Contracts,
+    Messages,
+}
+
+impl StateChunkKind {
+    pub const ALL: [StateChunkKind; 3] = [
+        StateChunkKind::Coins,
+        StateChunkKind::Contracts,
+        StateChunkKind::Messages,
+    ];
+
+    /// The maximum number of entries of this kind in one chunk.
+    pub const fn max_entries(&self) -> usize {
+        match self {
+            StateChunkKind::Coins => 1000,
+            // Contracts contain the code and the storage, so they are much bigger.
+            StateChunkKind::Contracts => 10,
+            StateChunkKind::Messages => 1000,
+        }
+    }
+}
+
+/// The request for the chunk of the on-chain state at the `height`.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, Eq, PartialEq)]
+pub struct StateChunkRequest {
+    pub height: BlockHeight,
+    pub kind: StateChunkKind,
+    /// The index of the chunk. Entries are split into chunks in the order of their keys.
+    pub index: u32,
+}
+
+/// The entries of the on-chain state.
+#[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+pub enum StateChunkEntries {
+    Coins(Vec<CoinConfig>),
+    Contracts(Vec<ContractConfig>),
+    Messages(Vec<MessageConfig>),
+}
+
+impl StateChunkEntries {
+    pub fn kind(&self) -> StateChunkKind {
+        match self {
+            StateChunkEntries::Coins(_) => StateChunkKind::Coins,
+            StateChunkEntries::Contracts(_) => StateChunkKind::Contracts,
+            StateChunkEntries::Messages(_) => StateChunkKind::Messages,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        match self {
+            StateChunkEntries::Coins(coins) => coins.len(),
+            StateChunkEntries::Contracts(contracts) => contracts.len(),
+            StateChunkEntries::Messages(messages) => messages.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the approximate size of the entries in memory, in bytes.
+    pub fn size(&self) -> usize {
+        use core::mem::size_of;
+
+        match self {
+            StateChunkEntries::Coins(coins) => {
+                coins.len().saturating_mul(size_of::<CoinConfig>())
+            }
+            StateChunkEntries::Contracts(contracts) => {
+                contracts.iter().fold(0usize, |size, contract| {
+                    let state = contract.state.as_ref().map_or(0, Vec::len);
+                    let balances = contract.balances.as_ref().map_or(0, Vec::len);
+                    size.saturating_add(size_of::<ContractConfig>())
+                        .saturating_add(contract.code.len())
+                        .saturating_add(
+                            state.saturating_mul(size_of::<(Bytes32, Bytes32)>()),
+                        )
+                        .saturating_add(
+                            balances.saturating_mul(size_of::<(AssetId, u64)>()),
+                        )
+                })
+            }
+            StateChunkEntries::Messages(messages) => {
+                messages.iter().fold(0usize, |size, message| {
+                    size.saturating_add(size_of::<MessageConfig>())
+                        .saturating_add(message.data.len())
+                })
+            }
+        }
+    }
+}
+
+/// The chunk of the on-chain state at the `height`.
+#[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+pub struct StateChunk {
+    pub height: BlockHeight,
+    pub index: u32,
+    /// `true` if it is the last chunk of this kind.
+    pub last: bool,
+    pub entries: StateChunkEntries,
+}
+
+impl StateChunk {
+    /// Returns `true` if the chunk is a valid response to the `request`.
+    pub fn is_response_to(&self, request: &StateChunkRequest) -> bool {
+        self.height == request.height
+            && self.index == request.index
+            && self.entries.kind() == request.kind
+            && self.entries.len() <= request.kind.max_entries()
+    }
+}
+
+impl StateConfig {
+    /// Appends the entries of the chunk to the state.
+    pub fn extend_with_chunk(&mut self, chunk: StateChunk) {
+        match chunk.entries {
+            StateChunkEntries::Coins(coins) => {
+                self.coins.get_or_insert_with(Vec::new).extend(coins)
+            }
+            StateChunkEntries::Contracts(contracts) => self
+                .contracts
+                .get_or_insert_with(Vec::new)
+                .extend(contracts),
+            StateChunkEntries::Messages(messages) => {
+                self.messages.get_or_insert_with(Vec::new).extend(messages)
+            }
+        }
+    }
+}
@@ -9,6 +9,7 @@ use fuel_core_storage::MerkleRoot;
 use fuel_core_types::{
     entities::coins::coin::CompressedCoin,
     fuel_crypto::Hasher,
+    fuel_tx::UtxoId,
     fuel_types::{
         Address,
         AssetId,
@@ -56,6 +57,33 @@ pub struct CoinConfig {
     pub asset_id: AssetId,
 }
 
+impl CoinConfig {
+    /// Returns the UTXO id of the coin created by the genesis. The missing parts of
+    /// the id are generated from the `generated_output_index` shared by all coins,
+    /// which is increased for each generated output index.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn utxo_id(&self, generated_output_index: &mut u64) -> UtxoId {
+        let tx_id = self
+            .tx_id
+            .unwrap_or_else(|| generated_tx_id(*generated_output_index));
+        let output_index = self.output_index.unwrap_or_else(|| {
+            *generated_output_index = generated_output_index
+                .checked_add(1)
+                .expect("The maximum number of UTXOs supported in the genesis configuration has been exceeded.");
+            (*generated_output_index % 255) as u8
+        });
+        UtxoId::new(tx_id, output_index)
+    }
+}
+
+/// Returns the transaction id `[0..[generated_output_index / 255]]` of the genesis
+/// outputs without the explicit transaction id.
+pub(crate) fn generated_tx_id(generated_output_index: u64) -> Bytes32 {
+    let mut tx_id = [0u8; 32];
+    tx_id[24..].copy_from_slice(&(generated_output_index / 255).to_be_bytes());
+    tx_id.into()
+}
+
 impl GenesisCommitment for CompressedCoin {
     fn root(&self) -> anyhow::Result<MerkleRoot> {
         let owner = self.owner();
@@ -1,12 +1,16 @@
-use crate::serialization::{
-    HexNumber,
-    HexType,
+use crate::{
+    config::coin::generated_tx_id,
+    serialization::{
+        HexNumber,
+        HexType,
+    },
 };
 use fuel_core_types::{
     fuel_tx::{
         Contract,
         ContractId,
         StorageSlot,
+        UtxoId,
     },
     fuel_types::{
         AssetId,
@@ -81,4 +85,15 @@ impl ContractConfig {
         let contract_id = contract.id(&salt, &root, &state_root);
         self.contract_id = contract_id;
     }
+
+    /// Returns the UTXO id of the contract created by the genesis. Unless both parts
+    /// of the id are set, it is generated from the `index` of the contract.
+    pub fn utxo_id(&self, index: usize) -> UtxoId {
+        if let (Some(tx_id), Some(output_index)) = (self.tx_id, self.output_index) {
+            return UtxoId::new(tx_id, output_index)
+        }
+        #[allow(clippy::cast_possible_truncation)]
+        let output_index = index as u8;
+        UtxoId::new(generated_tx_id(index as u64), output_index)
+    }
 }
@@ -0,0 +1,187 @@
+//! The chain config split into the chunks of the state.
+//!
+//! Large initial states are hard to edit and review as a single JSON file.
+//! The snapshot directory contains the [`CHAIN_CONFIG_FILE`] without the entries
+//! of the state and the `state` directory with the [`StateChunk`]s of each kind,
+//! named `{kind}_{index}.json`. The chunks are of the same size as the chunks
+//! of the state synced from peers.
+
+use super::{
+    chain::ChainConfig,
+    checkpoint::{
+        StateChunk,
+        StateChunkEntries,
+        StateChunkKind,
+    },
+    state::StateConfig,
+};
+#[cfg(feature = "std")]
+use anyhow::{
+    anyhow,
+    Context,
+};
+#[cfg(feature = "std")]
+use std::path::{
+    Path,
+    PathBuf,
+};
+
+/// The name of the file with the chain config in the snapshot directory.
+pub const CHAIN_CONFIG_FILE: &str = "chain_config.json";
+/// The name of the directory with the state chunks in the snapshot directory.
+pub const STATE_DIR: &str = "state";
+
+impl StateChunkKind {
+    /// The name of the kind used in the file names of the chunks.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            StateChunkKind::Coins => "coins",
+            StateChunkKind::Contracts => "contracts",
+            StateChunkKind::Messages => "messages",
+        }
+    }
+}
+
+impl StateConfig {
+    /// Splits the entries of the state into the chunks.
+    /// Each kind has at least one chunk, so the last chunk is always present.
+    pub fn to_chunks(&self) -> Vec<StateChunk> {
+        let height = self.height.unwrap_or_default();
+        let coins = self.coins.clone().unwrap_or_default();
+        let contracts = self.contracts.clone().unwrap_or_default();
+        let messages = self.messages.clone().unwrap_or_default();
+
+        let mut chunks = vec![];
+        chunks.extend(split(
+            coins,
+            StateChunkKind::Coins,
+            StateChunkEntries::Coins,
+        ));
+        chunks.extend(split(
+            contracts,
+            StateChunkKind::Contracts,
+            StateChunkEntries::Contracts,
+        ));
+        chunks.extend(split(
+            messages,
+            StateChunkKind::Messages,
+            StateChunkEntries::Messages,
+        ));
+        chunks
+            .into_iter()
+            .map(|(index, last, entries)| StateChunk {
+                height,
+                index,
+                last,
+                entries,
+            })
+            .collect()
+    }
+}
+
+fn split<T: Clone>(
+    entries: Vec<T>,
+    kind: StateChunkKind,
+    wrap: fn(Vec<T>) -> StateChunkEntries,
+) -> Vec<(u32, bool, StateChunkEntries)> {
+    if entries.is_empty() {
+        return vec![(0, true, wrap(vec![]))]
+    }
+    let chunks = entries.chunks(kind.max_entries()).collect::<Vec<_>>();
+    let count = chunks.len();
+    chunks
+        .into_iter()
+        .enumerate()
+        .map(|(index, chunk)| {
+            let last = index.saturating_add(1) == count;
+            let index =
+                u32::try_from(index).expect("The number of chunks fits into `u32`");
+            (index, last, wrap(chunk.to_vec()))
+        })
+        .collect()
+}
+
+#[cfg(feature = "std")]
+impl ChainConfig {
+    /// Writes the config into the snapshot `dir`, splitting the state into the chunks.
+    pub fn write_snapshot(&self, dir: &Path) -> anyhow::Result<()> {
+        let state_dir = dir.join(STATE_DIR);
+        std::fs::create_dir_all(&state_dir).with_context(|| {
+            format!("failed to create the snapshot directory {}", dir.display())
+        })?;
+
+        let state = self.initial_state.clone().unwrap_or_default();
+        let config = ChainConfig {
+            initial_state: Some(StateConfig {
+                height: state.height,
+                ..Default::default()
+            }),
+            ..self.clone()
+        };
+        write_json(&dir.join(CHAIN_CONFIG_FILE), &config)?;
+        for chunk in state.to_chunks() {
+            write_json(&chunk_path(dir, chunk.entries.kind(), chunk.index), &chunk)?;
+        }
+        Ok(())
+    }
+
+    /// Loads the config from the snapshot `dir`, joining the chunks of the state.
+    pub fn load_snapshot(dir: &Path) -> anyhow::Result<Self> {
+        let mut config: ChainConfig = read_json(&dir.join(CHAIN_CONFIG_FILE))?;
+        let mut state = config.initial_state.take().unwrap_or_default();
+        let height = state.height.unwrap_or_default();
+
+        for kind in StateChunkKind::ALL {
+            let mut index = 0u32;
+            loop {
+                let path = chunk_path(dir, kind, index);
+                let chunk: StateChunk = read_json(&path)?;
+                if chunk.height != height
+                    || chunk.index != index
+                    || chunk.entries.kind() != kind
+                {
+                    return Err(anyhow!(
+                        "the chunk {} doesn't belong to the state at height {height}",
+                        path.display()
+                    ))
+                }
+                let last = chunk.last;
+                // The empty chunk is written for the missing entries.
+                if !chunk.entries.is_empty() {
+                    state.extend_with_chunk(chunk);
+                }
+                if last {
+                    break
+                }
+                index = index
+                    .checked_add(1)
+                    .ok_or(anyhow!("the number of {} chunks overflows", kind.name()))?;
+            }
+        }
+
+        config.initial_state = Some(state);
+        Ok(config)
+    }
+}
+
+#[cfg(feature = "std")]
+fn chunk_path(dir: &Path, kind: StateChunkKind, index: u32) -> PathBuf {
+    dir.join(STATE_DIR)
+        .join(format!("{}_{index}.json", kind.name()))
+}
+
+#[cfg(feature = "std")]
+fn write_json<T: serde::Serialize>(path: &Path, value: &T) -> anyhow::Result<()> {
+    let file = std::fs::File::create(path)
+        .with_context(|| format!("failed to create {}", path.display()))?;
+    serde_json::to_writer_pretty(file, value)
+        .with_context(|| format!("failed to write {}", path.display()))
+}
+
+#[cfg(feature = "std")]
+fn read_json<T: serde::de::DeserializeOwned>(path: &Path) -> anyhow::Result<T> {
+    let contents = std::fs::read(path)
+        .with_context(|| format!("failed to read {}", path.display()))?;
+    serde_json::from_slice(&contents)
+        .with_context(|| format!("failed to parse {}", path.display()))
+}
@@ -0,0 +1,183 @@
+//! The human-readable difference between two chain configs.
+//!
+//! The configs are compared in their JSON form, so the values are printed
+//! the same way as they are written in the config files.
+
+use crate::ChainConfig;
+use core::fmt;
+use serde_json::Value;
+
+/// The change of one field between two configs.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ConfigChange {
+    /// The field exists only in the new config.
+    Added { location: String, value: Value },
+    /// The field exists only in the old config.
+    Removed { location: String, value: Value },
+    /// The value of the field differs.
+    Changed {
+        location: String,
+        old: Value,
+        new: Value,
+    },
+}
+
+impl ConfigChange {
+    pub fn location(&self) -> &str {
+        match self {
+            ConfigChange::Added { location, .. }
+            | ConfigChange::Removed { location, .. }
+            | ConfigChange::Changed { location, .. } => location,
+        }
+    }
+}
+
+impl fmt::Display for ConfigChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigChange::Added { location, value } => {
+                write!(f, "+ {location}: {value}")
+            }
+            ConfigChange::Removed { location, value } => {
+                write!(f, "- {location}: {value}")
+            }
+            ConfigChange::Changed { location, old, new } => {
+                write!(f, "~ {location}: {old} -> {new}")
+            }
+        }
+    }
+}
+
+/// Returns the changes required to turn the `old` config into the `new` one.
+/// The fields are compared recursively, the arrays are compared element by element.
+pub fn diff(old: &ChainConfig, new: &ChainConfig) -> anyhow::Result<Vec<ConfigChange>> {
+    let old = serde_json::to_value(old)?;
+    let new = serde_json::to_value(new)?;
+    let mut changes = vec![];
+    diff_values(String::new(), &old, &new, &mut changes);
+    Ok(changes)
+}
+
+fn diff_values(
+    location: String,
+    old: &Value,
+    new: &Value,
+    changes: &mut Vec<ConfigChange>,
+) {
+    match (old, new) {
+        (Value::Object(old), Value::Object(new)) => {
+            for (key, old_value) in old {
+                let location = field_location(&location, key);
+                match new.get(key) {
+                    Some(new_value) => {
+                        diff_values(location, old_value, new_value, changes)
+                    }
+                    None => changes.push(ConfigChange::Removed {
+                        location,
+                        value: old_value.clone(),
+                    }),
+                }
+            }
+            for (key, new_value) in new {
+                if !old.contains_key(key) {
+                    changes.push(ConfigChange::Added {
+                        location: field_location(&location, key),
+                        value: new_value.clone(),
+                    });
+                }
+            }
+        }
+        (Value::Array(old), Value::Array(new)) => {
+            for index in 0..old.len().max(new.len()) {
+                let location = format!("{location}[{index}]");
+                match (old.get(index), new.get(index)) {
+                    (Some(old_value), Some(new_value)) => {
+                        diff_values(location, old_value, new_value, changes)
+                    }
+                    (Some(old_value), None) => changes.push(ConfigChange::Removed {
+                        location,
+                        value: old_value.clone(),
+                    }),
+                    (None, Some(new_value)) => changes.push(ConfigChange::Added {
+                        location,
+                        value: new_value.clone(),
+                    }),
+                    (None, None) => {}
+                }
+            }
+        }
+        (old, new) => {
+            if old != new {
+                changes.push(ConfigChange::Changed {
+                    location,
+                    old: old.clone(),
+                    new: new.clone(),
+                });
+            }
+        }
+    }
+}
+
+fn field_location(parent: &str, field: &str) -> String {
+    if parent.is_empty() {
+        field.to_string()
+    } else {
+        format!("{parent}.{field}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diff_of_same_configs_is_empty() {
+        // Given
+        let config = ChainConfig::local_testnet();
+
+        // When
+        let changes = diff(&config, &config).unwrap();
+
+        // Then
+        assert_eq!(changes, vec![]);
+    }
+
+    #[test]
+    fn diff_reports_changed_added_and_removed_fields() {
+        // Given
+        let old = ChainConfig::local_testnet();
+        let mut new = old.clone();
+        new.chain_name = "devnet".to_string();
+        let state = new.initial_state.as_mut().unwrap();
+        state.height = Some(5u32.into());
+        let coins = state.coins.as_mut().unwrap();
+        assert_eq!(coins.len(), 5);
+        let removed_coin = coins.pop().unwrap();
+
+        // When
+        let changes = diff(&old, &new).unwrap();
+
+        // Then
+        assert_eq!(
+            changes,
+            vec![
+                ConfigChange::Changed {
+                    location: "chain_name".to_string(),
+                    old: Value::from("local_testnet"),
+                    new: Value::from("devnet"),
+                },
+                ConfigChange::Removed {
+                    location: "initial_state.coins[4]".to_string(),
+                    value: serde_json::to_value(removed_coin).unwrap(),
+                },
+                ConfigChange::Added {
+                    location: "initial_state.height".to_string(),
+                    value: Value::from("0x00000005"),
+                },
+            ]
+        );
+        assert!(changes[1]
+            .to_string()
+            .starts_with("- initial_state.coins[4]: "));
+    }
+}
@@ -4,13 +4,17 @@
 #![deny(warnings)]
 
 pub mod config;
+#[cfg(feature = "std")]
+pub mod diff;
 pub mod fee_collection_contract;
 mod genesis;
 mod serialization;
+mod validation;
 
 pub use config::*;
 use fuel_core_types::fuel_vm::SecretKey;
 pub use genesis::GenesisCommitment;
+pub use validation::ValidationError;
 
 /// A default secret key to use for testing purposes only
 pub fn default_consensus_dev_key() -> SecretKey {
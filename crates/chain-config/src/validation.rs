@@ -0,0 +1,582 @@
+//! The semantic validation of the chain config.
+//!
+//! The deserialization only checks the format of the config. The checks below
+//! catch the mistakes that otherwise surface only during the genesis, like
+//! duplicated UTXO ids or contract ids not matching the contract code.
+
+use crate::{
+    ChainConfig,
+    CoinConfig,
+    ConsensusConfig,
+    ContractConfig,
+    MessageConfig,
+};
+use core::fmt;
+use fuel_core_types::{
+    fuel_tx::{
+        ConsensusParameters,
+        UtxoId,
+    },
+    fuel_types::{
+        Address,
+        BlockHeight,
+    },
+};
+use std::collections::{
+    hash_map::Entry,
+    HashMap,
+};
+
+/// The violated invariant of the chain config.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ValidationError {
+    /// The path to the invalid field, e.g. `initial_state.coins[3].output_index`.
+    pub location: String,
+    pub message: String,
+}
+
+impl ValidationError {
+    fn new(location: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            location: location.into(),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.location, self.message)
+    }
+}
+
+impl ChainConfig {
+    /// Checks the invariants of the config and returns all found violations.
+    pub fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut errors = vec![];
+        validate_consensus_parameters(
+            self.block_gas_limit,
+            &self.consensus_parameters,
+            &mut errors,
+        );
+        validate_consensus(&self.consensus, &mut errors);
+
+        if let Some(state) = &self.initial_state {
+            let height = state.height.unwrap_or_default();
+            // Coins and contracts are outputs of the genesis, so they share UTXO ids.
+            let mut utxo_ids = HashMap::new();
+            if let Some(coins) = &state.coins {
+                validate_coins(coins, height, &mut utxo_ids, &mut errors);
+            }
+            if let Some(contracts) = &state.contracts {
+                validate_contracts(
+                    contracts,
+                    height,
+                    &self.consensus_parameters,
+                    &mut utxo_ids,
+                    &mut errors,
+                );
+            }
+            if let Some(messages) = &state.messages {
+                validate_messages(messages, &self.consensus_parameters, &mut errors);
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+fn validate_consensus_parameters(
+    block_gas_limit: u64,
+    params: &ConsensusParameters,
+    errors: &mut Vec<ValidationError>,
+) {
+    let tx_params = &params.tx_params;
+    if tx_params.max_gas_per_tx == 0 {
+        errors.push(ValidationError::new(
+            "consensus_parameters.tx_params.max_gas_per_tx",
+            "must be greater than zero",
+        ));
+    }
+    if block_gas_limit < tx_params.max_gas_per_tx {
+        errors.push(ValidationError::new(
+            "block_gas_limit",
+            format!(
+                "{block_gas_limit} is less than `consensus_parameters.tx_params.max_gas_per_tx` {}, \
+                the transaction with the maximum gas doesn't fit into the block",
+                tx_params.max_gas_per_tx
+            ),
+        ));
+    }
+    if tx_params.max_inputs == 0 {
+        errors.push(ValidationError::new(
+            "consensus_parameters.tx_params.max_inputs",
+            "must be greater than zero",
+        ));
+    }
+    if tx_params.max_outputs == 0 {
+        errors.push(ValidationError::new(
+            "consensus_parameters.tx_params.max_outputs",
+            "must be greater than zero",
+        ));
+    }
+    if params.fee_params.gas_price_factor == 0 {
+        errors.push(ValidationError::new(
+            "consensus_parameters.fee_params.gas_price_factor",
+            "must be greater than zero",
+        ));
+    }
+
+    let predicate_params = &params.predicate_params;
+    if predicate_params.max_gas_per_predicate > tx_params.max_gas_per_tx {
+        errors.push(ValidationError::new(
+            "consensus_parameters.predicate_params.max_gas_per_predicate",
+            format!(
+                "{} exceeds `consensus_parameters.tx_params.max_gas_per_tx` {}",
+                predicate_params.max_gas_per_predicate, tx_params.max_gas_per_tx
+            ),
+        ));
+    }
+
+    // Each of these parts is a part of the transaction, so it can't be larger
+    // than the transaction itself.
+    let tx_parts = [
+        (
+            "consensus_parameters.predicate_params.max_predicate_length",
+            predicate_params.max_predicate_length,
+        ),
+        (
+            "consensus_parameters.predicate_params.max_predicate_data_length",
+            predicate_params.max_predicate_data_length,
+        ),
+        (
+            "consensus_parameters.script_params.max_script_length",
+            params.script_params.max_script_length,
+        ),
+        (
+            "consensus_parameters.script_params.max_script_data_length",
+            params.script_params.max_script_data_length,
+        ),
+        (
+            "consensus_parameters.contract_params.contract_max_size",
+            params.contract_params.contract_max_size,
+        ),
+    ];
+    for (location, size) in tx_parts {
+        if size > tx_params.max_size {
+            errors.push(ValidationError::new(
+                location,
+                format!(
+                    "{size} exceeds `consensus_parameters.tx_params.max_size` {}",
+                    tx_params.max_size
+                ),
+            ));
+        }
+    }
+}
+
+fn validate_consensus(consensus: &ConsensusConfig, errors: &mut Vec<ValidationError>) {
+    match consensus {
+        ConsensusConfig::PoA { signing_key } => {
+            if *signing_key == Address::zeroed() {
+                errors.push(ValidationError::new(
+                    "consensus.PoA.signing_key",
+                    "the zero address can't sign blocks",
+                ));
+            }
+        }
+    }
+}
+
+fn validate_coins(
+    coins: &[CoinConfig],
+    height: BlockHeight,
+    utxo_ids: &mut HashMap<UtxoId, String>,
+    errors: &mut Vec<ValidationError>,
+) {
+    let mut generated_output_index = 0;
+    for (index, coin) in coins.iter().enumerate() {
+        let location = format!("initial_state.coins[{index}]");
+        let utxo_id = coin.utxo_id(&mut generated_output_index);
+        validate_utxo_id(&location, utxo_id, utxo_ids, errors);
+        validate_tx_pointer(&location, coin.tx_pointer_block_height, height, errors);
+    }
+}
+
+fn validate_contracts(
+    contracts: &[ContractConfig],
+    height: BlockHeight,
+    params: &ConsensusParameters,
+    utxo_ids: &mut HashMap<UtxoId, String>,
+    errors: &mut Vec<ValidationError>,
+) {
+    let mut contract_ids = HashMap::new();
+    for (index, contract) in contracts.iter().enumerate() {
+        let location = format!("initial_state.contracts[{index}]");
+
+        let mut expected = contract.clone();
+        expected.calculate_contract_id();
+        if expected.contract_id != contract.contract_id {
+            errors.push(ValidationError::new(
+                format!("{location}.contract_id"),
+                format!(
+                    "{:#x} doesn't match the id {:#x} calculated from the code, salt and state",
+                    contract.contract_id, expected.contract_id
+                ),
+            ));
+        }
+        let first = *contract_ids.entry(contract.contract_id).or_insert(index);
+        if first != index {
+            errors.push(ValidationError::new(
+                format!("{location}.contract_id"),
+                format!(
+                    "the contract {:#x} is already defined by `initial_state.contracts[{first}]`",
+                    contract.contract_id
+                ),
+            ));
+        }
+        validate_utxo_id(&location, contract.utxo_id(index), utxo_ids, errors);
+        validate_tx_pointer(&location, contract.tx_pointer_block_height, height, errors);
+
+        let code_size = contract.code.len() as u64;
+        if code_size > params.contract_params.contract_max_size {
+            errors.push(ValidationError::new(
+                format!("{location}.code"),
+                format!(
+                    "the size {code_size} exceeds `consensus_parameters.contract_params.contract_max_size` {}",
+                    params.contract_params.contract_max_size
+                ),
+            ));
+        }
+        let slots = contract.state.as_ref().map_or(0, |state| state.len()) as u64;
+        if slots > params.contract_params.max_storage_slots {
+            errors.push(ValidationError::new(
+                format!("{location}.state"),
+                format!(
+                    "{slots} slots exceed `consensus_parameters.contract_params.max_storage_slots` {}",
+                    params.contract_params.max_storage_slots
+                ),
+            ));
+        }
+    }
+}
+
+fn validate_messages(
+    messages: &[MessageConfig],
+    params: &ConsensusParameters,
+    errors: &mut Vec<ValidationError>,
+) {
+    let mut nonces = HashMap::new();
+    for (index, message) in messages.iter().enumerate() {
+        let location = format!("initial_state.messages[{index}]");
+        let first = *nonces.entry(message.nonce).or_insert(index);
+        if first != index {
+            errors.push(ValidationError::new(
+                format!("{location}.nonce"),
+                format!(
+                    "the nonce {:#x} is already used by `initial_state.messages[{first}]`",
+                    message.nonce
+                ),
+            ));
+        }
+        let data_length = message.data.len() as u64;
+        if data_length > params.predicate_params.max_message_data_length {
+            errors.push(ValidationError::new(
+                format!("{location}.data"),
+                format!(
+                    "the length {data_length} exceeds `consensus_parameters.predicate_params.max_message_data_length` {}",
+                    params.predicate_params.max_message_data_length
+                ),
+            ));
+        }
+    }
+}
+
+/// Records the location of the `utxo_id` and reports it if the id is already used.
+/// The `utxo_id` is the one created by the genesis, so the explicit ids are checked
+/// against the generated ones too.
+fn validate_utxo_id(
+    location: &str,
+    utxo_id: UtxoId,
+    utxo_ids: &mut HashMap<UtxoId, String>,
+    errors: &mut Vec<ValidationError>,
+) {
+    match utxo_ids.entry(utxo_id) {
+        Entry::Occupied(first) => errors.push(ValidationError::new(
+            location,
+            format!(
+                "the UTXO id {utxo_id:#x} is already used by `{}`",
+                first.get()
+            ),
+        )),
+        Entry::Vacant(entry) => {
+            entry.insert(location.to_string());
+        }
+    }
+}
+
+fn validate_tx_pointer(
+    location: &str,
+    tx_pointer_block_height: Option<BlockHeight>,
+    height: BlockHeight,
+    errors: &mut Vec<ValidationError>,
+) {
+    if let Some(tx_pointer_height) = tx_pointer_block_height {
+        if tx_pointer_height > height {
+            errors.push(ValidationError::new(
+                format!("{location}.tx_pointer_block_height"),
+                format!(
+                    "{tx_pointer_height} is above the genesis height {height}, \
+                    the UTXO can't come from a future block"
+                ),
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StateConfig;
+    use fuel_core_types::{
+        fuel_tx::Contract,
+        fuel_types::Bytes32,
+    };
+
+    fn contract(code: Vec<u8>) -> ContractConfig {
+        let mut contract = ContractConfig {
+            contract_id: Default::default(),
+            code,
+            salt: Default::default(),
+            state: None,
+            balances: None,
+            tx_id: None,
+            output_index: None,
+            tx_pointer_block_height: None,
+            tx_pointer_tx_idx: None,
+        };
+        contract.calculate_contract_id();
+        contract
+    }
+
+    fn with_state(state: StateConfig) -> ChainConfig {
+        ChainConfig {
+            initial_state: Some(state),
+            ..ChainConfig::local_testnet()
+        }
+    }
+
+    fn locations(config: &ChainConfig) -> Vec<String> {
+        config
+            .validate()
+            .unwrap_err()
+            .into_iter()
+            .map(|e| e.location)
+            .collect()
+    }
+
+    #[test]
+    fn local_testnet_is_valid() {
+        assert_eq!(ChainConfig::local_testnet().validate(), Ok(()));
+    }
+
+    #[test]
+    fn validate_reports_duplicated_coin_utxo_ids() {
+        // Given
+        let coin = CoinConfig {
+            tx_id: Some(Bytes32::from([1; 32])),
+            output_index: Some(0),
+            ..Default::default()
+        };
+        let config = with_state(StateConfig {
+            coins: Some(vec![coin.clone(), CoinConfig::default(), coin]),
+            ..Default::default()
+        });
+
+        // When
+        let locations = locations(&config);
+
+        // Then
+        assert_eq!(locations, vec!["initial_state.coins[2]".to_string()]);
+    }
+
+    #[test]
+    fn validate_reports_contract_utxo_ids_used_by_coins() {
+        // Given
+        let tx_id = Bytes32::from([1; 32]);
+        let coin = CoinConfig {
+            tx_id: Some(tx_id),
+            output_index: Some(0),
+            ..Default::default()
+        };
+        let mut contract = contract(vec![1, 2, 3]);
+        contract.tx_id = Some(tx_id);
+        contract.output_index = Some(0);
+        let config = with_state(StateConfig {
+            coins: Some(vec![coin]),
+            contracts: Some(vec![contract]),
+            ..Default::default()
+        });
+
+        // When
+        let errors = config.validate().unwrap_err();
+
+        // Then
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].location, "initial_state.contracts[0]");
+        assert!(
+            errors[0]
+                .message
+                .ends_with("is already used by `initial_state.coins[0]`"),
+            "{}",
+            errors[0].message
+        );
+    }
+
+    #[test]
+    fn validate_reports_explicit_coin_utxo_ids_used_by_generated_ones() {
+        // Given
+        let explicit = CoinConfig {
+            tx_id: Some(Bytes32::zeroed()),
+            output_index: Some(1),
+            ..Default::default()
+        };
+        let config = with_state(StateConfig {
+            coins: Some(vec![CoinConfig::default(), explicit]),
+            ..Default::default()
+        });
+
+        // When
+        let locations = locations(&config);
+
+        // Then
+        assert_eq!(locations, vec!["initial_state.coins[1]".to_string()]);
+    }
+
+    #[test]
+    fn validate_reports_partial_coin_utxo_ids() {
+        // Given
+        let tx_id = Bytes32::from([1; 32]);
+        let partial = CoinConfig {
+            tx_id: Some(tx_id),
+            ..Default::default()
+        };
+        let explicit = CoinConfig {
+            tx_id: Some(tx_id),
+            output_index: Some(1),
+            ..Default::default()
+        };
+        let config = with_state(StateConfig {
+            coins: Some(vec![partial, explicit]),
+            ..Default::default()
+        });
+
+        // When
+        let locations = locations(&config);
+
+        // Then
+        assert_eq!(locations, vec!["initial_state.coins[1]".to_string()]);
+    }
+
+    #[test]
+    fn validate_reports_generated_contract_utxo_ids_used_by_generated_coins() {
+        // Given
+        let config = with_state(StateConfig {
+            coins: Some(vec![CoinConfig::default()]),
+            contracts: Some(vec![contract(vec![1]), contract(vec![2])]),
+            ..Default::default()
+        });
+
+        // When
+        let errors = config.validate().unwrap_err();
+
+        // Then
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].location, "initial_state.contracts[1]");
+        assert!(
+            errors[0]
+                .message
+                .ends_with("is already used by `initial_state.coins[0]`"),
+            "{}",
+            errors[0].message
+        );
+    }
+
+    #[test]
+    fn validate_reports_contract_id_not_matching_code() {
+        // Given
+        let mut invalid = contract(vec![1, 2, 3]);
+        invalid.contract_id = Contract::from(vec![4]).id(
+            &Default::default(),
+            &Default::default(),
+            &Default::default(),
+        );
+        let config = with_state(StateConfig {
+            contracts: Some(vec![contract(vec![1]), invalid]),
+            ..Default::default()
+        });
+
+        // When
+        let locations = locations(&config);
+
+        // Then
+        assert_eq!(
+            locations,
+            vec!["initial_state.contracts[1].contract_id".to_string()]
+        );
+    }
+
+    #[test]
+    fn validate_reports_tx_pointers_above_genesis_height() {
+        // Given
+        let coin = CoinConfig {
+            tx_pointer_block_height: Some(11u32.into()),
+            ..Default::default()
+        };
+        let mut contract = contract(vec![1]);
+        contract.tx_pointer_block_height = Some(10u32.into());
+        let config = with_state(StateConfig {
+            coins: Some(vec![coin]),
+            contracts: Some(vec![contract]),
+            height: Some(10u32.into()),
+            ..Default::default()
+        });
+
+        // When
+        let locations = locations(&config);
+
+        // Then
+        assert_eq!(
+            locations,
+            vec!["initial_state.coins[0].tx_pointer_block_height".to_string()]
+        );
+    }
+
+    #[test]
+    fn validate_reports_inconsistent_consensus_parameters() {
+        // Given
+        let mut config = ChainConfig::local_testnet();
+        config.block_gas_limit = config
+            .consensus_parameters
+            .tx_params
+            .max_gas_per_tx
+            .saturating_sub(1);
+        config.consensus_parameters.fee_params.gas_price_factor = 0;
+
+        // When
+        let locations = locations(&config);
+
+        // Then
+        assert_eq!(
+            locations,
+            vec![
+                "block_gas_limit".to_string(),
+                "consensus_parameters.fee_params.gas_price_factor".to_string(),
+            ]
+        );
+    }
+}
@@ -24,17 +24,23 @@ use crate::client::{
         },
     },
 };
-use anyhow::Context;
+use builder::{
+    FuelClientBuilder,
+    RetryConfig,
+};
 #[cfg(feature = "subscriptions")]
 use cynic::StreamingOperation;
 use cynic::{
-    http::ReqwestExt,
-    GraphQlResponse,
     Id,
     MutationBuilder,
     Operation,
     QueryBuilder,
 };
+use error::{
+    ClientError,
+    ClientResult,
+    GraphQlResponse,
+};
 use fuel_core_types::{
     fuel_asm::{
         Instruction,
@@ -53,15 +59,28 @@ use fuel_core_types::{
     },
     services::{
         executor::TransactionExecutionStatus,
-        p2p::PeerInfo,
+        p2p::{
+            BannedPeerInfo,
+            PeerId,
+            PeerInfo,
+        },
     },
 };
 #[cfg(feature = "subscriptions")]
-use futures::StreamExt;
+use futures::{
+    stream::BoxStream,
+    StreamExt,
+};
+use futures::{
+    Future,
+    Stream,
+    TryStreamExt,
+};
 use itertools::Itertools;
 use pagination::{
     PageDirection,
     PaginatedResult,
+    PaginationConfig,
     PaginationRequest,
 };
 use schema::{
@@ -95,17 +114,22 @@ use schema::{
 use std::future;
 use std::{
     convert::TryInto,
-    io::{
-        self,
-        ErrorKind,
-    },
     net,
     str::{
         self,
         FromStr,
     },
+    sync::{
+        atomic::{
+            AtomicUsize,
+            Ordering,
+        },
+        Arc,
+    },
+    time::Duration,
 };
 use tai64::Tai64;
+use tokio::sync::Semaphore;
 use tracing as _;
 use types::{
     TransactionResponse,
@@ -120,8 +144,11 @@ use self::schema::{
     },
 };
 
+pub mod builder;
+pub mod error;
 pub mod pagination;
 pub mod schema;
+pub mod transfer;
 pub mod types;
 
 type RegisterId = u32;
@@ -130,43 +157,23 @@ type RegisterId = u32;
 pub struct FuelClient {
     client: reqwest::Client,
     #[cfg(feature = "subscriptions")]
-    cookie: std::sync::Arc<reqwest::cookie::Jar>,
-    url: reqwest::Url,
+    cookie: Arc<reqwest::cookie::Jar>,
+    /// The endpoints of the nodes.
+    urls: Arc<Vec<reqwest::Url>>,
+    /// The index of the endpoint used for requests, shared by all clones.
+    active_url: Arc<AtomicUsize>,
+    timeout: Option<Duration>,
+    retry: RetryConfig,
+    pagination: PaginationConfig,
+    /// Limits the number of page requests of paginated streams.
+    pagination_permits: Option<Arc<Semaphore>>,
 }
 
 impl FromStr for FuelClient {
     type Err = anyhow::Error;
 
     fn from_str(str: &str) -> Result<Self, Self::Err> {
-        let mut raw_url = str.to_string();
-        if !raw_url.starts_with("http") {
-            raw_url = format!("http://{raw_url}");
-        }
-
-        let mut url = reqwest::Url::parse(&raw_url)
-            .map_err(anyhow::Error::msg)
-            .with_context(|| format!("Invalid fuel-core URL: {str}"))?;
-        url.set_path("/graphql");
-
-        #[cfg(feature = "subscriptions")]
-        {
-            let cookie = std::sync::Arc::new(reqwest::cookie::Jar::default());
-            let client = reqwest::Client::builder()
-                .cookie_provider(cookie.clone())
-                .build()
-                .map_err(anyhow::Error::msg)?;
-            Ok(Self {
-                client,
-                cookie,
-                url,
-            })
-        }
-
-        #[cfg(not(feature = "subscriptions"))]
-        {
-            let client = reqwest::Client::new();
-            Ok(Self { client, url })
-        }
+        FuelClientBuilder::new().endpoint(str).build()
     }
 }
 
@@ -182,51 +189,153 @@ where
     }
 }
 
-pub fn from_strings_errors_to_std_error(errors: Vec<String>) -> io::Error {
-    let e = errors
-        .into_iter()
-        .fold(String::from("Response errors"), |mut s, e| {
-            s.push_str("; ");
-            s.push_str(e.as_str());
-            s
-        });
-    io::Error::new(io::ErrorKind::Other, e)
-}
-
 impl FuelClient {
     pub fn new(url: impl AsRef<str>) -> anyhow::Result<Self> {
         Self::from_str(url.as_ref())
     }
 
+    /// Returns the builder of the client with many endpoints, timeouts and retries.
+    pub fn builder() -> FuelClientBuilder {
+        FuelClientBuilder::new()
+    }
+
+    /// Returns the endpoint used for requests.
+    pub fn url(&self) -> &reqwest::Url {
+        let index = self.active_url.load(Ordering::Relaxed);
+        self.urls.get(index).unwrap_or(&self.urls[0])
+    }
+
     /// Send the GraphQL query to the client.
+    ///
+    /// Queries failed because of the transport error or timeout are retried
+    /// according to the [`RetryConfig`], so operations built with the
+    /// [`MutationBuilder`] must be sent with [`FuelClient::mutate`] instead.
+    /// The failed endpoint is replaced with the next healthy one.
     pub async fn query<ResponseData, Vars>(
         &self,
         q: Operation<ResponseData, Vars>,
-    ) -> io::Result<ResponseData>
+    ) -> ClientResult<ResponseData>
     where
         Vars: serde::Serialize,
         ResponseData: serde::de::DeserializeOwned + 'static,
     {
-        let response = self
-            .client
-            .post(self.url.clone())
-            .run_graphql(q)
-            .await
-            .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+        self.send(q, self.retry.max_retries).await
+    }
+
+    /// Send the GraphQL mutation built with the [`MutationBuilder`] to the client.
+    ///
+    /// Unlike queries, mutations are not idempotent and are sent only once.
+    /// The failed endpoint is replaced with the next healthy one.
+    pub async fn mutate<ResponseData, Vars>(
+        &self,
+        q: Operation<ResponseData, Vars>,
+    ) -> ClientResult<ResponseData>
+    where
+        Vars: serde::Serialize,
+        ResponseData: serde::de::DeserializeOwned + 'static,
+    {
+        self.send(q, 0).await
+    }
+
+    async fn send<ResponseData, Vars>(
+        &self,
+        q: Operation<ResponseData, Vars>,
+        max_retries: u32,
+    ) -> ClientResult<ResponseData>
+    where
+        Vars: serde::Serialize,
+        ResponseData: serde::de::DeserializeOwned + 'static,
+    {
+        let mut attempt = 0;
+        loop {
+            let url = self.url().clone();
+            match self.query_endpoint(&url, &q).await {
+                Err(e) if e.is_retriable() => {
+                    tracing::warn!("The request to {url} failed: {e}");
+                    self.failover(&url).await;
+                    if attempt >= max_retries {
+                        return Err(e)
+                    }
+                    tokio::time::sleep(self.retry.backoff(attempt)).await;
+                    attempt = attempt.saturating_add(1);
+                }
+                result => return result,
+            }
+        }
+    }
+
+    async fn query_endpoint<ResponseData, Vars>(
+        &self,
+        url: &reqwest::Url,
+        q: &Operation<ResponseData, Vars>,
+    ) -> ClientResult<ResponseData>
+    where
+        Vars: serde::Serialize,
+        ResponseData: serde::de::DeserializeOwned + 'static,
+    {
+        let mut request = self.client.post(url.clone()).json(q);
+        if let Some(timeout) = self.timeout {
+            request = request.timeout(timeout);
+        }
+        let response = request.send().await?;
+        let status = response.status();
+        let body = response.text().await?;
+
+        match serde_json::from_str::<GraphQlResponse<ResponseData>>(&body) {
+            Ok(response) => Self::decode_response(response),
+            Err(_) if !status.is_success() => Err(ClientError::Transport(format!(
+                "The node responded with {status}: {body}"
+            ))),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Switches from the `failed` endpoint to the next healthy endpoint.
+    /// If none of the endpoints is healthy, switches to the next one.
+    async fn failover(&self, failed: &reqwest::Url) {
+        let len = self.urls.len();
+        let current = self.active_url.load(Ordering::Relaxed);
+        if len <= 1 || self.urls.get(current) != Some(failed) {
+            // Nothing to switch to, or another request already switched.
+            return
+        }
+
+        let next_after = |offset: usize| {
+            current
+                .saturating_add(offset)
+                .checked_rem(len)
+                .unwrap_or_default()
+        };
+        let mut next = next_after(1);
+        for offset in 1..len {
+            let candidate = next_after(offset);
+            if self.is_healthy(&self.urls[candidate]).await {
+                next = candidate;
+                break
+            }
+        }
+        if self
+            .active_url
+            .compare_exchange(current, next, Ordering::Relaxed, Ordering::Relaxed)
+            .is_ok()
+        {
+            tracing::info!("Switched to the endpoint {}", self.urls[next]);
+        }
+    }
 
-        Self::decode_response(response)
+    async fn is_healthy(&self, url: &reqwest::Url) -> bool {
+        let query = schema::Health::build(());
+        matches!(self.query_endpoint(url, &query).await, Ok(r) if r.health)
     }
 
-    fn decode_response<R>(response: GraphQlResponse<R>) -> io::Result<R>
+    fn decode_response<R>(response: GraphQlResponse<R>) -> ClientResult<R>
     where
         R: serde::de::DeserializeOwned + 'static,
     {
         match (response.data, response.errors) {
             (Some(d), _) => Ok(d),
-            (_, Some(e)) => Err(from_strings_errors_to_std_error(
-                e.into_iter().map(|e| e.message).collect(),
-            )),
-            _ => Err(io::Error::new(io::ErrorKind::Other, "Invalid response")),
+            (_, Some(e)) => Err(ClientError::from_graphql_errors(e)),
+            _ => Err(ClientError::Other("Invalid response".to_string())),
         }
     }
 
@@ -235,49 +344,40 @@ impl FuelClient {
     async fn subscribe<ResponseData, Vars>(
         &self,
         q: StreamingOperation<ResponseData, Vars>,
-    ) -> io::Result<impl futures::Stream<Item = io::Result<ResponseData>>>
+    ) -> ClientResult<BoxStream<'static, ClientResult<ResponseData>>>
     where
         Vars: serde::Serialize,
-        ResponseData: serde::de::DeserializeOwned + 'static,
+        ResponseData: serde::de::DeserializeOwned + Send + 'static,
     {
         use core::ops::Deref;
         use eventsource_client as es;
         use hyper_rustls as _;
         use reqwest::cookie::CookieStore;
-        let mut url = self.url.clone();
+        let base_url = self.url().clone();
+        let mut url = base_url.clone();
         url.set_path("/graphql-sub");
         let json_query = serde_json::to_string(&q)?;
         let mut client_builder = es::ClientBuilder::for_url(url.as_str())
-            .map_err(|e| {
-                io::Error::new(
-                    io::ErrorKind::Other,
-                    format!("Failed to start client {e:?}"),
-                )
-            })?
+            .map_err(|e| ClientError::Transport(format!("Failed to start client {e:?}")))?
             .body(json_query)
             .method("POST".to_string())
             .header("content-type", "application/json")
             .map_err(|e| {
-                io::Error::new(
-                    io::ErrorKind::Other,
-                    format!("Failed to add header to client {e:?}"),
-                )
+                ClientError::Transport(format!("Failed to add header to client {e:?}"))
             })?;
 
-        if let Some(value) = self.cookie.deref().cookies(&self.url) {
+        if let Some(value) = self.cookie.deref().cookies(&base_url) {
             let value = value.to_str().map_err(|e| {
-                io::Error::new(
-                    io::ErrorKind::Other,
-                    format!("Unable convert header value to string {e:?}"),
-                )
+                ClientError::Transport(format!(
+                    "Unable convert header value to string {e:?}"
+                ))
             })?;
             client_builder = client_builder
                 .header(reqwest::header::COOKIE.as_str(), value)
                 .map_err(|e| {
-                    io::Error::new(
-                        io::ErrorKind::Other,
-                        format!("Failed to add header from `reqwest` to client {e:?}"),
-                    )
+                    ClientError::Transport(format!(
+                        "Failed to add header from `reqwest` to client {e:?}"
+                    ))
                 })?;
         }
 
@@ -316,41 +416,36 @@ impl FuelClient {
                                             _ => Some(Ok(resp)),
                                         }
                                     }
-                                    Err(e) => Some(Err(io::Error::new(
-                                        io::ErrorKind::Other,
-                                        format!("Decode error: {e:?}"),
-                                    ))),
+                                    Err(e) => Some(Err(e)),
                                 }
                             }
-                            Err(e) => Some(Err(io::Error::new(
-                                io::ErrorKind::Other,
-                                format!("Json error: {e:?}"),
-                            ))),
+                            Err(e) => Some(Err(ClientError::Other(format!(
+                                "Json error: {e:?}"
+                            )))),
                         }
                     }
                     Ok(_) => None,
-                    Err(e) => Some(Err(io::Error::new(
-                        io::ErrorKind::Other,
-                        format!("Graphql error: {e:?}"),
-                    ))),
+                    Err(e) => {
+                        Some(Err(ClientError::Transport(format!("Graphql error: {e:?}"))))
+                    }
                 };
                 futures::future::ready(r)
             });
 
-        Ok(stream)
+        Ok(stream.boxed())
     }
 
-    pub async fn health(&self) -> io::Result<bool> {
+    pub async fn health(&self) -> ClientResult<bool> {
         let query = schema::Health::build(());
         self.query(query).await.map(|r| r.health)
     }
 
-    pub async fn node_info(&self) -> io::Result<types::NodeInfo> {
+    pub async fn node_info(&self) -> ClientResult<types::NodeInfo> {
         let query = schema::node_info::QueryNodeInfo::build(());
         self.query(query).await.map(|r| r.node_info.into())
     }
 
-    pub async fn latest_gas_price(&self) -> io::Result<LatestGasPrice> {
+    pub async fn latest_gas_price(&self) -> ClientResult<LatestGasPrice> {
         let query = schema::gas_price::QueryLatestGasPrice::build(());
         self.query(query).await.map(|r| r.latest_gas_price.into())
     }
@@ -358,19 +453,56 @@ impl FuelClient {
     pub async fn estimate_gas_price(
         &self,
         block_horizon: u32,
-    ) -> io::Result<EstimateGasPrice> {
+    ) -> ClientResult<EstimateGasPrice> {
         let query = schema::gas_price::QueryEstimateGasPrice::build(block_horizon.into());
         self.query(query).await.map(|r| r.estimate_gas_price)
     }
 
-    pub async fn connected_peers_info(&self) -> io::Result<Vec<PeerInfo>> {
+    pub async fn connected_peers_info(&self) -> ClientResult<Vec<PeerInfo>> {
         let query = schema::node_info::QueryPeersInfo::build(());
         self.query(query)
             .await
             .map(|r| r.node_info.peers.into_iter().map(Into::into).collect())
     }
 
-    pub async fn chain_info(&self) -> io::Result<types::ChainInfo> {
+    pub async fn banned_peers_info(&self) -> ClientResult<Vec<BannedPeerInfo>> {
+        let query = schema::node_info::QueryBannedPeersInfo::build(());
+        self.query(query).await.map(|r| {
+            r.node_info
+                .banned_peers
+                .into_iter()
+                .map(Into::into)
+                .collect()
+        })
+    }
+
+    /// Bans the peer for the `duration`, or for the ban duration configured on the node.
+    /// Requires the node to run in the `debug` mode.
+    pub async fn ban_peer(
+        &self,
+        peer_id: &PeerId,
+        duration: Option<Duration>,
+    ) -> ClientResult<bool> {
+        let query =
+            schema::node_info::BanPeerMutation::build(schema::node_info::BanPeerArgs {
+                id: peer_id.to_string(),
+                duration_seconds: duration.map(|duration| duration.as_secs().into()),
+            });
+        self.mutate(query).await.map(|r| r.ban_peer)
+    }
+
+    /// Removes the ban of the peer. Returns `false` if the peer wasn't banned.
+    /// Requires the node to run in the `debug` mode.
+    pub async fn unban_peer(&self, peer_id: &PeerId) -> ClientResult<bool> {
+        let query = schema::node_info::UnbanPeerMutation::build(
+            schema::node_info::UnbanPeerArgs {
+                id: peer_id.to_string(),
+            },
+        );
+        self.mutate(query).await.map(|r| r.unban_peer)
+    }
+
+    pub async fn chain_info(&self) -> ClientResult<types::ChainInfo> {
         let query = schema::chain::ChainQuery::build(());
         self.query(query).await.map(|r| r.chain.into())
     }
@@ -379,7 +511,7 @@ impl FuelClient {
     pub async fn dry_run(
         &self,
         txs: &[Transaction],
-    ) -> io::Result<Vec<TransactionExecutionStatus>> {
+    ) -> ClientResult<Vec<TransactionExecutionStatus>> {
         self.dry_run_opt(txs, None).await
     }
 
@@ -389,7 +521,7 @@ impl FuelClient {
         txs: &[Transaction],
         // Disable utxo input checks (exists, unspent, and valid signature)
         utxo_validation: Option<bool>,
-    ) -> io::Result<Vec<TransactionExecutionStatus>> {
+    ) -> ClientResult<Vec<TransactionExecutionStatus>> {
         let txs = txs
             .iter()
             .map(|tx| HexString(Bytes(tx.to_bytes())))
@@ -399,7 +531,7 @@ impl FuelClient {
                 txs,
                 utxo_validation,
             });
-        let tx_statuses = self.query(query).await.map(|r| r.dry_run)?;
+        let tx_statuses = self.mutate(query).await.map(|r| r.dry_run)?;
         tx_statuses
             .into_iter()
             .map(|tx_status| tx_status.try_into().map_err(Into::into))
@@ -407,7 +539,7 @@ impl FuelClient {
     }
 
     /// Estimate predicates for the transaction
-    pub async fn estimate_predicates(&self, tx: &mut Transaction) -> io::Result<()> {
+    pub async fn estimate_predicates(&self, tx: &mut Transaction) -> ClientResult<()> {
         let serialized_tx = tx.to_bytes();
         let query = schema::tx::EstimatePredicates::build(TxArg {
             tx: HexString(Bytes(serialized_tx)),
@@ -421,13 +553,13 @@ impl FuelClient {
     pub async fn submit(
         &self,
         tx: &Transaction,
-    ) -> io::Result<types::primitives::TransactionId> {
+    ) -> ClientResult<types::primitives::TransactionId> {
         let tx = tx.clone().to_bytes();
         let query = schema::tx::Submit::build(TxArg {
             tx: HexString(Bytes(tx)),
         });
 
-        let id = self.query(query).await.map(|r| r.submit)?.id.into();
+        let id = self.mutate(query).await.map(|r| r.submit)?.id.into();
         Ok(id)
     }
 
@@ -440,7 +572,7 @@ impl FuelClient {
     pub async fn submit_and_await_commit(
         &self,
         tx: &Transaction,
-    ) -> io::Result<TransactionStatus> {
+    ) -> ClientResult<TransactionStatus> {
         use cynic::SubscriptionBuilder;
         let tx = tx.clone().to_bytes();
         let s = schema::tx::SubmitAndAwaitSubscription::build(TxArg {
@@ -448,48 +580,47 @@ impl FuelClient {
         });
 
         let mut stream = self.subscribe(s).await?.map(
-            |r: io::Result<schema::tx::SubmitAndAwaitSubscription>| {
+            |r: ClientResult<schema::tx::SubmitAndAwaitSubscription>| {
                 let status: TransactionStatus = r?.submit_and_await.try_into()?;
-                Result::<_, io::Error>::Ok(status)
+                ClientResult::Ok(status)
             },
         );
 
-        let status = stream.next().await.ok_or(io::Error::new(
-            io::ErrorKind::Other,
-            "Failed to get status from the submission",
+        let status = stream.next().await.ok_or(ClientError::Other(
+            "Failed to get status from the submission".to_string(),
         ))??;
 
         Ok(status)
     }
 
-    pub async fn start_session(&self) -> io::Result<String> {
+    pub async fn start_session(&self) -> ClientResult<String> {
         let query = schema::StartSession::build(());
 
-        self.query(query)
+        self.mutate(query)
             .await
             .map(|r| r.start_session.into_inner())
     }
 
-    pub async fn end_session(&self, id: &str) -> io::Result<bool> {
+    pub async fn end_session(&self, id: &str) -> ClientResult<bool> {
         let query = schema::EndSession::build(IdArg { id: id.into() });
 
-        self.query(query).await.map(|r| r.end_session)
+        self.mutate(query).await.map(|r| r.end_session)
     }
 
-    pub async fn reset(&self, id: &str) -> io::Result<bool> {
+    pub async fn reset(&self, id: &str) -> ClientResult<bool> {
         let query = schema::Reset::build(IdArg { id: id.into() });
 
-        self.query(query).await.map(|r| r.reset)
+        self.mutate(query).await.map(|r| r.reset)
     }
 
-    pub async fn execute(&self, id: &str, op: &Instruction) -> io::Result<bool> {
+    pub async fn execute(&self, id: &str, op: &Instruction) -> ClientResult<bool> {
         let op = serde_json::to_string(op)?;
         let query = schema::Execute::build(schema::ExecuteArgs { id: id.into(), op });
 
-        self.query(query).await.map(|r| r.execute)
+        self.mutate(query).await.map(|r| r.execute)
     }
 
-    pub async fn register(&self, id: &str, register: RegisterId) -> io::Result<Word> {
+    pub async fn register(&self, id: &str, register: RegisterId) -> ClientResult<Word> {
         let query = schema::Register::build(RegisterArgs {
             id: id.into(),
             register: register.into(),
@@ -498,7 +629,7 @@ impl FuelClient {
         Ok(self.query(query).await?.register.0 as Word)
     }
 
-    pub async fn memory(&self, id: &str, start: u32, size: u32) -> io::Result<Vec<u8>> {
+    pub async fn memory(&self, id: &str, start: u32, size: u32) -> ClientResult<Vec<u8>> {
         let query = schema::Memory::build(MemoryArgs {
             id: id.into(),
             start: start.into(),
@@ -515,7 +646,7 @@ impl FuelClient {
         session_id: &str,
         contract: fuel_types::ContractId,
         pc: u64,
-    ) -> io::Result<()> {
+    ) -> ClientResult<()> {
         let operation = SetBreakpoint::build(SetBreakpointArgs {
             id: Id::new(session_id),
             bp: schema::Breakpoint {
@@ -524,7 +655,7 @@ impl FuelClient {
             },
         });
 
-        let response = self.query(operation).await?;
+        let response = self.mutate(operation).await?;
         assert!(
             response.set_breakpoint,
             "Setting breakpoint returned invalid reply"
@@ -536,12 +667,12 @@ impl FuelClient {
         &self,
         session_id: &str,
         enable: bool,
-    ) -> io::Result<()> {
+    ) -> ClientResult<()> {
         let operation = SetSingleStepping::build(SetSingleSteppingArgs {
             id: Id::new(session_id),
             enable,
         });
-        self.query(operation).await?;
+        self.mutate(operation).await?;
         Ok(())
     }
 
@@ -549,27 +680,27 @@ impl FuelClient {
         &self,
         session_id: &str,
         tx: &Transaction,
-    ) -> io::Result<RunResult> {
+    ) -> ClientResult<RunResult> {
         let operation = StartTx::build(StartTxArgs {
             id: Id::new(session_id),
             tx: serde_json::to_string(tx).expect("Couldn't serialize tx to json"),
         });
-        let response = self.query(operation).await?.start_tx;
+        let response = self.mutate(operation).await?.start_tx;
         Ok(response)
     }
 
-    pub async fn continue_tx(&self, session_id: &str) -> io::Result<RunResult> {
+    pub async fn continue_tx(&self, session_id: &str) -> ClientResult<RunResult> {
         let operation = ContinueTx::build(ContinueTxArgs {
             id: Id::new(session_id),
         });
-        let response = self.query(operation).await?.continue_tx;
+        let response = self.mutate(operation).await?.continue_tx;
         Ok(response)
     }
 
     pub async fn transaction(
         &self,
         id: &TxId,
-    ) -> io::Result<Option<TransactionResponse>> {
+    ) -> ClientResult<Option<TransactionResponse>> {
         let query = schema::tx::TransactionQuery::build(TxIdArgs { id: (*id).into() });
 
         let transaction = self.query(query).await?.transaction;
@@ -578,23 +709,17 @@ impl FuelClient {
     }
 
     /// Get the status of a transaction
-    pub async fn transaction_status(&self, id: &TxId) -> io::Result<TransactionStatus> {
+    pub async fn transaction_status(&self, id: &TxId) -> ClientResult<TransactionStatus> {
         let query = schema::tx::TransactionQuery::build(TxIdArgs { id: (*id).into() });
 
         let tx = self.query(query).await?.transaction.ok_or_else(|| {
-            io::Error::new(
-                ErrorKind::NotFound,
-                format!("status not found for transaction {id} "),
-            )
+            ClientError::NotFound(format!("status not found for transaction {id} "))
         })?;
 
         let status = tx
             .status
             .ok_or_else(|| {
-                io::Error::new(
-                    ErrorKind::NotFound,
-                    format!("status not found for transaction {id}"),
-                )
+                ClientError::NotFound(format!("status not found for transaction {id}"))
             })?
             .try_into()?;
         Ok(status)
@@ -603,12 +728,39 @@ impl FuelClient {
     #[tracing::instrument(skip(self), level = "debug")]
     #[cfg(feature = "subscriptions")]
     /// Subscribe to the status of a transaction
+    ///
+    /// If the connection to the node is lost before the final status, the client
+    /// resubscribes according to the [`RetryConfig`] and continues from the last
+    /// seen status.
     pub async fn subscribe_transaction_status(
         &self,
         id: &TxId,
-    ) -> io::Result<impl futures::Stream<Item = io::Result<TransactionStatus>>> {
+    ) -> ClientResult<BoxStream<'static, ClientResult<TransactionStatus>>> {
+        let stream = self.subscribe_transaction_status_once(*id).await?;
+        let subscription = StatusSubscription {
+            client: self.clone(),
+            id: *id,
+            stream: Some(stream),
+            last: None,
+            attempt: 0,
+        };
+
+        Ok(
+            futures::stream::unfold(subscription, |mut subscription| async move {
+                let status = subscription.next().await?;
+                Some((status, subscription))
+            })
+            .boxed(),
+        )
+    }
+
+    #[cfg(feature = "subscriptions")]
+    async fn subscribe_transaction_status_once(
+        &self,
+        id: TxId,
+    ) -> ClientResult<BoxStream<'static, ClientResult<TransactionStatus>>> {
         use cynic::SubscriptionBuilder;
-        let tx_id: TransactionId = (*id).into();
+        let tx_id: TransactionId = id.into();
         let s = schema::tx::StatusChangeSubscription::build(TxIdArgs { id: tx_id });
 
         tracing::debug!("subscribing");
@@ -619,7 +771,7 @@ impl FuelClient {
             Ok(status)
         });
 
-        Ok(stream)
+        Ok(stream.boxed())
     }
 
     #[cfg(feature = "subscriptions")]
@@ -630,7 +782,7 @@ impl FuelClient {
     pub async fn await_transaction_commit(
         &self,
         id: &TxId,
-    ) -> io::Result<TransactionStatus> {
+    ) -> ClientResult<TransactionStatus> {
         // skip until we've reached a final status and then stop consuming the stream
         // to avoid an EOF which the eventsource client considers as an error.
         let status_result = self
@@ -645,10 +797,9 @@ impl FuelClient {
         if let Some(Ok(status)) = status_result {
             Ok(status)
         } else {
-            Err(io::Error::new(
-                io::ErrorKind::Other,
-                format!("Failed to get status for transaction {status_result:?}"),
-            ))
+            Err(ClientError::Other(format!(
+                "Failed to get status for transaction {status_result:?}"
+            )))
         }
     }
 
@@ -656,7 +807,7 @@ impl FuelClient {
     pub async fn transactions(
         &self,
         request: PaginationRequest<String>,
-    ) -> io::Result<PaginatedResult<TransactionResponse, String>> {
+    ) -> ClientResult<PaginatedResult<TransactionResponse, String>> {
         let query = schema::tx::TransactionsQuery::build(request.into());
         let transactions = self.query(query).await?.transactions.try_into()?;
         Ok(transactions)
@@ -667,7 +818,7 @@ impl FuelClient {
         &self,
         owner: &Address,
         request: PaginationRequest<String>,
-    ) -> io::Result<PaginatedResult<TransactionResponse, String>> {
+    ) -> ClientResult<PaginatedResult<TransactionResponse, String>> {
         let owner: schema::Address = (*owner).into();
         let query = schema::tx::TransactionsByOwnerQuery::build((owner, request).into());
 
@@ -675,11 +826,11 @@ impl FuelClient {
         Ok(transactions)
     }
 
-    pub async fn receipts(&self, id: &TxId) -> io::Result<Option<Vec<Receipt>>> {
+    pub async fn receipts(&self, id: &TxId) -> ClientResult<Option<Vec<Receipt>>> {
         let query = schema::tx::TransactionQuery::build(TxIdArgs { id: (*id).into() });
 
         let tx = self.query(query).await?.transaction.ok_or_else(|| {
-            io::Error::new(ErrorKind::NotFound, format!("transaction {id} not found"))
+            ClientError::NotFound(format!("transaction {id} not found"))
         })?;
 
         let receipts = match tx.status {
@@ -707,7 +858,7 @@ impl FuelClient {
     }
 
     #[cfg(feature = "test-helpers")]
-    pub async fn all_receipts(&self) -> io::Result<Vec<Receipt>> {
+    pub async fn all_receipts(&self) -> ClientResult<Vec<Receipt>> {
         let query = schema::tx::AllReceipts::build(());
         let receipts = self.query(query).await?.all_receipts;
 
@@ -723,19 +874,19 @@ impl FuelClient {
         &self,
         blocks_to_produce: u32,
         start_timestamp: Option<u64>,
-    ) -> io::Result<BlockHeight> {
+    ) -> ClientResult<BlockHeight> {
         let query = schema::block::BlockMutation::build(ProduceBlockArgs {
             blocks_to_produce: blocks_to_produce.into(),
             start_timestamp: start_timestamp
                 .map(|timestamp| Tai64Timestamp::from(Tai64(timestamp))),
         });
 
-        let new_height = self.query(query).await?.produce_blocks;
+        let new_height = self.mutate(query).await?.produce_blocks;
 
         Ok(new_height.into())
     }
 
-    pub async fn block(&self, id: &BlockId) -> io::Result<Option<types::Block>> {
+    pub async fn block(&self, id: &BlockId) -> ClientResult<Option<types::Block>> {
         let query = schema::block::BlockByIdQuery::build(BlockByIdArgs {
             id: Some((*id).into()),
         });
@@ -748,7 +899,7 @@ impl FuelClient {
     pub async fn block_by_height(
         &self,
         height: BlockHeight,
-    ) -> io::Result<Option<types::Block>> {
+    ) -> ClientResult<Option<types::Block>> {
         let query = schema::block::BlockByHeightQuery::build(BlockByHeightArgs {
             height: Some(U32(height.into())),
         });
@@ -762,7 +913,7 @@ impl FuelClient {
     pub async fn blocks(
         &self,
         request: PaginationRequest<String>,
-    ) -> io::Result<PaginatedResult<types::Block, String>> {
+    ) -> ClientResult<PaginatedResult<types::Block, String>> {
         let query = schema::block::BlocksQuery::build(request.into());
 
         let blocks = self.query(query).await?.blocks.into();
@@ -770,7 +921,7 @@ impl FuelClient {
         Ok(blocks)
     }
 
-    pub async fn coin(&self, id: &UtxoId) -> io::Result<Option<types::Coin>> {
+    pub async fn coin(&self, id: &UtxoId) -> ClientResult<Option<types::Coin>> {
         let query = schema::coins::CoinByIdQuery::build(CoinByIdArgs {
             utxo_id: (*id).into(),
         });
@@ -784,7 +935,7 @@ impl FuelClient {
         owner: &Address,
         asset_id: Option<&AssetId>,
         request: PaginationRequest<String>,
-    ) -> io::Result<PaginatedResult<types::Coin, String>> {
+    ) -> ClientResult<PaginatedResult<types::Coin, String>> {
         let owner: schema::Address = (*owner).into();
         let asset_id: schema::AssetId = match asset_id {
             Some(asset_id) => (*asset_id).into(),
@@ -803,7 +954,7 @@ impl FuelClient {
         spend_query: Vec<(AssetId, u64, Option<u32>)>,
         // (Utxos, Messages Nonce)
         excluded_ids: Option<(Vec<UtxoId>, Vec<Nonce>)>,
-    ) -> io::Result<Vec<Vec<types::CoinType>>> {
+    ) -> ClientResult<Vec<Vec<types::CoinType>>> {
         let owner: schema::Address = (*owner).into();
         let spend_query: Vec<SpendQueryElementInput> = spend_query
             .iter()
@@ -839,7 +990,10 @@ impl FuelClient {
         Ok(coins_per_asset)
     }
 
-    pub async fn contract(&self, id: &ContractId) -> io::Result<Option<types::Contract>> {
+    pub async fn contract(
+        &self,
+        id: &ContractId,
+    ) -> ClientResult<Option<types::Contract>> {
         let query = schema::contract::ContractByIdQuery::build(ContractByIdArgs {
             id: (*id).into(),
         });
@@ -851,7 +1005,7 @@ impl FuelClient {
         &self,
         id: &ContractId,
         asset: Option<&AssetId>,
-    ) -> io::Result<u64> {
+    ) -> ClientResult<u64> {
         let asset_id: schema::AssetId = match asset {
             Some(asset) => (*asset).into(),
             None => schema::AssetId::default(),
@@ -872,7 +1026,7 @@ impl FuelClient {
         &self,
         owner: &Address,
         asset_id: Option<&AssetId>,
-    ) -> io::Result<u64> {
+    ) -> ClientResult<u64> {
         let owner: schema::Address = (*owner).into();
         let asset_id: schema::AssetId = match asset_id {
             Some(asset_id) => (*asset_id).into(),
@@ -888,7 +1042,7 @@ impl FuelClient {
         &self,
         owner: &Address,
         request: PaginationRequest<String>,
-    ) -> io::Result<PaginatedResult<types::Balance, String>> {
+    ) -> ClientResult<PaginatedResult<types::Balance, String>> {
         let owner: schema::Address = (*owner).into();
         let query = schema::balance::BalancesQuery::build((owner, request).into());
 
@@ -900,7 +1054,7 @@ impl FuelClient {
         &self,
         contract: &ContractId,
         request: PaginationRequest<String>,
-    ) -> io::Result<PaginatedResult<types::ContractBalance, String>> {
+    ) -> ClientResult<PaginatedResult<types::ContractBalance, String>> {
         let contract_id: schema::ContractId = (*contract).into();
         let query =
             schema::contract::ContractBalancesQuery::build((contract_id, request).into());
@@ -911,7 +1065,7 @@ impl FuelClient {
     }
 
     // Retrieve a message by its nonce
-    pub async fn message(&self, nonce: &Nonce) -> io::Result<Option<types::Message>> {
+    pub async fn message(&self, nonce: &Nonce) -> ClientResult<Option<types::Message>> {
         let query = schema::message::MessageQuery::build(NonceArgs {
             nonce: (*nonce).into(),
         });
@@ -923,7 +1077,7 @@ impl FuelClient {
         &self,
         owner: Option<&Address>,
         request: PaginationRequest<String>,
-    ) -> io::Result<PaginatedResult<types::Message, String>> {
+    ) -> ClientResult<PaginatedResult<types::Message, String>> {
         let owner: Option<schema::Address> = owner.map(|owner| (*owner).into());
         let query = schema::message::OwnedMessageQuery::build((owner, request).into());
 
@@ -932,7 +1086,7 @@ impl FuelClient {
         Ok(messages)
     }
 
-    pub async fn message_status(&self, nonce: &Nonce) -> io::Result<MessageStatus> {
+    pub async fn message_status(&self, nonce: &Nonce) -> ClientResult<MessageStatus> {
         let query = schema::message::MessageStatusQuery::build(MessageStatusArgs {
             nonce: (*nonce).into(),
         });
@@ -948,7 +1102,7 @@ impl FuelClient {
         nonce: &Nonce,
         commit_block_id: Option<&BlockId>,
         commit_block_height: Option<BlockHeight>,
-    ) -> io::Result<Option<types::MessageProof>> {
+    ) -> ClientResult<Option<types::MessageProof>> {
         let transaction_id: TransactionId = (*transaction_id).into();
         let nonce: schema::Nonce = (*nonce).into();
         let commit_block_id: Option<schema::BlockId> =
@@ -967,12 +1121,205 @@ impl FuelClient {
     }
 }
 
+/// Streams over all results of paginated queries. Each stream requests pages
+/// of the [`PaginationConfig::page_size`] one by one, following the cursors,
+/// until the last page.
+impl FuelClient {
+    fn paginate<'a, T, F, Fut>(
+        &'a self,
+        fetch: F,
+    ) -> impl Stream<Item = ClientResult<T>> + 'a
+    where
+        T: 'a,
+        F: Fn(PaginationRequest<String>) -> Fut + 'a,
+        Fut: Future<Output = ClientResult<PaginatedResult<T, String>>> + 'a,
+    {
+        // `None` means that the last page was already fetched.
+        let first_cursor: Option<Option<String>> = Some(None);
+        futures::stream::try_unfold(first_cursor, move |cursor| {
+            let page = cursor.map(|cursor| {
+                fetch(PaginationRequest {
+                    cursor,
+                    results: self.pagination.page_size,
+                    direction: PageDirection::Forward,
+                })
+            });
+            async move {
+                let page = match page {
+                    Some(page) => page,
+                    None => return Ok(None),
+                };
+                let _permit = match &self.pagination_permits {
+                    Some(permits) => Some(permits.acquire().await.map_err(|e| {
+                        ClientError::Other(format!("Failed to acquire the permit {e}"))
+                    })?),
+                    None => None,
+                };
+                let page = page.await?;
+                let next_cursor = if page.has_next_page {
+                    page.cursor.map(Some)
+                } else {
+                    None
+                };
+                Ok(Some((page.results, next_cursor)))
+            }
+        })
+        .map_ok(|results| futures::stream::iter(results.into_iter().map(Ok)))
+        .try_flatten()
+    }
+
+    /// Returns the stream of all coins of the owner.
+    pub fn coins_stream(
+        &self,
+        owner: &Address,
+        asset_id: Option<&AssetId>,
+    ) -> impl Stream<Item = ClientResult<types::Coin>> + '_ {
+        let owner = *owner;
+        let asset_id = asset_id.copied();
+        self.paginate(move |request| async move {
+            self.coins(&owner, asset_id.as_ref(), request).await
+        })
+    }
+
+    /// Returns the stream of all blocks from the genesis block.
+    pub fn blocks_stream(&self) -> impl Stream<Item = ClientResult<types::Block>> + '_ {
+        self.paginate(move |request| self.blocks(request))
+    }
+
+    /// Returns the stream of all transactions sorted by the block height.
+    pub fn transactions_stream(
+        &self,
+    ) -> impl Stream<Item = ClientResult<TransactionResponse>> + '_ {
+        self.paginate(move |request| self.transactions(request))
+    }
+
+    /// Returns the stream of all transactions associated with the owner.
+    pub fn transactions_by_owner_stream(
+        &self,
+        owner: &Address,
+    ) -> impl Stream<Item = ClientResult<TransactionResponse>> + '_ {
+        let owner = *owner;
+        self.paginate(move |request| async move {
+            self.transactions_by_owner(&owner, request).await
+        })
+    }
+
+    /// Returns the stream of all messages of the owner, or all messages
+    /// if the owner is not specified.
+    pub fn messages_stream(
+        &self,
+        owner: Option<&Address>,
+    ) -> impl Stream<Item = ClientResult<types::Message>> + '_ {
+        let owner = owner.copied();
+        self.paginate(move |request| async move {
+            self.messages(owner.as_ref(), request).await
+        })
+    }
+
+    /// Returns the stream of all balances of the owner.
+    pub fn balances_stream(
+        &self,
+        owner: &Address,
+    ) -> impl Stream<Item = ClientResult<types::Balance>> + '_ {
+        let owner = *owner;
+        self.paginate(move |request| async move { self.balances(&owner, request).await })
+    }
+
+    /// Returns the stream of all balances of the contract.
+    pub fn contract_balances_stream(
+        &self,
+        contract: &ContractId,
+    ) -> impl Stream<Item = ClientResult<types::ContractBalance>> + '_ {
+        let contract = *contract;
+        self.paginate(move |request| async move {
+            self.contract_balances(&contract, request).await
+        })
+    }
+}
+
+/// The subscription to the status of the transaction that resubscribes
+/// after the loss of the connection.
+#[cfg(feature = "subscriptions")]
+struct StatusSubscription {
+    client: FuelClient,
+    id: TxId,
+    stream: Option<BoxStream<'static, ClientResult<TransactionStatus>>>,
+    last: Option<TransactionStatus>,
+    attempt: u32,
+}
+
+#[cfg(feature = "subscriptions")]
+impl StatusSubscription {
+    async fn next(&mut self) -> Option<ClientResult<TransactionStatus>> {
+        let retry = self.client.retry;
+        loop {
+            let stream = match self.stream.as_mut() {
+                Some(stream) => stream,
+                None => {
+                    if self.is_final() || self.attempt >= retry.max_retries {
+                        return None
+                    }
+                    tokio::time::sleep(retry.backoff(self.attempt)).await;
+                    self.attempt = self.attempt.saturating_add(1);
+                    match self.client.subscribe_transaction_status_once(self.id).await {
+                        Ok(stream) => self.stream = Some(stream),
+                        Err(e) if e.is_retriable() => {
+                            self.client.failover(&self.client.url().clone()).await;
+                        }
+                        Err(e) => {
+                            self.attempt = retry.max_retries;
+                            return Some(Err(e))
+                        }
+                    }
+                    continue
+                }
+            };
+
+            match stream.next().await {
+                Some(Ok(status)) => {
+                    self.attempt = 0;
+                    // The node sends the current status after the resubscription.
+                    let duplicate = matches!(
+                        (&self.last, &status),
+                        (
+                            Some(TransactionStatus::Submitted { .. }),
+                            TransactionStatus::Submitted { .. }
+                        )
+                    );
+                    self.last = Some(status.clone());
+                    if !duplicate {
+                        return Some(Ok(status))
+                    }
+                }
+                Some(Err(e)) if e.is_retriable() && retry.max_retries > 0 => {
+                    tracing::warn!("The subscription to {} failed: {e}", self.id);
+                    self.stream = None;
+                    self.client.failover(&self.client.url().clone()).await;
+                }
+                Some(Err(e)) => return Some(Err(e)),
+                None => self.stream = None,
+            }
+        }
+    }
+
+    fn is_final(&self) -> bool {
+        matches!(
+            self.last,
+            Some(
+                TransactionStatus::Success { .. }
+                    | TransactionStatus::SqueezedOut { .. }
+                    | TransactionStatus::Failure { .. }
+            )
+        )
+    }
+}
+
 #[cfg(any(test, feature = "test-helpers"))]
 impl FuelClient {
     pub async fn transparent_transaction(
         &self,
         id: &TxId,
-    ) -> io::Result<Option<Transaction>> {
+    ) -> ClientResult<Option<Transaction>> {
         let query = schema::tx::TransactionQuery::build(TxIdArgs { id: (*id).into() });
 
         let transaction = self.query(query).await?.transaction;
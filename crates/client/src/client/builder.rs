@@ -0,0 +1,237 @@
+use super::{
+    pagination::PaginationConfig,
+    FuelClient,
+};
+use anyhow::Context;
+use std::{
+    sync::{
+        atomic::AtomicUsize,
+        Arc,
+    },
+    time::Duration,
+};
+use tokio::sync::Semaphore;
+
+/// The policy of retries of idempotent requests.
+///
+/// Queries that failed because of the transport error or timeout are retried with
+/// the exponential backoff. Mutations sent with [`FuelClient::mutate`] are never
+/// retried because they may be already applied by the node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryConfig {
+    /// The maximum number of retries of one request. `0` disables retries.
+    /// The same limit is used to resubscribe to the subscriptions.
+    pub max_retries: u32,
+    /// The delay before the first retry.
+    pub initial_backoff: Duration,
+    /// The maximum delay between retries.
+    pub max_backoff: Duration,
+}
+
+impl RetryConfig {
+    /// Returns the delay before the `attempt` retry, starting from `0`.
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        self.initial_backoff
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max_backoff)
+    }
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(10),
+        }
+    }
+}
+
+/// The builder of the [`FuelClient`] with many endpoints, timeouts and retries.
+///
+/// The client sends requests to one endpoint at a time. If the endpoint doesn't respond,
+/// the client switches to the next healthy endpoint.
+#[derive(Debug, Clone, Default)]
+pub struct FuelClientBuilder {
+    endpoints: Vec<String>,
+    timeout: Option<Duration>,
+    retry: RetryConfig,
+    pagination: PaginationConfig,
+}
+
+impl FuelClientBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the URL of the node. The first endpoint is used until it fails.
+    pub fn endpoint(mut self, url: impl AsRef<str>) -> Self {
+        self.endpoints.push(url.as_ref().to_string());
+        self
+    }
+
+    /// Adds the URLs of the nodes.
+    pub fn endpoints<I, S>(mut self, urls: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.endpoints
+            .extend(urls.into_iter().map(|url| url.as_ref().to_string()));
+        self
+    }
+
+    /// Sets the timeout of each request to the node.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the policy of retries.
+    pub fn retry(mut self, retry: RetryConfig) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Sets the page size and the concurrency limit of paginated streams.
+    pub fn pagination(mut self, pagination: PaginationConfig) -> Self {
+        self.pagination = pagination;
+        self
+    }
+
+    pub fn build(self) -> anyhow::Result<FuelClient> {
+        if self.endpoints.is_empty() {
+            anyhow::bail!("At least one endpoint of the fuel-core is required");
+        }
+        let urls = self
+            .endpoints
+            .iter()
+            .map(|url| parse_url(url))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        let pagination_permits = self
+            .pagination
+            .max_concurrent_requests
+            .map(|limit| Arc::new(Semaphore::new(limit.max(1))));
+
+        #[cfg(feature = "subscriptions")]
+        {
+            let cookie = Arc::new(reqwest::cookie::Jar::default());
+            let client = reqwest::Client::builder()
+                .cookie_provider(cookie.clone())
+                .build()
+                .map_err(anyhow::Error::msg)?;
+            Ok(FuelClient {
+                client,
+                cookie,
+                urls: Arc::new(urls),
+                active_url: Arc::new(AtomicUsize::new(0)),
+                timeout: self.timeout,
+                retry: self.retry,
+                pagination: self.pagination,
+                pagination_permits,
+            })
+        }
+
+        #[cfg(not(feature = "subscriptions"))]
+        {
+            let client = reqwest::Client::new();
+            Ok(FuelClient {
+                client,
+                urls: Arc::new(urls),
+                active_url: Arc::new(AtomicUsize::new(0)),
+                timeout: self.timeout,
+                retry: self.retry,
+                pagination: self.pagination,
+                pagination_permits,
+            })
+        }
+    }
+}
+
+fn parse_url(str: &str) -> anyhow::Result<reqwest::Url> {
+    let mut raw_url = str.to_string();
+    if !raw_url.starts_with("http") {
+        raw_url = format!("http://{raw_url}");
+    }
+
+    let mut url = reqwest::Url::parse(&raw_url)
+        .map_err(anyhow::Error::msg)
+        .with_context(|| format!("Invalid fuel-core URL: {str}"))?;
+    url.set_path("/graphql");
+    Ok(url)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_grows_exponentially_up_to_the_limit() {
+        // Given
+        let retry = RetryConfig {
+            max_retries: 10,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(1),
+        };
+
+        // When
+        let backoffs = (0..6)
+            .map(|attempt| retry.backoff(attempt))
+            .collect::<Vec<_>>();
+
+        // Then
+        assert_eq!(
+            backoffs,
+            vec![
+                Duration::from_millis(100),
+                Duration::from_millis(200),
+                Duration::from_millis(400),
+                Duration::from_millis(800),
+                Duration::from_secs(1),
+                Duration::from_secs(1),
+            ]
+        );
+    }
+
+    #[test]
+    fn default_retry_config_retries_requests_and_subscriptions() {
+        // When
+        let retry = RetryConfig::default();
+
+        // Then
+        assert!(retry.max_retries > 0);
+    }
+
+    #[test]
+    fn build_fails_without_endpoints() {
+        // When
+        let result = FuelClientBuilder::new().build();
+
+        // Then
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn build_parses_all_endpoints() {
+        // When
+        let client = FuelClientBuilder::new()
+            .endpoint("127.0.0.1:4000")
+            .endpoints(["http://node-1:4000", "https://node-2"])
+            .build()
+            .unwrap();
+
+        // Then
+        assert_eq!(
+            client
+                .urls
+                .iter()
+                .map(|url| url.as_str())
+                .collect::<Vec<_>>(),
+            vec![
+                "http://127.0.0.1:4000/graphql",
+                "http://node-1:4000/graphql",
+                "https://node-2/graphql",
+            ]
+        );
+    }
+}
@@ -0,0 +1,267 @@
+//! The errors returned by the [`FuelClient`](super::FuelClient).
+
+use crate::client::schema::ConversionError;
+use fuel_core_types::services::{
+    graphql_api::error_extensions,
+    txpool::ErrorKind as TxPoolErrorKind,
+};
+use std::{
+    fmt,
+    io,
+    str::FromStr,
+};
+
+/// The result of the request to the node.
+pub type ClientResult<T> = Result<T, ClientError>;
+
+/// The error of the request to the node.
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum ClientError {
+    /// The request didn't reach the node, or the node returned an invalid response.
+    #[error("Transport error: {0}")]
+    Transport(String),
+    /// The node didn't respond in time.
+    #[error("The request timed out")]
+    Timeout,
+    /// The node returned errors that don't have a dedicated variant.
+    #[error("{}", GraphQlErrors(.0))]
+    GraphQl(Vec<GraphQlError>),
+    /// The requested entity doesn't exist.
+    #[error("{0}")]
+    NotFound(String),
+    /// The TxPool rejected the transaction.
+    #[error("Response errors; {message}")]
+    TxPool {
+        /// The kind of the error of the TxPool.
+        kind: TxPoolErrorKind,
+        /// The description of the error from the node.
+        message: String,
+    },
+    /// The response from the node can't be converted into the client type.
+    #[error(transparent)]
+    Conversion(#[from] ConversionError),
+    /// Any other error.
+    #[error("{0}")]
+    Other(String),
+}
+
+impl ClientError {
+    /// Returns `true` if the requested entity doesn't exist.
+    pub fn is_not_found(&self) -> bool {
+        matches!(self, ClientError::NotFound(_))
+    }
+
+    /// Returns `true` if the request may succeed if it is sent again,
+    /// for example, to another endpoint.
+    pub fn is_retriable(&self) -> bool {
+        matches!(self, ClientError::Transport(_) | ClientError::Timeout)
+    }
+
+    /// Returns the kind of the error if the TxPool rejected the transaction.
+    pub fn txpool_error(&self) -> Option<TxPoolErrorKind> {
+        match self {
+            ClientError::TxPool { kind, .. } => Some(*kind),
+            _ => None,
+        }
+    }
+
+    /// Creates the error from the errors in the GraphQL response.
+    ///
+    /// If the response contains exactly one error with a known code,
+    /// the dedicated variant is used.
+    pub fn from_graphql_errors(mut errors: Vec<GraphQlError>) -> Self {
+        if errors.len() == 1 {
+            let error = &errors[0];
+            match error.code.as_deref() {
+                Some(error_extensions::TXPOOL) => {
+                    let kind = error
+                        .txpool_error
+                        .as_deref()
+                        .and_then(|kind| TxPoolErrorKind::from_str(kind).ok());
+                    if let Some(kind) = kind {
+                        let error = errors.remove(0);
+                        return ClientError::TxPool {
+                            kind,
+                            message: error.message,
+                        }
+                    }
+                }
+                Some(error_extensions::NOT_FOUND) => {
+                    let error = errors.remove(0);
+                    return ClientError::NotFound(error.message)
+                }
+                _ => {}
+            }
+        }
+        ClientError::GraphQl(errors)
+    }
+}
+
+impl From<reqwest::Error> for ClientError {
+    fn from(e: reqwest::Error) -> Self {
+        if e.is_timeout() {
+            ClientError::Timeout
+        } else {
+            ClientError::Transport(e.to_string())
+        }
+    }
+}
+
+impl From<serde_json::Error> for ClientError {
+    fn from(e: serde_json::Error) -> Self {
+        ClientError::Other(e.to_string())
+    }
+}
+
+impl From<ClientError> for io::Error {
+    fn from(e: ClientError) -> Self {
+        let kind = match &e {
+            ClientError::NotFound(_) => io::ErrorKind::NotFound,
+            ClientError::Timeout => io::ErrorKind::TimedOut,
+            _ => io::ErrorKind::Other,
+        };
+        io::Error::new(kind, e)
+    }
+}
+
+/// The error from the `errors` of the GraphQL response.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
+#[serde(from = "RawGraphQlError")]
+pub struct GraphQlError {
+    /// The description of the error.
+    pub message: String,
+    /// The path to the field of the response that caused the error.
+    pub path: Vec<String>,
+    /// The code of the error from the `extensions`.
+    pub code: Option<String>,
+    /// The kind of the TxPool error from the `extensions`.
+    pub txpool_error: Option<String>,
+}
+
+#[derive(serde::Deserialize)]
+struct RawGraphQlError {
+    message: String,
+    path: Option<Vec<serde_json::Value>>,
+    extensions: Option<RawExtensions>,
+}
+
+#[derive(Default, serde::Deserialize)]
+struct RawExtensions {
+    code: Option<String>,
+    txpool_error: Option<String>,
+}
+
+impl From<RawGraphQlError> for GraphQlError {
+    fn from(error: RawGraphQlError) -> Self {
+        let extensions = error.extensions.unwrap_or_default();
+        let path = error
+            .path
+            .unwrap_or_default()
+            .into_iter()
+            .map(|segment| match segment {
+                serde_json::Value::String(field) => field,
+                index => index.to_string(),
+            })
+            .collect();
+        Self {
+            message: error.message,
+            path,
+            code: extensions.code,
+            txpool_error: extensions.txpool_error,
+        }
+    }
+}
+
+/// The response of the GraphQL API.
+#[derive(Debug, serde::Deserialize)]
+pub(crate) struct GraphQlResponse<T> {
+    pub data: Option<T>,
+    pub errors: Option<Vec<GraphQlError>>,
+}
+
+struct GraphQlErrors<'a>(&'a [GraphQlError]);
+
+impl fmt::Display for GraphQlErrors<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Response errors")?;
+        for error in self.0 {
+            write!(f, "; {}", error.message)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decode(errors: serde_json::Value) -> ClientError {
+        let response: GraphQlResponse<()> =
+            serde_json::from_value(serde_json::json!({ "errors": errors })).unwrap();
+        ClientError::from_graphql_errors(response.errors.unwrap())
+    }
+
+    #[test]
+    fn txpool_error_is_decoded_from_extensions() {
+        // Given
+        let errors = serde_json::json!([{
+            "message": "Transaction is not inserted. UTXO is spent: 0x00",
+            "path": ["submit"],
+            "extensions": {
+                "code": "TXPOOL",
+                "txpool_error": "NotInsertedInputUtxoIdSpent"
+            }
+        }]);
+
+        // When
+        let error = decode(errors);
+
+        // Then
+        assert_eq!(
+            error.txpool_error(),
+            Some(TxPoolErrorKind::NotInsertedInputUtxoIdSpent)
+        );
+        assert_eq!(
+            error.to_string(),
+            "Response errors; Transaction is not inserted. UTXO is spent: 0x00"
+        );
+    }
+
+    #[test]
+    fn not_found_error_is_decoded_from_extensions() {
+        // Given
+        let errors = serde_json::json!([{
+            "message": "Message is not found",
+            "extensions": { "code": "NOT_FOUND" }
+        }]);
+
+        // When
+        let error = decode(errors);
+
+        // Then
+        assert!(error.is_not_found());
+        assert_eq!(io::Error::from(error).kind(), io::ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn errors_without_known_code_keep_codes_and_paths() {
+        // Given
+        let errors = serde_json::json!([
+            { "message": "first", "path": ["blocks", 1, "header"] },
+            { "message": "second", "extensions": { "code": "UNKNOWN" } }
+        ]);
+
+        // When
+        let error = decode(errors);
+
+        // Then
+        let errors = match &error {
+            ClientError::GraphQl(errors) => errors,
+            _ => panic!("Expected GraphQL errors, got {error:?}"),
+        };
+        assert_eq!(errors[0].path, vec!["blocks", "1", "header"]);
+        assert_eq!(errors[1].code.as_deref(), Some("UNKNOWN"));
+        assert_eq!(error.to_string(), "Response errors; first; second");
+    }
+}
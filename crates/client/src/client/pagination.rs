@@ -22,3 +22,23 @@ pub struct PaginatedResult<T, C> {
     pub has_next_page: bool,
     pub has_previous_page: bool,
 }
+
+/// The configuration of streams that follow the cursors of paginated queries,
+/// like [`FuelClient::coins_stream`](super::FuelClient::coins_stream).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PaginationConfig {
+    /// The number of results requested in one page.
+    pub page_size: i32,
+    /// The maximum number of page requests sent at the same time by all streams
+    /// of the client and its clones. `None` means no limit.
+    pub max_concurrent_requests: Option<usize>,
+}
+
+impl Default for PaginationConfig {
+    fn default() -> Self {
+        Self {
+            page_size: 100,
+            max_concurrent_requests: None,
+        }
+    }
+}
@@ -4,6 +4,7 @@ use crate::client::schema::{
     U64,
 };
 use fuel_core_types::services::p2p::{
+    BannedPeerInfo as BannedPeer,
     HeartbeatData,
     PeerId,
 };
@@ -75,6 +76,69 @@ impl From<PeerInfo> for fuel_core_types::services::p2p::PeerInfo {
     }
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "NodeInfo")]
+pub struct BannedPeersInfo {
+    pub banned_peers: Vec<BannedPeerInfo>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct QueryBannedPeersInfo {
+    pub node_info: BannedPeersInfo,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct BannedPeerInfo {
+    pub id: String,
+    pub banned_until_ms: U64,
+}
+
+impl From<BannedPeerInfo> for BannedPeer {
+    fn from(info: BannedPeerInfo) -> Self {
+        Self {
+            id: PeerId::from_str(info.id.as_str()).unwrap_or_default(),
+            banned_until: UNIX_EPOCH
+                .checked_add(Duration::from_millis(info.banned_until_ms.0))
+                .unwrap_or(UNIX_EPOCH),
+        }
+    }
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct BanPeerArgs {
+    pub id: String,
+    pub duration_seconds: Option<U64>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    variables = "BanPeerArgs",
+    graphql_type = "Mutation"
+)]
+pub struct BanPeerMutation {
+    #[arguments(id: $id, durationSeconds: $duration_seconds)]
+    pub ban_peer: bool,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct UnbanPeerArgs {
+    pub id: String,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    variables = "UnbanPeerArgs",
+    graphql_type = "Mutation"
+)]
+pub struct UnbanPeerMutation {
+    #[arguments(id: $id)]
+    pub unban_peer: bool,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -92,4 +156,28 @@ mod tests {
         let operation = QueryPeersInfo::build(());
         insta::assert_snapshot!(operation.query)
     }
+
+    #[test]
+    fn banned_peers_info_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = QueryBannedPeersInfo::build(());
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn ban_peer_mutation_gql_output() {
+        use cynic::MutationBuilder;
+        let operation = BanPeerMutation::build(BanPeerArgs {
+            id: String::new(),
+            duration_seconds: None,
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn unban_peer_mutation_gql_output() {
+        use cynic::MutationBuilder;
+        let operation = UnbanPeerMutation::build(UnbanPeerArgs { id: String::new() });
+        insta::assert_snapshot!(operation.query)
+    }
 }
@@ -0,0 +1,316 @@
+//! The helpers to build, sign and submit transfers of coins.
+//!
+//! The [`FuelClient::build_transfer`] selects coins of the signer to cover the outputs
+//! and the fee, adds change outputs, estimates the gas of the script with the dry run
+//! and signs the transaction.
+
+#[cfg(feature = "subscriptions")]
+use super::types::TransactionStatus;
+use super::{
+    error::{
+        ClientError,
+        ClientResult,
+    },
+    types::CoinType,
+    FuelClient,
+};
+use fuel_core_types::{
+    fuel_asm::{
+        op,
+        RegId,
+    },
+    fuel_crypto::{
+        Message,
+        SecretKey,
+        Signature,
+    },
+    fuel_tx::{
+        field::Witnesses,
+        Address,
+        AssetId,
+        ConsensusParameters,
+        Finalizable,
+        Input,
+        Output,
+        Receipt,
+        Script,
+        Transaction,
+        TransactionBuilder,
+        TransactionFee,
+        TxPointer,
+        UniqueIdentifier,
+        Witness,
+    },
+};
+use std::collections::BTreeMap;
+
+/// The number of attempts to select coins that cover the fee. Each attempt uses
+/// the fee of the transaction from the previous attempt.
+const MAX_FEE_ATTEMPTS: usize = 3;
+
+/// The margin in percents added to the gas used by the script during the dry run.
+/// The dry run is executed against the latest state, and the script may use more
+/// gas by the time the transaction is included in the block.
+const GAS_MARGIN_PERCENT: u64 = 20;
+
+/// The owner of coins that signs transactions spending them.
+pub trait Signer: Send + Sync {
+    /// The address of the owner of coins.
+    fn address(&self) -> Address;
+
+    /// Signs the `message` with the key of the owner.
+    fn sign(&self, message: &Message) -> Signature;
+}
+
+impl Signer for SecretKey {
+    fn address(&self) -> Address {
+        Input::owner(&self.public_key())
+    }
+
+    fn sign(&self, message: &Message) -> Signature {
+        Signature::sign(self, message)
+    }
+}
+
+/// The options of the transfer.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TransferOptions {
+    /// The gas price of the transaction. By default, the latest gas price of the node.
+    pub gas_price: Option<u64>,
+    /// The gas limit of the script. By default, the gas used by the script
+    /// during the dry run with the margin of 20 percents.
+    pub script_gas_limit: Option<u64>,
+}
+
+impl FuelClient {
+    /// Builds the signed transaction that creates `outputs` from coins of the `signer`.
+    ///
+    /// Only `Output::Coin` outputs are funded. Not spent coins return to the signer
+    /// with change outputs, one per asset.
+    pub async fn build_transfer<S>(
+        &self,
+        signer: &S,
+        outputs: Vec<Output>,
+        options: TransferOptions,
+    ) -> ClientResult<Transaction>
+    where
+        S: Signer + ?Sized,
+    {
+        let owner = signer.address();
+        let params = self.chain_info().await?.consensus_parameters;
+        let gas_price = match options.gas_price {
+            Some(gas_price) => gas_price,
+            None => self.latest_gas_price().await?.gas_price,
+        };
+        let required = required_amounts(&outputs)?;
+
+        let mut fee = 0u64;
+        for _ in 0..MAX_FEE_ATTEMPTS {
+            let mut spend_query = required.clone();
+            let base_amount = spend_query.entry(params.base_asset_id).or_default();
+            *base_amount = base_amount.checked_add(fee).ok_or_else(|| {
+                ClientError::Other("The amount of the base asset overflows".to_string())
+            })?;
+            let spend_query = spend_query
+                .into_iter()
+                .filter(|(_, amount)| *amount > 0)
+                .map(|(asset_id, amount)| (asset_id, amount, None))
+                .collect::<Vec<_>>();
+            if spend_query.is_empty() {
+                return Err(ClientError::Other(
+                    "The transfer doesn't spend any coins".to_string(),
+                ))
+            }
+            let coins = self
+                .coins_to_spend(&owner, spend_query, None)
+                .await?
+                .into_iter()
+                .flatten()
+                .collect::<Vec<_>>();
+
+            let build = |script_gas_limit| {
+                build_script(
+                    owner,
+                    &coins,
+                    &outputs,
+                    &params,
+                    gas_price,
+                    script_gas_limit,
+                )
+            };
+            let script_gas_limit = match options.script_gas_limit {
+                Some(script_gas_limit) => script_gas_limit,
+                None => {
+                    // Leaves the room for the gas of the transaction bytes.
+                    let max_script_gas = params.tx_params.max_gas_per_tx >> 1;
+                    let gas_used =
+                        self.estimate_script_gas(build(max_script_gas)).await?;
+                    with_gas_margin(gas_used).min(max_script_gas)
+                }
+            };
+            let script = build(script_gas_limit);
+
+            let max_fee = TransactionFee::checked_from_tx(
+                &params.gas_costs,
+                &params.fee_params,
+                &script,
+            )
+            .ok_or_else(|| {
+                ClientError::Other("The fee of the transaction overflows".to_string())
+            })?
+            .max_fee();
+
+            if max_fee <= fee {
+                return Ok(sign_script(signer, script, &params).into())
+            }
+            fee = max_fee;
+        }
+
+        Err(ClientError::Other(format!(
+            "Failed to select coins covering the fee of the transfer after \
+            {MAX_FEE_ATTEMPTS} attempts"
+        )))
+    }
+
+    /// Builds the transfer with [`FuelClient::build_transfer`], submits it and
+    /// waits for it either to be included in a block or removed from `TxPool`.
+    #[cfg(feature = "subscriptions")]
+    pub async fn transfer<S>(
+        &self,
+        signer: &S,
+        outputs: Vec<Output>,
+        options: TransferOptions,
+    ) -> ClientResult<TransactionStatus>
+    where
+        S: Signer + ?Sized,
+    {
+        let tx = self.build_transfer(signer, outputs, options).await?;
+        self.submit_and_await_commit(&tx).await
+    }
+
+    /// Returns the gas used by the script during the dry run without UTXO validation.
+    async fn estimate_script_gas(&self, script: Script) -> ClientResult<u64> {
+        let statuses = self.dry_run_opt(&[script.into()], Some(false)).await?;
+        statuses
+            .iter()
+            .flat_map(|status| status.result.receipts())
+            .find_map(|receipt| match receipt {
+                Receipt::ScriptResult { gas_used, .. } => Some(*gas_used),
+                _ => None,
+            })
+            .ok_or_else(|| {
+                ClientError::Other(
+                    "The dry run of the transfer didn't return the script result"
+                        .to_string(),
+                )
+            })
+    }
+}
+
+/// Returns the `gas_used` increased by [`GAS_MARGIN_PERCENT`] percents.
+fn with_gas_margin(gas_used: u64) -> u64 {
+    let margin = gas_used
+        .saturating_mul(GAS_MARGIN_PERCENT)
+        .checked_div(100)
+        .unwrap_or_default();
+    gas_used.saturating_add(margin)
+}
+
+/// Returns the sum of amounts of coin outputs per asset.
+fn required_amounts(outputs: &[Output]) -> ClientResult<BTreeMap<AssetId, u64>> {
+    let mut required = BTreeMap::<AssetId, u64>::new();
+    for output in outputs {
+        if let Output::Coin {
+            amount, asset_id, ..
+        } = output
+        {
+            let total = required.entry(*asset_id).or_default();
+            *total = total.checked_add(*amount).ok_or_else(|| {
+                ClientError::Other(format!(
+                    "The amount of the asset {asset_id} overflows"
+                ))
+            })?;
+        }
+    }
+    Ok(required)
+}
+
+fn build_script(
+    owner: Address,
+    coins: &[CoinType],
+    outputs: &[Output],
+    params: &ConsensusParameters,
+    gas_price: u64,
+    script_gas_limit: u64,
+) -> Script {
+    let script = [op::ret(RegId::ONE)].into_iter().collect();
+    let mut builder = TransactionBuilder::script(script, vec![]);
+    builder
+        .script_gas_limit(script_gas_limit)
+        .gas_price(gas_price)
+        .with_params(params.clone());
+
+    let mut change_assets = vec![params.base_asset_id];
+    for coin in coins {
+        match coin {
+            CoinType::Coin(coin) => {
+                builder.add_input(Input::coin_signed(
+                    coin.utxo_id,
+                    coin.owner,
+                    coin.amount,
+                    coin.asset_id,
+                    TxPointer::default(),
+                    0,
+                    coin.maturity.into(),
+                ));
+                if !change_assets.contains(&coin.asset_id) {
+                    change_assets.push(coin.asset_id);
+                }
+            }
+            CoinType::MessageCoin(message) => {
+                builder.add_input(Input::message_coin_signed(
+                    message.sender,
+                    message.recipient,
+                    message.amount,
+                    message.nonce,
+                    0,
+                ));
+            }
+            CoinType::Unknown => {}
+        }
+    }
+
+    for output in outputs {
+        builder.add_output(*output);
+    }
+    for asset_id in change_assets {
+        builder.add_output(Output::change(owner, 0, asset_id));
+    }
+    // The placeholder of the signature, so the size of the transaction and the fee
+    // don't change after signing.
+    builder.add_witness(Witness::from(vec![0u8; Signature::LEN]));
+
+    builder.finalize()
+}
+
+fn sign_script<S>(signer: &S, mut script: Script, params: &ConsensusParameters) -> Script
+where
+    S: Signer + ?Sized,
+{
+    let id = script.id(&params.chain_id);
+    let signature = signer.sign(&Message::from_bytes(*id));
+    script.witnesses_mut()[0] = Witness::from(signature.to_vec());
+    script
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn with_gas_margin_adds_margin_to_gas_used() {
+        assert_eq!(with_gas_margin(0), 0);
+        assert_eq!(with_gas_margin(1_000), 1_200);
+        assert_eq!(with_gas_margin(u64::MAX), u64::MAX);
+    }
+}
@@ -7,6 +7,7 @@ use fuel_core_types::{
     fuel_tx::ConsensusParameters,
 };
 
+#[derive(serde::Serialize)]
 pub struct ChainInfo {
     pub da_height: u64,
     pub name: String,
@@ -9,7 +9,7 @@ use crate::client::{
     PaginatedResult,
 };
 
-#[derive(Debug)]
+#[derive(Debug, serde::Serialize)]
 pub enum CoinType {
     Coin(Coin),
     MessageCoin(MessageCoin),
@@ -26,7 +26,7 @@ impl CoinType {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, serde::Serialize)]
 pub struct Coin {
     pub amount: u64,
     pub block_created: u32,
@@ -36,7 +36,7 @@ pub struct Coin {
     pub owner: Address,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, serde::Serialize)]
 pub struct MessageCoin {
     pub amount: u64,
     pub sender: Address,
@@ -9,13 +9,14 @@ use crate::client::{
     PaginatedResult,
 };
 
+#[derive(serde::Serialize)]
 pub struct Contract {
     pub id: ContractId,
     pub bytecode: Bytes,
     pub salt: Salt,
 }
 
-#[derive(Debug)]
+#[derive(Debug, serde::Serialize)]
 pub struct ContractBalance {
     pub contract: ContractId,
     pub amount: u64,
@@ -1,6 +1,7 @@
 use crate::client::schema;
 use fuel_core_types::fuel_types::BlockHeight;
 
+#[derive(serde::Serialize)]
 pub struct LatestGasPrice {
     pub gas_price: u64,
     pub block_height: BlockHeight,
@@ -16,6 +17,7 @@ impl From<schema::gas_price::LatestGasPrice> for LatestGasPrice {
     }
 }
 
+#[derive(serde::Serialize)]
 pub struct EstimateGasPrice {
     pub gas_price: u64,
 }
@@ -12,7 +12,7 @@ use crate::client::{
     PaginatedResult,
 };
 
-#[derive(Debug)]
+#[derive(Debug, serde::Serialize)]
 pub struct Message {
     pub amount: u64,
     pub sender: Address,
@@ -22,7 +22,7 @@ pub struct Message {
     pub da_height: u64,
 }
 
-#[derive(Debug)]
+#[derive(Debug, serde::Serialize)]
 pub struct MessageProof {
     /// Proof that message is contained within the provided block header.
     pub message_proof: MerkleProof,
@@ -46,7 +46,7 @@ pub struct MessageProof {
     pub data: Bytes,
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, serde::Serialize)]
 pub enum MessageStatus {
     Unspent,
     Spent,
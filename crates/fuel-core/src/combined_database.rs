@@ -2,23 +2,33 @@ use crate::database::{
     database_description::{
         off_chain::OffChain,
         on_chain::OnChain,
+        p2p::P2p,
         relayer::Relayer,
     },
+    inspect::{
+        self,
+        Inconsistency,
+    },
+    migration::MigrationReport,
     Database,
     Result as DatabaseResult,
 };
+#[cfg(feature = "rocksdb")]
+use crate::service::config::DbType;
 use fuel_core_storage::Result as StorageResult;
 use fuel_core_types::{
     blockchain::primitives::DaBlockHeight,
     fuel_types::BlockHeight,
 };
+use std::num::NonZeroU32;
 
-/// A database that combines the on-chain, off-chain and relayer databases into one entity.
+/// A database that combines the on-chain, off-chain, relayer and p2p databases into one entity.
 #[derive(Default, Clone)]
 pub struct CombinedDatabase {
     on_chain: Database<OnChain>,
     off_chain: Database<OffChain>,
     relayer: Database<Relayer>,
+    p2p: Database<P2p>,
 }
 
 impl CombinedDatabase {
@@ -26,24 +36,71 @@ impl CombinedDatabase {
         on_chain: Database<OnChain>,
         off_chain: Database<OffChain>,
         relayer: Database<Relayer>,
+        p2p: Database<P2p>,
     ) -> Self {
         Self {
             on_chain,
             off_chain,
             relayer,
+            p2p,
         }
     }
 
     #[cfg(feature = "rocksdb")]
     pub fn open(path: &std::path::Path, capacity: usize) -> DatabaseResult<Self> {
+        Self::open_with_config(path, capacity, &Default::default())
+    }
+
+    /// Opens the databases with the `config` of RocksDB columns.
+    /// The `config` is shared by all databases, so each configured column
+    /// should belong to at least one of them.
+    #[cfg(feature = "rocksdb")]
+    pub fn open_with_config(
+        path: &std::path::Path,
+        capacity: usize,
+        config: &crate::state::rocks_db::RocksDbConfig,
+    ) -> DatabaseResult<Self> {
+        use crate::database::database_description::DatabaseDescription;
+        use fuel_core_storage::kv_store::StorageColumn;
+
+        fn column_names<Description: DatabaseDescription>(
+        ) -> impl Iterator<Item = &'static str> {
+            enum_iterator::all::<Description::Column>().map(|column| column.name())
+        }
+
+        config
+            .validate_columns(
+                column_names::<OnChain>()
+                    .chain(column_names::<OffChain>())
+                    .chain(column_names::<Relayer>())
+                    .chain(column_names::<P2p>()),
+            )
+            .map_err(crate::database::Error::Other)?;
+
         // TODO: Use different cache sizes for different databases
-        let on_chain = Database::open(path, capacity)?;
-        let off_chain = Database::open(path, capacity)?;
-        let relayer = Database::open(path, capacity)?;
+        let on_chain = Database::open_with_config(path, capacity, config)?;
+        let off_chain = Database::open_with_config(path, capacity, config)?;
+        let relayer = Database::open_with_config(path, capacity, config)?;
+        let p2p = Database::open_with_config(path, capacity, config)?;
         Ok(Self {
             on_chain,
             off_chain,
             relayer,
+            p2p,
+        })
+    }
+
+    #[cfg(feature = "redb")]
+    pub fn open_redb(path: &std::path::Path, capacity: usize) -> DatabaseResult<Self> {
+        let on_chain = Database::open_redb(path, capacity)?;
+        let off_chain = Database::open_redb(path, capacity)?;
+        let relayer = Database::open_redb(path, capacity)?;
+        let p2p = Database::open_redb(path, capacity)?;
+        Ok(Self {
+            on_chain,
+            off_chain,
+            relayer,
+            p2p,
         })
     }
 
@@ -52,6 +109,7 @@ impl CombinedDatabase {
             Database::in_memory(),
             Database::in_memory(),
             Database::in_memory(),
+            Database::in_memory(),
         )
     }
 
@@ -63,6 +121,24 @@ impl CombinedDatabase {
         self.on_chain.init(block_height)?;
         self.off_chain.init(block_height)?;
         self.relayer.init(da_block_height)?;
+        self.p2p.init(&())?;
+        if self.on_chain.reindex_in_progress()? {
+            return Err(anyhow::anyhow!(
+                "The re-indexing of the off-chain database was interrupted, so it may \
+                miss blocks. Run `fuel-core db reindex` again to finish it"
+            )
+            .into())
+        }
+        if let Some(height) = self.on_chain.rollback_target()? {
+            return Err(anyhow::anyhow!(
+                "The rollback to the height {} was interrupted, so the databases may be \
+                at different heights. Run `fuel-core rollback --to-height {}` to finish it",
+                *height,
+                *height
+            )
+            .into())
+        }
+        self.resume_checkpoint_import()?;
         Ok(())
     }
 
@@ -88,10 +164,247 @@ impl CombinedDatabase {
         &self.relayer
     }
 
+    pub fn p2p(&self) -> &Database<P2p> {
+        &self.p2p
+    }
+
+    /// Rolls back the on-chain and off-chain databases to the `height`.
+    /// The reverse changes of both databases are checked before writing,
+    /// so if any of them are missing, none of the databases are modified.
+    ///
+    /// The databases are separate storages, so the rollback is not atomic across them.
+    /// Each database is rolled back one height per write, and the databases may
+    /// stay at different heights if the node stops in the middle of the rollback.
+    /// To recover from it, the `height` is recorded in the on-chain database before
+    /// the rollback and removed when its rollback is finished. If the node stops
+    /// in between, the [`CombinedDatabase::init`] fails until the rollback is finished
+    /// by the [`CombinedDatabase::resume_rollback`], e.g. with the `fuel-core rollback`.
+    pub fn rollback_to(&self, height: &BlockHeight) -> StorageResult<()> {
+        self.on_chain.check_rollback(height)?;
+        self.off_chain.check_rollback(height)?;
+        self.on_chain.record_rollback_target(height)?;
+        // The off-chain database is built from the on-chain database, so roll it back first.
+        // The repeated rollback does nothing for databases that are already at the `height`.
+        self.off_chain.rollback_to(height)?;
+        self.on_chain.rollback_to(height)?;
+        Ok(())
+    }
+
+    /// Sets the number of the latest blocks that the on-chain and off-chain databases
+    /// can be rolled back. The reverse changes of older blocks are removed.
+    pub fn set_history_retention(&self, retention: NonZeroU32) -> StorageResult<()> {
+        self.on_chain.set_history_retention(retention)?;
+        self.off_chain.set_history_retention(retention)?;
+        Ok(())
+    }
+
+    /// Finishes the rollback interrupted by the stop of the node, if any.
+    pub fn resume_rollback(&self) -> StorageResult<()> {
+        if let Some(height) = self.on_chain.rollback_target()? {
+            tracing::warn!(
+                "Resuming the interrupted rollback to the height {}",
+                *height
+            );
+            self.rollback_to(&height)?;
+        }
+        Ok(())
+    }
+
+    /// Finishes the import of the checkpoint interrupted by the stop of the node, if any.
+    /// If the state of the checkpoint wasn't committed, the checkpoint is forgotten,
+    /// and the synchronization downloads it again.
+    pub fn resume_checkpoint_import(&self) -> StorageResult<()> {
+        let checkpoint = match self.on_chain.pending_checkpoint()? {
+            Some(checkpoint) => checkpoint,
+            None => return Ok(()),
+        };
+        if self.on_chain.latest_height()? != checkpoint.height {
+            return self.on_chain.clear_pending_checkpoint()
+        }
+        tracing::warn!(
+            "Resuming the interrupted import of the checkpoint at the height {}",
+            *checkpoint.height
+        );
+        crate::service::genesis::finish_checkpoint_import(
+            &self.on_chain,
+            &self.off_chain,
+            &checkpoint,
+        )?;
+        Ok(())
+    }
+
+    /// Creates a backup of the on-chain, off-chain and relayer databases in the `path`.
+    /// The off-chain database is updated after the on-chain database, so their heights
+    /// in the backup may differ. In this case, the backup of the database that is ahead
+    /// is rolled back to the height of another one. Returns the block height of the backup.
+    ///
+    /// The `database_type` should be the type of these databases.
+    #[cfg(feature = "rocksdb")]
+    pub fn backup(
+        &self,
+        database_type: &DbType,
+        path: &std::path::Path,
+    ) -> StorageResult<BlockHeight> {
+        Self::check_backup_support(database_type)?;
+        std::fs::create_dir_all(path).map_err(|e| anyhow::anyhow!(e))?;
+        self.off_chain.checkpoint(path)?;
+        self.on_chain.checkpoint(path)?;
+        self.relayer.checkpoint(path)?;
+
+        let on_chain = open_copy::<OnChain>(database_type, path)?;
+        let off_chain = open_copy::<OffChain>(database_type, path)?;
+        let height =
+            core::cmp::min(on_chain.latest_height()?, off_chain.latest_height()?);
+        on_chain.rollback_to(&height)?;
+        off_chain.rollback_to(&height)?;
+        on_chain.flush()?;
+        off_chain.flush()?;
+        Ok(height)
+    }
+
+    /// Restores the on-chain, off-chain and relayer databases from the `backup`
+    /// created by the [`CombinedDatabase::backup`] into the `path`.
+    /// The `path` should not contain these databases.
+    #[cfg(feature = "rocksdb")]
+    pub fn restore(
+        database_type: &DbType,
+        backup: &std::path::Path,
+        path: &std::path::Path,
+    ) -> StorageResult<()> {
+        Self::check_backup_support(database_type)?;
+        std::fs::create_dir_all(path).map_err(|e| anyhow::anyhow!(e))?;
+        open_copy::<OnChain>(database_type, backup)?.checkpoint(path)?;
+        open_copy::<OffChain>(database_type, backup)?.checkpoint(path)?;
+        open_copy::<Relayer>(database_type, backup)?.checkpoint(path)?;
+        Ok(())
+    }
+
+    /// Returns an error if the databases of the `database_type` can't be backed up.
+    #[cfg(feature = "rocksdb")]
+    pub fn check_backup_support(database_type: &DbType) -> StorageResult<()> {
+        match database_type {
+            DbType::RocksDb => Ok(()),
+            #[cfg(feature = "redb")]
+            DbType::Redb => Ok(()),
+            database_type => Err(anyhow::anyhow!(
+                "The {database_type} database can't be backed up"
+            )
+            .into()),
+        }
+    }
+
+    /// Checks the invariants between the on-chain and off-chain databases.
+    pub fn check_consistency(&self) -> StorageResult<Vec<Inconsistency>> {
+        inspect::check_consistency(&self.on_chain, &self.off_chain)
+    }
+
+    /// Rebuilds the indexes of the off-chain database derived from the on-chain state.
+    pub fn rebuild_off_chain_indexes(&self) -> StorageResult<()> {
+        inspect::rebuild_off_chain_indexes(&self.on_chain, &self.off_chain)
+    }
+
+    /// Upgrades all databases to the versions expected by the node.
+    /// If `dry_run` is `true`, only reports the migrations without applying them.
+    pub fn migrate(&self, dry_run: bool) -> StorageResult<Vec<MigrationReport>> {
+        let mut reports = self.on_chain.migrate(dry_run)?;
+        reports.extend(self.off_chain.migrate(dry_run)?);
+        reports.extend(self.relayer.migrate(dry_run)?);
+        reports.extend(self.p2p.migrate(dry_run)?);
+        Ok(reports)
+    }
+
     pub fn flush(self) -> DatabaseResult<()> {
         self.on_chain.flush()?;
         self.off_chain.flush()?;
         self.relayer.flush()?;
+        self.p2p.flush()?;
         Ok(())
     }
 }
+
+/// Opens the copy of the database in the `path` with the backend of the `database_type`.
+#[cfg(feature = "rocksdb")]
+fn open_copy<Description>(
+    database_type: &DbType,
+    path: &std::path::Path,
+) -> StorageResult<Database<Description>>
+where
+    Description: crate::database::database_description::DatabaseDescription,
+{
+    let database = match database_type {
+        DbType::RocksDb => Database::open(path, None)?,
+        #[cfg(feature = "redb")]
+        DbType::Redb => Database::open_redb(path, None)?,
+        database_type => {
+            return Err(anyhow::anyhow!(
+                "The {database_type} database can't be backed up"
+            )
+            .into())
+        }
+    };
+    Ok(database)
+}
+
+#[cfg(all(test, feature = "rocksdb", feature = "redb"))]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+    use fuel_core_storage::{
+        tables::Coins,
+        StorageAsMut,
+        StorageAsRef,
+    };
+    use fuel_core_types::{
+        entities::coins::coin::CompressedCoin,
+        fuel_tx::UtxoId,
+    };
+
+    #[test]
+    fn backup_and_restore__work_with_redb() {
+        // given
+        let db_dir = tempfile::TempDir::new().unwrap();
+        let mut database =
+            CombinedDatabase::open_redb(db_dir.path(), 1024 * 1024).unwrap();
+        database.init(&10u32.into(), &0u64.into()).unwrap();
+        let utxo_id = UtxoId::new(Default::default(), 0);
+        database
+            .on_chain_mut()
+            .storage_as_mut::<Coins>()
+            .insert(&utxo_id, &CompressedCoin::default())
+            .unwrap();
+        let backup_dir = tempfile::TempDir::new().unwrap();
+        let backup_path = backup_dir.path().join("backup");
+        let restored_dir = tempfile::TempDir::new().unwrap();
+        let restored_path = restored_dir.path().join("db");
+
+        // when
+        let height = database.backup(&DbType::Redb, &backup_path).unwrap();
+        CombinedDatabase::restore(&DbType::Redb, &backup_path, &restored_path).unwrap();
+
+        // then
+        assert_eq!(height, 10u32.into());
+        let restored = CombinedDatabase::open_redb(&restored_path, 1024 * 1024).unwrap();
+        assert_eq!(restored.on_chain().latest_height().unwrap(), height);
+        assert_eq!(restored.off_chain().latest_height().unwrap(), height);
+        let coin = restored
+            .on_chain()
+            .storage::<Coins>()
+            .get(&utxo_id)
+            .unwrap();
+        assert!(coin.is_some());
+    }
+
+    #[test]
+    fn backup__rejects_in_memory_database() {
+        // given
+        let database = CombinedDatabase::in_memory();
+        let backup_dir = tempfile::TempDir::new().unwrap();
+
+        // when
+        let result = database.backup(&DbType::InMemory, backup_dir.path());
+
+        // then
+        assert!(result.is_err());
+    }
+}
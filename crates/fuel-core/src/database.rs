@@ -66,11 +66,16 @@ pub type Result<T> = core::result::Result<T, Error>;
 type DatabaseResult<T> = Result<T>;
 
 // TODO: Extract `Database` and all belongs into `fuel-core-database`.
+#[cfg(feature = "redb")]
+use crate::state::redb::Redb;
 #[cfg(feature = "rocksdb")]
-use crate::state::rocks_db::RocksDb;
-#[cfg(feature = "rocksdb")]
+use crate::state::rocks_db::{
+    RocksDb,
+    RocksDbConfig,
+};
+#[cfg(any(feature = "rocksdb", feature = "redb"))]
 use std::path::Path;
-#[cfg(feature = "rocksdb")]
+#[cfg(any(feature = "rocksdb", feature = "redb"))]
 use tempfile::TempDir;
 
 // Storages implementation
@@ -79,8 +84,11 @@ pub mod block;
 pub mod coin;
 pub mod contracts;
 pub mod database_description;
+pub mod inspect;
 pub mod message;
 pub mod metadata;
+pub mod migration;
+pub mod modifications_history;
 pub mod sealed_block;
 pub mod state;
 pub mod statistic;
@@ -148,8 +156,30 @@ where
 
     #[cfg(feature = "rocksdb")]
     pub fn open(path: &Path, capacity: impl Into<Option<usize>>) -> DatabaseResult<Self> {
+        Self::open_with_config(path, capacity, &RocksDbConfig::default())
+    }
+
+    #[cfg(feature = "rocksdb")]
+    pub fn open_with_config(
+        path: &Path,
+        capacity: impl Into<Option<usize>>,
+        config: &RocksDbConfig,
+    ) -> DatabaseResult<Self> {
         use anyhow::Context;
-        let db = RocksDb::<Description>::default_open(path, capacity.into()).map_err(Into::<anyhow::Error>::into).context("Failed to open rocksdb, you may need to wipe a pre-existing incompatible db `rm -rf ~/.fuel/db`")?;
+        let db = RocksDb::<Description>::open_with_config(path, capacity.into(), config).map_err(Into::<anyhow::Error>::into).context("Failed to open rocksdb, you may need to wipe a pre-existing incompatible db `rm -rf ~/.fuel/db`")?;
+
+        Ok(Database {
+            data: StructuredStorage::new(Arc::new(db).into()),
+            _drop: Default::default(),
+        })
+    }
+
+    #[cfg(feature = "redb")]
+    pub fn open_redb(
+        path: &Path,
+        capacity: impl Into<Option<usize>>,
+    ) -> DatabaseResult<Self> {
+        let db = Redb::<Description>::open(path, capacity.into())?;
 
         Ok(Database {
             data: StructuredStorage::new(Arc::new(db).into()),
@@ -182,6 +212,24 @@ where
         }
     }
 
+    #[cfg(feature = "redb")]
+    pub fn redb() -> Self {
+        let tmp_dir = TempDir::new().unwrap();
+        let db = Redb::<Description>::open(tmp_dir.path(), None).unwrap();
+        Self {
+            data: StructuredStorage::new(Arc::new(db).into()),
+            _drop: Arc::new(
+                {
+                    move || {
+                        // cleanup temp dir
+                        drop(tmp_dir);
+                    }
+                }
+                .into(),
+            ),
+        }
+    }
+
     pub fn transaction(&self) -> DatabaseTransaction<Description> {
         self.into()
     }
@@ -189,6 +237,26 @@ where
     pub fn flush(self) -> DatabaseResult<()> {
         self.data.as_ref().flush()
     }
+
+    /// Creates a consistent copy of the database in the `path`.
+    pub fn checkpoint(&self, path: &std::path::Path) -> DatabaseResult<()> {
+        self.data.as_ref().checkpoint(path)
+    }
+
+    /// Removes all entries from all columns of the database.
+    pub fn clear(&self) -> StorageResult<()> {
+        let data_source: &DataSource<Description> = self.data.as_ref();
+        for column in enum_iterator::all::<Description::Column>() {
+            let entries = data_source
+                .iter_all(column, None, None, IterDirection::Forward)
+                .map(|entry| entry.map(|(key, _)| (key, column, WriteOperation::Remove)))
+                .collect::<StorageResult<Vec<_>>>()?;
+            // The entries are written to the underlying storage directly,
+            // so the removal is not tracked in the history of modifications.
+            data_source.as_ref().batch_write(&mut entries.into_iter())?;
+        }
+        Ok(())
+    }
 }
 
 impl<Description> KeyValueStore for DataSource<Description>
@@ -261,7 +329,17 @@ where
         &self,
         entries: &mut dyn Iterator<Item = (Vec<u8>, Self::Column, WriteOperation)>,
     ) -> StorageResult<()> {
-        self.as_ref().batch_write(entries)
+        match Description::modifications_history_column() {
+            Some(history_column) => {
+                let entries = modifications_history::with_reverse_changes(
+                    self,
+                    history_column,
+                    entries,
+                )?;
+                self.as_ref().batch_write(&mut entries.into_iter())
+            }
+            None => self.as_ref().batch_write(entries),
+        }
     }
 }
 
@@ -504,6 +582,7 @@ mod tests {
     use crate::database::database_description::{
         off_chain::OffChain,
         on_chain::OnChain,
+        p2p::P2p,
         relayer::Relayer,
         DatabaseDescription,
     };
@@ -534,4 +613,9 @@ mod tests {
     fn column_keys_not_exceed_count_test_relayer() {
         column_keys_not_exceed_count::<Relayer>();
     }
+
+    #[test]
+    fn column_keys_not_exceed_count_test_p2p() {
+        column_keys_not_exceed_count::<P2p>();
+    }
 }
@@ -21,6 +21,7 @@ use fuel_core_storage::{
             FuelBlockMerkleMetadata,
         },
         FuelBlocks,
+        HeaderOnlyBlocks,
         Transactions,
     },
     Error as StorageError,
@@ -121,17 +122,24 @@ impl Database {
         self.latest_compressed_block()
     }
 
-    /// Retrieve the full block and all associated transactions
+    /// Retrieve the full block and all associated transactions.
+    /// Returns `None` for the block imported by the header only until its body is fetched.
     pub(crate) fn get_full_block(
         &self,
         height: &BlockHeight,
     ) -> StorageResult<Option<Block>> {
         let db_block = self.storage::<FuelBlocks>().get(height)?;
         if let Some(block) = db_block {
+            let tx_ids = match self.storage::<HeaderOnlyBlocks>().get(height)? {
+                Some(body) => match body.into_owned() {
+                    Some(tx_ids) => tx_ids,
+                    None => return Ok(None),
+                },
+                None => block.transactions().to_vec(),
+            };
             // fetch all the transactions
             // TODO: optimize with multi-key get
-            let txs = block
-                .transactions()
+            let txs = tx_ids
                 .iter()
                 .map(|tx_id| {
                     self.storage::<Transactions>()
@@ -62,11 +62,29 @@ impl Database {
 
     pub fn get_coin_config(&self) -> StorageResult<Option<Vec<CoinConfig>>> {
         let configs = self
-            .iter_all::<Coins>(None)
-            .map(|raw_coin| -> StorageResult<CoinConfig> {
+            .coin_configs()
+            .collect::<StorageResult<Vec<CoinConfig>>>()?;
+
+        Ok(Some(configs))
+    }
+
+    /// Returns configs of all unspent coins in the order of their keys.
+    pub fn coin_configs(&self) -> impl Iterator<Item = StorageResult<CoinConfig>> + '_ {
+        self.coin_configs_from(None)
+            .map(|result| result.map(|(_, config)| config))
+    }
+
+    /// Returns configs of unspent coins along with their UTXO ids,
+    /// starting from the `start` UTXO id(inclusive).
+    pub fn coin_configs_from(
+        &self,
+        start: Option<&UtxoId>,
+    ) -> impl Iterator<Item = StorageResult<(UtxoId, CoinConfig)>> + '_ {
+        self.iter_all_by_start::<Coins>(start, None).map(
+            |raw_coin| -> StorageResult<(UtxoId, CoinConfig)> {
                 let (utxo_id, coin) = raw_coin?;
 
-                Ok(CoinConfig {
+                let config = CoinConfig {
                     tx_id: Some(*utxo_id.tx_id()),
                     output_index: Some(utxo_id.output_index()),
                     tx_pointer_block_height: Some(coin.tx_pointer().block_height()),
@@ -75,10 +93,9 @@ impl Database {
                     owner: *coin.owner(),
                     amount: *coin.amount(),
                     asset_id: *coin.asset_id(),
-                })
-            })
-            .collect::<StorageResult<Vec<CoinConfig>>>()?;
-
-        Ok(Some(configs))
+                };
+                Ok((utxo_id, config))
+            },
+        )
     }
 }
@@ -101,15 +101,34 @@ impl Database {
 
     pub fn get_contract_config(&self) -> StorageResult<Option<Vec<ContractConfig>>> {
         let configs = self
-            .iter_all::<ContractsRawCode>(None)
-            .map(|raw_contract_id| -> StorageResult<ContractConfig> {
-                let contract_id = raw_contract_id?.0;
-                self.get_contract_config_by_id(contract_id)
-            })
+            .contract_configs()
             .collect::<StorageResult<Vec<ContractConfig>>>()?;
 
         Ok(Some(configs))
     }
+
+    /// Returns configs of all contracts in the order of their ids.
+    pub fn contract_configs(
+        &self,
+    ) -> impl Iterator<Item = StorageResult<ContractConfig>> + '_ {
+        self.contract_configs_from(None)
+            .map(|result| result.map(|(_, config)| config))
+    }
+
+    /// Returns configs of contracts along with their ids,
+    /// starting from the `start` contract id(inclusive).
+    pub fn contract_configs_from(
+        &self,
+        start: Option<&ContractId>,
+    ) -> impl Iterator<Item = StorageResult<(ContractId, ContractConfig)>> + '_ {
+        self.iter_all_by_start::<ContractsRawCode>(start, None).map(
+            |raw_contract_id| -> StorageResult<(ContractId, ContractConfig)> {
+                let contract_id = raw_contract_id?.0;
+                let config = self.get_contract_config_by_id(contract_id)?;
+                Ok((contract_id, config))
+            },
+        )
+    }
 }
 
 #[cfg(test)]
@@ -1,8 +1,13 @@
-use core::fmt::Debug;
+use crate::database::migration::Migration;
+use core::{
+    fmt::Debug,
+    num::NonZeroU32,
+};
 use fuel_core_storage::kv_store::StorageColumn;
 
 pub mod off_chain;
 pub mod on_chain;
+pub mod p2p;
 pub mod relayer;
 
 /// The description of the database that makes it unique.
@@ -10,17 +15,33 @@ pub trait DatabaseDescription: 'static + Clone + Debug + Send + Sync {
     /// The type of the column used by the database.
     type Column: StorageColumn + strum::EnumCount + enum_iterator::Sequence;
     /// The type of the height of the database used to track commits.
-    type Height: Copy;
+    type Height: Copy + PartialOrd + serde::Serialize + serde::de::DeserializeOwned;
 
     /// Returns the expected version of the database.
     fn version() -> u32;
 
+    /// Returns the migrations that upgrade the database from previous versions
+    /// to the [`DatabaseDescription::version`].
+    fn migrations() -> Vec<Migration<Self>>;
+
     /// Returns the name of the database.
     fn name() -> &'static str;
 
     /// Returns the column used to store the metadata.
     fn metadata_column() -> Self::Column;
 
+    /// Returns the column used to store the reverse changes of each height,
+    /// or `None` if the database doesn't support rollback.
+    fn modifications_history_column() -> Option<Self::Column>;
+
+    /// Returns the height whose reverse changes are removed from the history
+    /// when the database reaches the `height` and keeps the reverse changes
+    /// of the last `retention` heights, or `None` if nothing is removed.
+    fn pruned_history_height(
+        height: &Self::Height,
+        retention: NonZeroU32,
+    ) -> Option<Self::Height>;
+
     /// Returns the prefix for the column.
     fn prefix(column: &Self::Column) -> Option<usize>;
 }
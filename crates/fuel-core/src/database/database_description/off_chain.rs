@@ -1,8 +1,13 @@
 use crate::{
-    database::database_description::DatabaseDescription,
+    database::{
+        database_description::DatabaseDescription,
+        migration::Migration,
+        modifications_history,
+    },
     fuel_core_graphql_api,
 };
 use fuel_core_types::fuel_types::BlockHeight;
+use std::num::NonZeroU32;
 
 #[derive(Clone, Debug)]
 pub struct OffChain;
@@ -15,6 +20,10 @@ impl DatabaseDescription for OffChain {
         0
     }
 
+    fn migrations() -> Vec<Migration<Self>> {
+        vec![]
+    }
+
     fn name() -> &'static str {
         "off_chain"
     }
@@ -23,6 +32,17 @@ impl DatabaseDescription for OffChain {
         Self::Column::Metadata
     }
 
+    fn modifications_history_column() -> Option<Self::Column> {
+        Some(Self::Column::ModificationsHistory)
+    }
+
+    fn pruned_history_height(
+        height: &Self::Height,
+        retention: NonZeroU32,
+    ) -> Option<Self::Height> {
+        modifications_history::pruned_height(height, retention)
+    }
+
     fn prefix(column: &Self::Column) -> Option<usize> {
         match column {
             Self::Column::OwnedCoins
@@ -1,5 +1,10 @@
-use crate::database::database_description::DatabaseDescription;
+use crate::database::{
+    database_description::DatabaseDescription,
+    migration::Migration,
+    modifications_history,
+};
 use fuel_core_types::fuel_types::BlockHeight;
+use std::num::NonZeroU32;
 
 #[derive(Clone, Debug)]
 pub struct OnChain;
@@ -12,6 +17,10 @@ impl DatabaseDescription for OnChain {
         0
     }
 
+    fn migrations() -> Vec<Migration<Self>> {
+        vec![]
+    }
+
     fn name() -> &'static str {
         "on_chain"
     }
@@ -20,6 +29,17 @@ impl DatabaseDescription for OnChain {
         Self::Column::Metadata
     }
 
+    fn modifications_history_column() -> Option<Self::Column> {
+        Some(Self::Column::ModificationsHistory)
+    }
+
+    fn pruned_history_height(
+        height: &Self::Height,
+        retention: NonZeroU32,
+    ) -> Option<Self::Height> {
+        modifications_history::pruned_height(height, retention)
+    }
+
     fn prefix(column: &Self::Column) -> Option<usize> {
         match column {
             Self::Column::ContractsAssets | Self::Column::ContractsState => {
@@ -0,0 +1,50 @@
+use crate::database::{
+    database_description::DatabaseDescription,
+    migration::Migration,
+};
+use std::num::NonZeroU32;
+
+#[cfg(not(feature = "p2p"))]
+use crate::database::database_description::relayer::DummyColumn;
+
+#[derive(Clone, Debug)]
+pub struct P2p;
+
+impl DatabaseDescription for P2p {
+    #[cfg(feature = "p2p")]
+    type Column = fuel_core_p2p::peer_store::Column;
+
+    #[cfg(not(feature = "p2p"))]
+    type Column = DummyColumn;
+
+    /// The peer store doesn't follow any chain, so it doesn't have a height.
+    type Height = ();
+
+    fn version() -> u32 {
+        0
+    }
+
+    fn migrations() -> Vec<Migration<Self>> {
+        vec![]
+    }
+
+    fn name() -> &'static str {
+        "p2p"
+    }
+
+    fn metadata_column() -> Self::Column {
+        Self::Column::Metadata
+    }
+
+    fn modifications_history_column() -> Option<Self::Column> {
+        None
+    }
+
+    fn pruned_history_height(_: &Self::Height, _: NonZeroU32) -> Option<Self::Height> {
+        None
+    }
+
+    fn prefix(_: &Self::Column) -> Option<usize> {
+        None
+    }
+}
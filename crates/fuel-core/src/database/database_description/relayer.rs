@@ -1,6 +1,10 @@
-use crate::database::database_description::DatabaseDescription;
+use crate::database::{
+    database_description::DatabaseDescription,
+    migration::Migration,
+};
 use fuel_core_storage::kv_store::StorageColumn;
 use fuel_core_types::blockchain::primitives::DaBlockHeight;
+use std::num::NonZeroU32;
 
 /// The column used by the relayer database in the case if the relayer is disabled.
 #[derive(
@@ -44,6 +48,10 @@ impl DatabaseDescription for Relayer {
         0
     }
 
+    fn migrations() -> Vec<Migration<Self>> {
+        vec![]
+    }
+
     fn name() -> &'static str {
         "relayer"
     }
@@ -52,6 +60,14 @@ impl DatabaseDescription for Relayer {
         Self::Column::Metadata
     }
 
+    fn modifications_history_column() -> Option<Self::Column> {
+        None
+    }
+
+    fn pruned_history_height(_: &Self::Height, _: NonZeroU32) -> Option<Self::Height> {
+        None
+    }
+
     fn prefix(_: &Self::Column) -> Option<usize> {
         None
     }
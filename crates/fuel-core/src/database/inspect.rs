@@ -0,0 +1,857 @@
+//! Tools for operators to look inside the databases and verify their consistency.
+
+#[cfg(feature = "relayer")]
+use crate::database::database_description::relayer::Relayer;
+use crate::{
+    database::{
+        database_description::{
+            off_chain::OffChain,
+            on_chain::OnChain,
+            DatabaseDescription,
+        },
+        Database,
+    },
+    fuel_core_graphql_api::storage::{
+        blocks::FuelBlockIdsToHeights,
+        coins::{
+            owner_coin_id_key,
+            OwnedCoinKey,
+            OwnedCoins,
+        },
+        messages::{
+            OwnedMessageIds,
+            OwnedMessageKey,
+        },
+        transactions::{
+            OwnedTransactions,
+            TransactionStatuses,
+        },
+    },
+    state::DataSource,
+};
+#[cfg(feature = "relayer")]
+use fuel_core_relayer::storage::{
+    DaHeightTable,
+    EventsHistory,
+};
+use fuel_core_storage::{
+    blueprint::Blueprint,
+    codec::Decode,
+    iter::IterDirection,
+    kv_store::{
+        KeyValueStore,
+        StorageColumn,
+    },
+    structured_storage::TableWithBlueprint,
+    tables::{
+        merkle::{
+            ContractsAssetsMerkleData,
+            ContractsAssetsMerkleMetadata,
+            ContractsStateMerkleData,
+            ContractsStateMerkleMetadata,
+            FuelBlockMerkleData,
+            FuelBlockMerkleMetadata,
+        },
+        Coins,
+        ContractsAssets,
+        ContractsInfo,
+        ContractsLatestUtxo,
+        ContractsRawCode,
+        ContractsState,
+        FuelBlocks,
+        Messages,
+        ProcessedTransactions,
+        SealedBlockConsensus,
+        SpentMessages,
+        Transactions,
+    },
+    transactional::Transaction,
+    Error as StorageError,
+    Mappable,
+    MerkleRootStorage,
+    Result as StorageResult,
+    StorageAsMut,
+    StorageAsRef,
+};
+use fuel_core_types::{
+    blockchain::primitives::BlockId,
+    fuel_merkle::sparse,
+    fuel_tx::{
+        Address,
+        Bytes32,
+        ContractId,
+        TxId,
+        UtxoId,
+    },
+    fuel_types::{
+        BlockHeight,
+        Nonce,
+    },
+};
+use std::{
+    collections::HashSet,
+    fmt::Debug,
+};
+
+/// The statistic about the content of the column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnStats {
+    /// The name of the column.
+    pub name: &'static str,
+    /// The number of keys in the column.
+    pub keys: u64,
+    /// The total size of keys and values in the column in bytes.
+    pub size: u64,
+}
+
+/// The entry of the table decoded with the codecs of the table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedEntry {
+    /// The debug representation of the key.
+    pub key: String,
+    /// The debug representation of the value.
+    pub value: String,
+}
+
+impl<Description> Database<Description>
+where
+    Description: DatabaseDescription,
+{
+    /// Returns the statistic about each column of the database.
+    /// It iterates over all entries, so it can take a while for big databases.
+    pub fn column_stats(&self) -> StorageResult<Vec<ColumnStats>> {
+        enum_iterator::all::<Description::Column>()
+            .map(|column| {
+                let mut stats = ColumnStats {
+                    name: column.name(),
+                    keys: 0,
+                    size: 0,
+                };
+                for entry in self.data.as_ref().iter_all(
+                    column,
+                    None,
+                    None,
+                    IterDirection::Forward,
+                ) {
+                    let (key, value) = entry?;
+                    let size = key.len().saturating_add(value.len());
+                    stats.keys = stats.keys.saturating_add(1);
+                    stats.size = stats.size.saturating_add(size as u64);
+                }
+                Ok(stats)
+            })
+            .collect()
+    }
+
+    fn decode<M>(&self, key: &[u8]) -> StorageResult<Option<DecodedEntry>>
+    where
+        M: Mappable + TableWithBlueprint<Column = Description::Column>,
+        M::Blueprint: Blueprint<M, DataSource<Description>>,
+        M::OwnedKey: Debug,
+        M::OwnedValue: Debug,
+    {
+        let value = match self.data.as_ref().get(key, M::column())? {
+            Some(value) => value,
+            None => return Ok(None),
+        };
+        let decoded_key =
+            <M::Blueprint as Blueprint<M, DataSource<Description>>>::KeyCodec::decode(
+                key,
+            )
+            .map_err(StorageError::Codec)?;
+        let decoded_value =
+            <M::Blueprint as Blueprint<M, DataSource<Description>>>::ValueCodec::decode(
+                value.as_slice(),
+            )
+            .map_err(StorageError::Codec)?;
+        Ok(Some(DecodedEntry {
+            key: format!("{decoded_key:?}"),
+            value: format!("{decoded_value:?}"),
+        }))
+    }
+}
+
+/// Implements decoding of entries for the listed tables of the database.
+macro_rules! decodable_tables {
+    ($description:ty, [$($table:ident),* $(,)?]) => {
+        impl Database<$description> {
+            /// Returns the names of tables that support decoding of entries.
+            pub fn decodable_tables() -> &'static [&'static str] {
+                &[$(stringify!($table)),*]
+            }
+
+            /// Decodes the entry of the `table` by the encoded `key`.
+            /// Returns `None` if the entry doesn't exist.
+            pub fn decode_entry(
+                &self,
+                table: &str,
+                key: &[u8],
+            ) -> StorageResult<Option<DecodedEntry>> {
+                match table {
+                    $(stringify!($table) => self.decode::<$table>(key),)*
+                    _ => Err(anyhow::anyhow!(
+                        "The table {table} is unknown for the {} database, \
+                        known tables are {:?}",
+                        <$description as DatabaseDescription>::name(),
+                        Self::decodable_tables()
+                    )
+                    .into()),
+                }
+            }
+        }
+    };
+}
+
+decodable_tables!(
+    OnChain,
+    [
+        ContractsRawCode,
+        ContractsInfo,
+        ContractsState,
+        ContractsLatestUtxo,
+        ContractsAssets,
+        Coins,
+        Transactions,
+        FuelBlocks,
+        FuelBlockMerkleData,
+        FuelBlockMerkleMetadata,
+        SpentMessages,
+        ContractsAssetsMerkleData,
+        ContractsAssetsMerkleMetadata,
+        ContractsStateMerkleData,
+        ContractsStateMerkleMetadata,
+        Messages,
+        ProcessedTransactions,
+        SealedBlockConsensus,
+    ]
+);
+
+decodable_tables!(
+    OffChain,
+    [
+        OwnedCoins,
+        TransactionStatuses,
+        OwnedTransactions,
+        OwnedMessageIds,
+        FuelBlockIdsToHeights,
+    ]
+);
+
+#[cfg(feature = "relayer")]
+decodable_tables!(Relayer, [DaHeightTable, EventsHistory]);
+
+/// The violation of the invariant between the on-chain and off-chain databases.
+#[derive(Debug, Clone, PartialEq, Eq, derive_more::Display)]
+pub enum Inconsistency {
+    /// The latest block doesn't match the height in the metadata.
+    #[display(
+        fmt = "The on-chain metadata height {metadata} doesn't match the latest block {latest_block:?}"
+    )]
+    OnChainHeight {
+        metadata: BlockHeight,
+        latest_block: Option<BlockHeight>,
+    },
+    /// The off-chain database is not at the height of the on-chain database.
+    #[display(
+        fmt = "The off-chain height {off_chain} doesn't match the on-chain height {on_chain}"
+    )]
+    OffChainHeight {
+        on_chain: BlockHeight,
+        off_chain: BlockHeight,
+    },
+    /// The `prev_root` of the block doesn't match the root of previous blocks.
+    #[display(
+        fmt = "The block {height} has the previous root {actual}, but the expected is {expected}"
+    )]
+    PrevRoot {
+        height: BlockHeight,
+        expected: Bytes32,
+        actual: Bytes32,
+    },
+    /// The block is absent in the `FuelBlockIdsToHeights` index.
+    #[display(fmt = "The block {height} with id {id} is not indexed")]
+    MissingBlockId { height: BlockHeight, id: BlockId },
+    /// The coin is absent in the `OwnedCoins` index.
+    #[display(fmt = "The coin {utxo_id} of the {owner} is not indexed")]
+    MissingOwnedCoin { owner: Address, utxo_id: UtxoId },
+    /// The `OwnedCoins` index contains a coin that doesn't exist.
+    #[display(fmt = "The coin {utxo_id} of the {owner} is indexed but doesn't exist")]
+    ExtraOwnedCoin { owner: Address, utxo_id: UtxoId },
+    /// The message is absent in the `OwnedMessageIds` index.
+    #[display(fmt = "The message {nonce} of the {owner} is not indexed")]
+    MissingOwnedMessage { owner: Address, nonce: Nonce },
+    /// The `OwnedMessageIds` index contains a message that doesn't exist.
+    #[display(fmt = "The message {nonce} of the {owner} is indexed but doesn't exist")]
+    ExtraOwnedMessage { owner: Address, nonce: Nonce },
+    /// The root of the contract state doesn't match the root in the metadata.
+    #[display(
+        fmt = "The state of the contract {contract_id} has the root {actual}, but the metadata root is {expected}"
+    )]
+    ContractStateRoot {
+        contract_id: ContractId,
+        expected: Bytes32,
+        actual: Bytes32,
+    },
+    /// The root of the contract assets doesn't match the root in the metadata.
+    #[display(
+        fmt = "The assets of the contract {contract_id} have the root {actual}, but the metadata root is {expected}"
+    )]
+    ContractAssetsRoot {
+        contract_id: ContractId,
+        expected: Bytes32,
+        actual: Bytes32,
+    },
+}
+
+/// Checks the invariants of the on-chain database and the indexes of the off-chain
+/// database built from it. Returns all found violations.
+pub fn check_consistency(
+    on_chain: &Database<OnChain>,
+    off_chain: &Database<OffChain>,
+) -> StorageResult<Vec<Inconsistency>> {
+    let mut inconsistencies = vec![];
+    check_blocks(on_chain, off_chain, &mut inconsistencies)?;
+    check_owned_coins(on_chain, off_chain, &mut inconsistencies)?;
+    check_owned_messages(on_chain, off_chain, &mut inconsistencies)?;
+    check_contract_roots(on_chain, &mut inconsistencies)?;
+    Ok(inconsistencies)
+}
+
+fn check_blocks(
+    on_chain: &Database<OnChain>,
+    off_chain: &Database<OffChain>,
+    inconsistencies: &mut Vec<Inconsistency>,
+) -> StorageResult<()> {
+    let on_chain_height = on_chain.latest_height()?;
+    let latest_block = on_chain
+        .latest_compressed_block()?
+        .map(|block| *block.header().height());
+    if latest_block != Some(on_chain_height) {
+        inconsistencies.push(Inconsistency::OnChainHeight {
+            metadata: on_chain_height,
+            latest_block,
+        });
+    }
+
+    let off_chain_height = off_chain.latest_height()?;
+    if off_chain_height != on_chain_height {
+        inconsistencies.push(Inconsistency::OffChainHeight {
+            on_chain: on_chain_height,
+            off_chain: off_chain_height,
+        });
+    }
+
+    let mut previous_height = None;
+    for entry in on_chain.iter_all::<FuelBlocks>(None) {
+        let (height, block) = entry?;
+        if let Some(previous_height) = previous_height {
+            let expected: Bytes32 = on_chain
+                .storage::<FuelBlocks>()
+                .root(&previous_height)?
+                .into();
+            let actual = *block.header().prev_root();
+            if expected != actual {
+                inconsistencies.push(Inconsistency::PrevRoot {
+                    height,
+                    expected,
+                    actual,
+                });
+            }
+        }
+        previous_height = Some(height);
+
+        let id = block.header().id();
+        let indexed_height = off_chain.get_block_height(&id)?;
+        if indexed_height != Some(height) {
+            inconsistencies.push(Inconsistency::MissingBlockId { height, id });
+        }
+    }
+    Ok(())
+}
+
+fn check_owned_coins(
+    on_chain: &Database<OnChain>,
+    off_chain: &Database<OffChain>,
+    inconsistencies: &mut Vec<Inconsistency>,
+) -> StorageResult<()> {
+    let mut coins = HashSet::new();
+    for entry in on_chain.iter_all::<Coins>(None) {
+        let (utxo_id, coin) = entry?;
+        let owner = *coin.owner();
+        let key = owner_coin_id_key(&owner, &utxo_id);
+        if !off_chain.storage::<OwnedCoins>().contains_key(&key)? {
+            inconsistencies.push(Inconsistency::MissingOwnedCoin { owner, utxo_id });
+        }
+        coins.insert(key);
+    }
+
+    for entry in off_chain.iter_all::<OwnedCoins>(None) {
+        let (key, _) = entry?;
+        if !coins.contains(&key) {
+            let (owner, utxo_id) = split_owned_coin_key(&key);
+            inconsistencies.push(Inconsistency::ExtraOwnedCoin { owner, utxo_id });
+        }
+    }
+    Ok(())
+}
+
+fn split_owned_coin_key(key: &OwnedCoinKey) -> (Address, UtxoId) {
+    let owner = Address::try_from(&key[..Address::LEN]).expect("The slice has size 32");
+    let tx_id = TxId::try_from(&key[Address::LEN..Address::LEN + TxId::LEN])
+        .expect("The slice has size 32");
+    (owner, UtxoId::new(tx_id, key[Address::LEN + TxId::LEN]))
+}
+
+fn check_owned_messages(
+    on_chain: &Database<OnChain>,
+    off_chain: &Database<OffChain>,
+    inconsistencies: &mut Vec<Inconsistency>,
+) -> StorageResult<()> {
+    let mut messages = HashSet::new();
+    for entry in on_chain.iter_all::<Messages>(None) {
+        let (nonce, message) = entry?;
+        let owner = *message.recipient();
+        let key = OwnedMessageKey::new(&owner, &nonce);
+        if !off_chain.storage::<OwnedMessageIds>().contains_key(&key)? {
+            inconsistencies.push(Inconsistency::MissingOwnedMessage { owner, nonce });
+        }
+        messages.insert((owner, nonce));
+    }
+
+    for entry in off_chain.iter_all::<OwnedMessageIds>(None) {
+        let (key, _) = entry?;
+        let (owner, nonce) = (*key.address(), *key.nonce());
+        if !messages.contains(&(owner, nonce)) {
+            inconsistencies.push(Inconsistency::ExtraOwnedMessage { owner, nonce });
+        }
+    }
+    Ok(())
+}
+
+/// Recalculates the roots of the state and assets of each contract from their entries
+/// and compares them with the roots in the `ContractsStateMerkleMetadata` and
+/// `ContractsAssetsMerkleMetadata` tables.
+fn check_contract_roots(
+    on_chain: &Database<OnChain>,
+    inconsistencies: &mut Vec<Inconsistency>,
+) -> StorageResult<()> {
+    for entry in on_chain.iter_all::<ContractsRawCode>(None) {
+        let (contract_id, _) = entry?;
+
+        let expected: Bytes32 = on_chain
+            .storage::<ContractsState>()
+            .root(&contract_id)?
+            .into();
+        let actual = on_chain.contract_root(ContractsState::column(), &contract_id)?;
+        if expected != actual {
+            inconsistencies.push(Inconsistency::ContractStateRoot {
+                contract_id,
+                expected,
+                actual,
+            });
+        }
+
+        let expected: Bytes32 = on_chain
+            .storage::<ContractsAssets>()
+            .root(&contract_id)?
+            .into();
+        let actual = on_chain.contract_root(ContractsAssets::column(), &contract_id)?;
+        if expected != actual {
+            inconsistencies.push(Inconsistency::ContractAssetsRoot {
+                contract_id,
+                expected,
+                actual,
+            });
+        }
+    }
+    Ok(())
+}
+
+impl Database<OnChain> {
+    /// Calculates the root of the sparse merkle tree over the entries
+    /// of the contract in the `column`, using the encoded keys and values.
+    fn contract_root(
+        &self,
+        column: <OnChain as DatabaseDescription>::Column,
+        contract_id: &ContractId,
+    ) -> StorageResult<Bytes32> {
+        let entries = self
+            .data
+            .as_ref()
+            .iter_all(
+                column,
+                Some(contract_id.as_ref()),
+                None,
+                IterDirection::Forward,
+            )
+            .collect::<StorageResult<Vec<_>>>()?;
+        let root = sparse::in_memory::MerkleTree::root_from_set(
+            entries
+                .iter()
+                .map(|(key, value)| (sparse::MerkleTreeKey::new(key), value.as_slice())),
+        );
+        Ok(root.into())
+    }
+}
+
+/// Rebuilds the indexes of the off-chain database that are derived from
+/// the current state of the on-chain database: `OwnedCoins`, `OwnedMessageIds`,
+/// and `FuelBlockIdsToHeights`. Other off-chain tables are left untouched.
+pub fn rebuild_off_chain_indexes(
+    on_chain: &Database<OnChain>,
+    off_chain: &Database<OffChain>,
+) -> StorageResult<()> {
+    let mut transaction = off_chain.transaction();
+    let database = transaction.as_mut();
+
+    let owned_coins = off_chain
+        .iter_all::<OwnedCoins>(None)
+        .map(|entry| entry.map(|(key, _)| key))
+        .collect::<StorageResult<Vec<_>>>()?;
+    for key in owned_coins {
+        database.storage_as_mut::<OwnedCoins>().remove(&key)?;
+    }
+    for entry in on_chain.iter_all::<Coins>(None) {
+        let (utxo_id, coin) = entry?;
+        database
+            .storage_as_mut::<OwnedCoins>()
+            .insert(&owner_coin_id_key(coin.owner(), &utxo_id), &())?;
+    }
+
+    let owned_messages = off_chain
+        .iter_all::<OwnedMessageIds>(None)
+        .map(|entry| entry.map(|(key, _)| key))
+        .collect::<StorageResult<Vec<_>>>()?;
+    for key in owned_messages {
+        database.storage_as_mut::<OwnedMessageIds>().remove(&key)?;
+    }
+    for entry in on_chain.iter_all::<Messages>(None) {
+        let (nonce, message) = entry?;
+        database
+            .storage_as_mut::<OwnedMessageIds>()
+            .insert(&OwnedMessageKey::new(message.recipient(), &nonce), &())?;
+    }
+
+    let block_ids = off_chain
+        .iter_all::<FuelBlockIdsToHeights>(None)
+        .map(|entry| entry.map(|(key, _)| key))
+        .collect::<StorageResult<Vec<_>>>()?;
+    for key in block_ids {
+        database
+            .storage_as_mut::<FuelBlockIdsToHeights>()
+            .remove(&key)?;
+    }
+    for entry in on_chain.iter_all::<FuelBlocks>(None) {
+        let (height, block) = entry?;
+        database
+            .storage_as_mut::<FuelBlockIdsToHeights>()
+            .insert(&block.header().id(), &height)?;
+    }
+
+    transaction.commit()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_storage::tables::merkle::SparseMerkleMetadata;
+    use fuel_core_types::{
+        blockchain::block::CompressedBlock,
+        entities::{
+            coins::coin::CompressedCoin,
+            message::{
+                Message,
+                MessageV1,
+            },
+        },
+        fuel_tx::AssetId,
+        fuel_vm::{
+            ContractsAssetKey,
+            ContractsStateKey,
+        },
+    };
+
+    fn block(height: u32, prev_root: Bytes32) -> CompressedBlock {
+        let mut block = CompressedBlock::default();
+        block.header_mut().set_block_height(height.into());
+        block.header_mut().set_previous_root(prev_root);
+        block
+    }
+
+    fn coin(owner: Address) -> CompressedCoin {
+        let mut coin = CompressedCoin::default();
+        coin.set_owner(owner);
+        coin.set_asset_id(AssetId::BASE);
+        coin
+    }
+
+    fn message(recipient: Address, nonce: Nonce) -> Message {
+        MessageV1 {
+            recipient,
+            nonce,
+            ..Default::default()
+        }
+        .into()
+    }
+
+    #[test]
+    fn check_owned_coins_reports_missing_and_extra_coins() {
+        // Given
+        let mut on_chain = Database::<OnChain>::default();
+        let mut off_chain = Database::<OffChain>::default();
+        let owner = Address::from([1; 32]);
+        let indexed = UtxoId::new(TxId::from([1; 32]), 0);
+        let not_indexed = UtxoId::new(TxId::from([2; 32]), 0);
+        let spent = UtxoId::new(TxId::from([3; 32]), 0);
+        for utxo_id in [indexed, not_indexed] {
+            on_chain
+                .storage_as_mut::<Coins>()
+                .insert(&utxo_id, &coin(owner))
+                .unwrap();
+        }
+        for utxo_id in [indexed, spent] {
+            off_chain
+                .storage_as_mut::<OwnedCoins>()
+                .insert(&owner_coin_id_key(&owner, &utxo_id), &())
+                .unwrap();
+        }
+
+        // When
+        let mut inconsistencies = vec![];
+        check_owned_coins(&on_chain, &off_chain, &mut inconsistencies).unwrap();
+
+        // Then
+        assert_eq!(
+            inconsistencies,
+            vec![
+                Inconsistency::MissingOwnedCoin {
+                    owner,
+                    utxo_id: not_indexed
+                },
+                Inconsistency::ExtraOwnedCoin {
+                    owner,
+                    utxo_id: spent
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn check_blocks_reports_wrong_prev_root_and_missing_block_id() {
+        // Given
+        let mut on_chain = Database::<OnChain>::default();
+        let mut off_chain = Database::<OffChain>::default();
+        on_chain.init(&1.into()).unwrap();
+        off_chain.init(&1.into()).unwrap();
+        let first = block(0, Bytes32::zeroed());
+        let second = block(1, Bytes32::from([1; 32]));
+        for block in [&first, &second] {
+            on_chain
+                .storage_as_mut::<FuelBlocks>()
+                .insert(block.header().height(), block)
+                .unwrap();
+        }
+        off_chain
+            .storage_as_mut::<FuelBlockIdsToHeights>()
+            .insert(&first.header().id(), &0.into())
+            .unwrap();
+
+        // When
+        let mut inconsistencies = vec![];
+        check_blocks(&on_chain, &off_chain, &mut inconsistencies).unwrap();
+
+        // Then
+        let expected_root: Bytes32 = on_chain
+            .storage::<FuelBlocks>()
+            .root(&0.into())
+            .unwrap()
+            .into();
+        assert_eq!(
+            inconsistencies,
+            vec![
+                Inconsistency::PrevRoot {
+                    height: 1.into(),
+                    expected: expected_root,
+                    actual: Bytes32::from([1; 32]),
+                },
+                Inconsistency::MissingBlockId {
+                    height: 1.into(),
+                    id: second.header().id(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn check_contract_roots_reports_wrong_state_root() {
+        // Given
+        let mut on_chain = Database::<OnChain>::default();
+        let contract_id = ContractId::from([1; 32]);
+        on_chain
+            .storage_as_mut::<ContractsRawCode>()
+            .insert(&contract_id, &[1, 2, 3])
+            .unwrap();
+        on_chain
+            .storage_as_mut::<ContractsState>()
+            .insert(
+                &ContractsStateKey::new(&contract_id, &Bytes32::from([2; 32])),
+                &Bytes32::from([3; 32]),
+            )
+            .unwrap();
+        let actual: Bytes32 = on_chain
+            .storage::<ContractsState>()
+            .root(&contract_id)
+            .unwrap()
+            .into();
+        on_chain
+            .storage_as_mut::<ContractsStateMerkleMetadata>()
+            .insert(&contract_id, &SparseMerkleMetadata::new([9; 32]))
+            .unwrap();
+
+        // When
+        let mut inconsistencies = vec![];
+        check_contract_roots(&on_chain, &mut inconsistencies).unwrap();
+
+        // Then
+        assert_eq!(
+            inconsistencies,
+            vec![Inconsistency::ContractStateRoot {
+                contract_id,
+                expected: Bytes32::from([9; 32]),
+                actual,
+            }]
+        );
+    }
+
+    #[test]
+    fn check_contract_roots_accepts_valid_contracts() {
+        // Given
+        let mut on_chain = Database::<OnChain>::default();
+        let contract_id = ContractId::from([1; 32]);
+        on_chain
+            .storage_as_mut::<ContractsRawCode>()
+            .insert(&contract_id, &[1, 2, 3])
+            .unwrap();
+        on_chain
+            .storage_as_mut::<ContractsState>()
+            .insert(
+                &ContractsStateKey::new(&contract_id, &Bytes32::from([2; 32])),
+                &Bytes32::from([3; 32]),
+            )
+            .unwrap();
+        on_chain
+            .storage_as_mut::<ContractsAssets>()
+            .insert(&ContractsAssetKey::new(&contract_id, &AssetId::BASE), &10)
+            .unwrap();
+
+        // When
+        let mut inconsistencies = vec![];
+        check_contract_roots(&on_chain, &mut inconsistencies).unwrap();
+
+        // Then
+        assert_eq!(inconsistencies, vec![]);
+    }
+
+    #[test]
+    fn rebuild_off_chain_indexes_removes_stale_block_ids() {
+        // Given
+        let mut on_chain = Database::<OnChain>::default();
+        let mut off_chain = Database::<OffChain>::default();
+        let indexed = block(0, Bytes32::zeroed());
+        on_chain
+            .storage_as_mut::<FuelBlocks>()
+            .insert(&0.into(), &indexed)
+            .unwrap();
+        let stale_id = block(5, Bytes32::zeroed()).header().id();
+        off_chain
+            .storage_as_mut::<FuelBlockIdsToHeights>()
+            .insert(&stale_id, &5.into())
+            .unwrap();
+
+        // When
+        rebuild_off_chain_indexes(&on_chain, &off_chain).unwrap();
+
+        // Then
+        let block_ids = off_chain
+            .iter_all::<FuelBlockIdsToHeights>(None)
+            .collect::<StorageResult<Vec<_>>>()
+            .unwrap();
+        assert_eq!(block_ids, vec![(indexed.header().id(), 0.into())]);
+    }
+
+    #[test]
+    fn rebuild_off_chain_indexes_fixes_owned_coins_and_messages() {
+        // Given
+        let mut on_chain = Database::<OnChain>::default();
+        let mut off_chain = Database::<OffChain>::default();
+        let owner = Address::from([1; 32]);
+        let utxo_id = UtxoId::new(TxId::from([1; 32]), 0);
+        let nonce = Nonce::from(1u64);
+        on_chain
+            .storage_as_mut::<Coins>()
+            .insert(&utxo_id, &coin(owner))
+            .unwrap();
+        on_chain
+            .storage_as_mut::<Messages>()
+            .insert(&nonce, &message(owner, nonce))
+            .unwrap();
+        off_chain
+            .storage_as_mut::<OwnedCoins>()
+            .insert(
+                &owner_coin_id_key(&owner, &UtxoId::new(TxId::from([2; 32]), 0)),
+                &(),
+            )
+            .unwrap();
+        off_chain
+            .storage_as_mut::<OwnedMessageIds>()
+            .insert(&OwnedMessageKey::new(&owner, &Nonce::from(2u64)), &())
+            .unwrap();
+
+        // When
+        rebuild_off_chain_indexes(&on_chain, &off_chain).unwrap();
+
+        // Then
+        let mut inconsistencies = vec![];
+        check_owned_coins(&on_chain, &off_chain, &mut inconsistencies).unwrap();
+        check_owned_messages(&on_chain, &off_chain, &mut inconsistencies).unwrap();
+        assert_eq!(inconsistencies, vec![]);
+    }
+
+    #[test]
+    fn decode_entry_decodes_the_value_of_the_table() {
+        // Given
+        let mut on_chain = Database::<OnChain>::default();
+        let nonce = Nonce::from(1u64);
+        on_chain
+            .storage_as_mut::<SpentMessages>()
+            .insert(&nonce, &())
+            .unwrap();
+
+        // When
+        let entry = on_chain
+            .decode_entry("SpentMessages", nonce.as_ref())
+            .unwrap();
+
+        // Then
+        assert_eq!(
+            entry,
+            Some(DecodedEntry {
+                key: format!("{nonce:?}"),
+                value: "()".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn decode_entry_fails_for_unknown_table() {
+        // Given
+        let on_chain = Database::<OnChain>::default();
+
+        // When
+        let result = on_chain.decode_entry("Unknown", &[]);
+
+        // Then
+        result.expect_err("Should fail because the table doesn't exist");
+    }
+}
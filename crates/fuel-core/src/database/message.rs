@@ -56,7 +56,25 @@ impl Database {
 
     pub fn get_message_config(&self) -> StorageResult<Option<Vec<MessageConfig>>> {
         let configs = self
-            .all_messages(None, None)
+            .message_configs()
+            .collect::<StorageResult<Vec<MessageConfig>>>()?;
+
+        Ok(Some(configs))
+    }
+
+    /// Returns configs of all unspent messages in the order of their nonces.
+    pub fn message_configs(
+        &self,
+    ) -> impl Iterator<Item = StorageResult<MessageConfig>> + '_ {
+        self.message_configs_from(None)
+    }
+
+    /// Returns configs of unspent messages starting from the `start` nonce(inclusive).
+    pub fn message_configs_from(
+        &self,
+        start: Option<Nonce>,
+    ) -> impl Iterator<Item = StorageResult<MessageConfig>> + '_ {
+        self.all_messages(start, None)
             .filter_map(|msg| {
                 // Return only unspent messages
                 if let Ok(msg) = msg {
@@ -81,9 +99,6 @@ impl Database {
                     da_height: msg.da_height(),
                 })
             })
-            .collect::<StorageResult<Vec<MessageConfig>>>()?;
-
-        Ok(Some(configs))
     }
 
     pub fn message_is_spent(&self, id: &Nonce) -> StorageResult<bool> {
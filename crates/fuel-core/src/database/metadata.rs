@@ -1,6 +1,7 @@
 use crate::{
     database::{
         database_description::{
+            on_chain::OnChain,
             DatabaseDescription,
             DatabaseMetadata,
         },
@@ -10,9 +11,18 @@ use crate::{
     },
     state::DataSource,
 };
+use fuel_core_chain_config::StateCheckpoint;
 use fuel_core_storage::{
     blueprint::plain::Plain,
-    codec::postcard::Postcard,
+    codec::{
+        postcard::Postcard,
+        Decode,
+        Encode,
+    },
+    kv_store::{
+        KeyValueStore,
+        WriteOperation,
+    },
     not_found,
     structured_storage::{
         StructuredStorage,
@@ -25,6 +35,16 @@ use fuel_core_storage::{
 };
 use fuel_core_types::fuel_merkle::storage::StorageMutate;
 
+/// The key in the metadata column of the checkpoint whose state is committed
+/// to the on-chain database, but not yet verified and imported into the off-chain
+/// database. It is present only while the import of the checkpoint is not finished.
+const PENDING_CHECKPOINT_KEY: &[u8] = b"pending_checkpoint";
+
+/// The key in the metadata column that is present while the off-chain database
+/// is re-indexed. It is stored in the on-chain database, because the re-indexing
+/// clears the off-chain database.
+const REINDEX_IN_PROGRESS_KEY: &[u8] = b"reindex_in_progress";
+
 /// The table that stores all metadata about the database.
 pub struct MetadataTable<Description>(core::marker::PhantomData<Description>);
 
@@ -62,7 +82,8 @@ where
     Description: DatabaseDescription,
     Self: StorageMutate<MetadataTable<Description>, Error = StorageError>,
 {
-    /// Ensures the database is initialized and that the database version is correct
+    /// Ensures the database is initialized and that the database version is correct.
+    /// The database of an older version is upgraded with migrations.
     pub fn init(&mut self, height: &Description::Height) -> StorageResult<()> {
         use fuel_core_storage::StorageAsMut;
 
@@ -88,6 +109,11 @@ where
             .get(&())?
             .expect("We checked its existence above");
 
+        if metadata.version() < Description::version() {
+            self.migrate(false)?;
+            return Ok(())
+        }
+
         if metadata.version() != Description::version() {
             return Err(DatabaseError::InvalidDatabaseVersion {
                 found: metadata.version(),
@@ -107,3 +133,68 @@ where
         Ok(*metadata.height())
     }
 }
+
+impl Database<OnChain> {
+    /// Records the `checkpoint` before its state is committed, so the import
+    /// can be finished if the node stops before the off-chain database is updated.
+    /// The checkpoint is removed by the [`Database::clear_pending_checkpoint`].
+    pub fn record_pending_checkpoint(
+        &self,
+        checkpoint: &StateCheckpoint,
+    ) -> StorageResult<()> {
+        self.write_untracked(vec![(
+            PENDING_CHECKPOINT_KEY.to_vec(),
+            OnChain::metadata_column(),
+            WriteOperation::Insert(<Postcard as Encode<_>>::encode_as_value(checkpoint)),
+        )])
+    }
+
+    /// Returns the checkpoint whose import is not finished, if any.
+    pub fn pending_checkpoint(&self) -> StorageResult<Option<StateCheckpoint>> {
+        let data_source: &DataSource<OnChain> = self.data.as_ref();
+        data_source
+            .get(PENDING_CHECKPOINT_KEY, OnChain::metadata_column())?
+            .map(|value| {
+                <Postcard as Decode<StateCheckpoint>>::decode(value.as_slice())
+                    .map_err(StorageError::Codec)
+            })
+            .transpose()
+    }
+
+    /// Removes the checkpoint recorded by the [`Database::record_pending_checkpoint`].
+    pub fn clear_pending_checkpoint(&self) -> StorageResult<()> {
+        self.write_untracked(vec![(
+            PENDING_CHECKPOINT_KEY.to_vec(),
+            OnChain::metadata_column(),
+            WriteOperation::Remove,
+        )])
+    }
+
+    /// Records that the off-chain database is being re-indexed, so the node refuses
+    /// to start with the partially re-indexed database.
+    /// The record is removed by the [`Database::clear_reindex_in_progress`].
+    pub fn record_reindex_in_progress(&self) -> StorageResult<()> {
+        self.write_untracked(vec![(
+            REINDEX_IN_PROGRESS_KEY.to_vec(),
+            OnChain::metadata_column(),
+            WriteOperation::Insert(Default::default()),
+        )])
+    }
+
+    /// Returns `true` if the re-indexing of the off-chain database is not finished.
+    pub fn reindex_in_progress(&self) -> StorageResult<bool> {
+        let data_source: &DataSource<OnChain> = self.data.as_ref();
+        Ok(data_source
+            .get(REINDEX_IN_PROGRESS_KEY, OnChain::metadata_column())?
+            .is_some())
+    }
+
+    /// Removes the record of the [`Database::record_reindex_in_progress`].
+    pub fn clear_reindex_in_progress(&self) -> StorageResult<()> {
+        self.write_untracked(vec![(
+            REINDEX_IN_PROGRESS_KEY.to_vec(),
+            OnChain::metadata_column(),
+            WriteOperation::Remove,
+        )])
+    }
+}
@@ -0,0 +1,400 @@
+//! Migrations upgrade the layout of columns of the database created by
+//! an older version of the node to the layout expected by the current version.
+//!
+//! Each [`DatabaseDescription`] has its own registry of migrations.
+//! A migration upgrades the database from one version to the next one,
+//! so the database is upgraded step by step until it reaches
+//! the version returned by [`DatabaseDescription::version`].
+//!
+//! The version is increased only when the released layout changes. The layout
+//! that was never released is changed in place without a migration.
+
+use crate::database::{
+    database_description::{
+        DatabaseDescription,
+        DatabaseMetadata,
+    },
+    metadata::MetadataTable,
+    Database,
+    Error as DatabaseError,
+};
+use fuel_core_storage::{
+    kv_store::{
+        BatchOperations,
+        WriteOperation,
+    },
+    not_found,
+    transactional::Transaction,
+    Error as StorageError,
+    Result as StorageResult,
+    StorageAsMut,
+    StorageAsRef,
+    StorageMutate,
+};
+
+/// The changes of the migration. Each change is a key, column and write operation.
+pub type Changes<Column> = Vec<(Vec<u8>, Column, WriteOperation)>;
+
+/// The number of processed items between two reports about the progress of the migration.
+const PROGRESS_REPORT_INTERVAL: usize = 10_000;
+
+/// The migration of the database from the `from_version` to the next version.
+pub struct Migration<Description>
+where
+    Description: DatabaseDescription,
+{
+    /// The version of the database before the migration.
+    pub from_version: u32,
+    /// The human-readable description of the migration.
+    pub description: &'static str,
+    /// Returns the changes that upgrade the database to the next version.
+    /// The database contains the changes of all previous migrations.
+    /// Each processed item should be recorded in the progress.
+    pub changes: fn(
+        &Database<Description>,
+        &mut MigrationProgress,
+    ) -> StorageResult<Changes<Description::Column>>,
+}
+
+/// The progress of one step of the migration.
+#[derive(Debug)]
+pub struct MigrationProgress {
+    database: &'static str,
+    from_version: u32,
+    items: usize,
+}
+
+impl MigrationProgress {
+    pub(crate) fn new(database: &'static str, from_version: u32) -> Self {
+        Self {
+            database,
+            from_version,
+            items: 0,
+        }
+    }
+
+    /// Records the processed item. The progress is reported
+    /// every [`PROGRESS_REPORT_INTERVAL`] items.
+    pub fn item_processed(&mut self) {
+        self.items = self.items.saturating_add(1);
+        if self.items.checked_rem(PROGRESS_REPORT_INTERVAL) == Some(0) {
+            tracing::info!(
+                "Migrating the {} database from the version {}: processed {} items",
+                self.database,
+                self.from_version,
+                self.items
+            );
+        }
+    }
+
+    /// Returns the number of processed items.
+    pub fn items(&self) -> usize {
+        self.items
+    }
+}
+
+/// The report about one step of the migration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MigrationReport {
+    /// The name of the migrated database.
+    pub database: &'static str,
+    /// The version of the database before the step.
+    pub from_version: u32,
+    /// The version of the database after the step.
+    pub to_version: u32,
+    /// The description of the migration.
+    pub description: &'static str,
+    /// The number of items processed by the migration.
+    pub items: usize,
+    /// The number of changed keys.
+    pub changes: usize,
+}
+
+impl<Description> Database<Description>
+where
+    Description: DatabaseDescription,
+    Self: StorageMutate<MetadataTable<Description>, Error = StorageError>,
+{
+    /// Returns the version of the database stored in the metadata.
+    pub fn stored_version(&self) -> StorageResult<u32> {
+        let metadata = self
+            .storage::<MetadataTable<Description>>()
+            .get(&())?
+            .ok_or(not_found!(MetadataTable<Description>))?;
+        Ok(metadata.version())
+    }
+
+    /// Upgrades the database to the version expected by the node with migrations
+    /// from the registry of the `Description`. All steps are applied atomically.
+    ///
+    /// The uninitialized database doesn't require migrations.
+    /// If `dry_run` is `true`, the migrations are executed without committing
+    /// the result, so only the reports are produced.
+    pub fn migrate(&self, dry_run: bool) -> StorageResult<Vec<MigrationReport>> {
+        self.migrate_with(&Description::migrations(), Description::version(), dry_run)
+    }
+
+    pub(crate) fn migrate_with(
+        &self,
+        migrations: &[Migration<Description>],
+        target_version: u32,
+        dry_run: bool,
+    ) -> StorageResult<Vec<MigrationReport>> {
+        let metadata = match self.storage::<MetadataTable<Description>>().get(&())? {
+            Some(metadata) => metadata.into_owned(),
+            // The uninitialized database is created with the current version.
+            None => return Ok(vec![]),
+        };
+        let mut version = metadata.version();
+        if version > target_version {
+            return Err(DatabaseError::InvalidDatabaseVersion {
+                found: version,
+                expected: target_version,
+            }
+            .into())
+        }
+
+        let mut transaction = self.transaction();
+        let mut reports = vec![];
+        while version < target_version {
+            let migration = migrations
+                .iter()
+                .find(|migration| migration.from_version == version)
+                .ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "The migration of the {} database from the version {} is missing",
+                        Description::name(),
+                        version
+                    )
+                })?;
+            let next_version = version
+                .checked_add(1)
+                .ok_or_else(|| anyhow::anyhow!("The database version overflowed"))?;
+            tracing::info!(
+                "Migrating the {} database from the version {} to {}: {}",
+                Description::name(),
+                version,
+                next_version,
+                migration.description
+            );
+
+            let mut progress = MigrationProgress::new(Description::name(), version);
+            let changes = (migration.changes)(transaction.as_ref(), &mut progress)?;
+            let report = MigrationReport {
+                database: Description::name(),
+                from_version: version,
+                to_version: next_version,
+                description: migration.description,
+                items: progress.items(),
+                changes: changes.len(),
+            };
+            transaction
+                .as_ref()
+                .data
+                .as_ref()
+                .batch_write(&mut changes.into_iter())?;
+            transaction
+                .as_mut()
+                .storage_as_mut::<MetadataTable<Description>>()
+                .insert(
+                    &(),
+                    &DatabaseMetadata::V1 {
+                        version: next_version,
+                        height: *metadata.height(),
+                    },
+                )?;
+            tracing::info!(
+                "Migrated the {} database to the version {}, processed {} items, changed {} keys",
+                Description::name(),
+                next_version,
+                report.items,
+                report.changes
+            );
+
+            reports.push(report);
+            version = next_version;
+        }
+
+        if !dry_run {
+            transaction.commit()?;
+        }
+        Ok(reports)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::database_description::{
+        off_chain::OffChain,
+        on_chain::OnChain,
+        p2p::P2p,
+        relayer::Relayer,
+    };
+    use fuel_core_storage::{
+        column::Column,
+        kv_store::KeyValueStore,
+    };
+    use fuel_core_types::fuel_types::BlockHeight;
+    use std::sync::Arc;
+
+    const FIRST_KEY: &[u8] = b"first";
+    const SECOND_KEY: &[u8] = b"second";
+
+    fn migrations() -> Vec<Migration<OnChain>> {
+        vec![
+            Migration {
+                from_version: 1,
+                description: "Moves the value to the second key",
+                changes: |database, progress| {
+                    progress.item_processed();
+                    let value = database
+                        .data
+                        .as_ref()
+                        .get(FIRST_KEY, Column::Metadata)?
+                        .ok_or(not_found!("first"))?;
+                    Ok(vec![
+                        (FIRST_KEY.to_vec(), Column::Metadata, WriteOperation::Remove),
+                        (
+                            SECOND_KEY.to_vec(),
+                            Column::Metadata,
+                            WriteOperation::Insert(value),
+                        ),
+                    ])
+                },
+            },
+            Migration {
+                from_version: 0,
+                description: "Inserts the value to the first key",
+                changes: |_, _| {
+                    Ok(vec![(
+                        FIRST_KEY.to_vec(),
+                        Column::Metadata,
+                        WriteOperation::Insert(Arc::new(vec![1, 2, 3])),
+                    )])
+                },
+            },
+        ]
+    }
+
+    fn database_with_version(version: u32) -> Database<OnChain> {
+        let mut database = Database::<OnChain>::default();
+        database
+            .storage_as_mut::<MetadataTable<OnChain>>()
+            .insert(
+                &(),
+                &DatabaseMetadata::V1 {
+                    version,
+                    height: BlockHeight::from(10),
+                },
+            )
+            .unwrap();
+        database
+    }
+
+    #[test]
+    fn migrate_applies_migrations_step_by_step() {
+        // Given
+        let database = database_with_version(0);
+
+        // When
+        let reports = database.migrate_with(&migrations(), 2, false).unwrap();
+
+        // Then
+        assert_eq!(
+            reports
+                .iter()
+                .map(|report| (
+                    report.from_version,
+                    report.to_version,
+                    report.items,
+                    report.changes
+                ))
+                .collect::<Vec<_>>(),
+            vec![(0, 1, 0, 1), (1, 2, 1, 2)]
+        );
+        assert_eq!(database.stored_version().unwrap(), 2);
+        assert_eq!(database.latest_height().unwrap(), BlockHeight::from(10));
+        let data = database.data.as_ref();
+        assert_eq!(data.get(FIRST_KEY, Column::Metadata).unwrap(), None);
+        assert_eq!(
+            data.get(SECOND_KEY, Column::Metadata).unwrap(),
+            Some(Arc::new(vec![1, 2, 3]))
+        );
+    }
+
+    #[test]
+    fn migrate_in_dry_run_does_not_change_database() {
+        // Given
+        let database = database_with_version(0);
+
+        // When
+        let reports = database.migrate_with(&migrations(), 2, true).unwrap();
+
+        // Then
+        assert_eq!(reports.len(), 2);
+        assert_eq!(database.stored_version().unwrap(), 0);
+        let data = database.data.as_ref();
+        assert_eq!(data.get(FIRST_KEY, Column::Metadata).unwrap(), None);
+        assert_eq!(data.get(SECOND_KEY, Column::Metadata).unwrap(), None);
+    }
+
+    #[test]
+    fn migrate_fails_without_migration_for_version() {
+        // Given
+        let database = database_with_version(0);
+
+        // When
+        let result = database.migrate_with(&migrations(), 3, false);
+
+        // Then
+        assert!(result.is_err());
+        assert_eq!(database.stored_version().unwrap(), 0);
+    }
+
+    #[test]
+    fn migrate_fails_for_newer_database() {
+        // Given
+        let database = database_with_version(3);
+
+        // When
+        let result = database.migrate_with(&migrations(), 2, false);
+
+        // Then
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn migrate_skips_uninitialized_database() {
+        // Given
+        let database = Database::<OnChain>::default();
+
+        // When
+        let reports = database.migrate_with(&migrations(), 2, false).unwrap();
+
+        // Then
+        assert!(reports.is_empty());
+    }
+
+    fn assert_registry_covers_all_versions<Description: DatabaseDescription>() {
+        let mut from_versions = Description::migrations()
+            .iter()
+            .map(|migration| migration.from_version)
+            .collect::<Vec<_>>();
+        from_versions.sort();
+        assert_eq!(
+            from_versions,
+            (0..Description::version()).collect::<Vec<_>>(),
+            "The migrations of the {} database",
+            Description::name()
+        );
+    }
+
+    #[test]
+    fn registries_cover_all_versions() {
+        assert_registry_covers_all_versions::<OnChain>();
+        assert_registry_covers_all_versions::<OffChain>();
+        assert_registry_covers_all_versions::<Relayer>();
+        assert_registry_covers_all_versions::<P2p>();
+    }
+}
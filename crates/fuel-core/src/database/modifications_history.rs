@@ -0,0 +1,783 @@
+//! The history of modifications allows rolling back the database to previous heights.
+//! Each commit that increases the height of the database also stores
+//! the reverse changes that restore the state before the commit.
+//! Commits that don't change the height, like rebuilding of indexes, extend
+//! the reverse changes of the current height.
+//!
+//! Only the reverse changes of the latest heights are kept in the history.
+//! The number of these heights is set by the [`Database::set_history_retention`]
+//! and is [`DEFAULT_MODIFICATIONS_HISTORY_RETENTION`] by default.
+
+use crate::{
+    database::{
+        database_description::{
+            DatabaseDescription,
+            DatabaseMetadata,
+        },
+        metadata::MetadataTable,
+        Database,
+    },
+    state::DataSource,
+};
+use fuel_core_storage::{
+    codec::{
+        postcard::Postcard,
+        Decode,
+        Encode,
+        Encoder,
+    },
+    iter::IterDirection,
+    kv_store::{
+        BatchOperations,
+        KeyValueStore,
+        StorageColumn,
+        WriteOperation,
+    },
+    Error as StorageError,
+    Result as StorageResult,
+    StorageMutate,
+};
+use fuel_core_types::fuel_types::BlockHeight;
+use std::{
+    collections::{
+        HashMap,
+        HashSet,
+    },
+    num::NonZeroU32,
+};
+
+type Entry<Column> = (Vec<u8>, Column, WriteOperation);
+
+/// The default number of the latest heights that keep their reverse changes.
+/// The database can't be rolled back deeper than that. It covers the default
+/// interval between state snapshots, so the latest state can be rolled back
+/// to the previous snapshot.
+pub const DEFAULT_MODIFICATIONS_HISTORY_RETENTION: u32 = 10_000;
+
+/// The key in the metadata column of the height to which the database is being
+/// rolled back. It is present only while the rollback is not finished.
+const ROLLBACK_TARGET_KEY: &[u8] = b"rollback_target";
+
+/// The key in the metadata column of the number of the latest heights
+/// that keep their reverse changes. If it is absent, the default retention is used.
+const HISTORY_RETENTION_KEY: &[u8] = b"history_retention";
+
+/// Returns the height whose reverse changes leave the retention window
+/// when the database reaches the `height`.
+pub(crate) fn pruned_height(
+    height: &BlockHeight,
+    retention: NonZeroU32,
+) -> Option<BlockHeight> {
+    (**height)
+        .checked_sub(retention.get())
+        .map(BlockHeight::from)
+}
+
+/// Returns the number of the latest heights that keep their reverse changes.
+fn history_retention<Description>(
+    storage: &DataSource<Description>,
+) -> StorageResult<NonZeroU32>
+where
+    Description: DatabaseDescription,
+{
+    let retention = storage
+        .get(HISTORY_RETENTION_KEY, Description::metadata_column())?
+        .map(|value| {
+            <Postcard as Decode<NonZeroU32>>::decode(value.as_slice())
+                .map_err(StorageError::Codec)
+        })
+        .transpose()?;
+    Ok(retention.unwrap_or(
+        NonZeroU32::new(DEFAULT_MODIFICATIONS_HISTORY_RETENTION)
+            .expect("The retention is not zero"),
+    ))
+}
+
+/// The change that restores the value of the `key` in the `column`.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ReverseChange {
+    /// The id of the column.
+    pub column: u32,
+    /// The key in the column.
+    pub key: Vec<u8>,
+    /// The value before the commit. `None` if the key didn't exist.
+    pub value: Option<Vec<u8>>,
+}
+
+fn metadata_key() -> Vec<u8> {
+    <Postcard as Encode<()>>::encode(&())
+        .as_bytes()
+        .into_owned()
+}
+
+fn history_key<Height>(height: &Height) -> Vec<u8>
+where
+    Height: serde::Serialize,
+{
+    <Postcard as Encode<Height>>::encode(height)
+        .as_bytes()
+        .into_owned()
+}
+
+fn decode_height<Description>(bytes: &[u8]) -> StorageResult<Description::Height>
+where
+    Description: DatabaseDescription,
+{
+    let metadata =
+        <Postcard as Decode<DatabaseMetadata<Description::Height>>>::decode(bytes)
+            .map_err(StorageError::Codec)?;
+    Ok(*metadata.height())
+}
+
+/// Appends the reverse changes of the `entries` to them.
+///
+/// If the `entries` increase the height of the database, the reverse changes are stored
+/// at the new height, and the height that leaves the retention window is pruned.
+/// Otherwise, the reverse changes of keys modified for the first time at the current height
+/// are added to the history of the current height, so rolling back below it reverts
+/// the `entries` as well. The history is extended only if it exists, so commits
+/// at the initial height, like the genesis, are not tracked.
+///
+/// Every commit to the database with the history reads the previous value of every
+/// written key to build the reverse changes, so each write costs an additional read.
+/// It is the main cost of the history, while the retention only affects the disk space.
+pub(crate) fn with_reverse_changes<Description>(
+    storage: &DataSource<Description>,
+    history_column: Description::Column,
+    entries: &mut dyn Iterator<Item = Entry<Description::Column>>,
+) -> StorageResult<Vec<Entry<Description::Column>>>
+where
+    Description: DatabaseDescription,
+{
+    let mut entries: Vec<_> = entries.collect();
+    let metadata_column = Description::metadata_column();
+    let metadata_key = metadata_key();
+
+    // The first height of the database is set during initialization or the genesis,
+    // and it is not possible to roll back below it.
+    let old_height = storage
+        .get(&metadata_key, metadata_column)?
+        .map(|value| decode_height::<Description>(value.as_slice()))
+        .transpose()?;
+    let old_height = match old_height {
+        Some(old_height) if !entries.is_empty() => old_height,
+        _ => return Ok(entries),
+    };
+
+    let new_height = entries
+        .iter()
+        .rev()
+        .find(|(key, column, _)| {
+            column.id() == metadata_column.id() && key == &metadata_key
+        })
+        .and_then(|(_, _, operation)| match operation {
+            WriteOperation::Insert(value) => Some(value.clone()),
+            WriteOperation::Remove => None,
+        })
+        .map(|value| decode_height::<Description>(value.as_slice()))
+        .transpose()?;
+
+    let (height, mut reverse_changes) = match new_height {
+        Some(new_height) if new_height > old_height => (new_height, vec![]),
+        Some(new_height) if new_height < old_height => return Ok(entries),
+        _ => {
+            let history = storage.get(&history_key(&old_height), history_column)?;
+            match history {
+                Some(history) => {
+                    let history = <Postcard as Decode<Vec<ReverseChange>>>::decode(
+                        history.as_slice(),
+                    )
+                    .map_err(StorageError::Codec)?;
+                    (old_height, history)
+                }
+                None => return Ok(entries),
+            }
+        }
+    };
+
+    // Keys that already have the reverse changes at the `height` keep them,
+    // because they restore older values.
+    let mut visited = reverse_changes
+        .iter()
+        .map(|change| (change.column, change.key.clone()))
+        .collect::<HashSet<_>>();
+    let known_changes = reverse_changes.len();
+    for (key, column, _) in entries.iter() {
+        if column.id() == history_column.id()
+            || !visited.insert((column.id(), key.clone()))
+        {
+            continue
+        }
+
+        let value = storage.get(key, *column)?.map(|value| value.to_vec());
+        reverse_changes.push(ReverseChange {
+            column: column.id(),
+            key: key.clone(),
+            value,
+        });
+    }
+    if reverse_changes.len() == known_changes && height == old_height {
+        return Ok(entries)
+    }
+
+    entries.push((
+        history_key(&height),
+        history_column,
+        WriteOperation::Insert(<Postcard as Encode<_>>::encode_as_value(
+            &reverse_changes,
+        )),
+    ));
+    if height != old_height {
+        let retention = history_retention(storage)?;
+        if let Some(pruned) = Description::pruned_history_height(&height, retention) {
+            entries.push((history_key(&pruned), history_column, WriteOperation::Remove));
+        }
+    }
+    Ok(entries)
+}
+
+impl<Description> Database<Description>
+where
+    Description: DatabaseDescription<Height = BlockHeight>,
+    Self: StorageMutate<MetadataTable<Description>, Error = StorageError>,
+{
+    /// Sets the number of the latest heights that keep their reverse changes.
+    /// The reverse changes of heights outside of the new retention window
+    /// are removed in the same write.
+    pub fn set_history_retention(&self, retention: NonZeroU32) -> StorageResult<()> {
+        let data_source: &DataSource<Description> = self.data.as_ref();
+        let old_retention = history_retention(data_source)?;
+        if old_retention == retention {
+            return Ok(())
+        }
+
+        let mut entries = if retention < old_retention {
+            self.outdated_history(retention)?
+        } else {
+            vec![]
+        };
+        entries.push((
+            HISTORY_RETENTION_KEY.to_vec(),
+            Description::metadata_column(),
+            WriteOperation::Insert(<Postcard as Encode<_>>::encode_as_value(&retention)),
+        ));
+        self.write_untracked(entries)
+    }
+
+    /// Returns the removal of the reverse changes of heights outside of the retention window.
+    fn outdated_history(
+        &self,
+        retention: NonZeroU32,
+    ) -> StorageResult<Vec<Entry<Description::Column>>> {
+        let history_column = Self::history_column()?;
+        let pruned =
+            match Description::pruned_history_height(&self.latest_height()?, retention) {
+                Some(pruned) => pruned,
+                None => return Ok(vec![]),
+            };
+
+        let mut changes = vec![];
+        // The keys are encoded with variable length, so they are not sorted by the height.
+        for entry in self.data.as_ref().iter_all(
+            history_column,
+            None,
+            None,
+            IterDirection::Forward,
+        ) {
+            let (key, _) = entry?;
+            let height = <Postcard as Decode<BlockHeight>>::decode(&key)
+                .map_err(StorageError::Codec)?;
+            if height <= pruned {
+                changes.push((key, history_column, WriteOperation::Remove));
+            }
+        }
+        Ok(changes)
+    }
+
+    /// Verifies that the reverse changes of all heights above the `height` are stored,
+    /// so the database can be rolled back to the `height`.
+    pub fn check_rollback(&self, height: &BlockHeight) -> StorageResult<()> {
+        let history_column = Self::history_column()?;
+        let data_source: &DataSource<Description> = self.data.as_ref();
+
+        let mut current = self.latest_height()?;
+        while current > *height {
+            if !data_source.exists(&history_key(&current), history_column)? {
+                return Err(anyhow::anyhow!(
+                    "The reverse changes of the block {} are missing in the {} database, \
+                    only the last {} blocks can be rolled back",
+                    *current,
+                    Description::name(),
+                    history_retention(data_source)?
+                )
+                .into())
+            }
+            current = current.pred().expect("The height is above another height");
+        }
+        Ok(())
+    }
+
+    /// Returns the write operations that roll back the database from the `height`
+    /// to the previous height by applying the reverse changes of the `height`.
+    pub fn rollback_changes(
+        &self,
+        height: &BlockHeight,
+    ) -> StorageResult<Vec<Entry<Description::Column>>> {
+        let history_column = Self::history_column()?;
+        let data_source: &DataSource<Description> = self.data.as_ref();
+
+        let key = history_key(height);
+        let reverse_changes =
+            data_source.get(&key, history_column)?.ok_or_else(|| {
+                anyhow::anyhow!(
+                    "The reverse changes of the block {} are missing in the {} database",
+                    **height,
+                    Description::name()
+                )
+            })?;
+        let reverse_changes =
+            <Postcard as Decode<Vec<ReverseChange>>>::decode(reverse_changes.as_slice())
+                .map_err(StorageError::Codec)?;
+
+        let columns = enum_iterator::all::<Description::Column>()
+            .map(|column| (column.id(), column))
+            .collect::<HashMap<_, _>>();
+        let mut entries = Vec::with_capacity(reverse_changes.len().saturating_add(1));
+        for change in reverse_changes {
+            let column = columns.get(&change.column).copied().ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Unknown column {} in the {} database",
+                    change.column,
+                    Description::name()
+                )
+            })?;
+            let operation = match change.value {
+                Some(value) => WriteOperation::Insert(std::sync::Arc::new(value)),
+                None => WriteOperation::Remove,
+            };
+            entries.push((change.key, column, operation));
+        }
+        entries.push((key, history_column, WriteOperation::Remove));
+
+        Ok(entries)
+    }
+
+    /// Records the `height` to which the database is being rolled back,
+    /// so the rollback can be resumed if it is interrupted.
+    /// The target is removed by the [`Database::rollback_to`] when it is finished.
+    pub fn record_rollback_target(&self, height: &BlockHeight) -> StorageResult<()> {
+        let entry = (
+            ROLLBACK_TARGET_KEY.to_vec(),
+            Description::metadata_column(),
+            WriteOperation::Insert(<Postcard as Encode<_>>::encode_as_value(height)),
+        );
+        self.write_untracked(vec![entry])
+    }
+
+    /// Returns the height to which the database is being rolled back,
+    /// or `None` if there is no unfinished rollback.
+    pub fn rollback_target(&self) -> StorageResult<Option<BlockHeight>> {
+        let data_source: &DataSource<Description> = self.data.as_ref();
+        data_source
+            .get(ROLLBACK_TARGET_KEY, Description::metadata_column())?
+            .map(|value| {
+                <Postcard as Decode<BlockHeight>>::decode(value.as_slice())
+                    .map_err(StorageError::Codec)
+            })
+            .transpose()
+    }
+
+    /// Rolls back the database to the `height` one height at a time, so only
+    /// the reverse changes of one height are kept in memory. Each step is atomic
+    /// and leaves the database at the previous height, so the interrupted rollback
+    /// can be continued from where it stopped.
+    ///
+    /// Nothing is modified if the reverse changes of any height are missing.
+    /// The rollback target is removed once the database reaches the `height`.
+    pub fn rollback_to(&self, height: &BlockHeight) -> StorageResult<()> {
+        self.check_rollback(height)?;
+
+        let mut current = self.latest_height()?;
+        while current > *height {
+            let entries = self.rollback_changes(&current)?;
+            self.write_untracked(entries)?;
+
+            let previous = self.latest_height()?;
+            if previous >= current {
+                return Err(anyhow::anyhow!(
+                    "The reverse changes of the block {} don't decrease the height \
+                    of the {} database",
+                    *current,
+                    Description::name()
+                )
+                .into())
+            }
+            current = previous;
+        }
+
+        self.write_untracked(vec![(
+            ROLLBACK_TARGET_KEY.to_vec(),
+            Description::metadata_column(),
+            WriteOperation::Remove,
+        )])
+    }
+
+    fn history_column() -> StorageResult<Description::Column> {
+        Description::modifications_history_column().ok_or_else(|| {
+            anyhow::anyhow!(
+                "The {} database doesn't track the history of modifications",
+                Description::name()
+            )
+            .into()
+        })
+    }
+
+    /// Writes the `entries` to the underlying storage directly, since the rollback
+    /// decreases the height and shouldn't be tracked in the history.
+    pub(crate) fn write_untracked(
+        &self,
+        entries: Vec<Entry<Description::Column>>,
+    ) -> StorageResult<()> {
+        let data_source: &DataSource<Description> = self.data.as_ref();
+        data_source.as_ref().batch_write(&mut entries.into_iter())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+    use crate::{
+        combined_database::CombinedDatabase,
+        database::database_description::{
+            off_chain::OffChain,
+            on_chain::OnChain,
+        },
+    };
+    use fuel_core_storage::{
+        kv_store::Value,
+        tables::SpentMessages,
+        transactional::Transaction,
+        StorageAsMut,
+        StorageAsRef,
+    };
+    use fuel_core_types::fuel_types::Nonce;
+
+    fn metadata(height: u32) -> DatabaseMetadata<BlockHeight> {
+        DatabaseMetadata::V1 {
+            version: OnChain::version(),
+            height: height.into(),
+        }
+    }
+
+    fn commit_block(
+        database: &Database<OnChain>,
+        height: u32,
+        spent: &[Nonce],
+        unspent: &[Nonce],
+    ) {
+        commit(database, Some(height), spent, unspent)
+    }
+
+    fn commit(
+        database: &Database<OnChain>,
+        height: Option<u32>,
+        spent: &[Nonce],
+        unspent: &[Nonce],
+    ) {
+        let mut transaction = database.transaction();
+        for nonce in spent {
+            transaction
+                .as_mut()
+                .storage_as_mut::<SpentMessages>()
+                .insert(nonce, &())
+                .unwrap();
+        }
+        for nonce in unspent {
+            transaction
+                .as_mut()
+                .storage_as_mut::<SpentMessages>()
+                .remove(nonce)
+                .unwrap();
+        }
+        if let Some(height) = height {
+            transaction
+                .as_mut()
+                .storage_as_mut::<MetadataTable<OnChain>>()
+                .insert(&(), &metadata(height))
+                .unwrap();
+        }
+        transaction.commit().unwrap();
+    }
+
+    fn commit_off_chain_block(database: &Database<OffChain>, height: u32) {
+        let mut transaction = database.transaction();
+        transaction
+            .as_mut()
+            .storage_as_mut::<MetadataTable<OffChain>>()
+            .insert(
+                &(),
+                &DatabaseMetadata::V1 {
+                    version: OffChain::version(),
+                    height: height.into(),
+                },
+            )
+            .unwrap();
+        transaction.commit().unwrap();
+    }
+
+    fn history(database: &Database<OnChain>, height: u32) -> Option<Value> {
+        let data_source: &DataSource<OnChain> = database.data.as_ref();
+        data_source
+            .get(
+                &history_key(&BlockHeight::from(height)),
+                fuel_core_storage::column::Column::ModificationsHistory,
+            )
+            .unwrap()
+    }
+
+    fn is_spent(database: &Database<OnChain>, nonce: &Nonce) -> bool {
+        database
+            .storage::<SpentMessages>()
+            .contains_key(nonce)
+            .unwrap()
+    }
+
+    #[test]
+    fn rollback_restores_the_state_of_previous_heights() {
+        // Given
+        let mut database = Database::<OnChain>::in_memory();
+        database.init(&0.into()).unwrap();
+        let first = Nonce::from(1u64);
+        let second = Nonce::from(2u64);
+        commit_block(&database, 1, &[first], &[]);
+        commit_block(&database, 2, &[second], &[first]);
+
+        // When
+        database.rollback_to(&1.into()).unwrap();
+
+        // Then
+        assert_eq!(database.latest_height().unwrap(), 1.into());
+        assert!(is_spent(&database, &first));
+        assert!(!is_spent(&database, &second));
+
+        // When
+        database.rollback_to(&0.into()).unwrap();
+
+        // Then
+        assert_eq!(database.latest_height().unwrap(), 0.into());
+        assert!(!is_spent(&database, &first));
+        assert!(!is_spent(&database, &second));
+    }
+
+    #[test]
+    fn rollback_to_the_latest_height_does_nothing() {
+        // Given
+        let mut database = Database::<OnChain>::in_memory();
+        database.init(&0.into()).unwrap();
+        let nonce = Nonce::from(1u64);
+        commit_block(&database, 1, &[nonce], &[]);
+
+        // When
+        database.rollback_to(&1.into()).unwrap();
+
+        // Then
+        assert_eq!(database.latest_height().unwrap(), 1.into());
+        assert!(is_spent(&database, &nonce));
+    }
+
+    #[test]
+    fn rollback_fails_without_reverse_changes() {
+        // Given
+        let mut database = Database::<OnChain>::in_memory();
+        database.init(&0.into()).unwrap();
+        let nonce = Nonce::from(1u64);
+        commit_block(&database, 1, &[nonce], &[]);
+        // The direct write bypasses the history of modifications.
+        database
+            .storage_as_mut::<MetadataTable<OnChain>>()
+            .insert(&(), &metadata(2))
+            .unwrap();
+
+        // When
+        let result = database.rollback_to(&0.into());
+
+        // Then
+        let err = result.expect_err("Should fail because the block 2 has no history");
+        assert!(err.to_string().contains("are missing"));
+        assert_eq!(database.latest_height().unwrap(), 2.into());
+        assert!(is_spent(&database, &nonce));
+    }
+
+    #[test]
+    fn rollback_reverts_commits_without_new_height() {
+        // Given
+        let mut database = Database::<OnChain>::in_memory();
+        database.init(&0.into()).unwrap();
+        let first = Nonce::from(1u64);
+        let second = Nonce::from(2u64);
+        commit_block(&database, 1, &[first], &[]);
+        commit(&database, None, &[second], &[first]);
+
+        // When
+        database.rollback_to(&0.into()).unwrap();
+
+        // Then
+        assert_eq!(database.latest_height().unwrap(), 0.into());
+        assert!(!is_spent(&database, &first));
+        assert!(!is_spent(&database, &second));
+    }
+
+    #[test]
+    fn commits_at_the_initial_height_are_not_tracked() {
+        // Given
+        let mut database = Database::<OnChain>::in_memory();
+        database.init(&0.into()).unwrap();
+        let nonce = Nonce::from(1u64);
+
+        // When
+        commit(&database, None, &[nonce], &[]);
+
+        // Then
+        assert!(history(&database, 0).is_none());
+    }
+
+    #[test]
+    fn history_outside_of_retention_window_is_pruned() {
+        // Given
+        let mut database = Database::<OnChain>::in_memory();
+        database.init(&0.into()).unwrap();
+        let nonce = Nonce::from(1u64);
+        commit_block(&database, 1, &[nonce], &[]);
+        assert!(history(&database, 1).is_some());
+
+        // When
+        let height = DEFAULT_MODIFICATIONS_HISTORY_RETENTION.saturating_add(1);
+        commit_block(&database, height, &[], &[nonce]);
+
+        // Then
+        assert!(history(&database, 1).is_none());
+        assert!(history(&database, height).is_some());
+    }
+
+    #[test]
+    fn set_history_retention__prunes_history_outside_of_new_retention_window() {
+        // Given
+        let mut database = Database::<OnChain>::in_memory();
+        database.init(&0.into()).unwrap();
+        for height in 1..=5 {
+            commit_block(&database, height, &[Nonce::from(u64::from(height))], &[]);
+        }
+
+        // When
+        let retention = NonZeroU32::new(2).unwrap();
+        database.set_history_retention(retention).unwrap();
+
+        // Then
+        for height in 1..=3 {
+            assert!(history(&database, height).is_none());
+        }
+        assert!(history(&database, 4).is_some());
+        assert!(history(&database, 5).is_some());
+        database.rollback_to(&3.into()).unwrap();
+        let err = database
+            .rollback_to(&2.into())
+            .expect_err("Should fail because the block 3 is outside of the retention");
+        assert!(err.to_string().contains("only the last 2 blocks"));
+    }
+
+    #[test]
+    fn commit__prunes_history_with_configured_retention() {
+        // Given
+        let mut database = Database::<OnChain>::in_memory();
+        database.init(&0.into()).unwrap();
+        database
+            .set_history_retention(NonZeroU32::new(2).unwrap())
+            .unwrap();
+        commit_block(&database, 1, &[Nonce::from(1u64)], &[]);
+        commit_block(&database, 2, &[Nonce::from(2u64)], &[]);
+        assert!(history(&database, 1).is_some());
+
+        // When
+        commit_block(&database, 3, &[Nonce::from(3u64)], &[]);
+
+        // Then
+        assert!(history(&database, 1).is_none());
+        assert!(history(&database, 2).is_some());
+        assert!(history(&database, 3).is_some());
+    }
+
+    #[test]
+    fn interrupted_rollback__fails_init_until_resumed() {
+        // Given
+        let mut database = CombinedDatabase::in_memory();
+        database.init(&0.into(), &0u64.into()).unwrap();
+        let nonce = Nonce::from(1u64);
+        for height in 1..=2 {
+            let spent = if height == 1 { vec![nonce] } else { vec![] };
+            commit_block(database.on_chain(), height, &spent, &[]);
+            commit_off_chain_block(database.off_chain(), height);
+        }
+        // The node stops after the off-chain database rolls back one block.
+        database
+            .on_chain()
+            .record_rollback_target(&0.into())
+            .unwrap();
+        let changes = database.off_chain().rollback_changes(&2.into()).unwrap();
+        database.off_chain().write_untracked(changes).unwrap();
+        assert_eq!(database.off_chain().latest_height().unwrap(), 1.into());
+        assert_eq!(database.on_chain().latest_height().unwrap(), 2.into());
+
+        assert!(database.init(&0.into(), &0u64.into()).is_err());
+
+        // When
+        database.resume_rollback().unwrap();
+
+        // Then
+        assert!(database.init(&0.into(), &0u64.into()).is_ok());
+        assert_eq!(database.on_chain().rollback_target().unwrap(), None);
+        assert_eq!(database.on_chain().latest_height().unwrap(), 0.into());
+        assert_eq!(database.off_chain().latest_height().unwrap(), 0.into());
+        assert!(!is_spent(database.on_chain(), &nonce));
+    }
+
+    #[test]
+    fn rollback_changes_revert_only_one_height() {
+        // Given
+        let mut database = Database::<OnChain>::in_memory();
+        database.init(&0.into()).unwrap();
+        let first = Nonce::from(1u64);
+        let second = Nonce::from(2u64);
+        commit_block(&database, 1, &[first], &[]);
+        commit_block(&database, 2, &[second], &[]);
+
+        // When
+        let changes = database.rollback_changes(&2.into()).unwrap();
+        database.write_untracked(changes).unwrap();
+
+        // Then
+        assert_eq!(database.latest_height().unwrap(), 1.into());
+        assert!(is_spent(&database, &first));
+        assert!(!is_spent(&database, &second));
+        assert!(history(&database, 1).is_some());
+        assert!(history(&database, 2).is_none());
+    }
+
+    #[test]
+    fn rollback_to_removes_rollback_target() {
+        // Given
+        let mut database = Database::<OnChain>::in_memory();
+        database.init(&0.into()).unwrap();
+        commit_block(&database, 1, &[Nonce::from(1u64)], &[]);
+        database.record_rollback_target(&0.into()).unwrap();
+        assert_eq!(database.rollback_target().unwrap(), Some(0.into()));
+
+        // When
+        database.rollback_to(&0.into()).unwrap();
+
+        // Then
+        assert_eq!(database.rollback_target().unwrap(), None);
+        assert_eq!(database.latest_height().unwrap(), 0.into());
+    }
+}
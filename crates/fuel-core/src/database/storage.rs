@@ -31,6 +31,7 @@ use fuel_core_storage::{
         ContractsLatestUtxo,
         ContractsRawCode,
         ContractsState,
+        HeaderOnlyBlocks,
         Messages,
         ProcessedTransactions,
         SealedBlockConsensus,
@@ -83,6 +84,7 @@ use_structured_implementation!(
     ContractsInfo,
     SpentMessages,
     SealedBlockConsensus,
+    HeaderOnlyBlocks,
     Transactions,
     ProcessedTransactions,
     ContractsStateMerkleMetadata,
@@ -104,6 +106,11 @@ use_structured_implementation!(
     fuel_core_relayer::storage::DaHeightTable,
     fuel_core_relayer::storage::EventsHistory
 );
+#[cfg(feature = "p2p")]
+use_structured_implementation!(
+    fuel_core_p2p::peer_store::KnownPeers,
+    fuel_core_p2p::peer_store::BannedPeers
+);
 
 impl<Description, M> StorageInspect<M> for Database<Description>
 where
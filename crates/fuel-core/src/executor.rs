@@ -2828,6 +2828,11 @@ mod tests {
             transactional::Transaction,
             StorageAsMut,
         };
+        use fuel_core_types::{
+            entities::relayer::transaction::RelayedTransaction,
+            fuel_tx::Transaction as FuelTx,
+            fuel_types::Nonce,
+        };
 
         fn database_with_genesis_block(da_block_height: u64) -> Database<OnChain> {
             let db = Database::default();
@@ -2854,6 +2859,39 @@ mod tests {
             db_transaction.commit().expect("Should commit events");
         }
 
+        fn add_events_to_relayer(
+            db: &mut Database<Relayer>,
+            da_height: DaBlockHeight,
+            events: &[Event],
+        ) {
+            let mut db_transaction = db.transaction();
+            db.storage::<EventsHistory>()
+                .insert(&da_height, events)
+                .expect("Should insert events");
+            db_transaction.commit().expect("Should commit events");
+        }
+
+        fn relayed_transaction(
+            nonce: u64,
+            da_height: u64,
+            max_gas: Word,
+            tx: &FuelTx,
+        ) -> RelayedTransaction {
+            let mut relayed_tx = RelayedTransaction::default();
+            relayed_tx.set_nonce(nonce.into());
+            relayed_tx.set_da_height(da_height.into());
+            relayed_tx.set_max_gas(max_gas);
+            relayed_tx.set_serialized_transaction(tx.to_bytes());
+            relayed_tx
+        }
+
+        fn forced_script() -> FuelTx {
+            TransactionBuilder::script(vec![], vec![])
+                .script_gas_limit(10)
+                .add_random_fee_input()
+                .finalize_as_transaction()
+        }
+
         fn add_messages_to_relayer(db: &mut Database<Relayer>, relayer_da_height: u64) {
             for da_height in 0..=relayer_da_height {
                 let mut message = Message::default();
@@ -3062,5 +3100,262 @@ mod tests {
                 ExecutorEvent::MessageConsumed(_)
             ));
         }
+
+        #[test]
+        fn block_producer_includes_forced_transaction_at_the_start_of_the_block() {
+            let genesis_da_height = 1u64;
+            let on_chain_db = database_with_genesis_block(genesis_da_height);
+            let mut relayer_db = Database::<Relayer>::default();
+
+            // Given
+            let block_height = 1u32;
+            let block_da_height = 2u64;
+            let forced_tx = forced_script();
+            let relayed_tx =
+                relayed_transaction(1, block_da_height, u64::MAX, &forced_tx);
+            add_events_to_relayer(
+                &mut relayer_db,
+                block_da_height.into(),
+                &[relayed_tx.into()],
+            );
+
+            // When
+            let producer = create_relayer_executor(on_chain_db, relayer_db);
+            let block = test_block(block_height.into(), block_da_height.into(), 1);
+            let pool_tx = block.transactions()[0].clone();
+            let result = producer
+                .execute_and_commit(
+                    ExecutionTypes::Production(block.into()),
+                    Default::default(),
+                )
+                .unwrap();
+
+            // Then
+            let chain_id = producer.config.consensus_parameters.chain_id;
+            let transactions = result.block.transactions();
+            assert!(result.skipped_transactions.is_empty());
+            assert_eq!(transactions.len(), 3);
+            assert_eq!(transactions[0].id(&chain_id), forced_tx.id(&chain_id));
+            assert_eq!(transactions[1].id(&chain_id), pool_tx.id(&chain_id));
+            assert!(matches!(transactions[2], FuelTx::Mint(_)));
+        }
+
+        #[test]
+        fn block_producer_skips_invalid_forced_transactions() {
+            let genesis_da_height = 1u64;
+            let on_chain_db = database_with_genesis_block(genesis_da_height);
+            let mut relayer_db = Database::<Relayer>::default();
+
+            // Given
+            let block_height = 1u32;
+            let block_da_height = 2u64;
+            let mut undecodable_tx = RelayedTransaction::default();
+            undecodable_tx.set_nonce(1u64.into());
+            undecodable_tx.set_da_height(block_da_height.into());
+            undecodable_tx.set_serialized_transaction(vec![1, 2, 3]);
+            let underpaid_tx =
+                relayed_transaction(2, block_da_height, 0, &forced_script());
+            add_events_to_relayer(
+                &mut relayer_db,
+                block_da_height.into(),
+                &[undecodable_tx.into(), underpaid_tx.into()],
+            );
+
+            // When
+            let producer = create_relayer_executor(on_chain_db, relayer_db);
+            let block = test_block(block_height.into(), block_da_height.into(), 0);
+            let result = producer
+                .execute_and_commit(
+                    ExecutionTypes::Production(block.into()),
+                    Default::default(),
+                )
+                .unwrap();
+
+            // Then
+            assert!(result.skipped_transactions.is_empty());
+            assert_eq!(result.block.transactions().len(), 1);
+            assert_eq!(result.events.len(), 2);
+            for (event, expected_nonce) in result.events.iter().zip([1u64, 2]) {
+                assert!(matches!(
+                    event,
+                    ExecutorEvent::ForcedTransactionFailed { nonce, .. }
+                        if *nonce == Nonce::from(expected_nonce)
+                ));
+            }
+        }
+
+        #[test]
+        fn block_producer_skips_forced_transactions_exceeding_block_gas_limit() {
+            let genesis_da_height = 1u64;
+            let on_chain_db = database_with_genesis_block(genesis_da_height);
+            let mut relayer_db = Database::<Relayer>::default();
+
+            // Given
+            let block_height = 1u32;
+            let block_da_height = 2u64;
+            let relayed_tx =
+                relayed_transaction(1, block_da_height, u64::MAX, &forced_script());
+            add_events_to_relayer(
+                &mut relayer_db,
+                block_da_height.into(),
+                &[relayed_tx.into()],
+            );
+            let config = Config {
+                block_gas_limit: 0,
+                ..Default::default()
+            };
+
+            // When
+            let producer = Executor {
+                database_view_provider: on_chain_db,
+                relayer_view_provider: relayer_db,
+                config: Arc::new(config),
+            };
+            let block = test_block(block_height.into(), block_da_height.into(), 0);
+            let result = producer
+                .execute_and_commit(
+                    ExecutionTypes::Production(block.into()),
+                    Default::default(),
+                )
+                .unwrap();
+
+            // Then
+            assert_eq!(result.block.transactions().len(), 1);
+            assert!(matches!(
+                &result.events[..],
+                [ExecutorEvent::ForcedTransactionFailed { nonce, .. }]
+                    if *nonce == Nonce::from(1u64)
+            ));
+        }
+
+        #[test]
+        fn block_producer_processes_only_first_forced_transaction_with_the_same_nonce() {
+            let genesis_da_height = 1u64;
+            let on_chain_db = database_with_genesis_block(genesis_da_height);
+            let mut relayer_db = Database::<Relayer>::default();
+
+            // Given
+            let block_height = 1u32;
+            let block_da_height = 2u64;
+            let first_tx = forced_script();
+            let first = relayed_transaction(1, block_da_height, u64::MAX, &first_tx);
+            let duplicate =
+                relayed_transaction(1, block_da_height, u64::MAX, &forced_script());
+            add_events_to_relayer(
+                &mut relayer_db,
+                block_da_height.into(),
+                &[first.into(), duplicate.into()],
+            );
+
+            // When
+            let producer = create_relayer_executor(on_chain_db, relayer_db);
+            let block = test_block(block_height.into(), block_da_height.into(), 0);
+            let result = producer
+                .execute_and_commit(
+                    ExecutionTypes::Production(block.into()),
+                    Default::default(),
+                )
+                .unwrap();
+
+            // Then
+            let chain_id = producer.config.consensus_parameters.chain_id;
+            let transactions = result.block.transactions();
+            assert!(!result.events.iter().any(|event| matches!(
+                event,
+                ExecutorEvent::ForcedTransactionFailed { .. }
+            )));
+            assert_eq!(transactions.len(), 2);
+            assert_eq!(transactions[0].id(&chain_id), first_tx.id(&chain_id));
+        }
+
+        #[test]
+        fn validator_accepts_block_with_forced_transaction() {
+            let genesis_da_height = 1u64;
+            let block_height = 1u32;
+            let block_da_height = 2u64;
+            let relayed_tx =
+                relayed_transaction(1, block_da_height, u64::MAX, &forced_script());
+            let mut relayer_db = Database::<Relayer>::default();
+            add_events_to_relayer(
+                &mut relayer_db,
+                block_da_height.into(),
+                &[relayed_tx.into()],
+            );
+            let producer = create_relayer_executor(
+                database_with_genesis_block(genesis_da_height),
+                relayer_db.clone(),
+            );
+            let block = test_block(block_height.into(), block_da_height.into(), 1);
+
+            // Given
+            let produced_block = producer
+                .execute_and_commit(
+                    ExecutionTypes::Production(block.into()),
+                    Default::default(),
+                )
+                .unwrap()
+                .block;
+
+            // When
+            let validator = create_relayer_executor(
+                database_with_genesis_block(genesis_da_height),
+                relayer_db,
+            );
+            let result = validator.execute_and_commit(
+                ExecutionTypes::Validation(produced_block),
+                Default::default(),
+            );
+
+            // Then
+            assert!(result.is_ok(), "{result:?}");
+        }
+
+        #[test]
+        fn validator_rejects_block_without_valid_forced_transaction() {
+            let genesis_da_height = 1u64;
+            let block_height = 1u32;
+            let block_da_height = 2u64;
+            let producer = create_relayer_executor(
+                database_with_genesis_block(genesis_da_height),
+                Database::<Relayer>::default(),
+            );
+            let block = test_block(block_height.into(), block_da_height.into(), 1);
+
+            // Given
+            let censored_block = producer
+                .execute_and_commit(
+                    ExecutionTypes::Production(block.into()),
+                    Default::default(),
+                )
+                .unwrap()
+                .block;
+            let forced_tx = forced_script();
+            let relayed_tx =
+                relayed_transaction(1, block_da_height, u64::MAX, &forced_tx);
+            let mut relayer_db = Database::<Relayer>::default();
+            add_events_to_relayer(
+                &mut relayer_db,
+                block_da_height.into(),
+                &[relayed_tx.into()],
+            );
+
+            // When
+            let validator = create_relayer_executor(
+                database_with_genesis_block(genesis_da_height),
+                relayer_db,
+            );
+            let result = validator.execute_and_commit(
+                ExecutionTypes::Validation(censored_block),
+                Default::default(),
+            );
+
+            // Then
+            let chain_id = validator.config.consensus_parameters.chain_id;
+            assert!(matches!(
+                result,
+                Err(ExecutorError::ForcedTransactionIsMissing(id))
+                    if id == forced_tx.id(&chain_id)
+            ));
+        }
     }
 }
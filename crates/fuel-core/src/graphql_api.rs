@@ -1,3 +1,4 @@
+use async_graphql::ErrorExtensions;
 use fuel_core_storage::{
     Error as StorageError,
     IsNotFound,
@@ -6,6 +7,10 @@ use fuel_core_types::{
     blockchain::primitives::SecretKeyWrapper,
     fuel_tx::ConsensusParameters,
     secrecy::Secret,
+    services::{
+        graphql_api::error_extensions,
+        txpool,
+    },
 };
 use std::net::SocketAddr;
 
@@ -51,3 +56,45 @@ impl<T> IntoApiResult<T> for Result<T, StorageError> {
         }
     }
 }
+
+/// Converts the error into the GraphQL error with `extensions`
+/// describing the error, so clients can handle it without parsing the message.
+pub trait IntoApiError {
+    fn into_api_error(self) -> async_graphql::Error;
+}
+
+impl IntoApiError for txpool::Error {
+    fn into_api_error(self) -> async_graphql::Error {
+        let kind: &'static str = self.kind().into();
+        async_graphql::Error::new(self.to_string()).extend_with(|_, extensions| {
+            extensions.set(error_extensions::CODE, error_extensions::TXPOOL);
+            extensions.set(error_extensions::TXPOOL_ERROR, kind);
+        })
+    }
+}
+
+impl IntoApiError for StorageError {
+    fn into_api_error(self) -> async_graphql::Error {
+        let is_not_found = matches!(self, StorageError::NotFound(_, _));
+        let error = async_graphql::Error::new(self.to_string());
+        if is_not_found {
+            error.extend_with(|_, extensions| {
+                extensions.set(error_extensions::CODE, error_extensions::NOT_FOUND);
+            })
+        } else {
+            error
+        }
+    }
+}
+
+impl IntoApiError for anyhow::Error {
+    fn into_api_error(self) -> async_graphql::Error {
+        match self.downcast::<txpool::Error>() {
+            Ok(error) => error.into_api_error(),
+            Err(error) => match error.downcast::<StorageError>() {
+                Ok(error) => error.into_api_error(),
+                Err(error) => error.into(),
+            },
+        }
+    }
+}
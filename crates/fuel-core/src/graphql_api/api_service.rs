@@ -2,6 +2,7 @@ use crate::{
     fuel_core_graphql_api::{
         metrics_extension::MetricsExtension,
         ports::{
+            BlockBodyPort,
             BlockProducerPort,
             ConsensusModulePort,
             OffChainDatabase,
@@ -87,6 +88,7 @@ pub type BlockProducer = Box<dyn BlockProducerPort>;
 pub type TxPool = Box<dyn TxPoolPort>;
 pub type ConsensusModule = Box<dyn ConsensusModulePort>;
 pub type P2pService = Box<dyn P2pPort>;
+pub type BlockBodyProvider = Box<dyn BlockBodyPort>;
 
 #[derive(Clone)]
 pub struct SharedState {
@@ -173,6 +175,7 @@ pub fn new_service<OnChain, OffChain>(
     producer: BlockProducer,
     consensus_module: ConsensusModule,
     p2p_service: P2pService,
+    block_body_provider: BlockBodyProvider,
     log_threshold_ms: Duration,
     request_timeout: Duration,
 ) -> anyhow::Result<Service>
@@ -192,6 +195,7 @@ where
         .data(producer)
         .data(consensus_module)
         .data(p2p_service)
+        .data(block_body_provider)
         .extension(async_graphql::extensions::Tracing)
         .extension(MetricsExtension::new(log_threshold_ms))
         .extension(ViewExtension::new())
@@ -9,6 +9,7 @@ use crate::fuel_core_graphql_api::{
         OffChainDatabase,
         OnChainDatabase,
     },
+    storage::relayed_transactions::ForcedTransactionFailure,
 };
 use fuel_core_storage::{
     iter::{
@@ -117,6 +118,10 @@ impl DatabaseBlocks for ReadView {
     fn latest_height(&self) -> StorageResult<BlockHeight> {
         self.on_chain.latest_height()
     }
+
+    fn block_body(&self, height: &BlockHeight) -> StorageResult<Option<Vec<TxId>>> {
+        self.on_chain.block_body(height)
+    }
 }
 
 impl<M> StorageInspect<M> for ReadView
@@ -221,4 +226,11 @@ impl OffChainDatabase for ReadView {
         self.off_chain
             .owned_transactions_ids(owner, start, direction)
     }
+
+    fn forced_transaction_failure(
+        &self,
+        nonce: &Nonce,
+    ) -> StorageResult<Option<ForcedTransactionFailure>> {
+        self.off_chain.forced_transaction_failure(nonce)
+    }
 }
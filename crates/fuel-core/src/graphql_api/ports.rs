@@ -1,3 +1,4 @@
+use super::storage::relayed_transactions::ForcedTransactionFailure;
 use async_trait::async_trait;
 use fuel_core_services::stream::BoxStream;
 use fuel_core_storage::{
@@ -23,6 +24,7 @@ use fuel_core_txpool::service::TxStatusMessage;
 use fuel_core_types::{
     blockchain::{
         block::CompressedBlock,
+        header::BlockHeader,
         primitives::{
             BlockId,
             DaBlockHeight,
@@ -48,7 +50,11 @@ use fuel_core_types::{
     services::{
         executor::TransactionExecutionStatus,
         graphql_api::ContractBalance,
-        p2p::PeerInfo,
+        p2p::{
+            BannedPeerInfo,
+            PeerId,
+            PeerInfo,
+        },
         txpool::{
             InsertionResult,
             TransactionStatus,
@@ -56,7 +62,10 @@ use fuel_core_types::{
     },
     tai64::Tai64,
 };
-use std::sync::Arc;
+use std::{
+    sync::Arc,
+    time::Duration,
+};
 
 pub trait OffChainDatabase: Send + Sync {
     fn block_height(&self, block_id: &BlockId) -> StorageResult<BlockHeight>;
@@ -83,6 +92,13 @@ pub trait OffChainDatabase: Send + Sync {
         start: Option<TxPointer>,
         direction: IterDirection,
     ) -> BoxedIter<StorageResult<(TxPointer, TxId)>>;
+
+    /// Returns the reason why the forced transaction with the `nonce`
+    /// was not included into the block.
+    fn forced_transaction_failure(
+        &self,
+        nonce: &Nonce,
+    ) -> StorageResult<Option<ForcedTransactionFailure>>;
 }
 
 /// The on chain database port expected by GraphQL API service.
@@ -111,6 +127,10 @@ pub trait DatabaseBlocks:
     ) -> BoxedIter<'_, StorageResult<CompressedBlock>>;
 
     fn latest_height(&self) -> StorageResult<BlockHeight>;
+
+    /// Returns the ids of the transactions of the block, or `None` if the node
+    /// imported only the header of the block and doesn't have its body.
+    fn block_body(&self, height: &BlockHeight) -> StorageResult<Option<Vec<TxId>>>;
 }
 
 /// Trait that specifies all the getters required for messages.
@@ -195,6 +215,28 @@ pub trait DatabaseMessageProof: Send + Sync {
 #[async_trait::async_trait]
 pub trait P2pPort: Send + Sync {
     async fn all_peer_info(&self) -> anyhow::Result<Vec<PeerInfo>>;
+
+    async fn banned_peers(&self) -> anyhow::Result<Vec<BannedPeerInfo>>;
+
+    /// Bans the peer for the `duration`, or for the configured ban duration if it is `None`.
+    async fn ban_peer(
+        &self,
+        peer_id: PeerId,
+        duration: Option<Duration>,
+    ) -> anyhow::Result<()>;
+
+    /// Removes the ban of the peer. Returns `true` if the peer was banned.
+    async fn unban_peer(&self, peer_id: PeerId) -> anyhow::Result<bool>;
+}
+
+#[async_trait::async_trait]
+pub trait BlockBodyPort: Send + Sync {
+    /// Fetches the body of the block imported by the header only from peers,
+    /// verifies it against the `header` and stores it.
+    async fn fetch_block_body(
+        &self,
+        header: &BlockHeader,
+    ) -> anyhow::Result<Vec<Transaction>>;
 }
 
 pub mod worker {
@@ -207,6 +249,7 @@ pub mod worker {
         fuel_core_graphql_api::storage::{
             coins::OwnedCoins,
             messages::OwnedMessageIds,
+            relayed_transactions::ForcedTransactionFailures,
         },
     };
     use fuel_core_services::stream::BoxStream;
@@ -235,6 +278,7 @@ pub mod worker {
         + StorageMutate<OwnedCoins, Error = StorageError>
         + StorageMutate<MetadataTable<OffChain>, Error = StorageError>
         + StorageMutate<FuelBlockIdsToHeights, Error = StorageError>
+        + StorageMutate<ForcedTransactionFailures, Error = StorageError>
         + Transactional<Storage = Self>
     {
         fn record_tx_id_owner(
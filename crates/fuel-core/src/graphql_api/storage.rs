@@ -3,6 +3,7 @@ use fuel_core_storage::kv_store::StorageColumn;
 pub mod blocks;
 pub mod coins;
 pub mod messages;
+pub mod relayed_transactions;
 pub mod transactions;
 
 /// GraphQL database tables column ids to the corresponding [`fuel_core_storage::Mappable`] table.
@@ -33,6 +34,10 @@ pub enum Column {
     Statistic = 5,
     /// See [`blocks::FuelBlockIdsToHeights`]
     FuelBlockIdsToHeights = 6,
+    /// The reverse changes of each block used to roll back the state.
+    ModificationsHistory = 7,
+    /// See [`relayed_transactions::ForcedTransactionFailures`]
+    ForcedTransactionFailures = 8,
 }
 
 impl Column {
@@ -0,0 +1,50 @@
+use fuel_core_storage::{
+    blueprint::plain::Plain,
+    codec::{
+        postcard::Postcard,
+        raw::Raw,
+    },
+    structured_storage::TableWithBlueprint,
+    Mappable,
+};
+use fuel_core_types::fuel_types::{
+    BlockHeight,
+    Nonce,
+};
+
+/// The reason why the forced transaction relayed from the DA layer
+/// was not included into the block.
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ForcedTransactionFailure {
+    /// The height of the block where the transaction should have been included.
+    pub block_height: BlockHeight,
+    /// The description of the failure.
+    pub failure: String,
+}
+
+/// The table of the failures of the forced transactions by their nonces.
+pub struct ForcedTransactionFailures;
+
+impl Mappable for ForcedTransactionFailures {
+    /// The nonce of the relayed transaction.
+    type Key = Nonce;
+    type OwnedKey = Self::Key;
+    type Value = ForcedTransactionFailure;
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for ForcedTransactionFailures {
+    type Blueprint = Plain<Raw, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::ForcedTransactionFailures
+    }
+}
+
+#[cfg(test)]
+fuel_core_storage::basic_storage_tests!(
+    ForcedTransactionFailures,
+    <ForcedTransactionFailures as Mappable>::Key::default(),
+    <ForcedTransactionFailures as Mappable>::Value::default()
+);
@@ -84,7 +84,7 @@ fn owned_tx_index_key(
 
 pub type TransactionIndex = u16;
 
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct OwnedTransactionIndexKey {
     pub owner: Address,
     pub block_height: BlockHeight,
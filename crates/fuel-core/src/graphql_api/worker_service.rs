@@ -19,6 +19,10 @@ use crate::{
                 OwnedMessageIds,
                 OwnedMessageKey,
             },
+            relayed_transactions::{
+                ForcedTransactionFailure,
+                ForcedTransactionFailures,
+            },
         },
     },
 };
@@ -87,7 +91,19 @@ impl<D> Task<D>
 where
     D: ports::worker::OffChainDatabase,
 {
-    fn process_block(&mut self, result: SharedImportResult) -> anyhow::Result<()> {
+    /// Creates the task without the stream of imported blocks.
+    /// Blocks should be passed to the [`Task::process_block`] manually.
+    pub(crate) fn without_block_importer(database: D) -> Self {
+        Self {
+            block_importer: futures::stream::empty().boxed(),
+            database,
+        }
+    }
+
+    pub(crate) fn process_block(
+        &mut self,
+        result: SharedImportResult,
+    ) -> anyhow::Result<()> {
         let block = &result.sealed_block.entity;
         let mut transaction = self.database.transaction();
         // save the status for every transaction using the finalized block id
@@ -173,6 +189,25 @@ where
                         .storage_as_mut::<OwnedCoins>()
                         .remove(&key)?;
                 }
+                Event::ForcedTransactionFailed {
+                    nonce,
+                    block_height,
+                    failure,
+                } => {
+                    tracing::debug!(
+                        "The forced transaction {nonce:#x} wasn't included into \
+                        the block {block_height}: {failure}"
+                    );
+                    block_st_transaction
+                        .storage_as_mut::<ForcedTransactionFailures>()
+                        .insert(
+                            nonce,
+                            &ForcedTransactionFailure {
+                                block_height: *block_height,
+                                failure: failure.clone(),
+                            },
+                        )?;
+                }
             }
         }
         Ok(())
@@ -11,6 +11,8 @@ pub use fuel_core_chain_config as chain_config;
 #[doc(no_inline)]
 pub use fuel_core_p2p as p2p;
 #[doc(no_inline)]
+pub use fuel_core_poa as poa;
+#[doc(no_inline)]
 pub use fuel_core_producer as producer;
 #[cfg(feature = "relayer")]
 #[doc(no_inline)]
@@ -31,6 +33,7 @@ pub mod model;
 #[cfg(all(feature = "p2p", feature = "test-helpers"))]
 pub mod p2p_test_helpers;
 pub mod query;
+pub mod remote_signer;
 pub mod schema;
 pub mod service;
 pub mod state;
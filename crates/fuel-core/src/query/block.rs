@@ -17,11 +17,17 @@ use fuel_core_types::{
         block::CompressedBlock,
         consensus::Consensus,
     },
+    fuel_tx::TxId,
     fuel_types::BlockHeight,
 };
 
 pub trait SimpleBlockData: Send + Sync {
     fn block(&self, id: &BlockHeight) -> StorageResult<CompressedBlock>;
+
+    /// Returns the ids of the transactions of the block, or `None` if the node
+    /// has only the header of the block.
+    fn block_transaction_ids(&self, id: &BlockHeight)
+        -> StorageResult<Option<Vec<TxId>>>;
 }
 
 impl<D: OnChainDatabase + ?Sized> SimpleBlockData for D {
@@ -34,6 +40,13 @@ impl<D: OnChainDatabase + ?Sized> SimpleBlockData for D {
 
         Ok(block)
     }
+
+    fn block_transaction_ids(
+        &self,
+        id: &BlockHeight,
+    ) -> StorageResult<Option<Vec<TxId>>> {
+        self.block_body(id)
+    }
 }
 
 pub trait BlockQueryData: Send + Sync + SimpleBlockData {
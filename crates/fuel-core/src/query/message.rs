@@ -182,13 +182,18 @@ pub fn message_proof<T: MessageProofData + ?Sized>(
     };
 
     // Get the message fuel block header.
-    let (message_block_header, message_block_txs) = match database
+    let message_block_header = match database
         .block(&message_block_height)
         .into_api_result::<CompressedBlock, StorageError>()?
     {
-        Some(t) => t.into_inner(),
+        Some(t) => t.into_inner().0,
         None => return Ok(None),
     };
+    let message_block_txs = database
+        .block_transaction_ids(&message_block_height)?
+        .ok_or(anyhow::anyhow!(
+            "The node has only the header of the block {message_block_height}"
+        ))?;
 
     let message_id = compute_message_id(&sender, &recipient, &nonce, amount, &data);
 
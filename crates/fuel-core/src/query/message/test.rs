@@ -60,6 +60,7 @@ mockall::mock! {
     pub ProofDataStorage {}
     impl SimpleBlockData for ProofDataStorage {
         fn block(&self, height: &BlockHeight) -> StorageResult<CompressedBlock>;
+        fn block_transaction_ids(&self, height: &BlockHeight) -> StorageResult<Option<Vec<TxId>>>;
     }
 
     impl DatabaseMessageProof for ProofDataStorage {
@@ -198,6 +199,10 @@ async fn can_build_message_proof() {
         }
     });
 
+    data.expect_block_transaction_ids()
+        .with(eq(message_block_height))
+        .returning(|_| Ok(Some(TXNS.to_vec())));
+
     let data: Box<dyn MessageProofData> = Box::new(data);
 
     let proof = message_proof(
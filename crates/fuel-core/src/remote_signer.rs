@@ -0,0 +1,56 @@
+//! The signing of blocks by the remote signer.
+//!
+//! The signer is a separate process holding the consensus key. The node sends
+//! the header of the produced block to `POST /v1/sign` and receives the signature
+//! of the block id. The signer computes the id from the header itself, so it knows
+//! the height of the signed block and refuses to sign the blocks below the height
+//! of the last signed block. The connection can be protected with mutual TLS, or the daemon
+//! can listen on the Unix socket accessible only by its owner.
+
+use fuel_core_types::{
+    blockchain::header::BlockHeader,
+    fuel_crypto::Signature,
+};
+use std::path::PathBuf;
+
+mod client;
+mod daemon;
+
+pub use client::{
+    RemoteBlockSigner,
+    RemoteSignerConfig,
+};
+pub use daemon::{
+    new_service,
+    DaemonAddress,
+    Service,
+    SharedState,
+    SigningDaemonConfig,
+};
+pub use reqwest::Url;
+
+/// The path of the signing endpoint.
+pub const SIGN_PATH: &str = "/v1/sign";
+/// The path of the endpoint answering if the daemon is up.
+pub const HEALTH_PATH: &str = "/v1/health";
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct SignRequest {
+    pub header: BlockHeader,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct SignResponse {
+    pub signature: Signature,
+}
+
+/// The PEM-encoded certificates and the key used for mutual TLS.
+#[derive(Clone, Debug)]
+pub struct TlsConfig {
+    /// The certificate chain of this side of the connection.
+    pub cert: PathBuf,
+    /// The private key of the certificate.
+    pub key: PathBuf,
+    /// The certificate authority used to verify the other side of the connection.
+    pub ca_cert: PathBuf,
+}
@@ -0,0 +1,218 @@
+use super::{
+    SignRequest,
+    SignResponse,
+    TlsConfig,
+    HEALTH_PATH,
+    SIGN_PATH,
+};
+use anyhow::{
+    anyhow,
+    Context,
+};
+use fuel_core_poa::ports::BlockSigner;
+use fuel_core_types::{
+    blockchain::{
+        block::Block,
+        consensus::{
+            poa::PoAConsensus,
+            Consensus,
+        },
+    },
+    fuel_tx::Input,
+    fuel_types::Address,
+};
+use reqwest::{
+    header::CONTENT_TYPE,
+    Method,
+    StatusCode,
+};
+use std::time::Duration;
+
+#[derive(Clone, Debug)]
+pub struct RemoteSignerConfig {
+    /// The URL of the signer. The `unix:///path/to/socket` URL connects
+    /// to the signer over the Unix socket.
+    pub url: reqwest::Url,
+    /// The mutual TLS configuration. If not set, the connection is not authenticated.
+    pub tls: Option<TlsConfig>,
+    pub timeout: Duration,
+}
+
+/// Signs the blocks with the key held by the remote signer.
+pub struct RemoteBlockSigner {
+    transport: Transport,
+    /// The address of the consensus key. The signatures of other keys are rejected.
+    address: Address,
+}
+
+enum Transport {
+    Http {
+        client: reqwest::Client,
+        /// The base URL of the daemon.
+        url: reqwest::Url,
+    },
+    #[cfg(unix)]
+    Unix {
+        path: std::path::PathBuf,
+        timeout: Duration,
+    },
+}
+
+impl RemoteBlockSigner {
+    pub fn new(config: &RemoteSignerConfig, address: Address) -> anyhow::Result<Self> {
+        #[cfg(unix)]
+        if config.url.scheme() == "unix" {
+            if config.tls.is_some() {
+                return Err(anyhow!("TLS is not supported on the Unix socket"))
+            }
+            let transport = Transport::Unix {
+                path: config.url.path().into(),
+                timeout: config.timeout,
+            };
+            return Ok(Self { transport, address })
+        }
+
+        let mut builder = reqwest::Client::builder().timeout(config.timeout);
+        if let Some(tls) = &config.tls {
+            let ca_cert = std::fs::read(&tls.ca_cert).with_context(|| {
+                format!(
+                    "Failed to read the CA certificate {}",
+                    tls.ca_cert.display()
+                )
+            })?;
+            let mut identity = std::fs::read(&tls.cert).with_context(|| {
+                format!("Failed to read the certificate {}", tls.cert.display())
+            })?;
+            let key = std::fs::read(&tls.key).with_context(|| {
+                format!("Failed to read the private key {}", tls.key.display())
+            })?;
+            identity.extend(key);
+            builder = builder
+                .tls_built_in_root_certs(false)
+                .add_root_certificate(reqwest::Certificate::from_pem(&ca_cert)?)
+                .identity(reqwest::Identity::from_pem(&identity)?)
+                .https_only(true);
+        }
+        // Validate the URL once, so requests don't fail because of it.
+        config
+            .url
+            .join(SIGN_PATH)
+            .context("Invalid URL of the remote signer")?;
+
+        Ok(Self {
+            transport: Transport::Http {
+                client: builder.build()?,
+                url: config.url.clone(),
+            },
+            address,
+        })
+    }
+
+    /// Sends the request to the `path` of the daemon and returns the status
+    /// and the body of the response. The `body` is sent as JSON.
+    async fn send(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<Vec<u8>>,
+    ) -> anyhow::Result<(StatusCode, Vec<u8>)> {
+        match &self.transport {
+            Transport::Http { client, url } => {
+                let url = url.join(path).context("Invalid URL of the remote signer")?;
+                let mut request = client.request(method, url);
+                if let Some(body) = body {
+                    request = request.header(CONTENT_TYPE, "application/json").body(body);
+                }
+                let response = request
+                    .send()
+                    .await
+                    .context("Failed to reach the remote signer")?;
+                let status = response.status();
+                let body = response.bytes().await?;
+                Ok((status, body.to_vec()))
+            }
+            #[cfg(unix)]
+            Transport::Unix {
+                path: socket,
+                timeout,
+            } => tokio::time::timeout(*timeout, send_unix(socket, method, path, body))
+                .await
+                .map_err(|_| anyhow!("The request to the remote signer timed out"))?
+                .context("Failed to reach the remote signer"),
+        }
+    }
+}
+
+#[cfg(unix)]
+async fn send_unix(
+    socket: &std::path::Path,
+    method: Method,
+    path: &str,
+    body: Option<Vec<u8>>,
+) -> anyhow::Result<(StatusCode, Vec<u8>)> {
+    let stream = tokio::net::UnixStream::connect(socket).await?;
+    let (mut sender, connection) = hyper::client::conn::handshake(stream).await?;
+    tokio::spawn(async move {
+        if let Err(e) = connection.await {
+            tracing::warn!("The connection to the remote signer failed: {e}");
+        }
+    });
+    let request = hyper::Request::builder()
+        .method(method)
+        .uri(path)
+        .header(reqwest::header::HOST, "localhost");
+    let request = match body {
+        Some(body) => request
+            .header(CONTENT_TYPE, "application/json")
+            .body(hyper::Body::from(body))?,
+        None => request.body(hyper::Body::empty())?,
+    };
+    let response = sender.send_request(request).await?;
+    let status = response.status();
+    let body = hyper::body::to_bytes(response.into_body()).await?;
+    Ok((status, body.to_vec()))
+}
+
+#[async_trait::async_trait]
+impl BlockSigner for RemoteBlockSigner {
+    async fn seal_block(&self, block: &Block) -> anyhow::Result<Consensus> {
+        let request = SignRequest {
+            header: block.header().clone(),
+        };
+        let (status, body) = self
+            .send(Method::POST, SIGN_PATH, Some(serde_json::to_vec(&request)?))
+            .await?;
+        if !status.is_success() {
+            return Err(anyhow!(
+                "The remote signer refused to sign the block with {status}: {}",
+                String::from_utf8_lossy(&body)
+            ))
+        }
+        let SignResponse { signature } = serde_json::from_slice(&body)
+            .context("Invalid response of the remote signer")?;
+
+        let public_key = signature
+            .recover(&block.id().into_message())
+            .map_err(|e| anyhow!("Invalid signature from the remote signer: {e:?}"))?;
+        let signer = Input::owner(&public_key);
+        if signer != self.address {
+            return Err(anyhow!(
+                "The remote signer signed the block with the key of {signer}, \
+                but {} is expected",
+                self.address
+            ))
+        }
+
+        Ok(Consensus::PoA(PoAConsensus::new(signature)))
+    }
+
+    async fn is_available(&self) -> bool {
+        match self.send(Method::GET, HEALTH_PATH, None).await {
+            Ok((status, _)) => status.is_success(),
+            Err(e) => {
+                tracing::warn!("The remote signer is not available: {e}");
+                false
+            }
+        }
+    }
+}
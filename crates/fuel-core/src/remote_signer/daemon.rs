@@ -0,0 +1,464 @@
+use super::{
+    SignRequest,
+    SignResponse,
+    TlsConfig,
+    Url,
+    HEALTH_PATH,
+    SIGN_PATH,
+};
+use anyhow::Context;
+use axum::{
+    extract::Extension,
+    http::StatusCode,
+    routing::{
+        get,
+        post,
+    },
+    Json,
+    Router,
+};
+use fuel_core_poa::signer::SlashingProtection;
+use fuel_core_services::{
+    RunnableService,
+    RunnableTask,
+    StateWatcher,
+};
+use fuel_core_types::{
+    blockchain::primitives::SecretKeyWrapper,
+    fuel_crypto::Signature,
+    secrecy::{
+        ExposeSecret,
+        Secret,
+    },
+};
+use futures::{
+    stream::FuturesUnordered,
+    Stream,
+    StreamExt,
+};
+use std::{
+    future::Future,
+    io::BufReader,
+    net::{
+        SocketAddr,
+        TcpListener,
+    },
+    ops::Deref,
+    path::{
+        Path,
+        PathBuf,
+    },
+    pin::Pin,
+    sync::Arc,
+    time::Duration,
+};
+use tokio_rustls::{
+    rustls,
+    server::TlsStream,
+    TlsAcceptor,
+};
+
+pub type Service = fuel_core_services::ServiceRunner<SigningDaemon>;
+
+/// The maximum duration of the TLS handshake. The connections that don't finish
+/// the handshake in time are dropped.
+const TLS_HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The address the signing daemon listens on.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DaemonAddress {
+    Tcp(SocketAddr),
+    /// The Unix domain socket. The access to the daemon is limited to the users
+    /// allowed to connect to the socket file.
+    #[cfg(unix)]
+    Unix(PathBuf),
+}
+
+impl DaemonAddress {
+    /// Returns the URL of the daemon used by the [`super::RemoteSignerConfig`].
+    pub fn url(&self, tls: bool) -> anyhow::Result<Url> {
+        let url = match self {
+            DaemonAddress::Tcp(addr) if tls => format!("https://{addr}"),
+            DaemonAddress::Tcp(addr) => format!("http://{addr}"),
+            #[cfg(unix)]
+            DaemonAddress::Unix(path) => format!("unix://{}", path.display()),
+        };
+        url.parse().context("Invalid address of the signing daemon")
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct SigningDaemonConfig {
+    pub addr: DaemonAddress,
+    /// The mutual TLS configuration. The clients must present the certificate
+    /// signed by the `ca_cert`. If not set, the daemon accepts plain HTTP
+    /// only on the loopback interface. Not supported on the Unix socket.
+    pub tls: Option<TlsConfig>,
+    /// The file persisting the last signed block, so the daemon doesn't sign
+    /// a conflicting block after the restart.
+    pub slashing_protection_file: PathBuf,
+}
+
+#[derive(Clone)]
+pub struct SharedState {
+    pub bound_address: DaemonAddress,
+}
+
+pub struct SigningDaemon {
+    bound_address: DaemonAddress,
+}
+
+pub struct ServerParams {
+    router: Router,
+    listener: Listener,
+    tls: Option<TlsAcceptor>,
+}
+
+enum Listener {
+    Tcp(TcpListener),
+    #[cfg(unix)]
+    Unix(std::os::unix::net::UnixListener),
+}
+
+pub struct Task {
+    server: Pin<Box<dyn Future<Output = hyper::Result<()>> + Send + 'static>>,
+}
+
+struct Signer {
+    signing_key: Secret<SecretKeyWrapper>,
+    protection: SlashingProtection,
+}
+
+#[async_trait::async_trait]
+impl RunnableService for SigningDaemon {
+    const NAME: &'static str = "SigningDaemon";
+
+    type SharedData = SharedState;
+    type Task = Task;
+    type TaskParams = ServerParams;
+
+    fn shared_data(&self) -> Self::SharedData {
+        SharedState {
+            bound_address: self.bound_address.clone(),
+        }
+    }
+
+    async fn into_task(
+        self,
+        state: &StateWatcher,
+        params: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        let mut state = state.clone();
+        let shutdown = async move {
+            state
+                .while_started()
+                .await
+                .expect("The service is destroyed");
+        };
+        let ServerParams {
+            router,
+            listener,
+            tls,
+        } = params;
+
+        let server: Pin<Box<dyn Future<Output = hyper::Result<()>> + Send>> =
+            match (listener, tls) {
+                (Listener::Tcp(listener), Some(acceptor)) => {
+                    let listener = tokio::net::TcpListener::from_std(listener)?;
+                    let incoming = tls_incoming(listener, acceptor);
+                    Box::pin(
+                        axum::Server::builder(hyper::server::accept::from_stream(
+                            incoming,
+                        ))
+                        .serve(router.into_make_service())
+                        .with_graceful_shutdown(shutdown),
+                    )
+                }
+                (Listener::Tcp(listener), None) => Box::pin(
+                    axum::Server::from_tcp(listener)?
+                        .serve(router.into_make_service())
+                        .with_graceful_shutdown(shutdown),
+                ),
+                #[cfg(unix)]
+                (Listener::Unix(listener), _) => {
+                    let listener = tokio::net::UnixListener::from_std(listener)?;
+                    let incoming =
+                        futures::stream::unfold(listener, |listener| async move {
+                            loop {
+                                match listener.accept().await {
+                                    Ok((stream, _)) => {
+                                        return Some((
+                                            Ok::<_, std::io::Error>(stream),
+                                            listener,
+                                        ))
+                                    }
+                                    Err(e) => {
+                                        tracing::warn!(
+                                            "Failed to accept the connection: {e}"
+                                        );
+                                    }
+                                }
+                            }
+                        });
+                    Box::pin(
+                        axum::Server::builder(hyper::server::accept::from_stream(
+                            incoming,
+                        ))
+                        .serve(router.into_make_service())
+                        .with_graceful_shutdown(shutdown),
+                    )
+                }
+            };
+        let task = Task { server };
+
+        Ok(task)
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableTask for Task {
+    async fn run(&mut self, _: &mut StateWatcher) -> anyhow::Result<bool> {
+        self.server.as_mut().await?;
+        // The `axum::Server` has its internal loop. If `await` is finished, we get an internal
+        // error or stop signal.
+        Ok(false /* should_continue */)
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        // The `axum::Server` was already gracefully shutdown at this point.
+        Ok(())
+    }
+}
+
+/// Returns the stream of connections that finished the TLS handshake.
+///
+/// The handshakes run concurrently and are limited by [`TLS_HANDSHAKE_TIMEOUT`],
+/// so a slow or malicious client can't block other connections.
+fn tls_incoming(
+    listener: tokio::net::TcpListener,
+    acceptor: TlsAcceptor,
+) -> impl Stream<Item = std::io::Result<TlsStream<tokio::net::TcpStream>>> {
+    let handshakes = FuturesUnordered::new();
+    futures::stream::unfold(
+        (listener, acceptor, handshakes),
+        |(listener, acceptor, mut handshakes)| async move {
+            loop {
+                tokio::select! {
+                    accepted = listener.accept() => match accepted {
+                        Ok((stream, _)) => handshakes.push(tokio::time::timeout(
+                            TLS_HANDSHAKE_TIMEOUT,
+                            acceptor.accept(stream),
+                        )),
+                        Err(e) => {
+                            tracing::warn!("Failed to accept the connection: {e}");
+                        }
+                    },
+                    Some(handshake) = handshakes.next(), if !handshakes.is_empty() => {
+                        match handshake {
+                            Ok(Ok(stream)) => {
+                                return Some((
+                                    Ok(stream),
+                                    (listener, acceptor, handshakes),
+                                ))
+                            }
+                            Ok(Err(e)) => {
+                                tracing::warn!("The TLS handshake failed: {e}");
+                            }
+                            Err(_) => {
+                                tracing::warn!("The TLS handshake timed out");
+                            }
+                        }
+                    }
+                }
+            }
+        },
+    )
+}
+
+pub fn new_service(
+    signing_key: Secret<SecretKeyWrapper>,
+    config: SigningDaemonConfig,
+) -> anyhow::Result<Service> {
+    let protection =
+        SlashingProtection::with_state_file(config.slashing_protection_file)?;
+    let tls = config
+        .tls
+        .as_ref()
+        .map(|tls| {
+            server_tls_config(tls).map(|config| TlsAcceptor::from(Arc::new(config)))
+        })
+        .transpose()?;
+
+    let signer = Arc::new(Signer {
+        signing_key,
+        protection,
+    });
+    let router = Router::new()
+        .route(SIGN_PATH, post(sign))
+        .route(HEALTH_PATH, get(health))
+        .layer(Extension(signer));
+
+    let (listener, bound_address) = match config.addr {
+        DaemonAddress::Tcp(addr) => {
+            if tls.is_none() && !addr.ip().is_loopback() {
+                anyhow::bail!(
+                    "The signing daemon requires TLS to listen on the non-loopback \
+                    address {addr}"
+                );
+            }
+            let listener = TcpListener::bind(addr)?;
+            listener.set_nonblocking(true)?;
+            let bound_address = DaemonAddress::Tcp(listener.local_addr()?);
+            (Listener::Tcp(listener), bound_address)
+        }
+        #[cfg(unix)]
+        DaemonAddress::Unix(path) => {
+            if tls.is_some() {
+                anyhow::bail!("TLS is not supported on the Unix socket");
+            }
+            let listener = bind_unix_socket(&path)?;
+            (Listener::Unix(listener), DaemonAddress::Unix(path))
+        }
+    };
+
+    tracing::info!("Binding the signing daemon to {:?}", bound_address);
+
+    Ok(Service::new_with_params(
+        SigningDaemon { bound_address },
+        ServerParams {
+            router,
+            listener,
+            tls,
+        },
+    ))
+}
+
+/// Binds the Unix socket accessible only by the owner of the daemon.
+/// The socket left by the previous run of the daemon is replaced.
+///
+/// The socket is created in the directory accessible only by the owner, and moved
+/// to the `path` after its permissions are restricted. So nobody can connect
+/// to the socket while it has the permissions from the umask.
+#[cfg(unix)]
+fn bind_unix_socket(path: &Path) -> anyhow::Result<std::os::unix::net::UnixListener> {
+    use std::os::unix::fs::{
+        DirBuilderExt,
+        FileTypeExt,
+        PermissionsExt,
+    };
+
+    if let Ok(metadata) = std::fs::symlink_metadata(path) {
+        if !metadata.file_type().is_socket() {
+            anyhow::bail!("{} exists and is not a socket", path.display());
+        }
+        std::fs::remove_file(path)?;
+    }
+
+    let file_name = path
+        .file_name()
+        .with_context(|| format!("Invalid path of the Unix socket {}", path.display()))?;
+    let private_dir = path.with_file_name(format!(
+        ".{}.{}",
+        file_name.to_string_lossy(),
+        std::process::id()
+    ));
+    std::fs::DirBuilder::new()
+        .mode(0o700)
+        .create(&private_dir)
+        .with_context(|| {
+            format!("Failed to create the directory {}", private_dir.display())
+        })?;
+    let private_path = private_dir.join(file_name);
+    let bind = || -> anyhow::Result<std::os::unix::net::UnixListener> {
+        let listener = std::os::unix::net::UnixListener::bind(&private_path)?;
+        std::fs::set_permissions(&private_path, std::fs::Permissions::from_mode(0o600))?;
+        std::fs::rename(&private_path, path)?;
+        Ok(listener)
+    };
+    let listener = bind();
+    if let Err(e) = std::fs::remove_dir_all(&private_dir) {
+        tracing::warn!(
+            "Failed to remove the directory {}: {e}",
+            private_dir.display()
+        );
+    }
+    let listener = listener
+        .with_context(|| format!("Failed to bind the Unix socket {}", path.display()))?;
+    listener.set_nonblocking(true)?;
+    Ok(listener)
+}
+
+async fn sign(
+    Extension(signer): Extension<Arc<Signer>>,
+    Json(request): Json<SignRequest>,
+) -> Result<Json<SignResponse>, (StatusCode, String)> {
+    // The id is calculated from the header, so the client can't sign
+    // an arbitrary message.
+    let header = request.header;
+    if header.application_hash() != &header.application().hash() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "The application hash doesn't match the header".to_string(),
+        ))
+    }
+    let block_id = header.hash();
+    let height = *header.height();
+
+    signer
+        .protection
+        .check_and_record(height, block_id)
+        .map_err(|e| {
+            tracing::warn!("{e}");
+            (StatusCode::CONFLICT, e.to_string())
+        })?;
+
+    let signing_key = signer.signing_key.expose_secret().deref();
+    let signature = Signature::sign(signing_key, &block_id.into_message());
+    tracing::info!("Signed the block {block_id} at height {height}");
+
+    Ok(Json(SignResponse { signature }))
+}
+
+async fn health() -> StatusCode {
+    StatusCode::OK
+}
+
+fn server_tls_config(tls: &TlsConfig) -> anyhow::Result<rustls::ServerConfig> {
+    let mut roots = rustls::RootCertStore::empty();
+    for cert in load_certs(&tls.ca_cert)? {
+        roots
+            .add(&cert)
+            .context("Invalid CA certificate of the clients")?;
+    }
+    let verifier = rustls::server::AllowAnyAuthenticatedClient::new(roots).boxed();
+
+    rustls::ServerConfig::builder()
+        .with_safe_defaults()
+        .with_client_cert_verifier(verifier)
+        .with_single_cert(load_certs(&tls.cert)?, load_private_key(&tls.key)?)
+        .context("Invalid certificate or private key of the signing daemon")
+}
+
+fn load_certs(path: &Path) -> anyhow::Result<Vec<rustls::Certificate>> {
+    let file = std::fs::File::open(path)
+        .with_context(|| format!("Failed to open the certificate {}", path.display()))?;
+    let certs = rustls_pemfile::certs(&mut BufReader::new(file))
+        .with_context(|| format!("Invalid certificate {}", path.display()))?;
+    Ok(certs.into_iter().map(rustls::Certificate).collect())
+}
+
+fn load_private_key(path: &Path) -> anyhow::Result<rustls::PrivateKey> {
+    let file = std::fs::File::open(path)
+        .with_context(|| format!("Failed to open the private key {}", path.display()))?;
+    let items = rustls_pemfile::read_all(&mut BufReader::new(file))
+        .with_context(|| format!("Invalid private key {}", path.display()))?;
+    items
+        .into_iter()
+        .find_map(|item| match item {
+            rustls_pemfile::Item::PKCS8Key(key)
+            | rustls_pemfile::Item::RSAKey(key)
+            | rustls_pemfile::Item::ECKey(key) => Some(rustls::PrivateKey(key)),
+            _ => None,
+        })
+        .with_context(|| format!("No private key in {}", path.display()))
+}
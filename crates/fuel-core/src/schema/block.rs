@@ -4,7 +4,10 @@ use super::scalars::{
 };
 use crate::{
     fuel_core_graphql_api::{
-        api_service::ConsensusModule,
+        api_service::{
+            BlockBodyProvider,
+            ConsensusModule,
+        },
         database::ReadView,
         ports::OffChainDatabase,
         Config as GraphQLConfig,
@@ -49,6 +52,7 @@ use fuel_core_types::{
         block::CompressedBlock,
         header::BlockHeader,
     },
+    fuel_tx::UniqueIdentifier,
     fuel_types,
     fuel_types::BlockHeight,
 };
@@ -114,8 +118,29 @@ impl Block {
         ctx: &Context<'_>,
     ) -> async_graphql::Result<Vec<Transaction>> {
         let query: &ReadView = ctx.data_unchecked();
-        self.0
-            .transactions()
+        let height = self.0.header().height();
+        let tx_ids = match query.block_transaction_ids(height)? {
+            Some(tx_ids) => tx_ids,
+            None => {
+                // The node imported only the header of the block, fetch the body from peers.
+                let block_body: &BlockBodyProvider = ctx.data_unchecked();
+                let config: &GraphQLConfig = ctx.data_unchecked();
+                let transactions = block_body
+                    .fetch_block_body(self.0.header())
+                    .await
+                    .map_err(|e| {
+                        anyhow!("The body of the block {height} is not available: {e}")
+                    })?;
+                return Ok(transactions
+                    .into_iter()
+                    .map(|tx| {
+                        let tx_id = tx.id(&config.consensus_parameters.chain_id);
+                        Transaction::from_tx(tx_id, tx)
+                    })
+                    .collect())
+            }
+        };
+        tx_ids
             .iter()
             .map(|tx_id| {
                 let tx = query.transaction(tx_id)?;
@@ -1,6 +1,7 @@
 use crate::{
     fuel_core_graphql_api::{
         database::ReadView,
+        IntoApiError,
         IntoApiResult,
     },
     query::ContractQueryData,
@@ -45,7 +46,7 @@ impl Contract {
         query
             .contract_bytecode(self.0)
             .map(HexString)
-            .map_err(Into::into)
+            .map_err(IntoApiError::into_api_error)
     }
 
     async fn salt(&self, ctx: &Context<'_>) -> async_graphql::Result<Salt> {
@@ -53,7 +54,7 @@ impl Contract {
         query
             .contract_salt(self.0)
             .map(Into::into)
-            .map_err(Into::into)
+            .map_err(IntoApiError::into_api_error)
     }
 }
 
@@ -13,8 +13,12 @@ use crate::{
     fuel_core_graphql_api::{
         database::ReadView,
         ports::OffChainDatabase,
+        storage::relayed_transactions,
+    },
+    graphql_api::{
+        IntoApiError,
+        IntoApiResult,
     },
-    graphql_api::IntoApiResult,
     query::MessageQueryData,
     schema::scalars::{
         BlockId,
@@ -129,7 +133,9 @@ impl MessageQuery {
         let query: &ReadView = ctx.data_unchecked();
         let height = match (commit_block_id, commit_block_height) {
             (Some(commit_block_id), None) => {
-                query.block_height(&commit_block_id.0.into())?
+                query
+                    .block_height(&commit_block_id.0.into())
+                    .map_err(IntoApiError::into_api_error)?
             },
             (None, Some(commit_block_height)) => {
                 commit_block_height.0.into()
@@ -157,6 +163,33 @@ impl MessageQuery {
         let status = crate::query::message_status(query, nonce.into())?;
         Ok(status.into())
     }
+
+    /// Returns the reason why the forced transaction relayed from the DA layer
+    /// with the `nonce` was not included into the block.
+    async fn forced_transaction_failure(
+        &self,
+        ctx: &Context<'_>,
+        nonce: Nonce,
+    ) -> async_graphql::Result<Option<ForcedTransactionFailure>> {
+        let query: &ReadView = ctx.data_unchecked();
+        let failure = query.forced_transaction_failure(&nonce.0)?;
+        Ok(failure.map(ForcedTransactionFailure))
+    }
+}
+
+pub struct ForcedTransactionFailure(
+    pub(crate) relayed_transactions::ForcedTransactionFailure,
+);
+
+#[Object]
+impl ForcedTransactionFailure {
+    async fn block_height(&self) -> U32 {
+        self.0.block_height.into()
+    }
+
+    async fn failure(&self) -> String {
+        self.0.failure.clone()
+    }
 }
 pub struct MerkleProof(pub(crate) entities::message::MerkleProof);
 
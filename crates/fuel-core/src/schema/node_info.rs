@@ -3,6 +3,7 @@ use super::scalars::{
     U64,
 };
 use crate::fuel_core_graphql_api::Config as GraphQLConfig;
+use anyhow::anyhow;
 use async_graphql::{
     Context,
     Object,
@@ -60,6 +61,27 @@ impl NodeInfo {
             ))
         }
     }
+
+    /// Peers banned by the node, including bans restored after a restart.
+    async fn banned_peers(
+        &self,
+        _ctx: &Context<'_>,
+    ) -> async_graphql::Result<Vec<BannedPeerInfo>> {
+        #[cfg(feature = "p2p")]
+        {
+            let p2p: &crate::fuel_core_graphql_api::api_service::P2pService =
+                _ctx.data_unchecked();
+            let banned_peers = p2p.banned_peers().await?;
+            let peers = banned_peers.into_iter().map(BannedPeerInfo).collect();
+            Ok(peers)
+        }
+        #[cfg(not(feature = "p2p"))]
+        {
+            Err(async_graphql::Error::new(
+                "Peering is disabled in this build, try using the `p2p` feature flag.",
+            ))
+        }
+    }
 }
 
 #[derive(Default)]
@@ -83,6 +105,71 @@ impl NodeQuery {
     }
 }
 
+#[derive(Default)]
+pub struct NodeMutation;
+
+#[Object]
+impl NodeMutation {
+    /// Bans the peer for `duration_seconds` or for the configured ban duration.
+    /// The ban is persisted and survives restarts of the node.
+    async fn ban_peer(
+        &self,
+        ctx: &Context<'_>,
+        id: String,
+        duration_seconds: Option<U64>,
+    ) -> async_graphql::Result<bool> {
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+        if !config.debug {
+            return Err(anyhow!("`debug` must be enabled to use this endpoint").into())
+        }
+
+        #[cfg(feature = "p2p")]
+        {
+            let p2p: &crate::fuel_core_graphql_api::api_service::P2pService =
+                ctx.data_unchecked();
+            let peer_id = id.parse().map_err(|e: String| anyhow!(e))?;
+            let duration =
+                duration_seconds.map(|seconds| std::time::Duration::from_secs(seconds.0));
+            p2p.ban_peer(peer_id, duration).await?;
+            Ok(true)
+        }
+        #[cfg(not(feature = "p2p"))]
+        {
+            let _ = (id, duration_seconds);
+            Err(async_graphql::Error::new(
+                "Peering is disabled in this build, try using the `p2p` feature flag.",
+            ))
+        }
+    }
+
+    /// Removes the ban of the peer. Returns `false` if the peer wasn't banned.
+    async fn unban_peer(
+        &self,
+        ctx: &Context<'_>,
+        id: String,
+    ) -> async_graphql::Result<bool> {
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+        if !config.debug {
+            return Err(anyhow!("`debug` must be enabled to use this endpoint").into())
+        }
+
+        #[cfg(feature = "p2p")]
+        {
+            let p2p: &crate::fuel_core_graphql_api::api_service::P2pService =
+                ctx.data_unchecked();
+            let peer_id = id.parse().map_err(|e: String| anyhow!(e))?;
+            Ok(p2p.unban_peer(peer_id).await?)
+        }
+        #[cfg(not(feature = "p2p"))]
+        {
+            let _ = id;
+            Err(async_graphql::Error::new(
+                "Peering is disabled in this build, try using the `p2p` feature flag.",
+            ))
+        }
+    }
+}
+
 struct PeerInfo(fuel_core_types::services::p2p::PeerInfo);
 
 #[Object]
@@ -125,3 +212,24 @@ impl PeerInfo {
         self.0.app_score
     }
 }
+
+struct BannedPeerInfo(fuel_core_types::services::p2p::BannedPeerInfo);
+
+#[Object]
+impl BannedPeerInfo {
+    /// The libp2p peer id
+    async fn id(&self) -> String {
+        self.0.id.to_string()
+    }
+
+    /// The time when the ban expires in unix epoch time ms
+    async fn banned_until_ms(&self) -> U64 {
+        let time = self
+            .0
+            .banned_until
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis();
+        U64(time.try_into().unwrap_or_default())
+    }
+}
@@ -7,6 +7,7 @@ use crate::{
         database::ReadView,
         ports::OffChainDatabase,
         Config,
+        IntoApiError,
         IntoApiResult,
     },
     query::{
@@ -284,6 +285,7 @@ impl TxMutation {
             .insert(vec![Arc::new(tx.clone())])
             .await
             .into_iter()
+            .map(|result| result.map_err(IntoApiError::into_api_error))
             .try_collect()?;
         let id = tx.id(&config.consensus_parameters.chain_id);
 
@@ -351,6 +353,7 @@ impl TxStatusSubscription {
             .insert(vec![Arc::new(tx)])
             .await
             .into_iter()
+            .map(|result| result.map_err(IntoApiError::into_api_error))
             .try_collect()?;
 
         Ok(subscription
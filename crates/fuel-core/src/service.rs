@@ -7,7 +7,10 @@ use crate::{
             P2PAdapter,
             PoAAdapter,
         },
-        genesis::execute_genesis_block,
+        genesis::{
+            execute_genesis_block,
+            execute_genesis_block_without_state,
+        },
     },
 };
 use fuel_core_poa::ports::BlockImporter;
@@ -34,10 +37,16 @@ pub use config::{
 pub use fuel_core_services::Service as ServiceTrait;
 
 pub mod adapters;
+#[cfg(feature = "rocksdb")]
+pub mod backup;
 pub mod config;
 pub mod genesis;
 pub mod metrics;
 mod query;
+#[cfg(feature = "rocksdb")]
+pub mod reindex;
+#[cfg(feature = "p2p")]
+pub mod state_snapshots;
 pub mod sub_services;
 
 #[derive(Clone)]
@@ -113,12 +122,43 @@ impl FuelService {
                         config.database_path,
                         config.max_database_cache_size
                     );
-                    CombinedDatabase::open(
+                    CombinedDatabase::open_with_config(
+                        &config.database_path,
+                        config.max_database_cache_size,
+                        &config.rocksdb,
+                    )?
+                }
+            }
+            #[cfg(feature = "redb")]
+            DbType::Redb => {
+                if config.database_path.as_os_str().is_empty() {
+                    warn!(
+                        "No redb path configured, initializing database with a tmp directory"
+                    );
+                    CombinedDatabase::new(
+                        Database::redb(),
+                        Database::redb(),
+                        Database::redb(),
+                        Database::redb(),
+                    )
+                } else {
+                    tracing::info!(
+                        "Opening redb database {:?} with cache size \"{}\"",
+                        config.database_path,
+                        config.max_database_cache_size
+                    );
+                    CombinedDatabase::open_redb(
                         &config.database_path,
                         config.max_database_cache_size,
                     )?
                 }
             }
+            #[cfg(not(feature = "redb"))]
+            DbType::Redb => {
+                return Err(anyhow::anyhow!(
+                    "The `redb` feature must be enabled to use the redb database"
+                ))
+            }
             DbType::InMemory => CombinedDatabase::in_memory(),
             #[cfg(not(feature = "rocksdb"))]
             _ => CombinedDatabase::in_memory(),
@@ -132,8 +172,12 @@ impl FuelService {
         database: Database,
         config: Config,
     ) -> anyhow::Result<Self> {
-        let combined_database =
-            CombinedDatabase::new(database, Default::default(), Default::default());
+        let combined_database = CombinedDatabase::new(
+            database,
+            Default::default(),
+            Default::default(),
+            Default::default(),
+        );
         Self::from_combined_database(combined_database, config).await
     }
 
@@ -224,6 +268,7 @@ impl Task {
             .unwrap_or_default();
         let da_block_height = 0u64.into();
         database.init(&block_height, &da_block_height)?;
+        database.set_history_retention(config.history_retention)?;
 
         // initialize sub services
         tracing::info!("Initializing sub services");
@@ -258,15 +303,31 @@ impl RunnableService for Task {
         // check if chain is initialized
         if let Err(err) = on_view.get_genesis() {
             if err.is_not_found() {
-                let result = execute_genesis_block(&self.shared.config, &on_view)?;
+                // The state is downloaded from peers at the checkpoint, so there is no
+                // need to initialize the state of the genesis block.
+                #[cfg(feature = "p2p")]
+                let sync_from_checkpoint = self.shared.config.sync.checkpoint.is_some();
+                #[cfg(not(feature = "p2p"))]
+                let sync_from_checkpoint = false;
+
+                if sync_from_checkpoint {
+                    let result = execute_genesis_block_without_state(
+                        &self.shared.config,
+                        &on_view,
+                    )?;
+                    self.shared.block_importer.commit_result(result).await?;
+                } else {
+                    let result = execute_genesis_block(&self.shared.config, &on_view)?;
 
-                self.shared.block_importer.commit_result(result).await?;
+                    self.shared.block_importer.commit_result(result).await?;
 
-                let off_chain_db_transaction = genesis::off_chain::execute_genesis_block(
-                    &self.shared.config,
-                    &off_view,
-                )?;
-                off_chain_db_transaction.commit()?;
+                    let off_chain_db_transaction =
+                        genesis::off_chain::execute_genesis_block(
+                            &self.shared.config,
+                            &off_view,
+                        )?;
+                    off_chain_db_transaction.commit()?;
+                }
             }
         }
 
@@ -317,6 +378,8 @@ impl RunnableTask for Task {
 
 #[cfg(test)]
 mod tests {
+    #![allow(non_snake_case)]
+
     use crate::service::{
         Config,
         Task,
@@ -373,6 +436,60 @@ mod tests {
         assert_eq!(i, expected_services);
     }
 
+    #[cfg(feature = "p2p")]
+    #[test]
+    fn task_new__rejects_block_production_in_light_sync_mode() {
+        // Given
+        let mut config = Config::local_node();
+        config.sync.mode = fuel_core_sync::SyncMode::Light;
+
+        // When
+        let result = Task::new(Default::default(), config);
+
+        // Then
+        assert!(
+            matches!(result, Err(err) if err.to_string().contains("block production"))
+        );
+    }
+
+    #[cfg(feature = "p2p")]
+    #[test]
+    fn task_new__rejects_full_sync_mode_after_light_sync() {
+        use fuel_core_storage::{
+            tables::{
+                FuelBlocks,
+                HeaderOnlyBlocks,
+            },
+            StorageAsMut,
+        };
+        use fuel_core_types::blockchain::{
+            block::CompressedBlock,
+            header::BlockHeader,
+        };
+
+        // Given
+        let mut database = crate::combined_database::CombinedDatabase::default();
+        let mut header = BlockHeader::default();
+        header.set_block_height(1u32.into());
+        database
+            .on_chain_mut()
+            .storage_as_mut::<FuelBlocks>()
+            .insert(&1u32.into(), &CompressedBlock::header_only(header))
+            .unwrap();
+        database
+            .on_chain_mut()
+            .storage_as_mut::<HeaderOnlyBlocks>()
+            .insert(&1u32.into(), &None)
+            .unwrap();
+        let config = Config::local_node();
+
+        // When
+        let result = Task::new(database, config);
+
+        // Then
+        assert!(matches!(result, Err(err) if err.to_string().contains("header only")));
+    }
+
     #[tokio::test]
     async fn shutdown_stops_all_services() {
         let task = Task::new(Default::default(), Config::local_node()).unwrap();
@@ -1,6 +1,9 @@
 use crate::{
     database::{
-        database_description::relayer::Relayer,
+        database_description::{
+            off_chain::OffChain,
+            relayer::Relayer,
+        },
         Database,
     },
     service::sub_services::BlockProducerService,
@@ -12,8 +15,6 @@ use fuel_core_consensus_module::{
 use fuel_core_executor::executor::Executor;
 use fuel_core_services::stream::BoxStream;
 use fuel_core_txpool::service::SharedState as TxPoolSharedState;
-#[cfg(feature = "p2p")]
-use fuel_core_types::services::p2p::peer_reputation::AppScore;
 use fuel_core_types::{
     fuel_types::BlockHeight,
     services::block_importer::SharedImportResult,
@@ -36,6 +37,12 @@ pub struct PoAAdapter {
     shared_state: Option<fuel_core_poa::service::SharedState>,
 }
 
+/// The signer of the blocks produced by the PoA service.
+pub enum BlockSignerAdapter {
+    Local(fuel_core_poa::signer::LocalBlockSigner),
+    Remote(crate::remote_signer::RemoteBlockSigner),
+}
+
 #[derive(Clone)]
 pub struct TxPoolAdapter {
     service: TxPoolSharedState<P2PAdapter, Database>,
@@ -130,6 +137,10 @@ pub struct BlockProducerAdapter {
 pub struct BlockImporterAdapter {
     pub block_importer:
         Arc<fuel_core_importer::Importer<Database, ExecutorAdapter, VerifierAdapter>>,
+    /// The on-chain database records the checkpoint while its state is imported.
+    database: Database,
+    /// The off-chain database is used to insert the state downloaded at the checkpoint.
+    off_chain_database: Database<OffChain>,
 }
 
 impl BlockImporterAdapter {
@@ -142,21 +153,26 @@ impl BlockImporterAdapter {
     }
 }
 
-#[cfg(feature = "p2p")]
+/// Fetches the bodies of the blocks imported by the header only from peers.
 #[derive(Clone)]
-pub struct P2PAdapter {
-    service: Option<fuel_core_p2p::service::SharedState>,
-    peer_report_config: PeerReportConfig,
+pub struct BlockBodyAdapter {
+    p2p: P2PAdapter,
+    block_importer: BlockImporterAdapter,
+}
+
+impl BlockBodyAdapter {
+    pub fn new(p2p: P2PAdapter, block_importer: BlockImporterAdapter) -> Self {
+        Self {
+            p2p,
+            block_importer,
+        }
+    }
 }
 
 #[cfg(feature = "p2p")]
 #[derive(Clone)]
-pub struct PeerReportConfig {
-    pub successful_block_import: AppScore,
-    pub missing_block_headers: AppScore,
-    pub bad_block_header: AppScore,
-    pub missing_transactions: AppScore,
-    pub invalid_transactions: AppScore,
+pub struct P2PAdapter {
+    service: Option<fuel_core_p2p::service::SharedState>,
 }
 
 #[cfg(not(feature = "p2p"))]
@@ -165,14 +181,8 @@ pub struct P2PAdapter;
 
 #[cfg(feature = "p2p")]
 impl P2PAdapter {
-    pub fn new(
-        service: Option<fuel_core_p2p::service::SharedState>,
-        peer_report_config: PeerReportConfig,
-    ) -> Self {
-        Self {
-            service,
-            peer_report_config,
-        }
+    pub fn new(service: Option<fuel_core_p2p::service::SharedState>) -> Self {
+        Self { service }
     }
 }
 
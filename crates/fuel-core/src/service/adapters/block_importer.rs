@@ -1,17 +1,28 @@
 use super::TransactionsSource;
 use crate::{
-    database::Database,
-    service::adapters::{
-        BlockImporterAdapter,
-        ExecutorAdapter,
-        VerifierAdapter,
+    database::{
+        database_description::off_chain::OffChain,
+        Database,
     },
+    service::{
+        adapters::{
+            BlockImporterAdapter,
+            ExecutorAdapter,
+            VerifierAdapter,
+        },
+        genesis,
+    },
+};
+use fuel_core_chain_config::{
+    StateCheckpoint,
+    StateConfig,
 };
 use fuel_core_importer::{
     ports::{
         BlockVerifier,
         Executor,
         ExecutorDatabase,
+        HeaderDatabase,
         ImporterDatabase,
     },
     Config,
@@ -19,24 +30,36 @@ use fuel_core_importer::{
 };
 use fuel_core_storage::{
     iter::IterDirection,
+    not_found,
     tables::{
         FuelBlocks,
+        HeaderOnlyBlocks,
         SealedBlockConsensus,
         Transactions,
     },
     transactional::StorageTransaction,
     Result as StorageResult,
     StorageAsMut,
+    StorageAsRef,
 };
 use fuel_core_types::{
     blockchain::{
-        block::Block,
+        block::{
+            Block,
+            CompressedBlock,
+        },
         consensus::Consensus,
+        header::BlockHeader,
         SealedBlock,
+        SealedBlockHeader,
+    },
+    fuel_tx::{
+        Transaction,
+        UniqueIdentifier,
     },
-    fuel_tx::UniqueIdentifier,
     fuel_types::{
         BlockHeight,
+        Bytes32,
         ChainId,
     },
     services::executor::{
@@ -51,13 +74,16 @@ impl BlockImporterAdapter {
     pub fn new(
         config: Config,
         database: Database,
+        off_chain_database: Database<OffChain>,
         executor: ExecutorAdapter,
         verifier: VerifierAdapter,
     ) -> Self {
-        let importer = Importer::new(config, database, executor, verifier);
+        let importer = Importer::new(config, database.clone(), executor, verifier);
         importer.init_metrics();
         Self {
             block_importer: Arc::new(importer),
+            database,
+            off_chain_database,
         }
     }
 
@@ -68,6 +94,59 @@ impl BlockImporterAdapter {
         self.block_importer.execute_and_commit(sealed_block).await?;
         Ok(())
     }
+
+    pub async fn import_header(
+        &self,
+        sealed_header: SealedBlockHeader,
+    ) -> anyhow::Result<()> {
+        self.block_importer.commit_header(sealed_header).await?;
+        Ok(())
+    }
+
+    /// Stores the body of the block imported by the header only.
+    pub fn import_block_body(
+        &self,
+        height: &BlockHeight,
+        transactions: &[Transaction],
+    ) -> anyhow::Result<()> {
+        self.block_importer
+            .commit_block_body(height, transactions)?;
+        Ok(())
+    }
+
+    /// Commits the `state` downloaded at the `checkpoint` with its header.
+    ///
+    /// The on-chain and off-chain databases can't be committed in one transaction,
+    /// so the checkpoint is recorded in the on-chain database until the committed
+    /// state is verified and imported into the off-chain database. If the node stops
+    /// before that, the import is finished by the [`CombinedDatabase::init`].
+    ///
+    /// [`CombinedDatabase::init`]: crate::combined_database::CombinedDatabase::init
+    pub async fn import_checkpoint(
+        &self,
+        checkpoint: StateCheckpoint,
+        sealed_header: SealedBlockHeader,
+        state: StateConfig,
+    ) -> anyhow::Result<()> {
+        self.database.record_pending_checkpoint(&checkpoint)?;
+        let result = self
+            .block_importer
+            .commit_header_with_state(sealed_header, |database| {
+                genesis::import_checkpoint_state(database, &checkpoint, state)
+            })
+            .await;
+        if let Err(err) = result {
+            // Nothing is committed, so there is nothing to finish.
+            self.database.clear_pending_checkpoint()?;
+            return Err(err.into())
+        }
+
+        genesis::finish_checkpoint_import(
+            &self.database,
+            &self.off_chain_database,
+            &checkpoint,
+        )
+    }
 }
 
 impl BlockVerifier for VerifierAdapter {
@@ -117,6 +196,64 @@ impl ExecutorDatabase for Database {
     }
 }
 
+impl HeaderDatabase for Database {
+    fn block_header_merkle_root(&self, height: &BlockHeight) -> StorageResult<Bytes32> {
+        self.storage::<FuelBlocks>().root(height).map(Into::into)
+    }
+
+    fn store_new_header(&mut self, header: &SealedBlockHeader) -> StorageResult<bool> {
+        let height = header.entity.height();
+        let mut found = self
+            .storage::<FuelBlocks>()
+            .insert(height, &CompressedBlock::header_only(header.entity.clone()))?
+            .is_some();
+        found |= self
+            .storage::<SealedBlockConsensus>()
+            .insert(height, &header.consensus)?
+            .is_some();
+        found |= self
+            .storage::<HeaderOnlyBlocks>()
+            .insert(height, &None)?
+            .is_some();
+        Ok(!found)
+    }
+
+    fn bodiless_block_header(
+        &self,
+        height: &BlockHeight,
+    ) -> StorageResult<Option<BlockHeader>> {
+        let is_bodiless = matches!(
+            self.storage::<HeaderOnlyBlocks>().get(height)?.as_deref(),
+            Some(None)
+        );
+        if !is_bodiless {
+            return Ok(None)
+        }
+        let block = self
+            .storage::<FuelBlocks>()
+            .get(height)?
+            .ok_or(not_found!(FuelBlocks))?;
+        Ok(Some(block.header().clone()))
+    }
+
+    fn store_block_body(
+        &mut self,
+        chain_id: &ChainId,
+        height: &BlockHeight,
+        transactions: &[Transaction],
+    ) -> StorageResult<()> {
+        let mut tx_ids = Vec::with_capacity(transactions.len());
+        for tx in transactions {
+            let tx_id = tx.id(chain_id);
+            self.storage::<Transactions>().insert(&tx_id, tx)?;
+            tx_ids.push(tx_id);
+        }
+        self.storage::<HeaderOnlyBlocks>()
+            .insert(height, &Some(tx_ids))?;
+        Ok(())
+    }
+}
+
 impl Executor for ExecutorAdapter {
     type Database = Database;
 
@@ -4,6 +4,7 @@ use crate::{
     service::adapters::{
         BlockImporterAdapter,
         BlockProducerAdapter,
+        BlockSignerAdapter,
         P2PAdapter,
         PoAAdapter,
         TxPoolAdapter,
@@ -13,6 +14,7 @@ use anyhow::anyhow;
 use fuel_core_poa::{
     ports::{
         BlockImporter,
+        BlockSigner,
         P2pPort,
         TransactionPool,
         TransactionsSource,
@@ -25,6 +27,10 @@ use fuel_core_poa::{
 use fuel_core_services::stream::BoxStream;
 use fuel_core_storage::transactional::StorageTransaction;
 use fuel_core_types::{
+    blockchain::{
+        block::Block,
+        consensus::Consensus,
+    },
     fuel_asm::Word,
     fuel_tx::TxId,
     fuel_types::BlockHeight,
@@ -145,6 +151,23 @@ impl BlockImporter for BlockImporterAdapter {
     }
 }
 
+#[async_trait::async_trait]
+impl BlockSigner for BlockSignerAdapter {
+    async fn seal_block(&self, block: &Block) -> anyhow::Result<Consensus> {
+        match self {
+            BlockSignerAdapter::Local(signer) => signer.seal_block(block).await,
+            BlockSignerAdapter::Remote(signer) => signer.seal_block(block).await,
+        }
+    }
+
+    async fn is_available(&self) -> bool {
+        match self {
+            BlockSignerAdapter::Local(signer) => signer.is_available().await,
+            BlockSignerAdapter::Remote(signer) => signer.is_available().await,
+        }
+    }
+}
+
 #[cfg(feature = "p2p")]
 impl P2pPort for P2PAdapter {
     fn reserved_peers_count(&self) -> BoxStream<usize> {
@@ -1,4 +1,5 @@
 use super::{
+    BlockBodyAdapter,
     BlockImporterAdapter,
     BlockProducerAdapter,
 };
@@ -6,6 +7,7 @@ use crate::{
     database::Database,
     fuel_core_graphql_api::ports::{
         worker,
+        BlockBodyPort,
         BlockProducerPort,
         DatabaseMessageProof,
         P2pPort,
@@ -24,13 +26,18 @@ use fuel_core_txpool::{
     types::TxId,
 };
 use fuel_core_types::{
+    blockchain::header::BlockHeader,
     entities::message::MerkleProof,
     fuel_tx::Transaction,
     fuel_types::BlockHeight,
     services::{
         block_importer::SharedImportResult,
         executor::TransactionExecutionStatus,
-        p2p::PeerInfo,
+        p2p::{
+            BannedPeerInfo,
+            PeerId,
+            PeerInfo,
+        },
         txpool::InsertionResult,
     },
     tai64::Tai64,
@@ -38,6 +45,7 @@ use fuel_core_types::{
 use std::{
     ops::Deref,
     sync::Arc,
+    time::Duration,
 };
 
 mod off_chain;
@@ -137,6 +145,127 @@ impl P2pPort for P2PAdapter {
             Ok(vec![])
         }
     }
+
+    async fn banned_peers(&self) -> anyhow::Result<Vec<BannedPeerInfo>> {
+        #[cfg(feature = "p2p")]
+        {
+            if let Some(service) = &self.service {
+                let peers = service.get_banned_peers().await?;
+                Ok(peers
+                    .into_iter()
+                    .map(|(peer_id, banned_until)| BannedPeerInfo {
+                        id: PeerId::from(peer_id.to_bytes()),
+                        banned_until,
+                    })
+                    .collect())
+            } else {
+                Ok(vec![])
+            }
+        }
+        #[cfg(not(feature = "p2p"))]
+        {
+            Ok(vec![])
+        }
+    }
+
+    async fn ban_peer(
+        &self,
+        peer_id: PeerId,
+        duration: Option<Duration>,
+    ) -> anyhow::Result<()> {
+        #[cfg(feature = "p2p")]
+        {
+            let service = self
+                .service
+                .as_ref()
+                .ok_or_else(|| anyhow::anyhow!("The P2P service is disabled"))?;
+            let peer_id = fuel_core_p2p::PeerId::from_bytes(peer_id.as_ref())?;
+            service.ban_peer(peer_id, duration).await
+        }
+        #[cfg(not(feature = "p2p"))]
+        {
+            let _ = (peer_id, duration);
+            Err(anyhow::anyhow!("The P2P service is disabled"))
+        }
+    }
+
+    async fn unban_peer(&self, peer_id: PeerId) -> anyhow::Result<bool> {
+        #[cfg(feature = "p2p")]
+        {
+            let service = self
+                .service
+                .as_ref()
+                .ok_or_else(|| anyhow::anyhow!("The P2P service is disabled"))?;
+            let peer_id = fuel_core_p2p::PeerId::from_bytes(peer_id.as_ref())?;
+            service.unban_peer(peer_id).await
+        }
+        #[cfg(not(feature = "p2p"))]
+        {
+            let _ = peer_id;
+            Err(anyhow::anyhow!("The P2P service is disabled"))
+        }
+    }
+}
+
+#[async_trait]
+impl BlockBodyPort for BlockBodyAdapter {
+    async fn fetch_block_body(
+        &self,
+        header: &BlockHeader,
+    ) -> anyhow::Result<Vec<Transaction>> {
+        #[cfg(feature = "p2p")]
+        {
+            use fuel_core_types::services::p2p::peer_reputation::ReputationEvent;
+
+            let service = self
+                .p2p
+                .service
+                .as_ref()
+                .ok_or_else(|| anyhow::anyhow!("The P2P service is disabled"))?;
+            let height = u32::from(*header.height());
+            let end = height
+                .checked_add(1)
+                .ok_or_else(|| anyhow::anyhow!("The block height overflows"))?;
+
+            // Request the header first to find a peer with the same block.
+            let (peer_id, headers) =
+                service.get_sealed_block_headers(height..end).await?;
+            let has_block = headers
+                .unwrap_or_default()
+                .iter()
+                .any(|sealed| sealed.entity.id() == header.id());
+            if !has_block {
+                return Err(anyhow::anyhow!("The peer doesn't have the block"))
+            }
+
+            let transactions = service
+                .get_transactions_from_peer(peer_id.clone(), height..end)
+                .await?
+                .and_then(|mut blocks| blocks.pop())
+                .ok_or_else(|| {
+                    anyhow::anyhow!("The peer didn't return the transactions")
+                })?
+                .0;
+
+            if let Err(e) = self
+                .block_importer
+                .import_block_body(header.height(), &transactions)
+            {
+                let _ = service.report_peer_event(
+                    PeerId::from(peer_id),
+                    ReputationEvent::InvalidTransactions,
+                    "GraphQL",
+                );
+                return Err(e)
+            }
+            Ok(transactions)
+        }
+        #[cfg(not(feature = "p2p"))]
+        {
+            let _ = (header, &self.p2p, &self.block_importer);
+            Err(anyhow::anyhow!("The P2P service is disabled"))
+        }
+    }
 }
 
 impl worker::BlockImporter for BlockImporterAdapter {
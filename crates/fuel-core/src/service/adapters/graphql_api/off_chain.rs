@@ -8,7 +8,13 @@ use crate::{
             worker,
             OffChainDatabase,
         },
-        storage::transactions::OwnedTransactionIndexCursor,
+        storage::{
+            relayed_transactions::{
+                ForcedTransactionFailure,
+                ForcedTransactionFailures,
+            },
+            transactions::OwnedTransactionIndexCursor,
+        },
     },
 };
 use fuel_core_storage::{
@@ -20,6 +26,7 @@ use fuel_core_storage::{
     not_found,
     Error as StorageError,
     Result as StorageResult,
+    StorageAsRef,
 };
 use fuel_core_txpool::types::TxId;
 use fuel_core_types::{
@@ -36,6 +43,7 @@ use fuel_core_types::{
     },
     services::txpool::TransactionStatus,
 };
+use std::borrow::Cow;
 
 impl OffChainDatabase for Database<OffChain> {
     fn block_height(&self, id: &BlockId) -> StorageResult<BlockHeight> {
@@ -85,6 +93,17 @@ impl OffChainDatabase for Database<OffChain> {
             .map(|result| result.map_err(StorageError::from))
             .into_boxed()
     }
+
+    fn forced_transaction_failure(
+        &self,
+        nonce: &Nonce,
+    ) -> StorageResult<Option<ForcedTransactionFailure>> {
+        let failure = self
+            .storage::<ForcedTransactionFailures>()
+            .get(nonce)?
+            .map(Cow::into_owned);
+        Ok(failure)
+    }
 }
 
 impl worker::OffChainDatabase for Database<OffChain> {
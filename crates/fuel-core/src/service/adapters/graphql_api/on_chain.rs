@@ -16,9 +16,13 @@ use fuel_core_storage::{
         IterDirection,
     },
     not_found,
-    tables::FuelBlocks,
+    tables::{
+        FuelBlocks,
+        HeaderOnlyBlocks,
+    },
     Error as StorageError,
     Result as StorageResult,
+    StorageAsRef,
 };
 use fuel_core_txpool::types::ContractId;
 use fuel_core_types::{
@@ -27,7 +31,10 @@ use fuel_core_types::{
         primitives::DaBlockHeight,
     },
     entities::message::Message,
-    fuel_tx::AssetId,
+    fuel_tx::{
+        AssetId,
+        TxId,
+    },
     fuel_types::{
         BlockHeight,
         Nonce,
@@ -51,6 +58,19 @@ impl DatabaseBlocks for Database {
             .transpose()
             .ok_or(not_found!("BlockHeight"))?
     }
+
+    fn block_body(&self, height: &BlockHeight) -> StorageResult<Option<Vec<TxId>>> {
+        match self.storage::<HeaderOnlyBlocks>().get(height)? {
+            Some(body) => Ok(body.into_owned()),
+            None => {
+                let block = self
+                    .storage::<FuelBlocks>()
+                    .get(height)?
+                    .ok_or(not_found!(FuelBlocks))?;
+                Ok(Some(block.transactions().to_vec()))
+            }
+        }
+    }
 }
 
 impl DatabaseMessages for Database {
@@ -1,11 +1,35 @@
 use super::BlockImporterAdapter;
-use crate::database::Database;
-use fuel_core_p2p::ports::{
-    BlockHeightImporter,
-    P2pDb,
+use crate::{
+    database::{
+        database_description::p2p::P2p,
+        Database,
+    },
+    service::config::DbType,
+};
+use fuel_core_chain_config::{
+    StateChunk,
+    StateChunkRequest,
+};
+use fuel_core_p2p::{
+    peer_store::{
+        BannedPeers,
+        KnownPeer,
+        KnownPeers,
+    },
+    ports::{
+        BlockHeightImporter,
+        P2pDb,
+        PeerStore,
+    },
+    PeerId,
 };
 use fuel_core_services::stream::BoxStream;
-use fuel_core_storage::Result as StorageResult;
+use fuel_core_storage::{
+    transactional::AtomicView,
+    Error as StorageError,
+    Result as StorageResult,
+    StorageAsMut,
+};
 use fuel_core_types::{
     blockchain::{
         consensus::Genesis,
@@ -14,25 +38,117 @@ use fuel_core_types::{
     fuel_types::BlockHeight,
     services::p2p::Transactions,
 };
-use std::ops::Range;
+use state_snapshots::StateSnapshots;
+use std::{
+    num::NonZeroU32,
+    ops::Range,
+    path::PathBuf,
+    sync::{
+        Arc,
+        Mutex,
+    },
+    time::SystemTime,
+};
+
+mod state_snapshots;
+
+/// Provides views of the on-chain database to the P2P service.
+/// The views share the snapshots of the state used to serve state chunks.
+#[derive(Clone)]
+pub struct P2PViewProvider {
+    database: Database,
+    state_snapshots: Arc<Mutex<StateSnapshots>>,
+}
+
+impl P2PViewProvider {
+    /// Creates the provider. Snapshots of the state at heights divisible by
+    /// the `snapshot_interval` are stored in the `snapshots_directory`
+    /// if the database supports checkpoints.
+    pub fn new(
+        database: Database,
+        database_type: DbType,
+        snapshots_directory: Option<PathBuf>,
+        snapshot_interval: NonZeroU32,
+    ) -> Self {
+        Self {
+            database,
+            state_snapshots: Arc::new(Mutex::new(StateSnapshots::new(
+                database_type,
+                snapshots_directory,
+                snapshot_interval,
+            ))),
+        }
+    }
+
+    /// Pins the state at the `height` of the imported block, if it is divisible
+    /// by the snapshot interval. The next block should not be imported until it is done.
+    pub fn pin_imported_state(&self, height: BlockHeight) -> anyhow::Result<()> {
+        let mut state_snapshots = self
+            .state_snapshots
+            .lock()
+            .map_err(|e| anyhow::anyhow!("The state snapshots are poisoned: {e}"))?;
+        state_snapshots.pin_imported(&self.database, height)?;
+        Ok(())
+    }
 
-impl P2pDb for Database {
+    fn view(&self, database: Database) -> P2PView {
+        P2PView {
+            database,
+            state_snapshots: self.state_snapshots.clone(),
+        }
+    }
+}
+
+impl AtomicView for P2PViewProvider {
+    type View = P2PView;
+    type Height = BlockHeight;
+
+    fn latest_height(&self) -> BlockHeight {
+        AtomicView::latest_height(&self.database)
+    }
+
+    fn view_at(&self, height: &BlockHeight) -> StorageResult<P2PView> {
+        Ok(self.view(self.database.view_at(height)?))
+    }
+
+    fn latest_view(&self) -> P2PView {
+        self.view(self.database.latest_view())
+    }
+}
+
+pub struct P2PView {
+    database: Database,
+    state_snapshots: Arc<Mutex<StateSnapshots>>,
+}
+
+impl P2pDb for P2PView {
     fn get_sealed_headers(
         &self,
         block_height_range: Range<u32>,
     ) -> StorageResult<Vec<SealedBlockHeader>> {
-        self.get_sealed_block_headers(block_height_range)
+        self.database.get_sealed_block_headers(block_height_range)
     }
 
     fn get_transactions(
         &self,
         block_height_range: Range<u32>,
     ) -> StorageResult<Option<Vec<Transactions>>> {
-        self.get_transactions_on_blocks(block_height_range)
+        self.database.get_transactions_on_blocks(block_height_range)
     }
 
     fn get_genesis(&self) -> StorageResult<Genesis> {
-        self.get_genesis()
+        self.database.get_genesis()
+    }
+
+    fn get_state_chunk(
+        &self,
+        request: &StateChunkRequest,
+    ) -> StorageResult<Option<StateChunk>> {
+        let mut state_snapshots = self
+            .state_snapshots
+            .lock()
+            .map_err(|e| anyhow::anyhow!("The state snapshots are poisoned: {e}"))?;
+        state_snapshots.get_state_chunk(&self.database, request)
     }
 }
 
@@ -49,3 +165,100 @@ impl BlockHeightImporter for BlockImporterAdapter {
         )
     }
 }
+
+impl PeerStore for Database<P2p> {
+    fn known_peers(&self) -> StorageResult<Vec<(PeerId, KnownPeer)>> {
+        self.iter_all::<KnownPeers>(None)
+            .map(|result| {
+                let (peer_id, known_peer) = result?;
+                Ok((decode_peer_id(&peer_id)?, known_peer))
+            })
+            .collect()
+    }
+
+    fn insert_known_peer(
+        &mut self,
+        peer_id: &PeerId,
+        peer: &KnownPeer,
+    ) -> StorageResult<()> {
+        self.storage::<KnownPeers>()
+            .insert(&peer_id.to_bytes(), peer)?;
+        Ok(())
+    }
+
+    fn remove_known_peer(&mut self, peer_id: &PeerId) -> StorageResult<()> {
+        self.storage::<KnownPeers>().remove(&peer_id.to_bytes())?;
+        Ok(())
+    }
+
+    fn banned_peers(&self) -> StorageResult<Vec<(PeerId, SystemTime)>> {
+        self.iter_all::<BannedPeers>(None)
+            .map(|result| {
+                let (peer_id, banned_until) = result?;
+                Ok((decode_peer_id(&peer_id)?, banned_until))
+            })
+            .collect()
+    }
+
+    fn insert_banned_peer(
+        &mut self,
+        peer_id: &PeerId,
+        banned_until: SystemTime,
+    ) -> StorageResult<()> {
+        self.storage::<BannedPeers>()
+            .insert(&peer_id.to_bytes(), &banned_until)?;
+        Ok(())
+    }
+
+    fn remove_banned_peer(&mut self, peer_id: &PeerId) -> StorageResult<()> {
+        self.storage::<BannedPeers>().remove(&peer_id.to_bytes())?;
+        Ok(())
+    }
+}
+
+fn decode_peer_id(bytes: &[u8]) -> StorageResult<PeerId> {
+    PeerId::from_bytes(bytes).map_err(|e| StorageError::Codec(anyhow::anyhow!(e)))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn peer_store__known_peers_are_persisted() {
+        // given
+        let mut db = Database::<P2p>::default();
+        let peer_id = PeerId::random();
+        let known_peer = KnownPeer {
+            addresses: vec!["/ip4/127.0.0.1/tcp/4001".parse().unwrap()],
+            last_seen: SystemTime::UNIX_EPOCH + Duration::from_secs(100),
+            score: 42.,
+        };
+
+        // when
+        db.insert_known_peer(&peer_id, &known_peer).unwrap();
+
+        // then
+        assert_eq!(db.known_peers().unwrap(), vec![(peer_id, known_peer)]);
+    }
+
+    #[test]
+    fn peer_store__banned_peer_can_be_removed() {
+        // given
+        let mut db = Database::<P2p>::default();
+        let banned_peer = PeerId::random();
+        let other_peer = PeerId::random();
+        let banned_until = SystemTime::UNIX_EPOCH + Duration::from_secs(100);
+        db.insert_banned_peer(&banned_peer, banned_until).unwrap();
+        db.insert_banned_peer(&other_peer, banned_until).unwrap();
+
+        // when
+        db.remove_banned_peer(&banned_peer).unwrap();
+
+        // then
+        assert_eq!(db.banned_peers().unwrap(), vec![(other_peer, banned_until)]);
+    }
+}
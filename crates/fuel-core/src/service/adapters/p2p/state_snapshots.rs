@@ -0,0 +1,507 @@
+//! The state of the chain pinned at fixed heights to serve state chunks to peers.
+//!
+//! The database doesn't support views at past heights, so the state at heights
+//! divisible by the snapshot interval is copied into the checkpoint of the database
+//! when the block at this height is imported. All chunks at this height are served
+//! from the checkpoint, even after the node imports new blocks. The state at other
+//! heights is served from the live database until the next block.
+//!
+//! Each checkpoint is stored in its own subdirectory marked by [`SNAPSHOT_MARKER`],
+//! so only directories created by the node are removed.
+
+use crate::{
+    database::Database,
+    service::config::DbType,
+};
+use fuel_core_chain_config::{
+    StateChunk,
+    StateChunkEntries,
+    StateChunkKind,
+    StateChunkRequest,
+};
+use fuel_core_storage::Result as StorageResult;
+use fuel_core_types::{
+    fuel_tx::UtxoId,
+    fuel_types::{
+        BlockHeight,
+        ContractId,
+        Nonce,
+    },
+};
+use std::{
+    collections::VecDeque,
+    num::NonZeroU32,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+/// The maximum number of pinned snapshots. Syncing nodes usually use
+/// the latest checkpoint, so only the last few interval heights are kept.
+const MAX_SNAPSHOTS: usize = 2;
+
+/// The file marking the directory of the state snapshot created by the node.
+const SNAPSHOT_MARKER: &str = ".fuel-core-state-snapshot";
+
+pub struct StateSnapshots {
+    database_type: DbType,
+    /// The directory for checkpoints of the database.
+    /// If `None`, the state is served only from the live database.
+    directory: Option<PathBuf>,
+    /// Only the state at heights divisible by the interval is pinned.
+    interval: NonZeroU32,
+    snapshots: VecDeque<StateSnapshot>,
+}
+
+impl StateSnapshots {
+    pub fn new(
+        database_type: DbType,
+        directory: Option<PathBuf>,
+        interval: NonZeroU32,
+    ) -> Self {
+        // Checkpoints from the previous run are not tracked anymore.
+        if let Some(directory) = directory.as_ref() {
+            if let Err(e) = remove_snapshots(directory) {
+                tracing::warn!(
+                    "Failed to remove old state snapshots at {}: {e}",
+                    directory.display()
+                );
+            }
+        }
+
+        Self {
+            database_type,
+            directory,
+            interval,
+            snapshots: VecDeque::with_capacity(MAX_SNAPSHOTS),
+        }
+    }
+
+    /// Pins the state at the `height` of the imported block, if the `height` is
+    /// divisible by the interval. It should be called before the next block is
+    /// imported, so the `database` is still at the `height`.
+    pub fn pin_imported(
+        &mut self,
+        database: &Database,
+        height: BlockHeight,
+    ) -> StorageResult<()> {
+        if !self.is_pinned_height(height)
+            || self
+                .snapshots
+                .iter()
+                .any(|snapshot| snapshot.pinned && snapshot.height == height)
+        {
+            return Ok(())
+        }
+        if let Some(snapshot) = self.snapshot(database, height)? {
+            if snapshot.pinned {
+                self.push(snapshot);
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the chunk of the state at the requested height.
+    /// Returns `None` if the state at this height is not available.
+    pub fn get_state_chunk(
+        &mut self,
+        database: &Database,
+        request: &StateChunkRequest,
+    ) -> StorageResult<Option<StateChunk>> {
+        let latest_height = database.latest_height()?;
+        // Snapshots over the live database are valid only until the next block.
+        self.snapshots
+            .retain(|snapshot| snapshot.pinned || snapshot.height == latest_height);
+
+        let position = self
+            .snapshots
+            .iter()
+            .position(|snapshot| snapshot.height == request.height);
+        let position = match position {
+            Some(position) => position,
+            // The state can be pinned only at the latest height.
+            // The state at the interval height is pinned lazily if the node was
+            // started at this height, so the block was not imported by the node.
+            None if request.height == latest_height => {
+                match self.snapshot(database, latest_height)? {
+                    Some(snapshot) => self.push(snapshot),
+                    None => return Ok(None),
+                }
+            }
+            None => return Ok(None),
+        };
+
+        let snapshot = match self.snapshots.get_mut(position) {
+            Some(snapshot) => snapshot,
+            None => return Ok(None),
+        };
+        let chunk = snapshot.read_chunk(request)?;
+
+        // The new block could modify the live database while we were reading it.
+        if !snapshot.pinned && database.latest_height()? != request.height {
+            self.snapshots.remove(position);
+            return Ok(None)
+        }
+        Ok(chunk)
+    }
+
+    /// Returns `true` if the state at the `height` should be pinned.
+    fn is_pinned_height(&self, height: BlockHeight) -> bool {
+        (*height).checked_rem(self.interval.get()) == Some(0)
+    }
+
+    /// Adds the `snapshot` and returns its position. The oldest pinned snapshot
+    /// is removed if there are too many of them.
+    fn push(&mut self, snapshot: StateSnapshot) -> usize {
+        let pinned = self.snapshots.iter().filter(|s| s.pinned).count();
+        if snapshot.pinned && pinned >= MAX_SNAPSHOTS {
+            if let Some(oldest) = self.snapshots.iter().position(|s| s.pinned) {
+                self.snapshots.remove(oldest);
+            }
+        }
+        self.snapshots.push_back(snapshot);
+        self.snapshots.len().saturating_sub(1)
+    }
+
+    /// Creates the snapshot of the state at the latest `height`.
+    /// The state is pinned only at heights divisible by the interval.
+    fn snapshot(
+        &self,
+        database: &Database,
+        height: BlockHeight,
+    ) -> StorageResult<Option<StateSnapshot>> {
+        let pin = self.is_pinned_height(height);
+        let pinned = match pin.then(|| self.pin(database, height)).transpose() {
+            Ok(pinned) => pinned.flatten(),
+            Err(e) => {
+                tracing::warn!("Failed to pin the state at the height {height}: {e}");
+                None
+            }
+        };
+
+        let snapshot = match pinned {
+            // The block could be imported before the checkpoint was created.
+            Some(pinned) if pinned.latest_height()? != height => None,
+            Some(pinned) => Some(StateSnapshot::new(height, pinned, true)),
+            None => Some(StateSnapshot::new(height, database.clone(), false)),
+        };
+        Ok(snapshot)
+    }
+
+    /// Copies the database into the checkpoint and opens it.
+    /// Returns `None` if the database doesn't support checkpoints.
+    fn pin(
+        &self,
+        database: &Database,
+        height: BlockHeight,
+    ) -> anyhow::Result<Option<Database>> {
+        let supported = match self.database_type {
+            DbType::InMemory => false,
+            DbType::RocksDb => cfg!(feature = "rocksdb"),
+            DbType::Redb => cfg!(feature = "redb"),
+        };
+        let directory = match &self.directory {
+            Some(directory) if supported => directory,
+            _ => return Ok(None),
+        };
+
+        let path = directory.join(height.to_string());
+        if is_snapshot(&path) {
+            std::fs::remove_dir_all(&path)?;
+        }
+        std::fs::create_dir_all(&path)?;
+        std::fs::write(path.join(SNAPSHOT_MARKER), [])?;
+        let checkpoint_path = path.join("db");
+        database.checkpoint(&checkpoint_path)?;
+        let pinned = match open_checkpoint(&self.database_type, &checkpoint_path)? {
+            Some(pinned) => pinned,
+            None => return Ok(None),
+        };
+
+        let pinned = pinned.with_drop(Box::new(move || {
+            if let Err(e) = std::fs::remove_dir_all(&path) {
+                tracing::warn!(
+                    "Failed to remove the state snapshot at {}: {e}",
+                    path.display()
+                );
+            }
+        }));
+        Ok(Some(pinned))
+    }
+}
+
+/// Returns `true` if the `path` is the directory of the snapshot created by the node.
+fn is_snapshot(path: &Path) -> bool {
+    path.join(SNAPSHOT_MARKER).is_file()
+}
+
+/// Removes the snapshots created by the node from the `directory`.
+/// Other entries of the directory are not touched.
+fn remove_snapshots(directory: &Path) -> std::io::Result<()> {
+    if !directory.exists() {
+        return Ok(())
+    }
+    for entry in std::fs::read_dir(directory)? {
+        let path = entry?.path();
+        if is_snapshot(&path) {
+            std::fs::remove_dir_all(&path)?;
+        }
+    }
+    Ok(())
+}
+
+fn open_checkpoint(
+    database_type: &DbType,
+    path: &Path,
+) -> anyhow::Result<Option<Database>> {
+    let database = match database_type {
+        #[cfg(feature = "rocksdb")]
+        DbType::RocksDb => Some(Database::open(path, None)?),
+        #[cfg(feature = "redb")]
+        DbType::Redb => Some(Database::open_redb(path, None)?),
+        _ => None,
+    };
+    Ok(database)
+}
+
+/// The state at the `height` along with the positions of the served chunks.
+struct StateSnapshot {
+    height: BlockHeight,
+    database: Database,
+    /// `true` if the `database` is the checkpoint of the state at the `height`.
+    /// Otherwise, it is the live database.
+    pinned: bool,
+    /// The key of the last entry of each chunk that was read.
+    coins: Vec<UtxoId>,
+    contracts: Vec<ContractId>,
+    messages: Vec<Nonce>,
+}
+
+impl StateSnapshot {
+    fn new(height: BlockHeight, database: Database, pinned: bool) -> Self {
+        Self {
+            height,
+            database,
+            pinned,
+            coins: vec![],
+            contracts: vec![],
+            messages: vec![],
+        }
+    }
+
+    fn read_chunk(
+        &mut self,
+        request: &StateChunkRequest,
+    ) -> StorageResult<Option<StateChunk>> {
+        let database = &self.database;
+        let index = usize::try_from(request.index).unwrap_or(usize::MAX);
+        let max_entries = request.kind.max_entries();
+
+        let chunk = match request.kind {
+            StateChunkKind::Coins => {
+                read_chunk(&mut self.coins, index, max_entries, |start| {
+                    database.coin_configs_from(start)
+                })?
+                .map(|(coins, last)| (StateChunkEntries::Coins(coins), last))
+            }
+            StateChunkKind::Contracts => {
+                read_chunk(&mut self.contracts, index, max_entries, |start| {
+                    database.contract_configs_from(start)
+                })?
+                .map(|(contracts, last)| (StateChunkEntries::Contracts(contracts), last))
+            }
+            StateChunkKind::Messages => {
+                read_chunk(&mut self.messages, index, max_entries, |start| {
+                    database
+                        .message_configs_from(start.copied())
+                        .map(|result| result.map(|message| (message.nonce, message)))
+                })?
+                .map(|(messages, last)| (StateChunkEntries::Messages(messages), last))
+            }
+        };
+
+        Ok(chunk.map(|(entries, last)| StateChunk {
+            height: self.height,
+            index: request.index,
+            last,
+            entries,
+        }))
+    }
+}
+
+/// Reads the chunk at the `index` along with the flag whether it is the last one.
+///
+/// Reading starts from the end of the closest chunk read before, and the end of
+/// each chunk read on the way is remembered in the `cursors`. So each part of
+/// the state is read at most once per snapshot, regardless of the requested indexes.
+/// Returns `None` if the chunk at the `index` doesn't exist.
+fn read_chunk<K, T, I, F>(
+    cursors: &mut Vec<K>,
+    index: usize,
+    max_entries: usize,
+    entries: F,
+) -> StorageResult<Option<(Vec<T>, bool)>>
+where
+    K: Clone,
+    I: Iterator<Item = StorageResult<(K, T)>>,
+    F: Fn(Option<&K>) -> I,
+{
+    let mut current = cursors.len().min(index);
+    loop {
+        let start = current
+            .checked_sub(1)
+            .and_then(|previous| cursors.get(previous));
+        // The start is inclusive, so the last entry of the previous chunk is skipped.
+        let skip = usize::from(start.is_some());
+        // Take one more entry to know whether it is the last chunk.
+        let mut chunk = entries(start)
+            .skip(skip)
+            .take(max_entries.saturating_add(1))
+            .collect::<StorageResult<Vec<_>>>()?;
+        let last = chunk.len() <= max_entries;
+        chunk.truncate(max_entries);
+
+        if !last && cursors.len() == current {
+            if let Some((key, _)) = chunk.last() {
+                cursors.push(key.clone());
+            }
+        }
+
+        if current == index {
+            let chunk = chunk.into_iter().map(|(_, entry)| entry).collect();
+            return Ok(Some((chunk, last)))
+        }
+        if last {
+            return Ok(None)
+        }
+        current = current.saturating_add(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+    fn entries(
+        all: &[u32],
+        start: Option<&u32>,
+    ) -> impl Iterator<Item = StorageResult<(u32, u32)>> {
+        all.iter()
+            .copied()
+            .filter(|entry| match start {
+                Some(start) => entry >= start,
+                None => true,
+            })
+            .map(|entry| Ok((entry, entry)))
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    #[test]
+    fn read_chunk__returns_chunks_in_any_order() {
+        // given
+        let all = (0..10).collect::<Vec<u32>>();
+        let mut cursors = vec![];
+
+        // when
+        let third = read_chunk(&mut cursors, 2, 3, |start| entries(&all, start)).unwrap();
+        let first = read_chunk(&mut cursors, 0, 3, |start| entries(&all, start)).unwrap();
+        let last = read_chunk(&mut cursors, 3, 3, |start| entries(&all, start)).unwrap();
+        let missing =
+            read_chunk(&mut cursors, 4, 3, |start| entries(&all, start)).unwrap();
+
+        // then
+        assert_eq!(third, Some((vec![6, 7, 8], false)));
+        assert_eq!(first, Some((vec![0, 1, 2], false)));
+        assert_eq!(last, Some((vec![9], true)));
+        assert_eq!(missing, None);
+        assert_eq!(cursors, vec![2, 5, 8]);
+    }
+
+    #[cfg(any(feature = "rocksdb", feature = "redb"))]
+    #[test]
+    fn remove_snapshots__removes_only_marked_directories() {
+        // given
+        let dir = tempfile::TempDir::new().unwrap();
+        let snapshot = dir.path().join("10");
+        std::fs::create_dir_all(snapshot.join("db")).unwrap();
+        std::fs::write(snapshot.join(SNAPSHOT_MARKER), []).unwrap();
+        let foreign_dir = dir.path().join("20");
+        std::fs::create_dir(&foreign_dir).unwrap();
+        let foreign_file = dir.path().join("notes.txt");
+        std::fs::write(&foreign_file, "keep").unwrap();
+
+        // when
+        remove_snapshots(dir.path()).unwrap();
+
+        // then
+        assert!(!snapshot.exists());
+        assert!(foreign_dir.exists());
+        assert!(foreign_file.exists());
+    }
+
+    #[cfg(feature = "rocksdb")]
+    #[test]
+    fn pin_imported__serves_the_state_at_the_interval_height() {
+        use fuel_core_storage::{
+            tables::Coins,
+            StorageAsMut,
+        };
+        use fuel_core_types::entities::coins::coin::CompressedCoin;
+
+        // given
+        let dir = tempfile::TempDir::new().unwrap();
+        let mut database = Database::rocksdb();
+        database.init(&10u32.into()).unwrap();
+        database
+            .storage::<Coins>()
+            .insert(&UtxoId::new(Default::default(), 0), &CompressedCoin::default())
+            .unwrap();
+        let mut snapshots = StateSnapshots::new(
+            DbType::RocksDb,
+            Some(dir.path().to_path_buf()),
+            NonZeroU32::new(10).unwrap(),
+        );
+        let request = StateChunkRequest {
+            height: 10u32.into(),
+            kind: StateChunkKind::Coins,
+            index: 0,
+        };
+
+        // when
+        snapshots.pin_imported(&database, 9u32.into()).unwrap();
+        snapshots.pin_imported(&database, 10u32.into()).unwrap();
+        database
+            .storage::<Coins>()
+            .insert(&UtxoId::new(Default::default(), 1), &CompressedCoin::default())
+            .unwrap();
+        let chunk = snapshots.get_state_chunk(&database, &request).unwrap();
+
+        // then
+        assert_eq!(snapshots.snapshots.len(), 1);
+        assert!(snapshots.snapshots[0].pinned);
+        assert_eq!(chunk.unwrap().entries.len(), 1);
+    }
+
+    #[test]
+    fn read_chunk__starts_from_the_closest_cursor() {
+        // given
+        let all = (0..10).collect::<Vec<u32>>();
+        let mut cursors = vec![2, 5];
+        let reads = std::cell::Cell::new(0);
+
+        // when
+        let chunk = read_chunk(&mut cursors, 2, 3, |start| {
+            reads.set(reads.get().saturating_add(1));
+            entries(&all, start)
+        })
+        .unwrap();
+
+        // then
+        assert_eq!(chunk, Some((vec![6, 7, 8], false)));
+        assert_eq!(reads.get(), 1);
+    }
+}
@@ -3,6 +3,14 @@ use super::{
     ConsensusAdapter,
     P2PAdapter,
 };
+use crate::service::genesis;
+use fuel_core_chain_config::{
+    StateCheckpoint,
+    StateChunk,
+    StateChunkKind,
+    StateChunkRequest,
+    StateConfig,
+};
 use fuel_core_poa::ports::RelayerPort;
 use fuel_core_services::stream::BoxStream;
 use fuel_core_sync::ports::{
@@ -19,10 +27,7 @@ use fuel_core_types::{
     },
     fuel_types::BlockHeight,
     services::p2p::{
-        peer_reputation::{
-            AppScore,
-            PeerReport,
-        },
+        peer_reputation::ReputationEvent,
         PeerId,
         SourcePeer,
         Transactions,
@@ -82,11 +87,32 @@ impl PeerToPeerPort for P2PAdapter {
         }
     }
 
+    async fn get_state_chunk(
+        &self,
+        peer_id: Option<PeerId>,
+        request: StateChunkRequest,
+    ) -> anyhow::Result<SourcePeer<Option<StateChunk>>> {
+        let result = if let Some(service) = &self.service {
+            service
+                .get_state_chunk(peer_id.map(Into::into), request)
+                .await
+        } else {
+            Err(anyhow::anyhow!("No P2P service available"))
+        };
+        match result {
+            Ok((peer_id, chunk)) => {
+                let peer_id: PeerId = peer_id.into();
+                Ok(peer_id.bind(chunk))
+            }
+            Err(err) => Err(err),
+        }
+    }
+
     fn report_peer(&self, peer: PeerId, report: PeerReportReason) -> anyhow::Result<()> {
         if let Some(service) = &self.service {
             let service_name = "Sync";
-            let new_report = self.process_report(report);
-            service.report_peer(peer, new_report, service_name)?;
+            let event = reputation_event(report);
+            service.report_peer_event(peer, event, service_name)?;
             Ok(())
         } else {
             Err(anyhow::anyhow!("No P2P service available"))
@@ -94,34 +120,15 @@ impl PeerToPeerPort for P2PAdapter {
     }
 }
 
-impl P2PAdapter {
-    fn process_report(&self, reason: PeerReportReason) -> P2PAdapterPeerReport {
-        let score = match &reason {
-            PeerReportReason::SuccessfulBlockImport => {
-                self.peer_report_config.successful_block_import
-            }
-            PeerReportReason::MissingBlockHeaders => {
-                self.peer_report_config.missing_block_headers
-            }
-            PeerReportReason::BadBlockHeader => self.peer_report_config.bad_block_header,
-            PeerReportReason::MissingTransactions => {
-                self.peer_report_config.missing_transactions
-            }
-            PeerReportReason::InvalidTransactions => {
-                self.peer_report_config.invalid_transactions
-            }
-        };
-        P2PAdapterPeerReport { score }
-    }
-}
-
-struct P2PAdapterPeerReport {
-    score: AppScore,
-}
-
-impl PeerReport for P2PAdapterPeerReport {
-    fn get_score_from_report(&self) -> AppScore {
-        self.score
+fn reputation_event(reason: PeerReportReason) -> ReputationEvent {
+    match reason {
+        PeerReportReason::SuccessfulBlockImport => ReputationEvent::SuccessfulBlockImport,
+        PeerReportReason::MissingBlockHeaders => ReputationEvent::MissingBlockHeaders,
+        PeerReportReason::BadBlockHeader => ReputationEvent::BadBlockHeader,
+        PeerReportReason::BadSeal => ReputationEvent::BadSeal,
+        PeerReportReason::MissingTransactions => ReputationEvent::MissingTransactions,
+        PeerReportReason::InvalidTransactions => ReputationEvent::InvalidTransactions,
+        PeerReportReason::InvalidStateChunk => ReputationEvent::InvalidStateChunk,
     }
 }
 
@@ -141,6 +148,19 @@ impl BlockImporterPort for BlockImporterAdapter {
     async fn execute_and_commit(&self, block: SealedBlock) -> anyhow::Result<()> {
         self.execute_and_commit(block).await
     }
+
+    async fn import_header(&self, header: SealedBlockHeader) -> anyhow::Result<()> {
+        self.import_header(header).await
+    }
+
+    async fn import_checkpoint(
+        &self,
+        checkpoint: StateCheckpoint,
+        header: SealedBlockHeader,
+        state: StateConfig,
+    ) -> anyhow::Result<()> {
+        self.import_checkpoint(checkpoint, header, state).await
+    }
 }
 
 #[async_trait::async_trait]
@@ -156,4 +176,13 @@ impl ConsensusPort for ConsensusAdapter {
         )
         .await?
     }
+
+    fn check_state_root(
+        &self,
+        checkpoint: &StateCheckpoint,
+        kind: StateChunkKind,
+        state: &StateConfig,
+    ) -> anyhow::Result<bool> {
+        genesis::check_checkpoint_state_root(checkpoint, kind, state)
+    }
 }
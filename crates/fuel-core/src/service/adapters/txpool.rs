@@ -33,8 +33,10 @@ use fuel_core_types::{
     services::{
         block_importer::SharedImportResult,
         p2p::{
+            peer_reputation::ReputationEvent,
             GossipsubMessageAcceptance,
             GossipsubMessageInfo,
+            PeerId,
             TransactionGossipData,
         },
     },
@@ -85,6 +87,14 @@ impl fuel_core_txpool::ports::PeerToPeer for P2PAdapter {
             Ok(())
         }
     }
+
+    fn report_peer(&self, peer_id: PeerId, event: ReputationEvent) -> anyhow::Result<()> {
+        if let Some(service) = &self.service {
+            service.report_peer_event(peer_id, event, "TxPool")
+        } else {
+            Ok(())
+        }
+    }
 }
 
 #[cfg(not(feature = "p2p"))]
@@ -109,6 +119,14 @@ impl fuel_core_txpool::ports::PeerToPeer for P2PAdapter {
     ) -> anyhow::Result<()> {
         Ok(())
     }
+
+    fn report_peer(
+        &self,
+        _peer_id: PeerId,
+        _event: ReputationEvent,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
 }
 
 impl fuel_core_txpool::ports::TxPoolDb for Database {
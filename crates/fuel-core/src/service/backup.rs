@@ -0,0 +1,229 @@
+//! The service periodically creates backups of the databases.
+
+use crate::{
+    combined_database::CombinedDatabase,
+    service::config::DbType,
+};
+use fuel_core_services::{
+    stream::BoxStream,
+    EmptyShared,
+    RunnableService,
+    RunnableTask,
+    ServiceRunner,
+    StateWatcher,
+};
+use fuel_core_types::{
+    fuel_types::BlockHeight,
+    services::block_importer::SharedImportResult,
+};
+use futures::StreamExt;
+use std::{
+    num::NonZeroU32,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+#[derive(Clone, Debug)]
+pub struct BackupConfig {
+    /// The directory with backups. Each backup is stored in the sub-directory
+    /// named after the block height of the backup.
+    pub path: PathBuf,
+    /// The backup is created after each block with a height divisible by the `interval`.
+    pub interval: NonZeroU32,
+    /// The number of the latest backups to keep. Older backups are removed.
+    /// It should be at least one.
+    pub keep: usize,
+}
+
+/// The task creates backups of the databases after imported blocks.
+pub struct Task {
+    block_importer: BoxStream<SharedImportResult>,
+    database: CombinedDatabase,
+    /// The type of the `database`, so its backups are opened with the same backend.
+    database_type: DbType,
+    config: BackupConfig,
+    /// The backup that is being created in the background.
+    in_progress: Option<tokio::task::JoinHandle<()>>,
+}
+
+impl Task {
+    /// Starts the backup in the background, so the stream of imported blocks
+    /// is not blocked. The backup is skipped if the previous one is not finished.
+    fn start_backup(&mut self, height: BlockHeight) {
+        if let Some(in_progress) = &self.in_progress {
+            if !in_progress.is_finished() {
+                tracing::warn!(
+                    "Skipping the database backup at height {}, \
+                    because the previous backup is not finished",
+                    *height
+                );
+                return
+            }
+        }
+
+        let database = self.database.clone();
+        let database_type = self.database_type.clone();
+        let config = self.config.clone();
+        self.in_progress = Some(tokio::task::spawn_blocking(move || {
+            // The failed backup shouldn't stop the node.
+            if let Err(err) = backup(&database, &database_type, &config, height) {
+                tracing::error!(
+                    "Failed to create the database backup at height {}: {}",
+                    *height,
+                    err
+                );
+            }
+        }));
+    }
+}
+
+fn backup(
+    database: &CombinedDatabase,
+    database_type: &DbType,
+    config: &BackupConfig,
+    height: BlockHeight,
+) -> anyhow::Result<()> {
+    let backup_path = config.path.join((*height).to_string());
+    if backup_path.exists() {
+        return Ok(())
+    }
+
+    // Create the backup in the temporary directory first,
+    // so incomplete backups are never visible to operators.
+    let tmp_path = config.path.join(format!(".{}.tmp", *height));
+    if tmp_path.exists() {
+        std::fs::remove_dir_all(&tmp_path)?;
+    }
+    let backup_height = database.backup(database_type, &tmp_path)?;
+    let backup_path = config.path.join((*backup_height).to_string());
+    if backup_path.exists() {
+        std::fs::remove_dir_all(&tmp_path)?;
+    } else {
+        std::fs::rename(&tmp_path, &backup_path)?;
+        tracing::info!("Created the database backup at {}", backup_path.display());
+    }
+
+    remove_old_backups(&config.path, config.keep)
+}
+
+/// Removes all backups in the `path` except the `keep` latest ones.
+fn remove_old_backups(path: &Path, keep: usize) -> anyhow::Result<()> {
+    let mut heights = std::fs::read_dir(path)?
+        .filter_map(|entry| {
+            let entry = entry.ok()?;
+            entry.file_name().to_str()?.parse::<u32>().ok()
+        })
+        .collect::<Vec<_>>();
+    heights.sort_unstable_by(|a, b| b.cmp(a));
+
+    for height in heights.into_iter().skip(keep) {
+        let backup_path = path.join(height.to_string());
+        std::fs::remove_dir_all(&backup_path)?;
+        tracing::info!("Removed the database backup at {}", backup_path.display());
+    }
+    Ok(())
+}
+
+#[async_trait::async_trait]
+impl RunnableService for Task {
+    const NAME: &'static str = "DatabaseBackup";
+    type SharedData = EmptyShared;
+    type Task = Self;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {
+        EmptyShared
+    }
+
+    async fn into_task(
+        self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        if self.config.keep == 0 {
+            return Err(anyhow::anyhow!(
+                "The number of backups to keep should be above zero"
+            ))
+        }
+        CombinedDatabase::check_backup_support(&self.database_type)?;
+        std::fs::create_dir_all(&self.config.path)?;
+        Ok(self)
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableTask for Task {
+    async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
+        let should_continue;
+        tokio::select! {
+            biased;
+
+            _ = watcher.while_started() => {
+                should_continue = false;
+            }
+
+            result = self.block_importer.next() => {
+                if let Some(result) = result {
+                    let height = *result.sealed_block.entity.header().height();
+                    if (*height).checked_rem(self.config.interval.get()) == Some(0) {
+                        self.start_backup(height);
+                    }
+                    should_continue = true
+                } else {
+                    should_continue = false
+                }
+            }
+        }
+        Ok(should_continue)
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        // Let the started backup finish, so it is not left incomplete.
+        if let Some(in_progress) = self.in_progress {
+            in_progress.await?;
+        }
+        Ok(())
+    }
+}
+
+pub fn new_service(
+    block_importer: BoxStream<SharedImportResult>,
+    database: CombinedDatabase,
+    database_type: DbType,
+    config: BackupConfig,
+) -> ServiceRunner<Task> {
+    ServiceRunner::new(Task {
+        block_importer,
+        database,
+        database_type,
+        config,
+        in_progress: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn remove_old_backups_keeps_latest_backups() {
+        // Given
+        let dir = tempfile::TempDir::new().unwrap();
+        for height in [1, 2, 10, 20] {
+            std::fs::create_dir(dir.path().join(height.to_string())).unwrap();
+        }
+        std::fs::create_dir(dir.path().join(".30.tmp")).unwrap();
+
+        // When
+        remove_old_backups(dir.path(), 2).unwrap();
+
+        // Then
+        assert!(!dir.path().join("1").exists());
+        assert!(!dir.path().join("2").exists());
+        assert!(dir.path().join("10").exists());
+        assert!(dir.path().join("20").exists());
+        assert!(dir.path().join(".30.tmp").exists());
+    }
+}
@@ -1,3 +1,4 @@
+use crate::database::modifications_history::DEFAULT_MODIFICATIONS_HISTORY_RETENTION;
 use clap::ValueEnum;
 use fuel_core_chain_config::{
     default_consensus_dev_key,
@@ -12,6 +13,7 @@ use std::{
         Ipv4Addr,
         SocketAddr,
     },
+    num::NonZeroU32,
     path::PathBuf,
     time::Duration,
 };
@@ -41,6 +43,12 @@ pub struct Config {
     pub max_database_cache_size: usize,
     pub database_path: PathBuf,
     pub database_type: DbType,
+    /// The tuning of RocksDB column families.
+    #[cfg(feature = "rocksdb")]
+    pub rocksdb: crate::state::rocks_db::RocksDbConfig,
+    /// The number of the latest blocks that keep their reverse changes in the database,
+    /// so the node can be rolled back to any of them.
+    pub history_retention: NonZeroU32,
     pub chain_conf: ChainConfig,
     /// When `true`:
     /// - Enables manual block production.
@@ -60,7 +68,17 @@ pub struct Config {
     pub p2p: Option<P2PConfig<NotInitialized>>,
     #[cfg(feature = "p2p")]
     pub sync: fuel_core_sync::Config,
+    /// The state is pinned to serve it to syncing peers only at heights divisible
+    /// by the interval. The state at other heights is served until the next block.
+    #[cfg(feature = "p2p")]
+    pub state_snapshot_interval: NonZeroU32,
     pub consensus_key: Option<Secret<SecretKeyWrapper>>,
+    /// The remote signer of the blocks. If set, it is used instead of the `consensus_key`.
+    pub remote_signer: Option<crate::remote_signer::RemoteSignerConfig>,
+    /// The file persisting the last block signed by the `consensus_key`. The node always
+    /// refuses to sign a block below the height of the signed block, but without
+    /// the file, it forgets the signed blocks on restart.
+    pub slashing_protection_file: Option<PathBuf>,
     pub name: String,
     pub relayer_consensus_config: fuel_core_consensus_module::RelayerConsensusConfig,
     /// The number of reserved peers to connect to before starting to sync.
@@ -69,6 +87,9 @@ pub struct Config {
     pub time_until_synced: Duration,
     /// Time to wait after submitting a query before debug info will be logged about query.
     pub query_log_threshold_time: Duration,
+    /// The configuration of periodic database backups. Disabled if `None`.
+    #[cfg(feature = "rocksdb")]
+    pub database_backup: Option<super::backup::BackupConfig>,
 }
 
 impl Config {
@@ -88,6 +109,10 @@ impl Config {
             database_type: DbType::RocksDb,
             #[cfg(not(feature = "rocksdb"))]
             database_type: DbType::InMemory,
+            #[cfg(feature = "rocksdb")]
+            rocksdb: Default::default(),
+            history_retention: NonZeroU32::new(DEFAULT_MODIFICATIONS_HISTORY_RETENTION)
+                .expect("The retention is not zero"),
             debug: true,
             chain_conf: chain_conf.clone(),
             block_production: Trigger::Instant,
@@ -108,12 +133,19 @@ impl Config {
             p2p: Some(P2PConfig::<NotInitialized>::default("test_network")),
             #[cfg(feature = "p2p")]
             sync: fuel_core_sync::Config::default(),
+            #[cfg(feature = "p2p")]
+            state_snapshot_interval: NonZeroU32::new(10_000)
+                .expect("The interval is not zero"),
             consensus_key: Some(Secret::new(default_consensus_dev_key().into())),
+            remote_signer: None,
+            slashing_protection_file: None,
             name: String::default(),
             relayer_consensus_config: Default::default(),
             min_connected_reserved_peers: 0,
             time_until_synced: Duration::ZERO,
             query_log_threshold_time: Duration::from_secs(2),
+            #[cfg(feature = "rocksdb")]
+            database_backup: None,
         }
     }
 
@@ -148,7 +180,6 @@ impl From<&Config> for fuel_core_poa::Config {
         fuel_core_poa::Config {
             trigger: config.block_production,
             block_gas_limit: config.chain_conf.block_gas_limit,
-            signing_key: config.consensus_key.clone(),
             metrics: false,
             consensus_params: config.chain_conf.consensus_parameters.clone(),
             min_connected_reserved_peers: config.min_connected_reserved_peers,
@@ -169,4 +200,5 @@ pub struct VMConfig {
 pub enum DbType {
     InMemory,
     RocksDb,
+    Redb,
 }
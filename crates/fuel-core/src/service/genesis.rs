@@ -1,5 +1,8 @@
 use crate::{
-    database::Database,
+    database::{
+        database_description::off_chain::OffChain,
+        Database,
+    },
     service::config::Config,
 };
 use anyhow::anyhow;
@@ -7,6 +10,8 @@ use fuel_core_chain_config::{
     CoinConfig,
     ContractConfig,
     GenesisCommitment,
+    StateCheckpoint,
+    StateChunkKind,
     StateConfig,
 };
 use fuel_core_executor::refs::ContractRef;
@@ -52,10 +57,8 @@ use fuel_core_types::{
     fuel_tx::{
         Contract,
         TxPointer,
-        UtxoId,
     },
     fuel_types::{
-        bytes::WORD_SIZE,
         Bytes32,
         ContractId,
     },
@@ -64,7 +67,7 @@ use fuel_core_types::{
         UncommittedResult as UncommittedImportResult,
     },
 };
-use itertools::Itertools;
+use std::num::NonZeroU32;
 
 pub mod off_chain;
 
@@ -76,7 +79,30 @@ pub fn execute_genesis_block(
     // start a db transaction for bulk-writing
     let mut database_transaction = Transactional::transaction(original_database);
 
-    let database = database_transaction.as_mut();
+    let genesis = init_genesis_state(config, database_transaction.as_mut())?;
+
+    create_genesis_result(config, genesis, database_transaction)
+}
+
+/// Performs the importing of the genesis block without the initial state.
+/// It is used when the node downloads the state at the trusted checkpoint from peers,
+/// so the state of the genesis block is not required.
+pub fn execute_genesis_block_without_state(
+    config: &Config,
+    original_database: &Database,
+) -> anyhow::Result<UncommittedImportResult<StorageTransaction<Database>>> {
+    // The genesis commitment is still required to join the network,
+    // so calculate it on the side.
+    let genesis = init_genesis_state(config, &mut Database::in_memory())?;
+    let database_transaction = Transactional::transaction(original_database);
+
+    create_genesis_result(config, genesis, database_transaction)
+}
+
+fn init_genesis_state(
+    config: &Config,
+    database: &mut Database,
+) -> anyhow::Result<Genesis> {
     // Initialize the chain id and height.
 
     let chain_config_hash = config.chain_conf.root()?.into();
@@ -86,13 +112,19 @@ pub fn execute_genesis_block(
     let messages_root = init_da_messages(database, &config.chain_conf.initial_state)?;
     let messages_root = messages_root.into();
 
-    let genesis = Genesis {
+    Ok(Genesis {
         chain_config_hash,
         coins_root,
         contracts_root,
         messages_root,
-    };
+    })
+}
 
+fn create_genesis_result(
+    config: &Config,
+    genesis: Genesis,
+    database_transaction: StorageTransaction<Database>,
+) -> anyhow::Result<UncommittedImportResult<StorageTransaction<Database>>> {
     let block = create_genesis_block(config);
     let consensus = Consensus::Genesis(genesis);
     let block = SealedBlock {
@@ -137,6 +169,180 @@ pub fn create_genesis_block(config: &Config) -> Block {
     block
 }
 
+/// Inserts the `state` downloaded at the `checkpoint` into the database.
+/// Returns an error if the roots of the inserted state don't match the checkpoint.
+pub fn import_checkpoint_state(
+    database: &mut Database,
+    checkpoint: &StateCheckpoint,
+    state: StateConfig,
+) -> anyhow::Result<()> {
+    let state = Some(StateConfig {
+        height: Some(checkpoint.height),
+        ..state
+    });
+    let roots = StateRoots {
+        coins_root: init_coin_state(database, &state)?.into(),
+        contracts_root: init_contracts(database, &state)?.into(),
+        messages_root: init_da_messages(database, &state)?.into(),
+    };
+    let expected = StateRoots {
+        coins_root: checkpoint.coins_root,
+        contracts_root: checkpoint.contracts_root,
+        messages_root: checkpoint.messages_root,
+    };
+
+    if roots != expected {
+        return Err(anyhow!(
+            "The downloaded state doesn't match the checkpoint at height {}: \
+            expected {expected:?}, actual {roots:?}",
+            checkpoint.height
+        ))
+    }
+    Ok(())
+}
+
+/// Checks that the entries of the `kind` in the `state` downloaded at the `checkpoint`
+/// match the corresponding root of the checkpoint.
+pub fn check_checkpoint_state_root(
+    checkpoint: &StateCheckpoint,
+    kind: StateChunkKind,
+    state: &StateConfig,
+) -> anyhow::Result<bool> {
+    let height = Some(checkpoint.height);
+    // Calculate the root in the same way as during the import of the state.
+    let calculation_database = &mut Database::in_memory();
+    let matches = match kind {
+        StateChunkKind::Coins => {
+            let state = Some(StateConfig {
+                coins: state.coins.clone(),
+                height,
+                ..Default::default()
+            });
+            Bytes32::from(init_coin_state(calculation_database, &state)?)
+                == checkpoint.coins_root
+        }
+        StateChunkKind::Contracts => {
+            let state = Some(StateConfig {
+                contracts: state.contracts.clone(),
+                height,
+                ..Default::default()
+            });
+            Bytes32::from(init_contracts(calculation_database, &state)?)
+                == checkpoint.contracts_root
+        }
+        StateChunkKind::Messages => {
+            let state = Some(StateConfig {
+                messages: state.messages.clone(),
+                height,
+                ..Default::default()
+            });
+            Bytes32::from(init_da_messages(calculation_database, &state)?)
+                == checkpoint.messages_root
+        }
+    };
+    Ok(matches)
+}
+
+/// Checks that the state committed to the `database` at the `checkpoint` matches
+/// its roots. The roots are calculated both from the committed entries and from
+/// the merkle metadata of the contracts stored in the `database`.
+/// Returns the committed state.
+pub fn verify_checkpoint_state(
+    database: &Database,
+    checkpoint: &StateCheckpoint,
+) -> anyhow::Result<StateConfig> {
+    let state = StateConfig::generate_state_config(database.clone())?;
+    let height = Some(checkpoint.height);
+    let state = Some(StateConfig { height, ..state });
+
+    let calculation_database = &mut Database::in_memory();
+    let roots = StateRoots {
+        coins_root: init_coin_state(calculation_database, &state)?.into(),
+        contracts_root: init_contracts(calculation_database, &state)?.into(),
+        messages_root: init_da_messages(calculation_database, &state)?.into(),
+    };
+
+    let mut contracts_tree = binary::in_memory::MerkleTree::new();
+    let mut committed_database = database.clone();
+    let contracts = state.as_ref().and_then(|state| state.contracts.as_ref());
+    for contract in contracts.into_iter().flatten() {
+        let contract = ContractRef::new(&mut committed_database, contract.contract_id);
+        contracts_tree.push(contract.root()?.as_slice());
+    }
+    let stored_contracts_root: Bytes32 = contracts_tree.root().into();
+
+    let expected = StateRoots {
+        coins_root: checkpoint.coins_root,
+        contracts_root: checkpoint.contracts_root,
+        messages_root: checkpoint.messages_root,
+    };
+    if roots != expected || stored_contracts_root != expected.contracts_root {
+        return Err(anyhow!(
+            "The committed state doesn't match the checkpoint at height {}: \
+            expected {expected:?}, actual {roots:?} with the stored contracts root \
+            {stored_contracts_root}",
+            checkpoint.height
+        ))
+    }
+    Ok(state.unwrap_or_default())
+}
+
+/// Finishes the import of the `checkpoint` whose state is committed to the on-chain
+/// `database`. The committed state is verified and imported into the `off_chain`
+/// database, and then the pending checkpoint is removed from the `database`.
+///
+/// The import of the off-chain state only inserts indexes, so it can be repeated
+/// if the node stopped before the pending checkpoint was removed.
+pub fn finish_checkpoint_import(
+    database: &Database,
+    off_chain: &Database<OffChain>,
+    checkpoint: &StateCheckpoint,
+) -> anyhow::Result<()> {
+    let state = verify_checkpoint_state(database, checkpoint)?;
+    off_chain::import_checkpoint_state(&state, off_chain)?.commit()?;
+    database.clear_pending_checkpoint()?;
+    Ok(())
+}
+
+/// Creates the checkpoint of the state at the latest block of the database.
+/// Peers pin the state only at heights divisible by the `interval`, so the latest
+/// height of the database must be divisible by it.
+pub fn create_state_checkpoint(
+    database: &Database,
+    interval: NonZeroU32,
+) -> anyhow::Result<StateCheckpoint> {
+    let block = database
+        .get_current_block()?
+        .ok_or(anyhow!("The database doesn't contain any blocks"))?;
+    let height = *block.header().height();
+    if *height % interval.get() != 0 {
+        return Err(anyhow!(
+            "The height {} of the checkpoint is not divisible by the state snapshot \
+            interval {}, so peers don't serve the state at this height",
+            *height,
+            interval
+        ))
+    }
+    let state = Some(StateConfig::generate_state_config(database.clone())?);
+
+    // Calculate roots in the same way as during the import of the state.
+    let calculation_database = &mut Database::in_memory();
+    Ok(StateCheckpoint {
+        height,
+        block_id: block.id(),
+        coins_root: init_coin_state(calculation_database, &state)?.into(),
+        contracts_root: init_contracts(calculation_database, &state)?.into(),
+        messages_root: init_da_messages(calculation_database, &state)?.into(),
+    })
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct StateRoots {
+    coins_root: Bytes32,
+    contracts_root: Bytes32,
+    messages_root: Bytes32,
+}
+
 #[cfg(feature = "test-helpers")]
 pub async fn execute_and_commit_genesis_block(
     config: &Config,
@@ -202,29 +408,7 @@ fn init_contracts(
                 let contract = Contract::from(contract_config.code.as_slice());
                 let salt = contract_config.salt;
                 let contract_id = contract_config.contract_id;
-                let utxo_id = if let (Some(tx_id), Some(output_idx)) =
-                    (contract_config.tx_id, contract_config.output_index)
-                {
-                    UtxoId::new(tx_id, output_idx)
-                } else {
-                    #[allow(clippy::cast_possible_truncation)]
-                    UtxoId::new(
-                        // generated transaction id([0..[out_index/255]])
-                        Bytes32::try_from(
-                            (0..(Bytes32::LEN - WORD_SIZE))
-                                .map(|_| 0u8)
-                                .chain(
-                                    (generated_output_index as u64 / 255)
-                                        .to_be_bytes()
-                                        .into_iter(),
-                                )
-                                .collect_vec()
-                                .as_slice(),
-                        )
-                        .expect("Incorrect genesis transaction id byte length"),
-                        generated_output_index as u8,
-                    )
-                };
+                let utxo_id = contract_config.utxo_id(generated_output_index);
                 let tx_pointer = if let (Some(block_height), Some(tx_idx)) = (
                     contract_config.tx_pointer_block_height,
                     contract_config.tx_pointer_tx_idx,
@@ -328,28 +512,7 @@ fn init_contract_balance(
 
 // TODO: Remove when re-genesis PRs are merged. Instead we will use `UtxoId` from the `CoinConfig`.
 fn create_coin_from_config(coin: &CoinConfig, generated_output_index: &mut u64) -> Coin {
-    let utxo_id = UtxoId::new(
-        // generated transaction id([0..[out_index/255]])
-        coin.tx_id.unwrap_or_else(|| {
-            Bytes32::try_from(
-                (0..(Bytes32::LEN - WORD_SIZE))
-                    .map(|_| 0u8)
-                    .chain(
-                        (*generated_output_index / 255)
-                            .to_be_bytes(),
-                    )
-                    .collect_vec()
-                    .as_slice(),
-            )
-                .expect("Incorrect genesis transaction id byte length")
-        }),
-        coin.output_index.unwrap_or_else(|| {
-            *generated_output_index = generated_output_index
-                .checked_add(1)
-                .expect("The maximum number of UTXOs supported in the genesis configuration has been exceeded.");
-            (*generated_output_index % 255) as u8
-        }),
-    );
+    let utxo_id = coin.utxo_id(generated_output_index);
 
     Coin {
         utxo_id,
@@ -366,6 +529,8 @@ fn create_coin_from_config(coin: &CoinConfig, generated_output_index: &mut u64)
 
 #[cfg(test)]
 mod tests {
+    #![allow(non_snake_case)]
+
     use super::*;
 
     use crate::{
@@ -393,6 +558,7 @@ mod tests {
         blockchain::primitives::DaBlockHeight,
         entities::coins::coin::Coin,
         fuel_asm::op,
+        fuel_tx::UtxoId,
         fuel_types::{
             Address,
             AssetId,
@@ -408,6 +574,56 @@ mod tests {
     };
     use std::vec;
 
+    #[tokio::test]
+    async fn check_checkpoint_state_root__detects_modified_entries() {
+        // given
+        let service_config = Config::local_node();
+        let db = Database::default();
+        FuelService::from_database(db.clone(), service_config)
+            .await
+            .unwrap();
+        let checkpoint = create_state_checkpoint(&db, NonZeroU32::MIN).unwrap();
+        let mut state = StateConfig::generate_state_config(db).unwrap();
+
+        // when
+        let roots_match = StateChunkKind::ALL
+            .map(|kind| check_checkpoint_state_root(&checkpoint, kind, &state).unwrap());
+        let coin = state.coins.as_mut().unwrap().first_mut().unwrap();
+        coin.amount = coin.amount.saturating_add(1);
+        let modified_coins_root_matches =
+            check_checkpoint_state_root(&checkpoint, StateChunkKind::Coins, &state)
+                .unwrap();
+
+        // then
+        assert_eq!(roots_match, [true; 3]);
+        assert!(!modified_coins_root_matches);
+    }
+
+    #[tokio::test]
+    async fn create_state_checkpoint__rejects_height_not_divisible_by_interval() {
+        // given
+        let service_config = Config {
+            chain_conf: ChainConfig {
+                initial_state: Some(StateConfig {
+                    height: Some(BlockHeight::from(99u32)),
+                    ..Default::default()
+                }),
+                ..ChainConfig::local_testnet()
+            },
+            ..Config::local_node()
+        };
+        let db = Database::default();
+        FuelService::from_database(db.clone(), service_config)
+            .await
+            .unwrap();
+
+        // when
+        let result = create_state_checkpoint(&db, NonZeroU32::new(10).unwrap());
+
+        // then
+        assert!(result.is_err());
+    }
+
     #[tokio::test]
     async fn config_initializes_block_height() {
         let test_height = BlockHeight::from(99u32);
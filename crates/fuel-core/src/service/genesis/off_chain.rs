@@ -9,6 +9,7 @@ use crate::{
         Config,
     },
 };
+use fuel_core_chain_config::StateConfig;
 use fuel_core_storage::transactional::{
     StorageTransaction,
     Transactional,
@@ -28,31 +29,44 @@ pub fn execute_genesis_block(
     let mut database_transaction = Transactional::transaction(original_database);
 
     if let Some(state_config) = &config.chain_conf.initial_state {
-        if let Some(messages) = &state_config.messages {
-            let messages_events = messages.iter().map(|config| {
-                let message: Message = config.clone().into();
-                Cow::Owned(Event::MessageImported(message))
-            });
-
-            worker_service::Task::process_executor_events(
-                messages_events,
-                database_transaction.as_mut(),
-            )?;
-        }
-
-        if let Some(coins) = &state_config.coins {
-            let mut generated_output_index = 0;
-            let coin_events = coins.iter().map(|config| {
-                let coin = create_coin_from_config(config, &mut generated_output_index);
-                Cow::Owned(Event::CoinCreated(coin))
-            });
-
-            worker_service::Task::process_executor_events(
-                coin_events,
-                database_transaction.as_mut(),
-            )?;
-        }
+        import_state(state_config, database_transaction.as_mut())?;
     }
 
     Ok(database_transaction)
 }
+
+/// Performs the importing of the state downloaded at the checkpoint.
+pub fn import_checkpoint_state(
+    state_config: &StateConfig,
+    original_database: &Database<OffChain>,
+) -> anyhow::Result<StorageTransaction<Database<OffChain>>> {
+    let mut database_transaction = Transactional::transaction(original_database);
+    import_state(state_config, database_transaction.as_mut())?;
+    Ok(database_transaction)
+}
+
+fn import_state(
+    state_config: &StateConfig,
+    database: &mut Database<OffChain>,
+) -> anyhow::Result<()> {
+    if let Some(messages) = &state_config.messages {
+        let messages_events = messages.iter().map(|config| {
+            let message: Message = config.clone().into();
+            Cow::Owned(Event::MessageImported(message))
+        });
+
+        worker_service::Task::process_executor_events(messages_events, database)?;
+    }
+
+    if let Some(coins) = &state_config.coins {
+        let mut generated_output_index = 0;
+        let coin_events = coins.iter().map(|config| {
+            let coin = create_coin_from_config(config, &mut generated_output_index);
+            Cow::Owned(Event::CoinCreated(coin))
+        });
+
+        worker_service::Task::process_executor_events(coin_events, database)?;
+    }
+
+    Ok(())
+}
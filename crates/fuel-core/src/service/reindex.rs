@@ -0,0 +1,368 @@
+//! Re-indexing of the off-chain database from the blocks stored in the on-chain database.
+//!
+//! The results of the execution(transaction statuses and events) are not stored on-chain,
+//! so each block is executed again on the copy of the on-chain database rolled back to
+//! the height before the block. The results are processed by the same logic as
+//! the GraphQL worker uses for newly imported blocks.
+//!
+//! Blocks imported by the header only were not executed, so only their headers are
+//! stored in the copy, and they are replayed without transactions, the same way
+//! as the importer notifies about them.
+
+use crate::{
+    combined_database::CombinedDatabase,
+    database::{
+        database_description::on_chain::OnChain,
+        Database,
+    },
+    graphql_api::worker_service,
+    service::{
+        adapters::{
+            ExecutorAdapter,
+            TransactionsSource,
+        },
+        config::DbType,
+        genesis,
+        sub_services::executor_config,
+        Config,
+    },
+};
+use fuel_core_importer::ports::{
+    ExecutorDatabase,
+    HeaderDatabase,
+};
+use fuel_core_storage::{
+    iter::IterDirection,
+    not_found,
+    tables::{
+        FuelBlocks,
+        HeaderOnlyBlocks,
+        SealedBlockConsensus,
+    },
+    transactional::{
+        Transaction,
+        Transactional,
+    },
+    StorageAsRef,
+};
+use fuel_core_types::{
+    blockchain::{
+        block::Block,
+        SealedBlock,
+        SealedBlockHeader,
+    },
+    fuel_types::BlockHeight,
+    services::{
+        block_importer::ImportResult,
+        executor::{
+            ExecutionResult,
+            ExecutionTypes,
+        },
+    },
+};
+use std::{
+    collections::HashSet,
+    path::Path,
+    sync::Arc,
+};
+
+/// Rebuilds the off-chain database by replaying blocks stored in the on-chain database.
+///
+/// If `from` is `None`, the off-chain database is cleared and re-indexed starting
+/// from the genesis block. Otherwise, the off-chain database is rolled back to
+/// the height before `from`, and only blocks starting from `from` are replayed.
+///
+/// The off-chain database is modified only after the state to replay blocks from
+/// is prepared. If the on-chain database can't be rolled back to the genesis block,
+/// the genesis state is created again from the chain config.
+///
+/// The blocks are replayed into the off-chain database in place. If the re-indexing
+/// fails or is interrupted, the off-chain database is left cleared or rolled back
+/// and only partially replayed. The on-chain database records the re-indexing
+/// until it finishes, so the node refuses to start with such an off-chain database
+/// until the re-indexing is run again.
+///
+/// Returns the height of the last replayed block.
+pub fn rebuild_off_chain(
+    database: &CombinedDatabase,
+    config: &Config,
+    from: Option<BlockHeight>,
+) -> anyhow::Result<BlockHeight> {
+    let on_chain = database.on_chain();
+    let off_chain = database.off_chain();
+    let genesis_height = on_chain
+        .iter_all::<FuelBlocks>(Some(IterDirection::Forward))
+        .next()
+        .transpose()?
+        .map(|(height, _)| height)
+        .ok_or(not_found!(FuelBlocks))?;
+    let latest_height = on_chain.latest_height()?;
+    let chain_id = config.chain_conf.consensus_parameters.chain_id;
+
+    let from_genesis = from.is_none();
+    let (from, mut scratch, within_retention) = match from {
+        None => {
+            let from = next_height(genesis_height)?;
+            if on_chain.check_rollback(&genesis_height).is_ok() {
+                (from, scratch_at(on_chain, config, genesis_height)?, true)
+            } else {
+                tracing::info!(
+                    "The on-chain database can't be rolled back to the genesis block, \
+                    re-creating the genesis state from the chain config"
+                );
+                (from, scratch_from_genesis(on_chain, config)?, false)
+            }
+        }
+        Some(from) => {
+            if from <= genesis_height || from > latest_height {
+                return Err(anyhow::anyhow!(
+                    "The height {} should be above the genesis height {} \
+                    and not above the latest height {}",
+                    *from,
+                    *genesis_height,
+                    *latest_height
+                ))
+            }
+            let target_height = previous_height(from)?;
+            let off_chain_height = off_chain.latest_height()?;
+            if off_chain_height < target_height {
+                return Err(anyhow::anyhow!(
+                    "The off-chain database is at the height {}, \
+                    so it is not possible to re-index it from the height {}",
+                    *off_chain_height,
+                    *from
+                ))
+            }
+            off_chain.check_rollback(&target_height)?;
+            on_chain.check_rollback(&target_height)?;
+            (from, scratch_at(on_chain, config, target_height)?, true)
+        }
+    };
+
+    on_chain.record_reindex_in_progress()?;
+    let mut worker = worker_service::Task::without_block_importer(off_chain.clone());
+    if from_genesis {
+        off_chain.clear()?;
+        genesis::off_chain::execute_genesis_block(config, off_chain)?.commit()?;
+        let genesis_block = on_chain
+            .get_sealed_block_by_height(&genesis_height)?
+            .ok_or(not_found!(FuelBlocks))?;
+        worker.process_block(Arc::new(ImportResult::new_from_local(
+            genesis_block,
+            vec![],
+            vec![],
+        )))?;
+    } else {
+        off_chain.rollback_to(&previous_height(from)?)?;
+    }
+
+    let mut executor = ExecutorAdapter::new(
+        scratch.clone(),
+        database.relayer().clone(),
+        executor_config(config),
+    );
+
+    let mut last_height = previous_height(from)?;
+    for height in *from..=*latest_height {
+        let height = BlockHeight::from(height);
+        if on_chain
+            .storage::<HeaderOnlyBlocks>()
+            .contains_key(&height)?
+        {
+            let sealed_header = sealed_header(on_chain, &height)?;
+            let mut db_transaction = Transactional::transaction(&scratch);
+            db_transaction.as_mut().store_new_header(&sealed_header)?;
+            db_transaction.commit()?;
+
+            // The state may be imported along with the header. It can't be
+            // derived from blocks, so the scratch database is copied again.
+            if within_retention && imports_state(on_chain, &scratch, &height)? {
+                scratch = scratch_at(on_chain, config, height)?;
+                executor = ExecutorAdapter::new(
+                    scratch.clone(),
+                    database.relayer().clone(),
+                    executor_config(config),
+                );
+            }
+
+            worker.process_block(Arc::new(ImportResult::new_from_network(
+                SealedBlock {
+                    entity: Block::header_only(sealed_header.entity),
+                    consensus: sealed_header.consensus,
+                },
+                vec![],
+                vec![],
+            )))?;
+            last_height = height;
+            continue
+        }
+
+        let sealed_block = on_chain
+            .get_sealed_block_by_height(&height)?
+            .ok_or(not_found!(FuelBlocks))?;
+        let block_id = sealed_block.entity.id();
+
+        let (
+            ExecutionResult {
+                block,
+                skipped_transactions,
+                tx_status,
+                events,
+            },
+            mut db_transaction,
+        ) = executor
+            ._execute_without_commit::<TransactionsSource>(ExecutionTypes::Validation(
+                sealed_block.entity,
+            ))?
+            .into();
+        if !skipped_transactions.is_empty() || block.id() != block_id {
+            return Err(anyhow::anyhow!(
+                "The execution of the block {} doesn't match the stored block",
+                *height
+            ))
+        }
+
+        let sealed_block = SealedBlock {
+            entity: block,
+            consensus: sealed_block.consensus,
+        };
+        db_transaction
+            .as_mut()
+            .store_new_block(&chain_id, &sealed_block)?;
+        db_transaction.commit()?;
+
+        worker.process_block(Arc::new(ImportResult::new_from_network(
+            sealed_block,
+            tx_status,
+            events,
+        )))?;
+        last_height = height;
+
+        if (*height).checked_rem(1000) == Some(0) {
+            tracing::info!(
+                "Re-indexed the off-chain database up to the block {}",
+                *height
+            );
+        }
+    }
+
+    on_chain.clear_reindex_in_progress()?;
+    Ok(last_height)
+}
+
+/// Returns the header of the block imported by the header only.
+fn sealed_header(
+    on_chain: &Database<OnChain>,
+    height: &BlockHeight,
+) -> anyhow::Result<SealedBlockHeader> {
+    let header = on_chain
+        .storage::<FuelBlocks>()
+        .get(height)?
+        .ok_or(not_found!(FuelBlocks))?
+        .header()
+        .clone();
+    let consensus = on_chain
+        .storage::<SealedBlockConsensus>()
+        .get(height)?
+        .ok_or(not_found!(SealedBlockConsensus))?
+        .into_owned();
+    Ok(SealedBlockHeader {
+        entity: header,
+        consensus,
+    })
+}
+
+/// Returns `true` if the on-chain database changed columns at the `height` that
+/// storing the header in the `scratch` database didn't change.
+fn imports_state(
+    on_chain: &Database<OnChain>,
+    scratch: &Database<OnChain>,
+    height: &BlockHeight,
+) -> anyhow::Result<bool> {
+    let header_columns = scratch
+        .rollback_changes(height)?
+        .into_iter()
+        .map(|(_, column, _)| column)
+        .collect::<HashSet<_>>();
+    let imports_state = on_chain
+        .rollback_changes(height)?
+        .iter()
+        .any(|(_, column, _)| !header_columns.contains(column));
+    Ok(imports_state)
+}
+
+fn next_height(height: BlockHeight) -> anyhow::Result<BlockHeight> {
+    height
+        .succ()
+        .ok_or_else(|| anyhow::anyhow!("The block height overflowed"))
+}
+
+fn previous_height(height: BlockHeight) -> anyhow::Result<BlockHeight> {
+    height
+        .pred()
+        .ok_or_else(|| anyhow::anyhow!("The block height underflowed"))
+}
+
+/// Creates a temporary copy of the on-chain database at the `height`.
+/// The rollback is applied one height at a time, so it doesn't hold the reverse
+/// changes of all replayed blocks in memory.
+fn scratch_at(
+    on_chain: &Database<OnChain>,
+    config: &Config,
+    height: BlockHeight,
+) -> anyhow::Result<Database<OnChain>> {
+    let tmp_dir = tempfile::TempDir::new()?;
+    on_chain.checkpoint(tmp_dir.path())?;
+    let scratch = open_scratch(&config.database_type, tmp_dir.path())?
+        .with_drop(Box::new(move || drop(tmp_dir)));
+    scratch.rollback_to(&height)?;
+    Ok(scratch)
+}
+
+/// Creates a temporary database with the genesis state from the chain config.
+/// Fails if the genesis block doesn't match the genesis block of the `on_chain`.
+fn scratch_from_genesis(
+    on_chain: &Database<OnChain>,
+    config: &Config,
+) -> anyhow::Result<Database<OnChain>> {
+    let tmp_dir = tempfile::TempDir::new()?;
+    let scratch = open_scratch(&config.database_type, tmp_dir.path())?
+        .with_drop(Box::new(move || drop(tmp_dir)));
+
+    let (result, mut db_transaction) =
+        genesis::execute_genesis_block(config, &scratch)?.into();
+    let genesis_block = result.sealed_block;
+    let stored_block = on_chain
+        .get_sealed_block_by_height(genesis_block.entity.header().height())?
+        .ok_or(not_found!(FuelBlocks))?;
+    if stored_block.entity.id() != genesis_block.entity.id() {
+        return Err(anyhow::anyhow!(
+            "The genesis block from the chain config doesn't match the stored genesis block"
+        ))
+    }
+    db_transaction.as_mut().store_new_block(
+        &config.chain_conf.consensus_parameters.chain_id,
+        &genesis_block,
+    )?;
+    db_transaction.commit()?;
+    Ok(scratch)
+}
+
+/// Opens the scratch database with the same backend as the node uses.
+fn open_scratch(
+    database_type: &DbType,
+    path: &Path,
+) -> anyhow::Result<Database<OnChain>> {
+    let database = match database_type {
+        #[cfg(feature = "rocksdb")]
+        DbType::RocksDb => Database::open(path, None)?,
+        #[cfg(feature = "redb")]
+        DbType::Redb => Database::open_redb(path, None)?,
+        database_type => {
+            return Err(anyhow::anyhow!(
+                "The {database_type} database can't be re-indexed"
+            ))
+        }
+    };
+    Ok(database)
+}
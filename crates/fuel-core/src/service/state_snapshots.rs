@@ -0,0 +1,102 @@
+//! The service pins the state at heights divisible by the snapshot interval,
+//! so syncing peers can download the state at the checkpoint of these heights.
+
+use crate::service::adapters::p2p::P2PViewProvider;
+use fuel_core_services::{
+    stream::BoxStream,
+    EmptyShared,
+    RunnableService,
+    RunnableTask,
+    ServiceRunner,
+    StateWatcher,
+};
+use fuel_core_types::services::block_importer::SharedImportResult;
+use futures::StreamExt;
+
+/// The task pins the state after imported blocks.
+pub struct Task {
+    block_importer: BoxStream<SharedImportResult>,
+    view_provider: P2PViewProvider,
+}
+
+impl Task {
+    /// Pins the state at the height of the imported block. The `result` is held
+    /// until the state is pinned, so the importer doesn't commit the next block
+    /// before that.
+    async fn pin(&self, result: SharedImportResult) {
+        let height = *result.sealed_block.entity.header().height();
+        let view_provider = self.view_provider.clone();
+        let pinned = tokio::task::spawn_blocking(move || {
+            let pinned = view_provider.pin_imported_state(height);
+            drop(result);
+            pinned
+        })
+        .await
+        .map_err(anyhow::Error::from)
+        .and_then(|pinned| pinned);
+
+        // The failed pin shouldn't stop the node, the state at this height
+        // is still served while the height is the latest one.
+        if let Err(err) = pinned {
+            tracing::error!("Failed to pin the state at height {}: {}", *height, err);
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableService for Task {
+    const NAME: &'static str = "StateSnapshots";
+    type SharedData = EmptyShared;
+    type Task = Self;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {
+        EmptyShared
+    }
+
+    async fn into_task(
+        self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        Ok(self)
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableTask for Task {
+    async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
+        let should_continue;
+        tokio::select! {
+            biased;
+
+            _ = watcher.while_started() => {
+                should_continue = false;
+            }
+
+            result = self.block_importer.next() => {
+                if let Some(result) = result {
+                    self.pin(result).await;
+                    should_continue = true
+                } else {
+                    should_continue = false
+                }
+            }
+        }
+        Ok(should_continue)
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+pub fn new_service(
+    block_importer: BoxStream<SharedImportResult>,
+    view_provider: P2PViewProvider,
+) -> ServiceRunner<Task> {
+    ServiceRunner::new(Task {
+        block_importer,
+        view_provider,
+    })
+}
@@ -1,15 +1,19 @@
 #![allow(clippy::let_unit_value)]
 use super::adapters::P2PAdapter;
 use crate::{
+    chain_config::ConsensusConfig,
     combined_database::CombinedDatabase,
     database::Database,
     fuel_core_graphql_api,
     fuel_core_graphql_api::Config as GraphQLConfig,
+    remote_signer::RemoteBlockSigner,
     schema::build_schema,
     service::{
         adapters::{
+            BlockBodyAdapter,
             BlockImporterAdapter,
             BlockProducerAdapter,
+            BlockSignerAdapter,
             ExecutorAdapter,
             MaybeRelayerAdapter,
             PoAAdapter,
@@ -22,7 +26,13 @@ use crate::{
         SubServices,
     },
 };
-use fuel_core_poa::Trigger;
+use fuel_core_poa::{
+    signer::{
+        LocalBlockSigner,
+        SlashingProtection,
+    },
+    Trigger,
+};
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
@@ -31,10 +41,17 @@ use crate::relayer::Config as RelayerConfig;
 #[cfg(feature = "relayer")]
 use fuel_core_types::blockchain::primitives::DaBlockHeight;
 
-pub type PoAService =
-    fuel_core_poa::Service<TxPoolAdapter, BlockProducerAdapter, BlockImporterAdapter>;
+pub type PoAService = fuel_core_poa::Service<
+    TxPoolAdapter,
+    BlockProducerAdapter,
+    BlockImporterAdapter,
+    BlockSignerAdapter,
+>;
 #[cfg(feature = "p2p")]
-pub type P2PService = fuel_core_p2p::service::Service<Database>;
+pub type P2PService = fuel_core_p2p::service::Service<
+    super::adapters::p2p::P2PViewProvider,
+    Database<crate::database::database_description::p2p::P2p>,
+>;
 pub type TxPoolService = fuel_core_txpool::Service<P2PAdapter, Database>;
 pub type BlockProducerService = fuel_core_producer::block_producer::Producer<
     Database,
@@ -43,6 +60,87 @@ pub type BlockProducerService = fuel_core_producer::block_producer::Producer<
 >;
 pub type GraphQL = fuel_core_graphql_api::api_service::Service;
 
+pub(crate) fn executor_config(config: &Config) -> fuel_core_executor::Config {
+    fuel_core_executor::Config {
+        consensus_parameters: config.chain_conf.consensus_parameters.clone(),
+        block_gas_limit: config.chain_conf.block_gas_limit,
+        coinbase_recipient: config.block_producer.coinbase_recipient.unwrap_or_default(),
+        backtrace: config.vm.backtrace,
+        utxo_validation_default: config.utxo_validation,
+    }
+}
+
+fn block_signer(config: &Config) -> anyhow::Result<BlockSignerAdapter> {
+    let signer = match &config.remote_signer {
+        Some(remote_signer) => {
+            let address = match &config.chain_conf.consensus {
+                ConsensusConfig::PoA { signing_key } => *signing_key,
+            };
+            BlockSignerAdapter::Remote(RemoteBlockSigner::new(remote_signer, address)?)
+        }
+        None => {
+            let protection = match config.slashing_protection_file.clone() {
+                Some(state_file) => SlashingProtection::with_state_file(state_file)?,
+                None => SlashingProtection::in_memory(),
+            };
+            BlockSignerAdapter::Local(LocalBlockSigner::new(
+                config.consensus_key.clone(),
+                protection,
+            ))
+        }
+    };
+    Ok(signer)
+}
+
+/// Checks that the sync mode is compatible with the block production and
+/// the latest block of the database.
+#[cfg(feature = "p2p")]
+fn check_sync_mode(
+    config: &Config,
+    database: &CombinedDatabase,
+    last_height: fuel_core_types::fuel_types::BlockHeight,
+    production_enabled: bool,
+) -> anyhow::Result<()> {
+    use fuel_core_storage::{
+        tables::HeaderOnlyBlocks,
+        StorageAsRef,
+    };
+    use fuel_core_sync::SyncMode;
+
+    match config.sync.mode {
+        SyncMode::Light => {
+            if production_enabled {
+                return Err(anyhow::anyhow!(
+                    "The block production requires the state, so it can't be enabled \
+                    in the light sync mode"
+                ))
+            }
+        }
+        SyncMode::Full => {
+            // The state at the checkpoint is downloaded from peers, so the blocks
+            // up to it are imported by the header only.
+            let below_checkpoint = config
+                .sync
+                .checkpoint
+                .is_some_and(|checkpoint| last_height <= checkpoint.height);
+            let header_only = database
+                .on_chain()
+                .storage::<HeaderOnlyBlocks>()
+                .contains_key(&last_height)?;
+            if header_only && !below_checkpoint {
+                return Err(anyhow::anyhow!(
+                    "The latest block {} was imported by the header only in the light \
+                    sync mode, so the database doesn't have the state to continue \
+                    in the full sync mode. Use the light sync mode, or start from \
+                    the empty database",
+                    *last_height
+                ))
+            }
+        }
+    }
+    Ok(())
+}
+
 pub fn init_sub_services(
     config: &Config,
     database: CombinedDatabase,
@@ -60,15 +158,7 @@ pub fn init_sub_services(
     let executor = ExecutorAdapter::new(
         database.on_chain().clone(),
         database.relayer().clone(),
-        fuel_core_executor::Config {
-            consensus_parameters: config.chain_conf.consensus_parameters.clone(),
-            coinbase_recipient: config
-                .block_producer
-                .coinbase_recipient
-                .unwrap_or_default(),
-            backtrace: config.vm.backtrace,
-            utxo_validation_default: config.utxo_validation,
-        },
+        executor_config(config),
     );
 
     let verifier = VerifierAdapter::new(config, database.on_chain().clone());
@@ -76,6 +166,7 @@ pub fn init_sub_services(
     let importer_adapter = BlockImporterAdapter::new(
         config.block_importer.clone(),
         database.on_chain().clone(),
+        database.off_chain().clone(),
         executor.clone(),
         verifier.clone(),
     );
@@ -102,32 +193,30 @@ pub fn init_sub_services(
 
     #[cfg(feature = "p2p")]
     let mut network = config.p2p.clone().map(|p2p_config| {
-        fuel_core_p2p::service::new_service(
+        let view_provider = super::adapters::p2p::P2PViewProvider::new(
+            database.on_chain().clone(),
+            config.database_type.clone(),
+            (!config.database_path.as_os_str().is_empty())
+                .then(|| config.database_path.join("state-snapshots")),
+            config.state_snapshot_interval,
+        );
+        let state_snapshots = super::state_snapshots::new_service(
+            importer_adapter.events(),
+            view_provider.clone(),
+        );
+        let network = fuel_core_p2p::service::new_service(
             config.chain_conf.consensus_parameters.chain_id,
             p2p_config,
-            database.on_chain().clone(),
+            view_provider,
+            database.p2p().clone(),
             importer_adapter.clone(),
-        )
+        );
+        (network, state_snapshots)
     });
 
     #[cfg(feature = "p2p")]
-    let p2p_adapter = {
-        use crate::service::adapters::PeerReportConfig;
-
-        // Hardcoded for now, but left here to be configurable in the future.
-        // TODO: https://github.com/FuelLabs/fuel-core/issues/1340
-        let peer_report_config = PeerReportConfig {
-            successful_block_import: 5.,
-            missing_block_headers: -100.,
-            bad_block_header: -100.,
-            missing_transactions: -100.,
-            invalid_transactions: -100.,
-        };
-        P2PAdapter::new(
-            network.as_ref().map(|network| network.shared.clone()),
-            peer_report_config,
-        )
-    };
+    let p2p_adapter =
+        P2PAdapter::new(network.as_ref().map(|(network, _)| network.shared.clone()));
 
     #[cfg(not(feature = "p2p"))]
     let p2p_adapter = P2PAdapter::new();
@@ -159,16 +248,22 @@ pub fn init_sub_services(
         tracing::info!("Enabled manual block production because of `debug` flag");
     }
 
-    let poa = (production_enabled).then(|| {
-        fuel_core_poa::new_service(
-            &last_block_header,
-            poa_config,
-            tx_pool_adapter.clone(),
-            producer_adapter.clone(),
-            importer_adapter.clone(),
-            p2p_adapter.clone(),
-        )
-    });
+    #[cfg(feature = "p2p")]
+    check_sync_mode(config, &database, last_height, production_enabled)?;
+
+    let poa = production_enabled
+        .then(|| -> anyhow::Result<_> {
+            Ok(fuel_core_poa::new_service(
+                &last_block_header,
+                poa_config,
+                tx_pool_adapter.clone(),
+                producer_adapter.clone(),
+                importer_adapter.clone(),
+                p2p_adapter.clone(),
+                block_signer(config)?,
+            ))
+        })
+        .transpose()?;
     let poa_adapter = PoAAdapter::new(poa.as_ref().map(|service| service.shared.clone()));
 
     #[cfg(feature = "p2p")]
@@ -218,16 +313,27 @@ pub fn init_sub_services(
         Box::new(tx_pool_adapter),
         Box::new(producer_adapter),
         Box::new(poa_adapter.clone()),
-        Box::new(p2p_adapter),
+        Box::new(p2p_adapter.clone()),
+        Box::new(BlockBodyAdapter::new(p2p_adapter, importer_adapter.clone())),
         config.query_log_threshold_time,
         config.api_request_timeout,
     )?;
 
+    #[cfg(feature = "rocksdb")]
+    let database_backup = config.database_backup.clone().map(|backup_config| {
+        super::backup::new_service(
+            importer_adapter.events(),
+            database.clone(),
+            config.database_type.clone(),
+            backup_config,
+        )
+    });
+
     let shared = SharedState {
         poa_adapter,
         txpool: txpool.shared.clone(),
         #[cfg(feature = "p2p")]
-        network: network.as_ref().map(|n| n.shared.clone()),
+        network: network.as_ref().map(|(n, _)| n.shared.clone()),
         #[cfg(feature = "relayer")]
         relayer: relayer_service.as_ref().map(|r| r.shared.clone()),
         graph_ql: graph_ql.shared.clone(),
@@ -255,13 +361,19 @@ pub fn init_sub_services(
 
     #[cfg(feature = "p2p")]
     {
-        if let Some(network) = network.take() {
+        if let Some((network, state_snapshots)) = network.take() {
             services.push(Box::new(network));
+            services.push(Box::new(state_snapshots));
             services.push(Box::new(sync));
         }
     }
 
     services.push(Box::new(graphql_worker));
 
+    #[cfg(feature = "rocksdb")]
+    if let Some(database_backup) = database_backup {
+        services.push(Box::new(database_backup));
+    }
+
     Ok((services, shared))
 }
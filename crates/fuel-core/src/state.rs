@@ -20,10 +20,13 @@ use fuel_core_storage::{
 };
 use std::{
     fmt::Debug,
+    path::Path,
     sync::Arc,
 };
 
 pub mod in_memory;
+#[cfg(feature = "redb")]
+pub mod redb;
 #[cfg(feature = "rocksdb")]
 pub mod rocks_db;
 
@@ -54,6 +57,16 @@ where
     }
 }
 
+#[cfg(feature = "redb")]
+impl<Description> From<Arc<redb::Redb<Description>>> for DataSource<Description>
+where
+    Description: DatabaseDescription,
+{
+    fn from(inner: Arc<redb::Redb<Description>>) -> Self {
+        Self(inner)
+    }
+}
+
 impl<Description> From<Arc<MemoryStore<Description>>> for DataSource<Description>
 where
     Description: DatabaseDescription,
@@ -87,4 +100,8 @@ pub trait TransactableStorage:
     IteratorableStore + BatchOperations + Debug + Send + Sync
 {
     fn flush(&self) -> DatabaseResult<()>;
+
+    /// Creates a consistent copy of the storage in the `path`.
+    /// The `path` should not exist.
+    fn checkpoint(&self, path: &Path) -> DatabaseResult<()>;
 }
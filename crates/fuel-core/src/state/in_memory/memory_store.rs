@@ -29,6 +29,7 @@ use fuel_core_storage::{
 use std::{
     collections::BTreeMap,
     fmt::Debug,
+    path::Path,
     sync::{
         Arc,
         Mutex,
@@ -212,6 +213,10 @@ where
         }
         Ok(())
     }
+
+    fn checkpoint(&self, _: &Path) -> DatabaseResult<()> {
+        Err(anyhow::anyhow!("The in-memory database doesn't support checkpoints").into())
+    }
 }
 
 #[cfg(test)]
@@ -220,6 +225,11 @@ mod tests {
     use fuel_core_storage::column::Column;
     use std::sync::Arc;
 
+    fuel_core_storage::kv_store_tests!(
+        MemoryStore::<OnChain>::default(),
+        Column::Metadata
+    );
+
     #[test]
     fn can_use_unit_value() {
         let key = vec![0x00];
@@ -38,6 +38,7 @@ use std::{
     collections::HashMap,
     fmt::Debug,
     ops::DerefMut,
+    path::Path,
     sync::{
         Arc,
         Mutex,
@@ -286,6 +287,10 @@ where
         self.view_layer.flush()?;
         self.data_source.flush()
     }
+
+    fn checkpoint(&self, _: &Path) -> DatabaseResult<()> {
+        Err(anyhow::anyhow!("The database transaction doesn't support checkpoints").into())
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,592 @@
+//! The persistent storage backed by [redb](https://www.redb.org), an embedded
+//! key-value store based on copy-on-write B-trees. Unlike RocksDB, it doesn't
+//! require background compaction, which makes the latency of reads more predictable.
+//!
+//! Each column is stored in its own table of the same database file.
+
+use crate::{
+    database::{
+        database_description::DatabaseDescription,
+        Error as DatabaseError,
+        Result as DatabaseResult,
+    },
+    state::{
+        BatchOperations,
+        IterDirection,
+        TransactableStorage,
+    },
+};
+use fuel_core_metrics::core_metrics::database_metrics;
+use fuel_core_storage::{
+    iter::{
+        BoxedIter,
+        IntoBoxedIter,
+        IteratorableStore,
+    },
+    kv_store::{
+        KVItem,
+        KeyValueStore,
+        StorageColumn,
+        Value,
+        WriteOperation,
+    },
+    Result as StorageResult,
+};
+use redb::{
+    ReadableTableMetadata,
+    TableDefinition,
+};
+use std::{
+    collections::{
+        hash_map::Entry,
+        HashMap,
+    },
+    iter,
+    ops::Bound,
+    path::Path,
+    sync::Arc,
+};
+
+const DATABASE_FILE: &str = "data.redb";
+
+type Table<'a> = TableDefinition<'a, &'static [u8], &'static [u8]>;
+
+#[derive(Debug)]
+pub struct Redb<Description> {
+    db: redb::Database,
+    _marker: core::marker::PhantomData<Description>,
+}
+
+impl<Description> Redb<Description>
+where
+    Description: DatabaseDescription,
+{
+    /// Opens the database in the `path`, creating it if it doesn't exist.
+    /// The `capacity` is the size of the cache in bytes.
+    pub fn open<P: AsRef<Path>>(
+        path: P,
+        capacity: Option<usize>,
+    ) -> DatabaseResult<Self> {
+        let path = path.as_ref().join(Description::name());
+        std::fs::create_dir_all(&path).map_err(|e| DatabaseError::Other(e.into()))?;
+
+        let mut builder = redb::Database::builder();
+        if let Some(capacity) = capacity {
+            builder.set_cache_size(capacity);
+        }
+        let db = builder.create(path.join(DATABASE_FILE)).map_err(to_error)?;
+
+        // Creates tables for all columns, so readers can always open them.
+        let transaction = db.begin_write().map_err(to_error)?;
+        for column in enum_iterator::all::<Description::Column>() {
+            transaction
+                .open_table(Self::table(&Self::table_name(column)))
+                .map_err(to_error)?;
+        }
+        transaction.commit().map_err(to_error)?;
+
+        Ok(Self {
+            db,
+            _marker: Default::default(),
+        })
+    }
+
+    fn table_name(column: Description::Column) -> String {
+        format!("col-{}", column.as_usize())
+    }
+
+    fn table(name: &str) -> Table<'_> {
+        TableDefinition::new(name)
+    }
+
+    fn read_table(
+        &self,
+        column: Description::Column,
+    ) -> DatabaseResult<redb::ReadOnlyTable<&'static [u8], &'static [u8]>> {
+        let transaction = self.db.begin_read().map_err(to_error)?;
+        transaction
+            .open_table(Self::table(&Self::table_name(column)))
+            .map_err(to_error)
+    }
+
+    fn _iter_all(
+        &self,
+        column: Description::Column,
+        lower: Bound<&[u8]>,
+        upper: Bound<&[u8]>,
+        direction: IterDirection,
+    ) -> BoxedIter<KVItem> {
+        let range = self
+            .read_table(column)
+            .and_then(|table| table.range::<&[u8]>((lower, upper)).map_err(to_error));
+        let range = match range {
+            Ok(range) => range,
+            Err(err) => return iter::once(Err(err.into())).into_boxed(),
+        };
+
+        let range = range.map(|item| {
+            item.map(|(key, value)| {
+                let key = key.value().to_vec();
+                let value = value.value().to_vec();
+
+                database_metrics().read_meter.inc();
+                database_metrics()
+                    .bytes_read
+                    .observe((key.len().saturating_add(value.len())) as f64);
+
+                (key, Arc::new(value))
+            })
+            .map_err(|e| to_error(e).into())
+        });
+
+        match direction {
+            IterDirection::Forward => range.into_boxed(),
+            IterDirection::Reverse => range.rev().into_boxed(),
+        }
+    }
+}
+
+impl<Description> KeyValueStore for Redb<Description>
+where
+    Description: DatabaseDescription,
+{
+    type Column = Description::Column;
+
+    fn write(
+        &self,
+        key: &[u8],
+        column: Self::Column,
+        buf: &[u8],
+    ) -> StorageResult<usize> {
+        let r = buf.len();
+        self.batch_write(&mut iter::once((
+            key.to_vec(),
+            column,
+            WriteOperation::Insert(Arc::new(buf.to_vec())),
+        )))?;
+        Ok(r)
+    }
+
+    fn delete(&self, key: &[u8], column: Self::Column) -> StorageResult<()> {
+        self.batch_write(&mut iter::once((
+            key.to_vec(),
+            column,
+            WriteOperation::Remove,
+        )))
+    }
+
+    fn get(&self, key: &[u8], column: Self::Column) -> StorageResult<Option<Value>> {
+        database_metrics().read_meter.inc();
+
+        let value = self
+            .read_table(column)?
+            .get(key)
+            .map_err(to_error)?
+            .map(|value| value.value().to_vec());
+
+        if let Some(value) = &value {
+            database_metrics().bytes_read.observe(value.len() as f64);
+        }
+
+        Ok(value.map(Arc::new))
+    }
+}
+
+impl<Description> IteratorableStore for Redb<Description>
+where
+    Description: DatabaseDescription,
+{
+    fn iter_all(
+        &self,
+        column: Self::Column,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+    ) -> BoxedIter<KVItem> {
+        match (prefix, start) {
+            (None, None) => {
+                self._iter_all(column, Bound::Unbounded, Bound::Unbounded, direction)
+            }
+            (Some(prefix), None) => {
+                let next_prefix = next_prefix(prefix.to_vec());
+                let upper = match &next_prefix {
+                    Some(next_prefix) => Bound::Excluded(next_prefix.as_slice()),
+                    None => Bound::Unbounded,
+                };
+                self._iter_all(column, Bound::Included(prefix), upper, direction)
+            }
+            (None, Some(start)) => match direction {
+                IterDirection::Forward => self._iter_all(
+                    column,
+                    Bound::Included(start),
+                    Bound::Unbounded,
+                    direction,
+                ),
+                IterDirection::Reverse => self._iter_all(
+                    column,
+                    Bound::Unbounded,
+                    Bound::Included(start),
+                    direction,
+                ),
+            },
+            (Some(prefix), Some(start)) => {
+                // If the `start` doesn't have the same `prefix`, return nothing.
+                if !start.starts_with(prefix) {
+                    return iter::empty().into_boxed()
+                }
+
+                match direction {
+                    IterDirection::Forward => {
+                        let next_prefix = next_prefix(prefix.to_vec());
+                        let upper = match &next_prefix {
+                            Some(next_prefix) => Bound::Excluded(next_prefix.as_slice()),
+                            None => Bound::Unbounded,
+                        };
+                        self._iter_all(column, Bound::Included(start), upper, direction)
+                    }
+                    IterDirection::Reverse => self._iter_all(
+                        column,
+                        Bound::Included(prefix),
+                        Bound::Included(start),
+                        direction,
+                    ),
+                }
+            }
+        }
+    }
+}
+
+impl<Description> BatchOperations for Redb<Description>
+where
+    Description: DatabaseDescription,
+{
+    fn batch_write(
+        &self,
+        entries: &mut dyn Iterator<Item = (Vec<u8>, Self::Column, WriteOperation)>,
+    ) -> StorageResult<()> {
+        let transaction = self.db.begin_write().map_err(to_error)?;
+        let mut bytes_written = 0usize;
+        {
+            let mut tables = HashMap::new();
+            for (key, column, op) in entries {
+                let table = match tables.entry(column.as_usize()) {
+                    Entry::Occupied(entry) => entry.into_mut(),
+                    Entry::Vacant(entry) => entry.insert(
+                        transaction
+                            .open_table(Self::table(&Self::table_name(column)))
+                            .map_err(to_error)?,
+                    ),
+                };
+                match op {
+                    WriteOperation::Insert(value) => {
+                        bytes_written = bytes_written
+                            .saturating_add(key.len())
+                            .saturating_add(value.len());
+                        table
+                            .insert(key.as_slice(), value.as_slice())
+                            .map_err(to_error)?;
+                    }
+                    WriteOperation::Remove => {
+                        bytes_written = bytes_written.saturating_add(key.len());
+                        table.remove(key.as_slice()).map_err(to_error)?;
+                    }
+                }
+            }
+        }
+        transaction.commit().map_err(to_error)?;
+
+        database_metrics().write_meter.inc();
+        database_metrics()
+            .bytes_written
+            .observe(bytes_written as f64);
+
+        Ok(())
+    }
+}
+
+impl<Description> TransactableStorage for Redb<Description>
+where
+    Description: DatabaseDescription,
+{
+    fn flush(&self) -> DatabaseResult<()> {
+        // Each commit is durable, so there is nothing to flush.
+        Ok(())
+    }
+
+    fn checkpoint(&self, path: &Path) -> DatabaseResult<()> {
+        // redb doesn't support checkpoints, so all columns are copied
+        // into the new database within one read transaction.
+        let checkpoint = Self::open(path, None)?;
+        let read_transaction = self.db.begin_read().map_err(to_error)?;
+        let write_transaction = checkpoint.db.begin_write().map_err(to_error)?;
+        for column in enum_iterator::all::<Description::Column>() {
+            let name = Self::table_name(column);
+            let source = read_transaction
+                .open_table(Self::table(&name))
+                .map_err(to_error)?;
+            if source.is_empty().map_err(to_error)? {
+                continue
+            }
+            let mut destination = write_transaction
+                .open_table(Self::table(&name))
+                .map_err(to_error)?;
+            for item in source.range::<&[u8]>(..).map_err(to_error)? {
+                let (key, value) = item.map_err(to_error)?;
+                destination
+                    .insert(key.value(), value.value())
+                    .map_err(to_error)?;
+            }
+        }
+        write_transaction.commit().map_err(to_error)?;
+        Ok(())
+    }
+}
+
+fn to_error<E: Into<redb::Error>>(error: E) -> DatabaseError {
+    DatabaseError::Other(error.into().into())
+}
+
+/// Returns the smallest key that is greater than all keys with the `prefix`.
+/// Trailing `0xFF` bytes can't be incremented, so they are removed.
+/// The `None` means overflow, so there is not following prefix.
+fn next_prefix(mut prefix: Vec<u8>) -> Option<Vec<u8>> {
+    while let Some(byte) = prefix.pop() {
+        if let Some(new_byte) = byte.checked_add(1) {
+            prefix.push(new_byte);
+            return Some(prefix)
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        database::database_description::on_chain::OnChain,
+        state::in_memory::memory_store::MemoryStore,
+    };
+    use fuel_core_storage::column::Column;
+    use tempfile::TempDir;
+
+    fn create_db() -> (Redb<OnChain>, TempDir) {
+        let tmp_dir = TempDir::new().unwrap();
+        (Redb::open(tmp_dir.path(), None).unwrap(), tmp_dir)
+    }
+
+    /// The database that removes its directory on drop.
+    struct TestRedb {
+        db: Redb<OnChain>,
+        _tmp: TempDir,
+    }
+
+    impl core::ops::Deref for TestRedb {
+        type Target = Redb<OnChain>;
+
+        fn deref(&self) -> &Self::Target {
+            &self.db
+        }
+    }
+
+    fn test_redb() -> TestRedb {
+        let (db, _tmp) = create_db();
+        TestRedb { db, _tmp }
+    }
+
+    fuel_core_storage::kv_store_tests!(test_redb(), Column::ContractsState);
+
+    #[test]
+    fn can_put_and_read() {
+        let key = vec![0xA, 0xB, 0xC];
+
+        let (db, _tmp) = create_db();
+        let expected = Arc::new(vec![1, 2, 3]);
+        db.put(&key, Column::Metadata, expected.clone()).unwrap();
+
+        assert_eq!(db.get(&key, Column::Metadata).unwrap().unwrap(), expected)
+    }
+
+    #[test]
+    fn put_returns_previous_value() {
+        let key = vec![0xA, 0xB, 0xC];
+
+        let (db, _tmp) = create_db();
+        let expected = Arc::new(vec![1, 2, 3]);
+        db.put(&key, Column::Metadata, expected.clone()).unwrap();
+        let prev = db
+            .replace(&key, Column::Metadata, Arc::new(vec![2, 4, 6]))
+            .unwrap();
+
+        assert_eq!(prev, Some(expected));
+    }
+
+    #[test]
+    fn delete_and_get() {
+        let key = vec![0xA, 0xB, 0xC];
+
+        let (db, _tmp) = create_db();
+        let expected = Arc::new(vec![1, 2, 3]);
+        db.put(&key, Column::Metadata, expected.clone()).unwrap();
+        assert_eq!(db.get(&key, Column::Metadata).unwrap().unwrap(), expected);
+
+        db.delete(&key, Column::Metadata).unwrap();
+        assert_eq!(db.get(&key, Column::Metadata).unwrap(), None);
+    }
+
+    #[test]
+    fn key_exists() {
+        let key = vec![0xA, 0xB, 0xC];
+
+        let (db, _tmp) = create_db();
+        let expected = Arc::new(vec![1, 2, 3]);
+        db.put(&key, Column::Metadata, expected).unwrap();
+        assert!(db.exists(&key, Column::Metadata).unwrap());
+    }
+
+    #[test]
+    fn batch_write_inserts() {
+        let key = vec![0xA, 0xB, 0xC];
+        let value = Arc::new(vec![1, 2, 3]);
+
+        let (db, _tmp) = create_db();
+        let ops = vec![(
+            key.clone(),
+            Column::Metadata,
+            WriteOperation::Insert(value.clone()),
+        )];
+
+        db.batch_write(&mut ops.into_iter()).unwrap();
+        assert_eq!(db.get(&key, Column::Metadata).unwrap().unwrap(), value)
+    }
+
+    #[test]
+    fn batch_write_removes() {
+        let key = vec![0xA, 0xB, 0xC];
+        let value = Arc::new(vec![1, 2, 3]);
+
+        let (db, _tmp) = create_db();
+        db.put(&key, Column::Metadata, value).unwrap();
+
+        let ops = vec![(key.clone(), Column::Metadata, WriteOperation::Remove)];
+        db.batch_write(&mut ops.into_iter()).unwrap();
+
+        assert_eq!(db.get(&key, Column::Metadata).unwrap(), None);
+    }
+
+    #[test]
+    fn can_use_unit_key_and_value() {
+        let key: Vec<u8> = Vec::with_capacity(0);
+
+        let (db, _tmp) = create_db();
+        let expected = Arc::new(vec![]);
+        db.put(&key, Column::Metadata, expected.clone()).unwrap();
+
+        assert_eq!(db.get(&key, Column::Metadata).unwrap().unwrap(), expected);
+
+        assert!(db.exists(&key, Column::Metadata).unwrap());
+
+        assert_eq!(
+            db.iter_all(Column::Metadata, None, None, IterDirection::Forward)
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap()[0],
+            (key.clone(), expected.clone())
+        );
+
+        assert_eq!(db.take(&key, Column::Metadata).unwrap().unwrap(), expected);
+
+        assert!(!db.exists(&key, Column::Metadata).unwrap());
+    }
+
+    #[test]
+    fn iter_all_matches_in_memory_store() {
+        // Given
+        let (db, _tmp) = create_db();
+        let memory = MemoryStore::<OnChain>::default();
+        let keys: Vec<Vec<u8>> = vec![
+            vec![],
+            vec![0x00],
+            vec![0x01, 0x00],
+            vec![0x01, 0x01],
+            vec![0x01, 0xFF],
+            vec![0x01, 0xFF, 0x00],
+            vec![0x01, 0xFF, 0xFF],
+            vec![0x01, 0xFF, 0xFF, 0x01],
+            vec![0x02],
+            vec![0x02, 0x00],
+            vec![0xFF, 0xFF],
+        ];
+        for (i, key) in keys.iter().enumerate() {
+            let value = Arc::new(vec![u8::try_from(i).unwrap()]);
+            db.put(key, Column::ContractsState, value.clone()).unwrap();
+            memory.put(key, Column::ContractsState, value).unwrap();
+        }
+        let prefixes = [
+            None,
+            Some(vec![0x01]),
+            Some(vec![0x01, 0xFF]),
+            Some(vec![0x01, 0xFF, 0xFF]),
+            Some(vec![0xFF]),
+            Some(vec![0x03]),
+        ];
+        let starts = [
+            None,
+            Some(vec![0x01, 0x01]),
+            Some(vec![0x01, 0x02]),
+            Some(vec![0xFF, 0xFF]),
+        ];
+
+        for prefix in prefixes.iter() {
+            for start in starts.iter() {
+                for direction in [IterDirection::Forward, IterDirection::Reverse] {
+                    // When
+                    let actual = db
+                        .iter_all(
+                            Column::ContractsState,
+                            prefix.as_deref(),
+                            start.as_deref(),
+                            direction,
+                        )
+                        .collect::<Result<Vec<_>, _>>()
+                        .unwrap();
+
+                    // Then
+                    let expected = memory
+                        .iter_all(
+                            Column::ContractsState,
+                            prefix.as_deref(),
+                            start.as_deref(),
+                            direction,
+                        )
+                        .collect::<Result<Vec<_>, _>>()
+                        .unwrap();
+                    assert_eq!(
+                        actual, expected,
+                        "prefix: {prefix:?}, start: {start:?}, direction: {direction:?}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn checkpoint_copies_all_columns() {
+        // Given
+        let (db, _tmp) = create_db();
+        let key = vec![0xA, 0xB, 0xC];
+        let value = Arc::new(vec![1, 2, 3]);
+        db.put(&key, Column::Metadata, value.clone()).unwrap();
+        db.put(&key, Column::Coins, value.clone()).unwrap();
+        let checkpoint_dir = TempDir::new().unwrap();
+
+        // When
+        db.checkpoint(checkpoint_dir.path()).unwrap();
+
+        // Then
+        let checkpoint = Redb::<OnChain>::open(checkpoint_dir.path(), None).unwrap();
+        assert_eq!(
+            checkpoint.get(&key, Column::Metadata).unwrap(),
+            Some(value.clone())
+        );
+        assert_eq!(checkpoint.get(&key, Column::Coins).unwrap(), Some(value));
+    }
+}
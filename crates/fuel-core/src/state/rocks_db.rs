@@ -11,7 +11,10 @@ use crate::{
         TransactableStorage,
     },
 };
-use fuel_core_metrics::core_metrics::database_metrics;
+use fuel_core_metrics::{
+    core_metrics::database_metrics,
+    rocksdb_metrics::rocksdb_metrics,
+};
 use fuel_core_storage::{
     iter::{
         BoxedIter,
@@ -29,6 +32,7 @@ use fuel_core_storage::{
 };
 use rand::RngCore;
 use rocksdb::{
+    checkpoint::Checkpoint,
     BlockBasedOptions,
     BoundColumnFamily,
     Cache,
@@ -51,9 +55,22 @@ use std::{
         Path,
         PathBuf,
     },
-    sync::Arc,
+    sync::{
+        atomic::{
+            AtomicBool,
+            Ordering,
+        },
+        Arc,
+        Weak,
+    },
+    thread::JoinHandle,
+    time::Duration,
 };
 
+pub mod config;
+
+pub use config::RocksDbConfig;
+
 type DB = DBWithThreadMode<MultiThreaded>;
 
 /// Reimplementation of `tempdir::TempDir` that allows creating a new
@@ -91,12 +108,74 @@ impl Drop for ShallowTempDir {
     }
 }
 
+/// The RocksDB properties of each column family exported to the metrics.
+const EXPORTED_PROPERTIES: [&str; 6] = [
+    "rocksdb.estimate-num-keys",
+    "rocksdb.total-sst-files-size",
+    "rocksdb.cur-size-all-mem-tables",
+    "rocksdb.block-cache-usage",
+    "rocksdb.estimate-pending-compaction-bytes",
+    "rocksdb.num-running-compactions",
+];
+
+/// The interval between exports of the RocksDB properties.
+const PROPERTIES_EXPORT_INTERVAL: Duration = Duration::from_secs(10);
+
 #[derive(Debug)]
 pub struct RocksDb<Description> {
-    db: DB,
+    /// Declared before the `db` to stop the export before the database is closed.
+    _properties_exporter: PropertiesExporter,
+    db: Arc<DB>,
     _marker: core::marker::PhantomData<Description>,
 }
 
+/// The background thread that periodically exports the RocksDB properties
+/// to the metrics. The thread is stopped when the exporter is dropped.
+#[derive(Debug)]
+struct PropertiesExporter {
+    stop: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl PropertiesExporter {
+    fn spawn<Description>(db: Weak<DB>) -> DatabaseResult<Self>
+    where
+        Description: DatabaseDescription,
+    {
+        let stop = Arc::new(AtomicBool::new(false));
+        let handle = std::thread::Builder::new()
+            .name(format!("{}-rocksdb-properties", Description::name()))
+            .spawn({
+                let stop = stop.clone();
+                move || loop {
+                    std::thread::park_timeout(PROPERTIES_EXPORT_INTERVAL);
+                    if stop.load(Ordering::Relaxed) {
+                        break
+                    }
+                    match db.upgrade() {
+                        Some(db) => RocksDb::<Description>::export_properties(&db),
+                        None => break,
+                    }
+                }
+            })
+            .map_err(|e| DatabaseError::Other(e.into()))?;
+        Ok(Self {
+            stop,
+            handle: Some(handle),
+        })
+    }
+}
+
+impl Drop for PropertiesExporter {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            handle.thread().unpark();
+            let _ = handle.join();
+        }
+    }
+}
+
 impl<Description> RocksDb<Description>
 where
     Description: DatabaseDescription,
@@ -104,11 +183,20 @@ where
     pub fn default_open<P: AsRef<Path>>(
         path: P,
         capacity: Option<usize>,
+    ) -> DatabaseResult<Self> {
+        Self::open_with_config(path, capacity, &RocksDbConfig::default())
+    }
+
+    pub fn open_with_config<P: AsRef<Path>>(
+        path: P,
+        capacity: Option<usize>,
+        config: &RocksDbConfig,
     ) -> DatabaseResult<Self> {
         Self::open(
             path,
             enum_iterator::all::<Description::Column>().collect::<Vec<_>>(),
             capacity,
+            config,
         )
     }
 
@@ -116,31 +204,31 @@ where
         path: P,
         columns: Vec<Description::Column>,
         capacity: Option<usize>,
+        config: &RocksDbConfig,
     ) -> DatabaseResult<Self> {
         let path = path.as_ref().join(Description::name());
-        let mut block_opts = BlockBasedOptions::default();
-        // See https://github.com/facebook/rocksdb/blob/a1523efcdf2f0e8133b9a9f6e170a0dad49f928f/include/rocksdb/table.h#L246-L271 for details on what the format versions are/do.
-        block_opts.set_format_version(5);
-
-        if let Some(capacity) = capacity {
-            // Set cache size 1/3 of the capacity as recommended by
-            // https://github.com/facebook/rocksdb/wiki/Setup-Options-and-Basic-Tuning#block-cache-size
-            let block_cache_size = capacity / 3;
-            let cache = Cache::new_lru_cache(block_cache_size);
-            block_opts.set_block_cache(&cache);
-            // "index and filter blocks will be stored in block cache, together with all other data blocks."
-            // See: https://github.com/facebook/rocksdb/wiki/Memory-usage-in-RocksDB#indexes-and-filter-blocks
-            block_opts.set_cache_index_and_filter_blocks(true);
-            // Don't evict L0 filter/index blocks from the cache
-            block_opts.set_pin_l0_filter_and_index_blocks_in_cache(true);
-        } else {
-            block_opts.disable_cache();
-        }
-        block_opts.set_bloom_filter(10.0, true);
+        // Set cache size 1/3 of the capacity as recommended by
+        // https://github.com/facebook/rocksdb/wiki/Setup-Options-and-Basic-Tuning#block-cache-size
+        // unless the size of the shared cache is configured explicitly.
+        let block_cache = config
+            .default
+            .block_cache_size
+            .or(capacity.map(|capacity| capacity / 3))
+            .map(Cache::new_lru_cache);
+        let column_opts = columns
+            .iter()
+            .map(|column| {
+                (
+                    *column,
+                    Self::cf_opts(*column, block_cache.as_ref(), config),
+                )
+            })
+            .collect::<Vec<_>>();
 
-        let cf_descriptors = columns.clone().into_iter().map(|i| {
-            ColumnFamilyDescriptor::new(Self::col_name(i), Self::cf_opts(i, &block_opts))
-        });
+        let cf_descriptors = column_opts
+            .clone()
+            .into_iter()
+            .map(|(i, opts)| ColumnFamilyDescriptor::new(Self::col_name(i), opts));
 
         let mut opts = Options::default();
         opts.create_if_missing(true);
@@ -165,8 +253,7 @@ where
                 // setup cfs
                 match DB::open_cf(&opts, &path, &[] as &[&str]) {
                     Ok(db) => {
-                        for i in columns {
-                            let opts = Self::cf_opts(i, &block_opts);
+                        for (i, opts) in column_opts {
                             db.create_cf(Self::col_name(i), &opts)
                                 .map_err(|e| DatabaseError::Other(e.into()))?;
                         }
@@ -177,12 +264,10 @@ where
                         DB::repair(&opts, &path)
                             .map_err(|e| DatabaseError::Other(e.into()))?;
 
-                        let cf_descriptors = columns.clone().into_iter().map(|i| {
-                            ColumnFamilyDescriptor::new(
-                                Self::col_name(i),
-                                Self::cf_opts(i, &block_opts),
-                            )
-                        });
+                        let cf_descriptors =
+                            column_opts.into_iter().map(|(i, opts)| {
+                                ColumnFamilyDescriptor::new(Self::col_name(i), opts)
+                            });
                         DB::open_cf_descriptors(&opts, &path, cf_descriptors)
                     }
                 }
@@ -190,7 +275,11 @@ where
             ok => ok,
         }
         .map_err(|e| DatabaseError::Other(e.into()))?;
+        let db = Arc::new(db);
         let rocks_db = RocksDb {
+            _properties_exporter: PropertiesExporter::spawn::<Description>(
+                Arc::downgrade(&db),
+            )?,
             db,
             _marker: Default::default(),
         };
@@ -207,11 +296,46 @@ where
         format!("col-{}", column.as_usize())
     }
 
-    fn cf_opts(column: Description::Column, block_opts: &BlockBasedOptions) -> Options {
+    fn cf_opts(
+        column: Description::Column,
+        block_cache: Option<&Cache>,
+        config: &RocksDbConfig,
+    ) -> Options {
+        let column_config = config.column(column.name());
+
+        let mut block_opts = BlockBasedOptions::default();
+        // See https://github.com/facebook/rocksdb/blob/a1523efcdf2f0e8133b9a9f6e170a0dad49f928f/include/rocksdb/table.h#L246-L271 for details on what the format versions are/do.
+        block_opts.set_format_version(5);
+
+        let dedicated_cache = column_config.block_cache_size.map(Cache::new_lru_cache);
+        if let Some(cache) = dedicated_cache.as_ref().or(block_cache) {
+            block_opts.set_block_cache(cache);
+            // "index and filter blocks will be stored in block cache, together with all other data blocks."
+            // See: https://github.com/facebook/rocksdb/wiki/Memory-usage-in-RocksDB#indexes-and-filter-blocks
+            block_opts.set_cache_index_and_filter_blocks(true);
+            // Don't evict L0 filter/index blocks from the cache
+            block_opts.set_pin_l0_filter_and_index_blocks_in_cache(true);
+        } else {
+            block_opts.disable_cache();
+        }
+
+        let bloom_filter_bits = column_config
+            .bloom_filter_bits
+            .unwrap_or(config::DEFAULT_BLOOM_FILTER_BITS);
+        if bloom_filter_bits > 0.0 {
+            block_opts.set_bloom_filter(bloom_filter_bits, true);
+        }
+
         let mut opts = Options::default();
         opts.create_if_missing(true);
-        opts.set_compression_type(DBCompressionType::Lz4);
-        opts.set_block_based_table_factory(block_opts);
+        opts.set_compression_type(column_config.compression.unwrap_or_default().into());
+        opts.set_block_based_table_factory(&block_opts);
+        if let Some(size) = column_config.write_buffer_size {
+            opts.set_write_buffer_size(size);
+        }
+        if let Some(style) = column_config.compaction_style {
+            opts.set_compaction_style(style.into());
+        }
 
         // All double-keys should be configured here
         if let Some(size) = Description::prefix(&column) {
@@ -278,6 +402,24 @@ where
         }
     }
 
+    /// Exports the RocksDB properties of all column families to the metrics.
+    fn export_properties(db: &DB) {
+        for column in enum_iterator::all::<Description::Column>() {
+            if let Some(cf) = db.cf_handle(&Self::col_name(column)) {
+                for property in EXPORTED_PROPERTIES {
+                    if let Ok(Some(value)) = db.property_int_value_cf(&cf, property) {
+                        rocksdb_metrics().set_property(
+                            Description::name(),
+                            column.name(),
+                            property,
+                            value,
+                        );
+                    }
+                }
+            }
+        }
+    }
+
     fn _iter_all(
         &self,
         column: Description::Column,
@@ -488,7 +630,8 @@ where
 
         self.db
             .write(batch)
-            .map_err(|e| DatabaseError::Other(e.into()).into())
+            .map_err(|e| DatabaseError::Other(e.into()))?;
+        Ok(())
     }
 }
 
@@ -505,6 +648,20 @@ where
             .map_err(|e| anyhow::anyhow!("Unable to flush SST files: {}", e))?;
         Ok(())
     }
+
+    fn checkpoint(&self, path: &Path) -> DatabaseResult<()> {
+        let path = path.join(Description::name());
+        Checkpoint::new(&self.db)
+            .and_then(|checkpoint| checkpoint.create_checkpoint(&path))
+            .map_err(|e| {
+                anyhow::anyhow!(
+                    "Unable to create a checkpoint at {}: {}",
+                    path.display(),
+                    e
+                )
+            })?;
+        Ok(())
+    }
 }
 
 /// The `None` means overflow, so there is not following prefix.
@@ -533,6 +690,38 @@ mod tests {
         )
     }
 
+    #[test]
+    fn can_open_with_column_config() {
+        // Given
+        let tmp_dir = TempDir::new().unwrap();
+        let mut config = RocksDbConfig::default();
+        config.columns.insert(
+            Column::ContractsState.name().to_string(),
+            config::ColumnConfig {
+                compression: Some(config::Compression::Zstd),
+                bloom_filter_bits: Some(0.0),
+                block_cache_size: Some(1024 * 1024),
+                write_buffer_size: Some(4 * 1024 * 1024),
+                compaction_style: Some(config::CompactionStyle::Universal),
+            },
+        );
+        let key = vec![0xA, 0xB, 0xC];
+        let value = Arc::new(vec![1, 2, 3]);
+
+        // When
+        let db =
+            RocksDb::<OnChain>::open_with_config(tmp_dir.path(), Some(1024), &config)
+                .unwrap();
+        db.put(&key, Column::ContractsState, value.clone()).unwrap();
+        drop(db);
+        let db =
+            RocksDb::<OnChain>::open_with_config(tmp_dir.path(), Some(1024), &config)
+                .unwrap();
+
+        // Then
+        assert_eq!(db.get(&key, Column::ContractsState).unwrap(), Some(value));
+    }
+
     #[test]
     fn can_put_and_read() {
         let key = vec![0xA, 0xB, 0xC];
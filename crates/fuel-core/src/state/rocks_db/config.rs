@@ -0,0 +1,262 @@
+//! The tuning of RocksDB column families.
+//!
+//! The configuration is loaded from a JSON file like:
+//! ```json
+//! {
+//!   "default": { "compression": "lz4" },
+//!   "columns": {
+//!     "ContractsState": {
+//!       "compression": "zstd",
+//!       "block_cache_size": 268435456,
+//!       "write_buffer_size": 134217728
+//!     },
+//!     "Coins": { "bloom_filter_bits": 16.0 }
+//!   }
+//! }
+//! ```
+//! Options that are not set for a column are taken from the `default` section,
+//! and options not set there keep the built-in defaults. The only exception is
+//! the `block_cache_size`: in the `default` section, it is the size of the block
+//! cache shared by all columns that don't have their own cache.
+
+use rocksdb::{
+    DBCompactionStyle,
+    DBCompressionType,
+};
+use std::{
+    collections::{
+        HashMap,
+        HashSet,
+    },
+    path::Path,
+};
+
+/// The number of bits per key of the bloom filter used by default.
+pub const DEFAULT_BLOOM_FILTER_BITS: f64 = 10.0;
+
+/// The options of RocksDB column families.
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RocksDbConfig {
+    /// The options applied to all columns.
+    pub default: ColumnConfig,
+    /// The options of columns by the name of the column, for example, `ContractsState`.
+    /// They override the `default` options.
+    pub columns: HashMap<String, ColumnConfig>,
+}
+
+impl RocksDbConfig {
+    /// Loads the configuration from the JSON file.
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let contents = std::fs::read_to_string(path)?;
+        serde_json::from_str(&contents).map_err(|e| {
+            anyhow::anyhow!(
+                "Failed to parse the database config at {}: {}",
+                path.display(),
+                e
+            )
+        })
+    }
+
+    /// Returns the options of the column merged with the `default` options.
+    /// The `block_cache_size` is set only if the column has a dedicated cache.
+    pub fn column(&self, name: &str) -> ColumnConfig {
+        let default = ColumnConfig {
+            block_cache_size: None,
+            ..self.default.clone()
+        };
+        match self.columns.get(name) {
+            Some(column) => column.or(&default),
+            None => default,
+        }
+    }
+
+    /// Verifies that each configured column is one of the `known` columns.
+    /// Otherwise, a typo in the name of the column silently disables its options.
+    pub fn validate_columns<'a>(
+        &self,
+        known: impl IntoIterator<Item = &'a str>,
+    ) -> anyhow::Result<()> {
+        let known = known.into_iter().collect::<HashSet<_>>();
+        let mut unknown = self
+            .columns
+            .keys()
+            .filter(|name| !known.contains(name.as_str()))
+            .cloned()
+            .collect::<Vec<_>>();
+        if unknown.is_empty() {
+            return Ok(())
+        }
+        unknown.sort();
+        let mut known = known.into_iter().collect::<Vec<_>>();
+        known.sort();
+        Err(anyhow::anyhow!(
+            "Unknown columns in the database config: {}. The known columns are: {}",
+            unknown.join(", "),
+            known.join(", ")
+        ))
+    }
+}
+
+/// The options of one column family. `None` means the default value.
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ColumnConfig {
+    /// The compression of the data blocks. By default, it is `lz4`.
+    pub compression: Option<Compression>,
+    /// The number of bits per key of the bloom filter. `0` disables the bloom filter.
+    pub bloom_filter_bits: Option<f64>,
+    /// The size in bytes of the block cache dedicated to the column.
+    /// By default, the column uses the block cache shared by all columns.
+    /// In the `default` section, it is the size of the shared block cache.
+    pub block_cache_size: Option<usize>,
+    /// The size in bytes of the memtable of the column.
+    pub write_buffer_size: Option<usize>,
+    /// The compaction style of the column. By default, it is `level`.
+    pub compaction_style: Option<CompactionStyle>,
+}
+
+impl ColumnConfig {
+    /// Returns options of `self` with options missing in `self` taken from the `other`.
+    fn or(&self, other: &Self) -> Self {
+        Self {
+            compression: self.compression.or(other.compression),
+            bloom_filter_bits: self.bloom_filter_bits.or(other.bloom_filter_bits),
+            block_cache_size: self.block_cache_size.or(other.block_cache_size),
+            write_buffer_size: self.write_buffer_size.or(other.write_buffer_size),
+            compaction_style: self.compaction_style.or(other.compaction_style),
+        }
+    }
+}
+
+#[derive(
+    Copy, Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum Compression {
+    None,
+    #[default]
+    Lz4,
+    Lz4hc,
+    Zstd,
+}
+
+impl From<Compression> for DBCompressionType {
+    fn from(compression: Compression) -> Self {
+        match compression {
+            Compression::None => DBCompressionType::None,
+            Compression::Lz4 => DBCompressionType::Lz4,
+            Compression::Lz4hc => DBCompressionType::Lz4hc,
+            Compression::Zstd => DBCompressionType::Zstd,
+        }
+    }
+}
+
+#[derive(
+    Copy, Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum CompactionStyle {
+    #[default]
+    Level,
+    Universal,
+    Fifo,
+}
+
+impl From<CompactionStyle> for DBCompactionStyle {
+    fn from(style: CompactionStyle) -> Self {
+        match style {
+            CompactionStyle::Level => DBCompactionStyle::Level,
+            CompactionStyle::Universal => DBCompactionStyle::Universal,
+            CompactionStyle::Fifo => DBCompactionStyle::Fifo,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn column_config_overrides_default_config() {
+        // Given
+        let config: RocksDbConfig = serde_json::from_str(
+            r#"{
+                "default": { "compression": "lz4", "bloom_filter_bits": 12.0 },
+                "columns": {
+                    "ContractsState": { "compression": "zstd", "compaction_style": "universal" }
+                }
+            }"#,
+        )
+        .unwrap();
+
+        // When
+        let contracts_state = config.column("ContractsState");
+        let coins = config.column("Coins");
+
+        // Then
+        assert_eq!(
+            contracts_state,
+            ColumnConfig {
+                compression: Some(Compression::Zstd),
+                bloom_filter_bits: Some(12.0),
+                compaction_style: Some(CompactionStyle::Universal),
+                ..Default::default()
+            }
+        );
+        assert_eq!(coins, config.default);
+    }
+
+    #[test]
+    fn default_block_cache_size_is_not_applied_to_columns() {
+        // Given
+        let config: RocksDbConfig = serde_json::from_str(
+            r#"{
+                "default": { "block_cache_size": 1024 },
+                "columns": {
+                    "ContractsState": { "block_cache_size": 2048 },
+                    "Coins": { "compression": "zstd" }
+                }
+            }"#,
+        )
+        .unwrap();
+
+        // When
+        let contracts_state = config.column("ContractsState");
+        let coins = config.column("Coins");
+        let messages = config.column("Messages");
+
+        // Then
+        assert_eq!(contracts_state.block_cache_size, Some(2048));
+        assert_eq!(coins.block_cache_size, None);
+        assert_eq!(messages.block_cache_size, None);
+    }
+
+    #[test]
+    fn validate_columns_rejects_unknown_columns() {
+        // Given
+        let config: RocksDbConfig = serde_json::from_str(
+            r#"{ "columns": { "Coins": {}, "ContractState": {} } }"#,
+        )
+        .unwrap();
+
+        // When
+        let result = config.validate_columns(["Coins", "ContractsState"]);
+
+        // Then
+        let err = result.expect_err("Should reject the unknown column");
+        assert!(err.to_string().contains("ContractState"));
+        assert!(config.validate_columns(["Coins", "ContractState"]).is_ok());
+    }
+
+    #[test]
+    fn config_with_unknown_option_is_rejected() {
+        // When
+        let result = serde_json::from_str::<RocksDbConfig>(
+            r#"{ "default": { "compresion": "lz4" } }"#,
+        );
+
+        // Then
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,223 @@
+//! The deterministic keys derived from the BIP-39 mnemonic phrase.
+//!
+//! The keys are derived along the BIP-44 path with the coin type of Fuel:
+//! `m/44'/1179993420'/{index}'/0/0`, the same path used by the Fuel wallets.
+
+use crate::{
+    secret_response,
+    KeyType,
+    NewKeyResponse,
+};
+use anyhow::anyhow;
+use fuel_core_types::fuel_crypto::{
+    generate_mnemonic_phrase,
+    rand::{
+        prelude::StdRng,
+        SeedableRng,
+    },
+    SecretKey,
+};
+use serde::Serialize;
+use std::{
+    sync::atomic::{
+        AtomicBool,
+        AtomicU64,
+        Ordering,
+    },
+    thread,
+};
+
+/// The BIP-44 prefix of the derivation path of Fuel accounts.
+pub const FUEL_DERIVATION_PATH_PREFIX: &str = "m/44'/1179993420'";
+
+/// The number of words in the generated mnemonic phrase.
+pub const DEFAULT_WORD_COUNT: usize = 24;
+
+/// Returns the derivation path of the Fuel account with the `index`.
+pub fn derivation_path(index: u32) -> String {
+    format!("{FUEL_DERIVATION_PATH_PREFIX}/{index}'/0/0")
+}
+
+/// Generates the new random mnemonic phrase with the `word_count` words.
+pub fn new_mnemonic(word_count: usize) -> anyhow::Result<String> {
+    let mut rng = StdRng::from_entropy();
+    generate_mnemonic_phrase(&mut rng, word_count).map_err(|e| {
+        anyhow!("Failed to generate the mnemonic phrase of {word_count} words: {e}")
+    })
+}
+
+/// Derives the secret key from the mnemonic `phrase` along the `path`.
+pub fn derive_secret(phrase: &str, path: &str) -> anyhow::Result<SecretKey> {
+    SecretKey::new_from_mnemonic_phrase_with_path(phrase.trim(), path)
+        .map_err(|e| anyhow!("Failed to derive the key along {path}: {e}"))
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct DerivedKeyResponse {
+    path: String,
+    #[serde(flatten)]
+    key: NewKeyResponse,
+}
+
+impl DerivedKeyResponse {
+    pub fn key(&self) -> &NewKeyResponse {
+        &self.key
+    }
+}
+
+/// Derives the key along the `path`.
+pub fn derive_key(
+    phrase: &str,
+    path: &str,
+    key_type: KeyType,
+) -> anyhow::Result<DerivedKeyResponse> {
+    let secret = derive_secret(phrase, path)?;
+    Ok(DerivedKeyResponse {
+        path: path.to_string(),
+        key: secret_response(secret, key_type),
+    })
+}
+
+/// Derives `count` keys of the Fuel accounts starting from the index `start`.
+pub fn derive_keys(
+    phrase: &str,
+    key_type: KeyType,
+    start: u32,
+    count: u32,
+) -> anyhow::Result<Vec<DerivedKeyResponse>> {
+    let end = start
+        .checked_add(count)
+        .ok_or(anyhow!("The range of indexes overflows"))?;
+    (start..end)
+        .map(|index| derive_key(phrase, &derivation_path(index), key_type))
+        .collect()
+}
+
+/// The key with the address matching the requested pattern.
+#[derive(Clone, Debug, Serialize)]
+pub struct VanityKeyResponse {
+    attempts: u64,
+    #[serde(flatten)]
+    key: NewKeyResponse,
+}
+
+/// Searches for the random block production key with the address starting with
+/// the hex `prefix` and ending with the hex `suffix`, ignoring the case.
+///
+/// The search runs on all available cores and fails after `max_attempts`.
+pub fn vanity_key(
+    prefix: &str,
+    suffix: &str,
+    max_attempts: u64,
+) -> anyhow::Result<VanityKeyResponse> {
+    let prefix = normalize_pattern(prefix)?;
+    let suffix = normalize_pattern(suffix)?;
+
+    let threads = thread::available_parallelism().map_or(1, |n| n.get());
+    let found = AtomicBool::new(false);
+    let attempts = AtomicU64::new(0);
+
+    let secret = thread::scope(|scope| {
+        let handles = (0..threads)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut rng = StdRng::from_entropy();
+                    while !found.load(Ordering::Relaxed)
+                        && attempts.fetch_add(1, Ordering::Relaxed) < max_attempts
+                    {
+                        let secret = SecretKey::random(&mut rng);
+                        let address = crate::key_info(&secret, KeyType::BlockProduction)
+                            .address
+                            .expect("The block production key has the address");
+                        let address = hex::encode(address);
+                        if address.starts_with(&prefix) && address.ends_with(&suffix) {
+                            found.store(true, Ordering::Relaxed);
+                            return Some(secret)
+                        }
+                    }
+                    None
+                })
+            })
+            .collect::<Vec<_>>();
+        handles
+            .into_iter()
+            .filter_map(|handle| handle.join().expect("The search doesn't panic"))
+            .next()
+    });
+
+    let secret = secret.ok_or(anyhow!(
+        "No address matching the pattern is found in {max_attempts} attempts"
+    ))?;
+    Ok(VanityKeyResponse {
+        attempts: attempts.load(Ordering::Relaxed).min(max_attempts),
+        key: secret_response(secret, KeyType::BlockProduction),
+    })
+}
+
+fn normalize_pattern(pattern: &str) -> anyhow::Result<String> {
+    let pattern = pattern.trim_start_matches("0x").to_lowercase();
+    if !pattern.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(anyhow!("The pattern `{pattern}` is not hex"))
+    }
+    Ok(pattern)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ops::Deref;
+
+    const PHRASE: &str =
+        "winner alley monkey elephant sun off boil hope toward boss bronze dish";
+
+    #[test]
+    fn derive_secret_matches_known_key() {
+        // Given
+        let path = "m/44'/60'/0'/0/0";
+
+        // When
+        let secret = derive_secret(PHRASE, path).unwrap();
+
+        // Then
+        assert_eq!(
+            hex::encode(secret.deref()),
+            "fbe49178dac2df5fdea74a11a90f9977625fe023cdf6414bfd639d327a2e9ddb"
+        );
+    }
+
+    #[test]
+    fn derive_keys_returns_distinct_keys_of_consecutive_paths() {
+        // Given
+        let phrase = new_mnemonic(DEFAULT_WORD_COUNT).unwrap();
+
+        // When
+        let keys = derive_keys(&phrase, KeyType::BlockProduction, 3, 2).unwrap();
+
+        // Then
+        assert_eq!(keys.len(), 2);
+        assert_eq!(keys[0].path, derivation_path(3));
+        assert_eq!(keys[1].path, derivation_path(4));
+        assert_ne!(keys[0].key().secret(), keys[1].key().secret());
+        let recovered = derive_secret(&phrase, &derivation_path(4)).unwrap();
+        assert_eq!(&recovered, keys[1].key().secret());
+    }
+
+    #[test]
+    fn new_mnemonic_fails_with_invalid_word_count() {
+        // When
+        let result = new_mnemonic(13);
+
+        // Then
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn vanity_key_returns_address_with_prefix() {
+        // When
+        let response = vanity_key("0xA", "", u64::MAX).unwrap();
+
+        // Then
+        let address = response.key.address.expect("The address is set");
+        assert!(hex::encode(address).starts_with('a'));
+    }
+}
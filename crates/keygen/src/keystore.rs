@@ -0,0 +1,409 @@
+//! The encrypted storage of secret keys.
+//!
+//! The format is similar to the Ethereum keystore: the key of the cipher is derived
+//! from the password with scrypt, and the secret key is encrypted with AES-256-GCM.
+//! The authentication tag of GCM replaces the separate MAC of the Ethereum keystore.
+//!
+//! ```json
+//! {
+//!   "version": 1,
+//!   "type": "block-production",
+//!   "address": "0x...",
+//!   "crypto": {
+//!     "cipher": "aes-256-gcm",
+//!     "ciphertext": "...",
+//!     "nonce": "...",
+//!     "kdf": "scrypt",
+//!     "kdfparams": { "log_n": 15, "r": 8, "p": 1, "salt": "..." }
+//!   }
+//! }
+//! ```
+
+use crate::{
+    key_info,
+    KeyType,
+};
+use aes_gcm::{
+    aead::Aead,
+    Aes256Gcm,
+    KeyInit,
+    Nonce,
+};
+use anyhow::Context;
+use fuel_core_types::{
+    fuel_crypto::{
+        rand::{
+            prelude::StdRng,
+            RngCore,
+            SeedableRng,
+        },
+        SecretKey,
+    },
+    fuel_types::Address,
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use std::{
+    ops::Deref,
+    path::Path,
+};
+use zeroize::Zeroizing;
+
+/// The version of the keystore format.
+pub const KEYSTORE_VERSION: u32 = 1;
+
+const CIPHER: &str = "aes-256-gcm";
+const KDF: &str = "scrypt";
+const KEY_LEN: usize = 32;
+const SALT_LEN: usize = 32;
+const NONCE_LEN: usize = 12;
+/// The maximum memory used by scrypt to derive the key. It prevents keystores
+/// with huge parameters from exhausting the memory or the CPU of the node.
+const MAX_SCRYPT_MEMORY: u64 = 1 << 30;
+const MAX_SCRYPT_P: u32 = 16;
+
+/// The secret key encrypted with the password.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Keystore {
+    pub version: u32,
+    #[serde(rename = "type")]
+    pub key_type: KeyType,
+    /// The address of the block production key. It allows finding the keystore
+    /// without the password.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address: Option<Address>,
+    /// The peer id of the peering key.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub peer_id: Option<String>,
+    pub crypto: KeystoreCrypto,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeystoreCrypto {
+    pub cipher: String,
+    /// The hex-encoded secret key encrypted together with the authentication tag.
+    pub ciphertext: String,
+    /// The hex-encoded nonce of the cipher.
+    pub nonce: String,
+    pub kdf: String,
+    pub kdfparams: ScryptParams,
+}
+
+/// The parameters of scrypt used to derive the key of the cipher.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScryptParams {
+    pub log_n: u8,
+    pub r: u32,
+    pub p: u32,
+    /// The hex-encoded salt.
+    pub salt: String,
+}
+
+/// The cost of the key derivation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct KdfCost {
+    pub log_n: u8,
+    pub r: u32,
+    pub p: u32,
+}
+
+impl Default for KdfCost {
+    fn default() -> Self {
+        let params = scrypt::Params::recommended();
+        Self {
+            log_n: params.log_n(),
+            r: params.r(),
+            p: params.p(),
+        }
+    }
+}
+
+impl Keystore {
+    /// Encrypts the `secret` with the `password` using the recommended cost of scrypt.
+    pub fn encrypt(
+        secret: &SecretKey,
+        key_type: KeyType,
+        password: &[u8],
+    ) -> anyhow::Result<Self> {
+        Self::encrypt_with_cost(secret, key_type, password, KdfCost::default())
+    }
+
+    pub fn encrypt_with_cost(
+        secret: &SecretKey,
+        key_type: KeyType,
+        password: &[u8],
+        cost: KdfCost,
+    ) -> anyhow::Result<Self> {
+        let mut rng = StdRng::from_entropy();
+        let mut salt = [0u8; SALT_LEN];
+        rng.fill_bytes(&mut salt);
+        let mut nonce = [0u8; NONCE_LEN];
+        rng.fill_bytes(&mut nonce);
+
+        let kdfparams = ScryptParams {
+            log_n: cost.log_n,
+            r: cost.r,
+            p: cost.p,
+            salt: hex::encode(salt),
+        };
+        let cipher = cipher(password, &kdfparams)?;
+        let ciphertext = cipher
+            .encrypt(Nonce::from_slice(&nonce), secret.deref().as_slice())
+            .map_err(|_| anyhow::anyhow!("Failed to encrypt the secret key"))?;
+
+        let info = key_info(secret, key_type);
+        Ok(Self {
+            version: KEYSTORE_VERSION,
+            key_type,
+            address: info.address,
+            peer_id: info.peer_id.map(|peer_id| peer_id.to_string()),
+            crypto: KeystoreCrypto {
+                cipher: CIPHER.to_string(),
+                ciphertext: hex::encode(ciphertext),
+                nonce: hex::encode(nonce),
+                kdf: KDF.to_string(),
+                kdfparams,
+            },
+        })
+    }
+
+    /// Decrypts the secret key with the `password`.
+    ///
+    /// Fails if the password is wrong, or the keystore was modified.
+    pub fn decrypt(&self, password: &[u8]) -> anyhow::Result<SecretKey> {
+        if self.version != KEYSTORE_VERSION {
+            anyhow::bail!("Unsupported version of the keystore: {}", self.version);
+        }
+        if self.crypto.cipher != CIPHER || self.crypto.kdf != KDF {
+            anyhow::bail!(
+                "Unsupported cipher `{}` or key derivation function `{}`",
+                self.crypto.cipher,
+                self.crypto.kdf
+            );
+        }
+        let nonce = hex::decode(&self.crypto.nonce).context("Invalid nonce")?;
+        if nonce.len() != NONCE_LEN {
+            anyhow::bail!("Invalid length of the nonce: {}", nonce.len());
+        }
+        let ciphertext =
+            hex::decode(&self.crypto.ciphertext).context("Invalid ciphertext")?;
+
+        let cipher = cipher(password, &self.crypto.kdfparams)?;
+        let bytes = cipher
+            .decrypt(Nonce::from_slice(&nonce), ciphertext.as_slice())
+            .map(Zeroizing::new)
+            .map_err(|_| anyhow::anyhow!("Wrong password or corrupted keystore"))?;
+        let secret = SecretKey::try_from(bytes.as_slice())
+            .map_err(|_| anyhow::anyhow!("Invalid secret key in the keystore"))?;
+
+        let info = key_info(&secret, self.key_type);
+        if info.address != self.address
+            || info.peer_id.map(|peer_id| peer_id.to_string()) != self.peer_id
+        {
+            anyhow::bail!("The secret key doesn't match the address of the keystore");
+        }
+        Ok(secret)
+    }
+
+    /// Loads the keystore from the JSON file.
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let contents = std::fs::read_to_string(path).with_context(|| {
+            format!("Failed to read the keystore at {}", path.display())
+        })?;
+        serde_json::from_str(&contents).with_context(|| {
+            format!("Failed to parse the keystore at {}", path.display())
+        })
+    }
+
+    /// Saves the keystore to the new JSON file readable only by the owner.
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        let contents = serde_json::to_string_pretty(self)?;
+        let mut options = std::fs::OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+        let mut file = options.open(path).with_context(|| {
+            format!("Failed to create the keystore at {}", path.display())
+        })?;
+        std::io::Write::write_all(&mut file, contents.as_bytes())?;
+        Ok(())
+    }
+}
+
+/// Reads the password from the file. The trailing line break is ignored.
+pub fn read_password_file(path: &Path) -> anyhow::Result<Zeroizing<String>> {
+    let password = Zeroizing::new(std::fs::read_to_string(path).with_context(|| {
+        format!("Failed to read the password file at {}", path.display())
+    })?);
+    Ok(Zeroizing::new(
+        password.trim_end_matches(['\r', '\n']).to_string(),
+    ))
+}
+
+/// Loads the secret key of the `key_type` from the keystore encrypted with
+/// the password from the `password_file`.
+pub fn load_secret(
+    keystore_path: &Path,
+    password_file: &Path,
+    key_type: KeyType,
+) -> anyhow::Result<SecretKey> {
+    let keystore = Keystore::load(keystore_path)?;
+    if keystore.key_type != key_type {
+        anyhow::bail!(
+            "The keystore at {} contains the {} key, but the {} key is expected",
+            keystore_path.display(),
+            <&'static str>::from(keystore.key_type),
+            <&'static str>::from(key_type),
+        );
+    }
+    let password = read_password_file(password_file)?;
+    keystore.decrypt(password.as_bytes())
+}
+
+fn cipher(password: &[u8], params: &ScryptParams) -> anyhow::Result<Aes256Gcm> {
+    let salt = hex::decode(&params.salt).context("Invalid salt")?;
+    check_scrypt_params(params)?;
+    let scrypt_params = scrypt::Params::new(params.log_n, params.r, params.p)
+        .map_err(|e| anyhow::anyhow!("Invalid scrypt parameters: {e}"))?;
+    let mut key = Zeroizing::new([0u8; KEY_LEN]);
+    scrypt::scrypt(password, &salt, &scrypt_params, key.as_mut())
+        .map_err(|e| anyhow::anyhow!("Failed to derive the key: {e}"))?;
+    Aes256Gcm::new_from_slice(key.as_ref())
+        .map_err(|_| anyhow::anyhow!("Invalid length of the derived key"))
+}
+
+/// Rejects the parameters of scrypt that require more than [`MAX_SCRYPT_MEMORY`]
+/// bytes or more than [`MAX_SCRYPT_P`] passes.
+fn check_scrypt_params(params: &ScryptParams) -> anyhow::Result<()> {
+    // scrypt uses `128 * r * 2^log_n` bytes of memory.
+    let memory = 2u64
+        .checked_pow(u32::from(params.log_n))
+        .and_then(|n| n.checked_mul(u64::from(params.r)))
+        .and_then(|memory| memory.checked_mul(128));
+    match memory {
+        Some(memory) if memory <= MAX_SCRYPT_MEMORY && params.p <= MAX_SCRYPT_P => Ok(()),
+        _ => anyhow::bail!(
+            "The scrypt parameters (log_n: {}, r: {}, p: {}) exceed the limits",
+            params.log_n,
+            params.r,
+            params.p
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_COST: KdfCost = KdfCost {
+        log_n: 4,
+        r: 8,
+        p: 1,
+    };
+
+    fn secret() -> SecretKey {
+        SecretKey::random(&mut StdRng::seed_from_u64(1234))
+    }
+
+    #[test]
+    fn decrypt_returns_encrypted_secret() {
+        // Given
+        let secret = secret();
+        let keystore = Keystore::encrypt_with_cost(
+            &secret,
+            KeyType::BlockProduction,
+            b"password",
+            TEST_COST,
+        )
+        .unwrap();
+        let json = serde_json::to_string(&keystore).unwrap();
+
+        // When
+        let keystore: Keystore = serde_json::from_str(&json).unwrap();
+        let decrypted = keystore.decrypt(b"password").unwrap();
+
+        // Then
+        assert_eq!(decrypted, secret);
+        assert!(!json.contains(&hex::encode(secret.deref())));
+    }
+
+    #[test]
+    fn decrypt_fails_with_wrong_password() {
+        // Given
+        let keystore = Keystore::encrypt_with_cost(
+            &secret(),
+            KeyType::Peering,
+            b"password",
+            TEST_COST,
+        )
+        .unwrap();
+
+        // When
+        let result = keystore.decrypt(b"wrong password");
+
+        // Then
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn decrypt_fails_if_scrypt_params_exceed_limits() {
+        // Given
+        let mut keystore = Keystore::encrypt_with_cost(
+            &secret(),
+            KeyType::BlockProduction,
+            b"password",
+            TEST_COST,
+        )
+        .unwrap();
+        keystore.crypto.kdfparams.log_n = 40;
+
+        // When
+        let result = keystore.decrypt(b"password");
+
+        // Then
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("exceed the limits"), "{err}");
+    }
+
+    #[test]
+    fn default_kdf_cost_is_within_limits() {
+        // Given
+        let cost = KdfCost::default();
+        let params = ScryptParams {
+            log_n: cost.log_n,
+            r: cost.r,
+            p: cost.p,
+            salt: String::new(),
+        };
+
+        // When
+        let result = check_scrypt_params(&params);
+
+        // Then
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn decrypt_fails_if_address_is_replaced() {
+        // Given
+        let mut keystore = Keystore::encrypt_with_cost(
+            &secret(),
+            KeyType::BlockProduction,
+            b"password",
+            TEST_COST,
+        )
+        .unwrap();
+        keystore.address = Some(Address::zeroed());
+
+        // When
+        let result = keystore.decrypt(b"password");
+
+        // Then
+        assert!(result.is_err());
+    }
+}
@@ -18,13 +18,21 @@ use libp2p_identity::{
     Keypair,
     PeerId,
 };
-use serde::Serialize;
+use serde::{
+    Deserialize,
+    Serialize,
+};
 use std::{
     ops::Deref,
     str::FromStr,
 };
 
-#[derive(Clone, Copy, Debug, Default, Serialize, ValueEnum)]
+pub mod hd;
+pub mod keystore;
+
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, ValueEnum,
+)]
 #[serde(rename_all = "kebab-case")]
 pub enum KeyType {
     #[default]
@@ -68,6 +76,12 @@ pub struct NewKeyResponse {
     typ: KeyType,
 }
 
+impl NewKeyResponse {
+    pub fn secret(&self) -> &SecretKey {
+        &self.secret
+    }
+}
+
 fn serialize_option_to_string<S, T>(
     opt: &Option<T>,
     serializer: S,
@@ -86,33 +100,27 @@ where
 pub fn new_key(key_type: KeyType) -> anyhow::Result<NewKeyResponse> {
     let mut rng = StdRng::from_entropy();
     let secret = SecretKey::random(&mut rng);
-    let public_key = secret.public_key();
+    Ok(secret_response(secret, key_type))
+}
 
-    Ok(match key_type {
-        KeyType::BlockProduction => {
-            let address = Input::owner(&public_key);
-            NewKeyResponse {
-                secret,
-                address: Some(address),
-                peer_id: None,
-                typ: key_type,
-            }
-        }
-        KeyType::Peering => {
-            let mut bytes = *secret.deref();
-            let p2p_secret = secp256k1::SecretKey::try_from_bytes(&mut bytes)
-                .expect("Should be a valid private key");
-            let p2p_keypair = secp256k1::Keypair::from(p2p_secret);
-            let libp2p_keypair = Keypair::from(p2p_keypair);
-            let peer_id = PeerId::from_public_key(&libp2p_keypair.public());
-            NewKeyResponse {
-                secret,
-                address: None,
-                peer_id: Some(peer_id),
-                typ: key_type,
-            }
-        }
-    })
+/// Generates `count` random keys, e.g. for the validators of the test network.
+pub fn new_keys(key_type: KeyType, count: usize) -> anyhow::Result<Vec<NewKeyResponse>> {
+    (0..count).map(|_| new_key(key_type)).collect()
+}
+
+/// Returns the secret with the address or the peer id derived from it.
+pub fn secret_response(secret: SecretKey, key_type: KeyType) -> NewKeyResponse {
+    let ParseSecretResponse {
+        address,
+        peer_id,
+        typ,
+    } = key_info(&secret, key_type);
+    NewKeyResponse {
+        secret,
+        address,
+        peer_id,
+        typ,
+    }
 }
 
 pub fn parse_secret(
@@ -121,7 +129,11 @@ pub fn parse_secret(
 ) -> anyhow::Result<ParseSecretResponse> {
     let secret =
         SecretKey::from_str(secret).map_err(|_| anyhow::anyhow!("invalid secret key"))?;
-    Ok(match key_type {
+    Ok(key_info(&secret, key_type))
+}
+
+fn key_info(secret: &SecretKey, key_type: KeyType) -> ParseSecretResponse {
+    match key_type {
         KeyType::BlockProduction => {
             let address = Input::owner(&secret.public_key());
             ParseSecretResponse {
@@ -143,5 +155,5 @@ pub fn parse_secret(
                 typ: key_type,
             }
         }
-    })
+    }
 }
@@ -10,7 +10,9 @@ pub mod future_tracker;
 pub mod graphql_metrics;
 pub mod importer;
 pub mod p2p_metrics;
+pub mod relayer_metrics;
 pub mod response;
+pub mod rocksdb_metrics;
 pub mod services;
 pub mod txpool_metrics;
 
@@ -1,15 +1,26 @@
 use once_cell::race::OnceBox;
 use prometheus_client::{
-    metrics::counter::Counter,
+    encoding::EncodeLabelSet,
+    metrics::{
+        counter::Counter,
+        family::Family,
+    },
     registry::Registry,
 };
 use std::sync::OnceLock;
 
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct PeerReportLabel {
+    // the reason of the report
+    reason: String,
+}
+
 pub struct P2PMetrics {
     pub gossip_sub_registry: OnceBox<Registry>,
     // For descriptions of each Counter, see the `new` function where each Counter/Histogram is initialized
     pub peer_metrics: Registry,
     pub unique_peers: Counter,
+    peer_reports: Family<PeerReportLabel, Counter>,
 }
 
 impl P2PMetrics {
@@ -17,11 +28,13 @@ impl P2PMetrics {
         let peer_metrics = Registry::default();
 
         let unique_peers = Counter::default();
+        let peer_reports = Family::<PeerReportLabel, Counter>::default();
 
         let mut metrics = P2PMetrics {
             gossip_sub_registry: OnceBox::new(),
             peer_metrics,
             unique_peers,
+            peer_reports,
         };
 
         metrics.peer_metrics.register(
@@ -30,8 +43,22 @@ impl P2PMetrics {
             metrics.unique_peers.clone(),
         );
 
+        metrics.peer_metrics.register(
+            "Peer_Reports",
+            "A Counter which keeps track of the peer reputation events grouped by the reason",
+            metrics.peer_reports.clone(),
+        );
+
         metrics
     }
+
+    pub fn peer_report(&self, reason: &str) {
+        self.peer_reports
+            .get_or_create(&PeerReportLabel {
+                reason: reason.to_string(),
+            })
+            .inc();
+    }
 }
 
 static P2P_METRICS: OnceLock<P2PMetrics> = OnceLock::new();
@@ -0,0 +1,96 @@
+use crate::timing_buckets;
+use prometheus_client::{
+    encoding::EncodeLabelSet,
+    metrics::{
+        counter::Counter,
+        family::Family,
+        gauge::Gauge,
+        histogram::Histogram,
+    },
+    registry::Registry,
+};
+use std::sync::OnceLock;
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct EndpointLabel {
+    // the DA endpoint without credentials
+    endpoint: String,
+}
+
+pub struct RelayerMetrics {
+    pub registry: Registry,
+    endpoint_requests: Family<EndpointLabel, Counter>,
+    endpoint_failures: Family<EndpointLabel, Counter>,
+    endpoint_healthy: Family<EndpointLabel, Gauge>,
+    endpoint_request_duration: Family<EndpointLabel, Histogram>,
+}
+
+impl RelayerMetrics {
+    fn new() -> Self {
+        let mut registry = Registry::default();
+
+        let endpoint_requests = Family::<EndpointLabel, Counter>::default();
+        let endpoint_failures = Family::<EndpointLabel, Counter>::default();
+        let endpoint_healthy = Family::<EndpointLabel, Gauge>::default();
+        let endpoint_request_duration =
+            Family::<EndpointLabel, Histogram>::new_with_constructor(|| {
+                Histogram::new(timing_buckets().iter().cloned())
+            });
+
+        registry.register(
+            "relayer_endpoint_requests",
+            "The number of requests sent to the DA endpoint",
+            endpoint_requests.clone(),
+        );
+        registry.register(
+            "relayer_endpoint_failures",
+            "The number of failed requests sent to the DA endpoint",
+            endpoint_failures.clone(),
+        );
+        registry.register(
+            "relayer_endpoint_healthy",
+            "Is set to 1 if the DA endpoint is considered healthy, 0 otherwise",
+            endpoint_healthy.clone(),
+        );
+        registry.register(
+            "relayer_endpoint_request_duration_s",
+            "Records the duration of requests to the DA endpoint",
+            endpoint_request_duration.clone(),
+        );
+
+        Self {
+            registry,
+            endpoint_requests,
+            endpoint_failures,
+            endpoint_healthy,
+            endpoint_request_duration,
+        }
+    }
+
+    pub fn observe_request(&self, endpoint: &str, duration: f64, success: bool) {
+        let label = EndpointLabel {
+            endpoint: endpoint.to_string(),
+        };
+        self.endpoint_requests.get_or_create(&label).inc();
+        self.endpoint_request_duration
+            .get_or_create(&label)
+            .observe(duration);
+        if !success {
+            self.endpoint_failures.get_or_create(&label).inc();
+        }
+    }
+
+    pub fn set_healthy(&self, endpoint: &str, healthy: bool) {
+        self.endpoint_healthy
+            .get_or_create(&EndpointLabel {
+                endpoint: endpoint.to_string(),
+            })
+            .set(i64::from(healthy));
+    }
+}
+
+static RELAYER_METRICS: OnceLock<RelayerMetrics> = OnceLock::new();
+
+pub fn relayer_metrics() -> &'static RelayerMetrics {
+    RELAYER_METRICS.get_or_init(RelayerMetrics::new)
+}
@@ -2,6 +2,8 @@ use crate::{
     graphql_metrics::graphql_metrics,
     importer::importer_metrics,
     p2p_metrics::p2p_metrics,
+    relayer_metrics::relayer_metrics,
+    rocksdb_metrics::rocksdb_metrics,
     services::services_metrics,
     txpool_metrics::txpool_metrics,
 };
@@ -50,6 +52,14 @@ pub fn encode_metrics_response() -> impl IntoResponse {
         return error_body()
     }
 
+    if encode(&mut encoded, &relayer_metrics().registry).is_err() {
+        return error_body()
+    }
+
+    if encode(&mut encoded, &rocksdb_metrics().registry).is_err() {
+        return error_body()
+    }
+
     Response::builder()
         .status(200)
         .body(Body::from(encoded))
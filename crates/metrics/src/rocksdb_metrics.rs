@@ -0,0 +1,59 @@
+use prometheus_client::{
+    encoding::EncodeLabelSet,
+    metrics::{
+        family::Family,
+        gauge::Gauge,
+    },
+    registry::Registry,
+};
+use std::sync::OnceLock;
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct PropertyLabel {
+    /// The name of the database, for example, `on_chain`.
+    database: String,
+    /// The name of the column family.
+    column: String,
+    /// The name of the RocksDB property without the `rocksdb.` prefix.
+    property: String,
+}
+
+pub struct RocksDbMetrics {
+    pub registry: Registry,
+    properties: Family<PropertyLabel, Gauge>,
+}
+
+impl RocksDbMetrics {
+    fn new() -> Self {
+        let mut registry = Registry::default();
+
+        let properties = Family::<PropertyLabel, Gauge>::default();
+
+        registry.register(
+            "rocksdb_property",
+            "The values of RocksDB internal properties of each column family",
+            properties.clone(),
+        );
+
+        Self {
+            registry,
+            properties,
+        }
+    }
+
+    pub fn set_property(&self, database: &str, column: &str, property: &str, value: u64) {
+        self.properties
+            .get_or_create(&PropertyLabel {
+                database: database.to_string(),
+                column: column.to_string(),
+                property: property.trim_start_matches("rocksdb.").to_string(),
+            })
+            .set(i64::try_from(value).unwrap_or(i64::MAX));
+    }
+}
+
+static ROCKSDB_METRICS: OnceLock<RocksDbMetrics> = OnceLock::new();
+
+pub fn rocksdb_metrics() -> &'static RocksDbMetrics {
+    ROCKSDB_METRICS.get_or_init(RocksDbMetrics::new)
+}
@@ -1,8 +1,6 @@
 use fuel_core_types::{
-    blockchain::primitives::SecretKeyWrapper,
     fuel_asm::Word,
     fuel_tx::ConsensusParameters,
-    secrecy::Secret,
 };
 use tokio::time::Duration;
 
@@ -10,7 +8,6 @@ use tokio::time::Duration;
 pub struct Config {
     pub trigger: Trigger,
     pub block_gas_limit: Word,
-    pub signing_key: Option<Secret<SecretKeyWrapper>>,
     pub metrics: bool,
     pub consensus_params: ConsensusParameters,
     pub min_connected_reserved_peers: usize,
@@ -22,7 +19,6 @@ impl Default for Config {
         Config {
             trigger: Trigger::default(),
             block_gas_limit: 0,
-            signing_key: None,
             metrics: false,
             consensus_params: ConsensusParameters::default(),
             min_connected_reserved_peers: 0,
@@ -5,6 +5,8 @@ use fuel_core_storage::{
 };
 use fuel_core_types::{
     blockchain::{
+        block::Block,
+        consensus::Consensus,
         header::BlockHeader,
         primitives::DaBlockHeight,
     },
@@ -65,6 +67,17 @@ pub trait BlockProducer: Send + Sync {
     ) -> anyhow::Result<UncommittedExecutionResult<StorageTransaction<Self::Database>>>;
 }
 
+/// The signer of the produced blocks.
+#[cfg_attr(test, mockall::automock)]
+#[async_trait::async_trait]
+pub trait BlockSigner: Send + Sync {
+    /// Signs the block and returns its seal.
+    async fn seal_block(&self, block: &Block) -> anyhow::Result<Consensus>;
+
+    /// Returns `true` if the signer is able to seal blocks.
+    async fn is_available(&self) -> bool;
+}
+
 #[cfg_attr(test, mockall::automock(type Database=EmptyStorage;))]
 #[async_trait::async_trait]
 pub trait BlockImporter: Send + Sync {
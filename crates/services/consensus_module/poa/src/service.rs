@@ -6,6 +6,7 @@ use crate::{
     ports::{
         BlockImporter,
         BlockProducer,
+        BlockSigner,
         P2pPort,
         TransactionPool,
         TransactionsSource,
@@ -32,26 +33,15 @@ use fuel_core_services::{
 use fuel_core_storage::transactional::StorageTransaction;
 use fuel_core_types::{
     blockchain::{
-        block::Block,
-        consensus::{
-            poa::PoAConsensus,
-            Consensus,
-        },
         header::BlockHeader,
-        primitives::SecretKeyWrapper,
         SealedBlock,
     },
     fuel_asm::Word,
-    fuel_crypto::Signature,
     fuel_tx::{
         Transaction,
         TxId,
     },
     fuel_types::BlockHeight,
-    secrecy::{
-        ExposeSecret,
-        Secret,
-    },
     services::{
         block_importer::ImportResult,
         executor::{
@@ -62,10 +52,7 @@ use fuel_core_types::{
     },
     tai64::Tai64,
 };
-use std::{
-    ops::Deref,
-    time::Duration,
-};
+use std::time::Duration;
 use tokio::{
     sync::{
         mpsc,
@@ -75,7 +62,7 @@ use tokio::{
 };
 use tokio_stream::StreamExt;
 
-pub type Service<T, B, I> = ServiceRunner<MainTask<T, B, I>>;
+pub type Service<T, B, I, S> = ServiceRunner<MainTask<T, B, I, S>>;
 #[derive(Clone)]
 pub struct SharedState {
     request_sender: mpsc::Sender<Request>,
@@ -129,9 +116,9 @@ pub(crate) enum RequestType {
     Trigger,
 }
 
-pub struct MainTask<T, B, I> {
+pub struct MainTask<T, B, I, S> {
     block_gas_limit: Word,
-    signing_key: Option<Secret<SecretKeyWrapper>>,
+    signer: S,
     block_producer: B,
     block_importer: I,
     txpool: T,
@@ -147,7 +134,7 @@ pub struct MainTask<T, B, I> {
     sync_task_handle: ServiceRunner<SyncTask>,
 }
 
-impl<T, B, I> MainTask<T, B, I>
+impl<T, B, I, S> MainTask<T, B, I, S>
 where
     T: TransactionPool,
     I: BlockImporter,
@@ -159,6 +146,7 @@ where
         block_producer: B,
         block_importer: I,
         p2p_port: P,
+        signer: S,
     ) -> Self {
         let tx_status_update_stream = txpool.transaction_status_events();
         let (request_sender, request_receiver) = mpsc::channel(1024);
@@ -170,7 +158,6 @@ where
 
         let Config {
             block_gas_limit,
-            signing_key,
             min_connected_reserved_peers,
             time_until_synced,
             trigger,
@@ -189,7 +176,7 @@ where
 
         Self {
             block_gas_limit,
-            signing_key,
+            signer,
             txpool,
             block_producer,
             block_importer,
@@ -245,11 +232,12 @@ where
     }
 }
 
-impl<D, T, B, I> MainTask<T, B, I>
+impl<D, T, B, I, S> MainTask<T, B, I, S>
 where
     T: TransactionPool,
     B: BlockProducer<Database = D>,
     I: BlockImporter<Database = D>,
+    S: BlockSigner,
 {
     // Request the block producer to make a new block, and return it when ready
     async fn signal_produce_block(
@@ -317,7 +305,7 @@ where
     ) -> anyhow::Result<()> {
         let last_block_created = Instant::now();
         // verify signing key is set
-        if self.signing_key.is_none() {
+        if !self.signer.is_available().await {
             return Err(anyhow!("unable to produce blocks without a consensus key"))
         }
 
@@ -351,7 +339,7 @@ where
         self.txpool.remove_txs(tx_ids_to_remove);
 
         // Sign the block and seal it
-        let seal = seal_block(&self.signing_key, &block)?;
+        let seal = self.signer.seal_block(&block).await?;
         let block = SealedBlock {
             entity: block,
             consensus: seal,
@@ -420,14 +408,14 @@ where
 }
 
 #[async_trait::async_trait]
-impl<T, B, I> RunnableService for MainTask<T, B, I>
+impl<T, B, I, S> RunnableService for MainTask<T, B, I, S>
 where
     Self: RunnableTask,
 {
     const NAME: &'static str = "PoA";
 
     type SharedData = SharedState;
-    type Task = MainTask<T, B, I>;
+    type Task = MainTask<T, B, I, S>;
     type TaskParams = ();
 
     fn shared_data(&self) -> Self::SharedData {
@@ -455,11 +443,12 @@ where
 }
 
 #[async_trait::async_trait]
-impl<D, T, B, I> RunnableTask for MainTask<T, B, I>
+impl<D, T, B, I, S> RunnableTask for MainTask<T, B, I, S>
 where
     T: TransactionPool,
     B: BlockProducer<Database = D>,
     I: BlockImporter<Database = D>,
+    S: BlockSigner,
 {
     async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
         let should_continue;
@@ -530,19 +519,21 @@ where
     }
 }
 
-pub fn new_service<D, T, B, I, P>(
+pub fn new_service<D, T, B, I, P, S>(
     last_block: &BlockHeader,
     config: Config,
     txpool: T,
     block_producer: B,
     block_importer: I,
     p2p_port: P,
-) -> Service<T, B, I>
+    signer: S,
+) -> Service<T, B, I, S>
 where
     T: TransactionPool + 'static,
     B: BlockProducer<Database = D> + 'static,
     I: BlockImporter<Database = D> + 'static,
     P: P2pPort,
+    S: BlockSigner + 'static,
 {
     Service::new(MainTask::new(
         last_block,
@@ -551,28 +542,10 @@ where
         block_producer,
         block_importer,
         p2p_port,
+        signer,
     ))
 }
 
-fn seal_block(
-    signing_key: &Option<Secret<SecretKeyWrapper>>,
-    block: &Block,
-) -> anyhow::Result<Consensus> {
-    if let Some(key) = signing_key {
-        let block_hash = block.id();
-        let message = block_hash.into_message();
-
-        // The length of the secret is checked
-        let signing_key = key.expose_secret().deref();
-
-        let poa_signature = Signature::sign(signing_key, &message);
-        let seal = Consensus::PoA(PoAConsensus::new(poa_signature));
-        Ok(seal)
-    } else {
-        Err(anyhow!("no PoA signing key configured"))
-    }
-}
-
 fn increase_time(time: Tai64, duration: Duration) -> anyhow::Result<Tai64> {
     let timestamp = time.0;
     let timestamp = timestamp
@@ -5,10 +5,15 @@ use crate::{
     ports::{
         MockBlockImporter,
         MockBlockProducer,
+        MockBlockSigner,
         MockP2pPort,
         MockTransactionPool,
     },
     service::MainTask,
+    signer::{
+        LocalBlockSigner,
+        SlashingProtection,
+    },
     Config,
     Service,
     Trigger,
@@ -24,8 +29,8 @@ use fuel_core_storage::{
 };
 use fuel_core_types::{
     blockchain::{
+        block::Block,
         header::BlockHeader,
-        primitives::SecretKeyWrapper,
         SealedBlock,
     },
     fuel_crypto::SecretKey,
@@ -159,6 +164,7 @@ impl TestContextBuilder {
             producer,
             importer,
             p2p_port,
+            test_signer(),
         );
         service.start().unwrap();
         TestContext { service }
@@ -166,7 +172,12 @@ impl TestContextBuilder {
 }
 
 struct TestContext {
-    service: Service<MockTransactionPool, MockBlockProducer, MockBlockImporter>,
+    service: Service<
+        MockTransactionPool,
+        MockBlockProducer,
+        MockBlockImporter,
+        LocalBlockSigner,
+    >,
 }
 
 impl TestContext {
@@ -329,7 +340,6 @@ async fn remove_skipped_transactions() {
     let config = Config {
         trigger: Trigger::Instant,
         block_gas_limit: 1000000,
-        signing_key: Some(Secret::new(secret_key.into())),
         metrics: false,
         ..Default::default()
     };
@@ -343,6 +353,10 @@ async fn remove_skipped_transactions() {
         block_producer,
         block_importer,
         p2p_port,
+        LocalBlockSigner::new(
+            Some(Secret::new(secret_key.into())),
+            SlashingProtection::in_memory(),
+        ),
     );
 
     assert!(task.produce_next_block().await.is_ok());
@@ -377,7 +391,6 @@ async fn does_not_produce_when_txpool_empty_in_instant_mode() {
     let config = Config {
         trigger: Trigger::Instant,
         block_gas_limit: 1000000,
-        signing_key: Some(Secret::new(secret_key.into())),
         metrics: false,
         ..Default::default()
     };
@@ -391,14 +404,153 @@ async fn does_not_produce_when_txpool_empty_in_instant_mode() {
         block_producer,
         block_importer,
         p2p_port,
+        LocalBlockSigner::new(
+            Some(Secret::new(secret_key.into())),
+            SlashingProtection::in_memory(),
+        ),
     );
 
     // simulate some txpool event to see if any block production is erroneously triggered
     task.on_txpool_event().await.unwrap();
 }
 
-fn test_signing_key() -> Secret<SecretKeyWrapper> {
+#[tokio::test]
+async fn does_not_import_block_when_signer_refuses_to_sign() {
+    // Given
+    let mut block_producer = MockBlockProducer::default();
+    block_producer
+        .expect_produce_and_execute_block()
+        .times(1)
+        .returning(|_, _, _, _| {
+            Ok(UncommittedResult::new(
+                ExecutionResult {
+                    block: Default::default(),
+                    skipped_transactions: Default::default(),
+                    tx_status: Default::default(),
+                    events: Default::default(),
+                },
+                StorageTransaction::new(EmptyStorage),
+            ))
+        });
+
+    let mut block_importer = MockBlockImporter::default();
+    block_importer
+        .expect_commit_result()
+        .returning(|_| panic!("Block importer should not be called"));
+    block_importer
+        .expect_block_stream()
+        .returning(|| Box::pin(tokio_stream::pending()));
+
+    let mut txpool = MockTransactionPool::no_tx_updates();
+    txpool.expect_remove_txs().returning(|_| vec![]);
+
+    let mut signer = MockBlockSigner::default();
+    signer.expect_is_available().returning(|| true);
+    signer.expect_seal_block().times(1).returning(|_| {
+        Err(anyhow::anyhow!(
+            "The block at this height is already signed"
+        ))
+    });
+
+    let config = Config {
+        trigger: Trigger::Instant,
+        block_gas_limit: 1000000,
+        metrics: false,
+        ..Default::default()
+    };
+
+    let mut task = MainTask::new(
+        &BlockHeader::new_block(BlockHeight::from(1u32), Tai64::now()),
+        config,
+        txpool,
+        block_producer,
+        block_importer,
+        generate_p2p_port(),
+        signer,
+    );
+
+    // When
+    let result = task.produce_next_block().await;
+
+    // Then
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn retries_block_at_the_same_height_after_failed_commit() {
+    // Given
+    let produced = Arc::new(StdMutex::new(0u64));
+    let mut block_producer = MockBlockProducer::default();
+    block_producer
+        .expect_produce_and_execute_block()
+        .times(2)
+        .returning(move |height, _, _, _| {
+            let mut produced = produced.lock().unwrap();
+            *produced += 1;
+            let mut block = Block::default();
+            block.header_mut().set_block_height(height);
+            block.header_mut().set_time(Tai64(*produced));
+            Ok(UncommittedResult::new(
+                ExecutionResult {
+                    block,
+                    skipped_transactions: Default::default(),
+                    tx_status: Default::default(),
+                    events: Default::default(),
+                },
+                StorageTransaction::new(EmptyStorage),
+            ))
+        });
+
+    let mut block_importer = MockBlockImporter::default();
+    let mut failed = false;
+    block_importer
+        .expect_commit_result()
+        .times(2)
+        .returning(move |_| {
+            if failed {
+                Ok(())
+            } else {
+                failed = true;
+                Err(anyhow::anyhow!("The import failed"))
+            }
+        });
+    block_importer
+        .expect_block_stream()
+        .returning(|| Box::pin(tokio_stream::pending()));
+
+    let mut txpool = MockTransactionPool::no_tx_updates();
+    txpool.expect_remove_txs().returning(|_| vec![]);
+
+    let config = Config {
+        trigger: Trigger::Instant,
+        block_gas_limit: 1000000,
+        metrics: false,
+        ..Default::default()
+    };
+
+    let mut task = MainTask::new(
+        &BlockHeader::new_block(BlockHeight::from(1u32), Tai64::now()),
+        config,
+        txpool,
+        block_producer,
+        block_importer,
+        generate_p2p_port(),
+        test_signer(),
+    );
+    assert!(task.produce_next_block().await.is_err());
+
+    // When
+    let result = task.produce_next_block().await;
+
+    // Then
+    assert!(result.is_ok());
+}
+
+fn test_signer() -> LocalBlockSigner {
     let mut rng = StdRng::seed_from_u64(0);
     let secret_key = SecretKey::random(&mut rng);
-    Secret::new(secret_key.into())
+    LocalBlockSigner::new(
+        Some(Secret::new(secret_key.into())),
+        SlashingProtection::in_memory(),
+    )
 }
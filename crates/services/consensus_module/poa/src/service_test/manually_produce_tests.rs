@@ -54,7 +54,6 @@ async fn can_manually_produce_block(
     ctx_builder.with_config(Config {
         trigger,
         block_gas_limit: 100_000,
-        signing_key: Some(test_signing_key()),
         metrics: false,
         consensus_params: consensus_params.clone(),
         ..Default::default()
@@ -83,8 +82,9 @@ async fn can_manually_produce_block(
     let mut producer = MockBlockProducer::default();
     producer
         .expect_produce_and_execute_block()
-        .returning(|_, time, _, _| {
+        .returning(|height, time, _, _| {
             let mut block = Block::default();
+            block.header_mut().set_block_height(height);
             block.header_mut().set_time(time);
             block.header_mut().recalculate_metadata();
             Ok(UncommittedResult::new(
@@ -13,7 +13,6 @@ async fn clean_startup_shutdown_each_trigger() -> anyhow::Result<()> {
         ctx_builder.with_config(Config {
             trigger,
             block_gas_limit: 100_000,
-            signing_key: Some(test_signing_key()),
             metrics: false,
             ..Default::default()
         });
@@ -34,7 +33,6 @@ async fn never_trigger_never_produces_blocks() {
     ctx_builder.with_config(Config {
         trigger: Trigger::Never,
         block_gas_limit: 100_000,
-        signing_key: Some(test_signing_key()),
         metrics: false,
         consensus_params: consensus_params.clone(),
         ..Default::default()
@@ -121,7 +119,6 @@ async fn instant_trigger_produces_block_instantly() {
     let mut ctx = DefaultContext::new(Config {
         trigger: Trigger::Instant,
         block_gas_limit: 100_000,
-        signing_key: Some(test_signing_key()),
         metrics: false,
         ..Default::default()
     });
@@ -141,7 +138,6 @@ async fn interval_trigger_produces_blocks_periodically() -> anyhow::Result<()> {
             block_time: Duration::new(2, 0),
         },
         block_gas_limit: 100_000,
-        signing_key: Some(test_signing_key()),
         metrics: false,
         ..Default::default()
     });
@@ -207,7 +203,6 @@ async fn interval_trigger_doesnt_react_to_full_txpool() -> anyhow::Result<()> {
             block_time: Duration::new(2, 0),
         },
         block_gas_limit: 100_000,
-        signing_key: Some(test_signing_key()),
         metrics: false,
         ..Default::default()
     });
@@ -0,0 +1,411 @@
+use crate::ports::BlockSigner;
+use anyhow::{
+    anyhow,
+    Context,
+};
+use fuel_core_types::{
+    blockchain::{
+        block::Block,
+        consensus::{
+            poa::PoAConsensus,
+            Consensus,
+        },
+        primitives::{
+            BlockId,
+            SecretKeyWrapper,
+        },
+    },
+    fuel_crypto::Signature,
+    fuel_types::BlockHeight,
+    secrecy::{
+        ExposeSecret,
+        Secret,
+    },
+};
+use std::{
+    ops::Deref,
+    path::{
+        Path,
+        PathBuf,
+    },
+    sync::Mutex,
+};
+
+/// The block signed by the signer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SignedBlock {
+    pub height: BlockHeight,
+    pub block_id: BlockId,
+}
+
+/// The guard against signing two different blocks at the same height.
+///
+/// It remembers the last signed block and refuses to sign a block at a lower height.
+/// The producer builds each block on top of the latest committed block, so the last
+/// signed block at the same height was not committed, e.g. because its import failed
+/// or the node stopped before the commit. Signing another block at this height
+/// is allowed, so the producer can retry.
+/// If the state file is set, the last signed block is persisted before the signature
+/// is released, and the guard survives restarts. After the rollback of the database,
+/// the record should be lowered with the [`SlashingProtection::rollback_to`].
+#[derive(Debug)]
+pub struct SlashingProtection {
+    last_signed: Mutex<Option<SignedBlock>>,
+    state_file: Option<PathBuf>,
+}
+
+impl SlashingProtection {
+    /// Creates the guard that forgets the signed blocks on restart.
+    pub fn in_memory() -> Self {
+        Self {
+            last_signed: Mutex::new(None),
+            state_file: None,
+        }
+    }
+
+    /// Creates the guard persisted in the `state_file`.
+    /// The last signed block is loaded from the file if it exists.
+    pub fn with_state_file(state_file: PathBuf) -> anyhow::Result<Self> {
+        let last_signed = if state_file.exists() {
+            let contents = std::fs::read_to_string(&state_file).with_context(|| {
+                format!(
+                    "Failed to read the slashing protection state at {}",
+                    state_file.display()
+                )
+            })?;
+            Some(parse_state(&contents).with_context(|| {
+                format!(
+                    "Invalid slashing protection state at {}",
+                    state_file.display()
+                )
+            })?)
+        } else {
+            None
+        };
+
+        Ok(Self {
+            last_signed: Mutex::new(last_signed),
+            state_file: Some(state_file),
+        })
+    }
+
+    /// Returns the last signed block.
+    pub fn last_signed(&self) -> Option<SignedBlock> {
+        *self.last_signed.lock().expect("The lock is poisoned")
+    }
+
+    /// Checks that the block at the `height` can be signed and records it as
+    /// the last signed block.
+    pub fn check_and_record(
+        &self,
+        height: BlockHeight,
+        block_id: BlockId,
+    ) -> anyhow::Result<()> {
+        let mut last_signed = self.last_signed.lock().expect("The lock is poisoned");
+        if let Some(last) = last_signed.as_ref() {
+            if height < last.height {
+                return Err(anyhow!(
+                    "Refusing to sign the block at height {height} because the block at \
+                    the higher height {} is already signed. If the database was rolled \
+                    back, roll back the slashing protection state as well",
+                    last.height
+                ))
+            }
+            if height == last.height {
+                if block_id == last.block_id {
+                    return Ok(())
+                }
+                tracing::warn!(
+                    "Signing the block {block_id} at height {height} instead of \
+                    the uncommitted block {}",
+                    last.block_id
+                );
+            }
+        }
+
+        let signed = SignedBlock { height, block_id };
+        if let Some(state_file) = &self.state_file {
+            persist_state(state_file, &signed)?;
+        }
+        *last_signed = Some(signed);
+        Ok(())
+    }
+
+    /// Lowers the last signed block to the `latest` block after the rollback
+    /// of the database, so blocks above it can be signed again.
+    /// Does nothing if the last signed block is not above the `latest` one.
+    pub fn rollback_to(&self, latest: SignedBlock) -> anyhow::Result<()> {
+        let mut last_signed = self.last_signed.lock().expect("The lock is poisoned");
+        match last_signed.as_ref() {
+            Some(last) if last.height > latest.height => {}
+            _ => return Ok(()),
+        }
+
+        if let Some(state_file) = &self.state_file {
+            persist_state(state_file, &latest)?;
+        }
+        *last_signed = Some(latest);
+        Ok(())
+    }
+}
+
+fn parse_state(contents: &str) -> anyhow::Result<SignedBlock> {
+    let mut parts = contents.split_whitespace();
+    let (height, block_id) = parts
+        .next()
+        .zip(parts.next())
+        .ok_or(anyhow!("Expected the height and the id of the block"))?;
+    let height = height.parse::<u32>().context("Invalid height")?;
+    let block_id = block_id
+        .parse::<BlockId>()
+        .map_err(|e| anyhow!("Invalid block id: {e}"))?;
+    if parts.next().is_some() {
+        return Err(anyhow!("Unexpected data after the id of the block"))
+    }
+    Ok(SignedBlock {
+        height: height.into(),
+        block_id,
+    })
+}
+
+fn persist_state(state_file: &Path, signed: &SignedBlock) -> anyhow::Result<()> {
+    use std::io::Write;
+
+    // Write to the temporary file first to not corrupt the state on a crash.
+    // Both the file and the directory are synced, so the state survives
+    // the power loss once the signature is released.
+    let tmp_file = state_file.with_extension("tmp");
+    let contents = format!("{} {}\n", *signed.height, signed.block_id);
+    let persist = || -> std::io::Result<()> {
+        let mut file = std::fs::File::create(&tmp_file)?;
+        file.write_all(contents.as_bytes())?;
+        file.sync_all()?;
+        std::fs::rename(&tmp_file, state_file)?;
+        sync_parent_dir(state_file)
+    };
+    persist().with_context(|| {
+        format!(
+            "Failed to persist the slashing protection state at {}",
+            state_file.display()
+        )
+    })
+}
+
+/// Syncs the directory of the `path`, so the rename of the file is durable.
+#[cfg(unix)]
+fn sync_parent_dir(path: &Path) -> std::io::Result<()> {
+    let parent = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    std::fs::File::open(parent)?.sync_all()
+}
+
+/// The directories can't be opened as files on other platforms,
+/// and the rename is durable once it returns.
+#[cfg(not(unix))]
+fn sync_parent_dir(_: &Path) -> std::io::Result<()> {
+    Ok(())
+}
+
+/// Signs the blocks with the secret key held by the node.
+///
+/// The signer always uses the slashing protection. By default, it is kept in memory,
+/// and the state file allows it to survive restarts.
+pub struct LocalBlockSigner {
+    signing_key: Option<Secret<SecretKeyWrapper>>,
+    protection: SlashingProtection,
+}
+
+impl LocalBlockSigner {
+    pub fn new(
+        signing_key: Option<Secret<SecretKeyWrapper>>,
+        protection: SlashingProtection,
+    ) -> Self {
+        Self {
+            signing_key,
+            protection,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl BlockSigner for LocalBlockSigner {
+    async fn seal_block(&self, block: &Block) -> anyhow::Result<Consensus> {
+        let key = self
+            .signing_key
+            .as_ref()
+            .ok_or(anyhow!("no PoA signing key configured"))?;
+        let block_id = block.id();
+        self.protection
+            .check_and_record(*block.header().height(), block_id)?;
+
+        // The length of the secret is checked
+        let signing_key = key.expose_secret().deref();
+
+        let poa_signature = Signature::sign(signing_key, &block_id.into_message());
+        Ok(Consensus::PoA(PoAConsensus::new(poa_signature)))
+    }
+
+    async fn is_available(&self) -> bool {
+        self.signing_key.is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_types::{
+        fuel_crypto::SecretKey,
+        tai64::Tai64,
+    };
+    use rand::{
+        rngs::StdRng,
+        Rng,
+        SeedableRng,
+    };
+
+    #[test]
+    fn check_and_record_allows_signing_the_same_block_again() {
+        // Given
+        let mut rng = StdRng::seed_from_u64(1);
+        let protection = SlashingProtection::in_memory();
+        let block_id: BlockId = rng.gen();
+        protection.check_and_record(1u32.into(), block_id).unwrap();
+
+        // When
+        let result = protection.check_and_record(1u32.into(), block_id);
+
+        // Then
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn check_and_record_allows_another_block_at_the_same_height() {
+        // Given
+        let mut rng = StdRng::seed_from_u64(2);
+        let protection = SlashingProtection::in_memory();
+        protection.check_and_record(1u32.into(), rng.gen()).unwrap();
+        let block_id: BlockId = rng.gen();
+
+        // When
+        let result = protection.check_and_record(1u32.into(), block_id);
+
+        // Then
+        assert!(result.is_ok());
+        assert_eq!(protection.last_signed().unwrap().block_id, block_id);
+    }
+
+    #[test]
+    fn check_and_record_refuses_lower_height() {
+        // Given
+        let mut rng = StdRng::seed_from_u64(3);
+        let protection = SlashingProtection::in_memory();
+        protection.check_and_record(2u32.into(), rng.gen()).unwrap();
+
+        // When
+        let result = protection.check_and_record(1u32.into(), rng.gen());
+
+        // Then
+        assert!(result.is_err());
+        assert_eq!(protection.last_signed().unwrap().height, 2u32.into());
+    }
+
+    #[tokio::test]
+    async fn local_signer_refuses_block_at_the_lower_height() {
+        // Given
+        let mut rng = StdRng::seed_from_u64(6);
+        let secret = SecretKey::random(&mut rng);
+        let signer = LocalBlockSigner::new(
+            Some(Secret::new(secret.into())),
+            SlashingProtection::in_memory(),
+        );
+        let mut first_block = Block::default();
+        first_block.header_mut().set_block_height(2u32.into());
+        let mut second_block = Block::default();
+        second_block.header_mut().set_block_height(1u32.into());
+        second_block.header_mut().set_time(Tai64(1));
+        signer.seal_block(&first_block).await.unwrap();
+
+        // When
+        let result = signer.seal_block(&second_block).await;
+
+        // Then
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn rollback_to_allows_signing_above_the_rolled_back_height() {
+        // Given
+        let mut rng = StdRng::seed_from_u64(7);
+        let dir = tempfile::tempdir().unwrap();
+        let state_file = dir.path().join("slashing_protection");
+        let protection = SlashingProtection::with_state_file(state_file.clone()).unwrap();
+        protection
+            .check_and_record(10u32.into(), rng.gen())
+            .unwrap();
+        let latest = SignedBlock {
+            height: 5u32.into(),
+            block_id: rng.gen(),
+        };
+
+        // When
+        protection.rollback_to(latest).unwrap();
+
+        // Then
+        let restored = SlashingProtection::with_state_file(state_file).unwrap();
+        assert_eq!(restored.last_signed(), Some(latest));
+        assert!(restored.check_and_record(6u32.into(), rng.gen()).is_ok());
+        assert!(restored.check_and_record(5u32.into(), rng.gen()).is_err());
+    }
+
+    #[test]
+    fn rollback_to_does_not_raise_the_last_signed_block() {
+        // Given
+        let mut rng = StdRng::seed_from_u64(8);
+        let protection = SlashingProtection::in_memory();
+        let signed = SignedBlock {
+            height: 5u32.into(),
+            block_id: rng.gen(),
+        };
+        protection
+            .check_and_record(signed.height, signed.block_id)
+            .unwrap();
+
+        // When
+        protection
+            .rollback_to(SignedBlock {
+                height: 10u32.into(),
+                block_id: rng.gen(),
+            })
+            .unwrap();
+
+        // Then
+        assert_eq!(protection.last_signed(), Some(signed));
+    }
+
+    #[test]
+    fn with_state_file_restores_last_signed_block() {
+        // Given
+        let mut rng = StdRng::seed_from_u64(4);
+        let dir = tempfile::tempdir().unwrap();
+        let state_file = dir.path().join("slashing_protection");
+        let signed = SignedBlock {
+            height: 5u32.into(),
+            block_id: rng.gen(),
+        };
+        let protection = SlashingProtection::with_state_file(state_file.clone()).unwrap();
+        protection
+            .check_and_record(signed.height, signed.block_id)
+            .unwrap();
+
+        // When
+        let restored = SlashingProtection::with_state_file(state_file).unwrap();
+
+        // Then
+        assert_eq!(restored.last_signed(), Some(signed));
+        assert!(restored
+            .check_and_record(signed.height.pred().unwrap(), rng.gen())
+            .is_err());
+    }
+}
@@ -1,12 +1,18 @@
-use fuel_core_types::fuel_tx::{
-    ConsensusParameters,
-    ContractId,
+use fuel_core_types::{
+    fuel_tx::{
+        ConsensusParameters,
+        ContractId,
+    },
+    fuel_types::Word,
 };
 
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug)]
 pub struct Config {
     /// Network-wide common parameters used for validating the chain
     pub consensus_parameters: ConsensusParameters,
+    /// The maximum gas of all transactions in the block.
+    /// Forced transactions that don't fit into it are skipped.
+    pub block_gas_limit: Word,
     /// The `ContractId` of the fee recipient.
     pub coinbase_recipient: ContractId,
     /// Print execution backtraces if transaction execution reverts.
@@ -14,3 +20,15 @@ pub struct Config {
     /// Default mode for utxo_validation
     pub utxo_validation_default: bool,
 }
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            consensus_parameters: Default::default(),
+            block_gas_limit: Word::MAX,
+            coinbase_recipient: Default::default(),
+            backtrace: false,
+            utxo_validation_default: false,
+        }
+    }
+}
@@ -43,6 +43,7 @@ use fuel_core_types::{
             CompressedCoinV1,
         },
         contract::ContractUtxoInfo,
+        relayer::transaction::RelayedTransaction,
     },
     fuel_asm::{
         RegId,
@@ -86,6 +87,7 @@ use fuel_core_types::{
         UtxoId,
     },
     fuel_types::{
+        canonical::Deserialize,
         BlockHeight,
         ContractId,
         MessageId,
@@ -133,7 +135,10 @@ use fuel_core_types::{
 use parking_lot::Mutex as ParkingMutex;
 use std::{
     borrow::Cow,
+    collections::HashSet,
+    iter::Peekable,
     sync::Arc,
+    vec,
 };
 use tracing::{
     debug,
@@ -547,14 +552,40 @@ where
         let mut remaining_gas_limit = component.gas_limit;
         let block_height = *block.header.height();
 
-        if self.relayer.enabled() {
-            self.process_da(block_st_transaction, &block.header, execution_data)?;
-        }
+        let forced_transactions = if self.relayer.enabled() {
+            self.process_da(block_st_transaction, &block.header, execution_data)?
+        } else {
+            Vec::with_capacity(0)
+        };
 
         // ALl transactions should be in the `TxSource`.
         // We use `block.transactions` to store executed transactions.
         debug_assert!(block.transactions.is_empty());
-        let mut iter = source.next(remaining_gas_limit).into_iter().peekable();
+        // The block under validation already contains forced transactions at the start,
+        // so we need its transactions before processing them. During the production,
+        // forced transactions go first and reduce the gas left for the `TxSource`.
+        let mut iter = if execution_kind == ExecutionKind::Validation {
+            source.next(remaining_gas_limit).into_iter().peekable()
+        } else {
+            Vec::new().into_iter().peekable()
+        };
+
+        if execution_kind != ExecutionKind::DryRun {
+            self.execute_forced_transactions(
+                forced_transactions,
+                &mut iter,
+                block_st_transaction,
+                block,
+                execution_data,
+                execution_kind,
+            )?;
+        }
+
+        if execution_kind != ExecutionKind::Validation {
+            remaining_gas_limit =
+                component.gas_limit.saturating_sub(execution_data.used_gas);
+            iter = source.next(remaining_gas_limit).into_iter().peekable();
+        }
 
         let mut execute_transaction = |execution_data: &mut ExecutionData,
                                        tx: MaybeCheckedTransaction|
@@ -659,12 +690,14 @@ where
         Ok(data)
     }
 
+    /// Processes the events from the DA layer and returns the transactions
+    /// that must be forcibly included at the start of the block.
     fn process_da(
         &self,
         block_st_transaction: &mut D,
         header: &PartialBlockHeader,
         execution_data: &mut ExecutionData,
-    ) -> ExecutorResult<()> {
+    ) -> ExecutorResult<Vec<RelayedTransaction>> {
         let block_height = *header.height();
         let prev_block_height = block_height
             .pred()
@@ -679,6 +712,7 @@ where
             return Err(ExecutorError::DaHeightExceededItsLimit)
         };
 
+        let mut forced_transactions = vec![];
         for da_height in next_unprocessed_da_height..=header.da_height.0 {
             let da_height = da_height.into();
             let events = self
@@ -698,6 +732,119 @@ where
                             .events
                             .push(ExecutorEvent::MessageImported(message));
                     }
+                    Event::Transaction(transaction) => {
+                        if transaction.da_height() != da_height {
+                            return Err(ExecutorError::RelayerGivesIncorrectMessages)
+                        }
+                        forced_transactions.push(transaction);
+                    }
+                }
+            }
+        }
+
+        Ok(forced_transactions)
+    }
+
+    /// Executes the forced transactions relayed from the DA layer.
+    ///
+    /// During the production, valid forced transactions are included into the block,
+    /// while invalid ones are skipped with the `ForcedTransactionFailed` event.
+    /// During the validation, the block must start with all valid forced transactions,
+    /// in the same order. The forced transaction missing in the block is re-executed
+    /// to prove that it is invalid.
+    ///
+    /// Forced transactions are limited by the block gas limit from the config instead
+    /// of the gas limit of the component, because the validator doesn't know
+    /// the limit used by the producer. Only the first transaction with the same
+    /// nonce is processed.
+    fn execute_forced_transactions(
+        &self,
+        forced_transactions: Vec<RelayedTransaction>,
+        block_transactions: &mut Peekable<vec::IntoIter<MaybeCheckedTransaction>>,
+        block_st_transaction: &mut D,
+        block: &mut PartialFuelBlock,
+        execution_data: &mut ExecutionData,
+        execution_kind: ExecutionKind,
+    ) -> ExecutorResult<()> {
+        let block_height = *block.header.height();
+        let chain_id = self.config.consensus_parameters.chain_id;
+        let mut processed_nonces = HashSet::with_capacity(forced_transactions.len());
+
+        for relayed_transaction in forced_transactions {
+            let nonce = *relayed_transaction.nonce();
+            if !processed_nonces.insert(nonce) {
+                debug!("Skipping the duplicated forced transaction {nonce:#x}");
+                continue
+            }
+
+            let remaining_gas = self
+                .config
+                .block_gas_limit
+                .saturating_sub(execution_data.used_gas);
+            let checked_tx = match self.check_relayed_transaction(
+                relayed_transaction,
+                block_height,
+                remaining_gas,
+            ) {
+                Ok(checked_tx) => MaybeCheckedTransaction::CheckedTransaction(checked_tx),
+                Err(failure) => {
+                    execution_data
+                        .events
+                        .push(ExecutorEvent::ForcedTransactionFailed {
+                            nonce,
+                            block_height,
+                            failure,
+                        });
+                    continue
+                }
+            };
+            let tx_id = checked_tx.id(&chain_id);
+
+            let included_into_block = execution_kind == ExecutionKind::Validation
+                && matches!(block_transactions.peek(), Some(tx) if tx.id(&chain_id) == tx_id);
+            let (tx, kind) = if included_into_block {
+                let tx = block_transactions
+                    .next()
+                    .expect("We've checked above that the transaction is present");
+                (tx, ExecutionKind::Validation)
+            } else {
+                (checked_tx, ExecutionKind::Production)
+            };
+
+            let tx_count = execution_data.tx_count;
+            let mut tx_st_transaction = block_st_transaction.transaction();
+            let result = self.execute_transaction(
+                tx,
+                &tx_id,
+                &block.header,
+                execution_data,
+                kind,
+                &mut tx_st_transaction,
+            );
+
+            match result {
+                Ok(_)
+                    if execution_kind == ExecutionKind::Validation
+                        && !included_into_block =>
+                {
+                    return Err(ExecutorError::ForcedTransactionIsMissing(tx_id))
+                }
+                Ok(tx) => {
+                    tx_st_transaction.commit()?;
+                    block.transactions.push(tx);
+                    execution_data.tx_count = tx_count
+                        .checked_add(1)
+                        .ok_or(ExecutorError::TooManyTransactions)?;
+                }
+                Err(err) if included_into_block => return Err(err),
+                Err(err) => {
+                    execution_data
+                        .events
+                        .push(ExecutorEvent::ForcedTransactionFailed {
+                            nonce,
+                            block_height,
+                            failure: err.to_string(),
+                        });
                 }
             }
         }
@@ -705,6 +852,45 @@ where
         Ok(())
     }
 
+    /// Decodes the relayed transaction and performs the basic checks.
+    /// Returns the reason of the failure if the transaction can't be included.
+    fn check_relayed_transaction(
+        &self,
+        relayed_transaction: RelayedTransaction,
+        block_height: BlockHeight,
+        remaining_gas: Word,
+    ) -> Result<CheckedTransaction, String> {
+        let tx = Transaction::from_bytes(relayed_transaction.serialized_transaction())
+            .map_err(|e| format!("Failed to decode the transaction: {e:?}"))?;
+        let checked_tx: CheckedTransaction = tx
+            .into_checked_basic(block_height, &self.config.consensus_parameters)
+            .map_err(|e| format!("The transaction is invalid: {e:?}"))?
+            .into();
+
+        let max_gas = match &checked_tx {
+            CheckedTransaction::Script(script) => script.metadata().fee.max_gas(),
+            CheckedTransaction::Create(create) => create.metadata().fee.max_gas(),
+            CheckedTransaction::Mint(_) => {
+                return Err("The `Mint` transaction can't be forced".to_string())
+            }
+        };
+
+        if max_gas > relayed_transaction.max_gas() {
+            return Err(format!(
+                "The transaction max gas {max_gas} exceeds the max gas {} paid on the DA layer",
+                relayed_transaction.max_gas()
+            ))
+        }
+
+        if max_gas > remaining_gas {
+            return Err(format!(
+                "The transaction max gas {max_gas} exceeds the gas {remaining_gas} left in the block"
+            ))
+        }
+
+        Ok(checked_tx)
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn execute_transaction(
         &self,
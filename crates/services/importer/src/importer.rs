@@ -3,6 +3,7 @@ use crate::{
     ports::{
         BlockVerifier,
         Executor,
+        HeaderDatabase,
         ImporterDatabase,
     },
     Config,
@@ -10,20 +11,27 @@ use crate::{
 use fuel_core_metrics::importer::importer_metrics;
 use fuel_core_storage::{
     not_found,
-    transactional::StorageTransaction,
+    transactional::{
+        StorageTransaction,
+        Transactional,
+    },
     Error as StorageError,
 };
 use fuel_core_types::{
     blockchain::{
+        block::Block,
         consensus::{
             Consensus,
             Sealed,
         },
         primitives::BlockId,
         SealedBlock,
+        SealedBlockHeader,
     },
+    fuel_tx::Transaction,
     fuel_types::{
         BlockHeight,
+        Bytes32,
         ChainId,
     },
     services::{
@@ -91,6 +99,20 @@ pub enum Error {
     ExecuteGenesis,
     #[display(fmt = "The database already contains the data at the height {_0}.")]
     NotUnique(BlockHeight),
+    #[display(
+        fmt = "The previous root of the header doesn't match the local one. Expected {_0} != Actual {_1}"
+    )]
+    InvalidPrevRoot(Bytes32, Bytes32),
+    #[display(
+        fmt = "The block at height {_0} wasn't imported by the header only or already has the body."
+    )]
+    NotBodilessBlock(BlockHeight),
+    #[display(
+        fmt = "The transactions don't match the header of the block at height {_0}."
+    )]
+    InvalidBlockBody(BlockHeight),
+    #[display(fmt = "The import of the state failed: {_0}.")]
+    FailedStateImport(anyhow::Error),
     #[from]
     StorageError(StorageError),
     UnsupportedConsensusVariant(String),
@@ -464,6 +486,149 @@ where
     }
 }
 
+impl<IDatabase, E, V> Importer<IDatabase, E, V>
+where
+    IDatabase: ImporterDatabase + Transactional,
+    IDatabase::Storage: HeaderDatabase,
+{
+    /// Commits the `SealedBlockHeader` without execution of the block. The header should
+    /// extend the local chain, and its consensus should be verified by the caller.
+    ///
+    /// The header doesn't have an execution result, so the subscribers are notified
+    /// about the block without transactions, events, and statuses.
+    /// Only one commit may be in progress at the same time.
+    pub async fn commit_header(
+        &self,
+        sealed_header: SealedBlockHeader,
+    ) -> Result<(), Error> {
+        self.commit_header_with_state(sealed_header, |_| Ok(()))
+            .await
+    }
+
+    /// Commits the `SealedBlockHeader` in the same way as [`Importer::commit_header`],
+    /// but also applies the `import_state` to the database within the same transaction.
+    /// It is used to insert the state downloaded at the checkpoint atomically with the
+    /// header of the checkpoint.
+    pub async fn commit_header_with_state<F>(
+        &self,
+        sealed_header: SealedBlockHeader,
+        import_state: F,
+    ) -> Result<(), Error>
+    where
+        F: FnOnce(&mut IDatabase::Storage) -> anyhow::Result<()>,
+    {
+        let _guard = self.lock()?;
+        // It is safe to unwrap the channel because we have the `_guard`.
+        let previous_block_result = self
+            .prev_block_process_result
+            .lock()
+            .expect("poisoned")
+            .take();
+
+        // Await until all receivers of the notification process the result.
+        if let Some(channel) = previous_block_result {
+            let _ = channel.await;
+        }
+
+        let header = &sealed_header.entity;
+        let actual_next_height = *header.height();
+
+        match &sealed_header.consensus {
+            Consensus::PoA(_) => {}
+            consensus => {
+                return Err(Error::UnsupportedConsensusVariant(format!(
+                    "{:?}",
+                    consensus
+                )))
+            }
+        }
+
+        if actual_next_height == BlockHeight::from(0u32) {
+            return Err(Error::ZeroNonGenericHeight)
+        }
+
+        let last_db_height = self
+            .database
+            .latest_block_height()?
+            .ok_or(not_found!("Latest block height"))?;
+        let expected_next_height: BlockHeight = last_db_height
+            .checked_add(1u32)
+            .ok_or(Error::Overflow)?
+            .into();
+
+        if expected_next_height != actual_next_height {
+            return Err(Error::IncorrectBlockHeight(
+                expected_next_height,
+                actual_next_height,
+            ))
+        }
+
+        let mut db_tx = self.database.transaction();
+        let db = db_tx.as_mut();
+
+        let expected_prev_root = db.block_header_merkle_root(&last_db_height)?;
+        if header.prev_root() != &expected_prev_root {
+            return Err(Error::InvalidPrevRoot(
+                expected_prev_root,
+                *header.prev_root(),
+            ))
+        }
+
+        if !db.store_new_header(&sealed_header)? {
+            return Err(Error::NotUnique(expected_next_height))
+        }
+
+        import_state(db).map_err(Error::FailedStateImport)?;
+
+        db_tx.commit()?;
+
+        importer_metrics()
+            .block_height
+            .set(*actual_next_height.deref() as i64);
+
+        tracing::info!("Committed header {:#x}", header.id());
+
+        let sealed_block = Sealed {
+            entity: Block::header_only(sealed_header.entity),
+            consensus: sealed_header.consensus,
+        };
+        let result = ImportResult::new_from_network(sealed_block, vec![], vec![]);
+        let (sender, receiver) = oneshot::channel();
+        let _ = self.broadcast.send(Arc::new(Awaiter::new(result, sender)));
+        *self.prev_block_process_result.lock().expect("poisoned") = Some(receiver);
+
+        Ok(())
+    }
+
+    /// Commits the body of the block imported by the header only, e.g. fetched
+    /// from peers on demand. The `transactions` are verified against the header.
+    ///
+    /// The body doesn't change the state, so it can be committed concurrently
+    /// with the import of new blocks.
+    pub fn commit_block_body(
+        &self,
+        height: &BlockHeight,
+        transactions: &[Transaction],
+    ) -> Result<(), Error> {
+        let mut db_tx = self.database.transaction();
+        let db = db_tx.as_mut();
+
+        let header = db
+            .bodiless_block_header(height)?
+            .ok_or(Error::NotBodilessBlock(*height))?;
+        if !header.validate_transactions(transactions) {
+            return Err(Error::InvalidBlockBody(*height))
+        }
+        db.store_block_body(&self.chain_id, height, transactions)?;
+
+        db_tx.commit()?;
+
+        tracing::info!("Committed body of the block {:#x}", header.id());
+
+        Ok(())
+    }
+}
+
 trait ShouldBeUnique {
     fn should_be_unique(&self, height: &BlockHeight) -> Result<(), Error>;
 }
@@ -2,6 +2,7 @@ use crate::{
     importer::Error,
     ports::{
         ExecutorDatabase,
+        HeaderDatabase,
         ImporterDatabase,
         MockBlockVerifier,
         MockExecutor,
@@ -13,6 +14,7 @@ use fuel_core_storage::{
     transactional::{
         StorageTransaction,
         Transaction as TransactionTrait,
+        Transactional,
     },
     Error as StorageError,
     Result as StorageResult,
@@ -21,11 +23,20 @@ use fuel_core_types::{
     blockchain::{
         block::Block,
         consensus::Consensus,
+        header::{
+            BlockHeader,
+            PartialBlockHeader,
+        },
         SealedBlock,
+        SealedBlockHeader,
+    },
+    fuel_tx::{
+        Transaction,
+        TxId,
     },
-    fuel_tx::TxId,
     fuel_types::{
         BlockHeight,
+        Bytes32,
         ChainId,
     },
     services::{
@@ -62,9 +73,33 @@ mockall::mock! {
         ) -> StorageResult<bool>;
     }
 
+    impl HeaderDatabase for Database {
+        fn block_header_merkle_root(&self, height: &BlockHeight) -> StorageResult<Bytes32>;
+
+        fn store_new_header(&mut self, header: &SealedBlockHeader) -> StorageResult<bool>;
+
+        fn bodiless_block_header(
+            &self,
+            height: &BlockHeight,
+        ) -> StorageResult<Option<BlockHeader>>;
+
+        fn store_block_body(
+            &mut self,
+            chain_id: &ChainId,
+            height: &BlockHeight,
+            transactions: &[Transaction],
+        ) -> StorageResult<()>;
+    }
+
     impl TransactionTrait<MockDatabase> for Database {
         fn commit(&mut self) -> StorageResult<()>;
     }
+
+    impl Transactional for Database {
+        type Storage = MockDatabase;
+
+        fn transaction(&self) -> StorageTransaction<MockDatabase>;
+    }
 }
 
 impl AsMut<MockDatabase> for MockDatabase {
@@ -142,6 +177,35 @@ where
     }
 }
 
+fn header_db<R, S>(
+    height: Option<u32>,
+    prev_root: R,
+    store_header: S,
+    commits: usize,
+) -> impl Fn() -> MockDatabase
+where
+    R: Fn() -> StorageResult<Bytes32> + Send + Clone + 'static,
+    S: Fn() -> StorageResult<bool> + Send + Clone + 'static,
+{
+    move || {
+        let prev_root = prev_root.clone();
+        let store_header = store_header.clone();
+        let mut db_tx = MockDatabase::default();
+        db_tx
+            .expect_block_header_merkle_root()
+            .returning(move |_| prev_root());
+        db_tx
+            .expect_store_new_header()
+            .returning(move |_| store_header());
+        db_tx.expect_commit().times(commits).returning(|| Ok(()));
+
+        let mut db = underlying_db(ok(height))();
+        db.expect_transaction()
+            .return_once(move || StorageTransaction::new(db_tx));
+        db
+    }
+}
+
 fn ok<T: Clone, Err>(entity: T) -> impl Fn() -> Result<T, Err> + Clone {
     move || Ok(entity.clone())
 }
@@ -439,6 +503,173 @@ async fn execute_and_commit_fail_when_locked() {
     );
 }
 
+//////////////////////////// Header ////////////////////////////
+#[test_case(
+    poa_block(113),
+    header_db(Some(112), ok(Bytes32::zeroed()), ok(true), 1)
+    => Ok(());
+    "successfully commits header when it extends the latest block"
+)]
+#[test_case(
+    genesis(113),
+    header_db(Some(112), ok(Bytes32::zeroed()), ok(true), 0)
+    => Err(Error::UnsupportedConsensusVariant(format!("{:?}", Consensus::Genesis(Default::default()))));
+    "fails to commit genesis header"
+)]
+#[test_case(
+    poa_block(0),
+    header_db(Some(0), ok(Bytes32::zeroed()), ok(true), 0)
+    => Err(Error::ZeroNonGenericHeight);
+    "fails to commit header with height 0"
+)]
+#[test_case(
+    poa_block(113),
+    header_db(Some(111), ok(Bytes32::zeroed()), ok(true), 0)
+    => Err(Error::IncorrectBlockHeight(112u32.into(), 113u32.into()));
+    "fails to commit header at height 113 when latest block height is 111"
+)]
+#[test_case(
+    poa_block(113),
+    header_db(Some(112), ok(Bytes32::new([1; 32])), ok(true), 0)
+    => Err(Error::InvalidPrevRoot(Bytes32::new([1; 32]), Bytes32::zeroed()));
+    "fails to commit header when previous root doesn't match"
+)]
+#[test_case(
+    poa_block(113),
+    header_db(Some(112), storage_failure, ok(true), 0)
+    => Err(storage_failure_error());
+    "fails to commit header when database fails to calculate the root"
+)]
+#[test_case(
+    poa_block(113),
+    header_db(Some(112), ok(Bytes32::zeroed()), ok(false), 0)
+    => Err(Error::NotUnique(113u32.into()));
+    "fails to commit header when block exists"
+)]
+#[tokio::test]
+async fn commit_header(
+    sealed_block: SealedBlock,
+    underlying_db: impl Fn() -> MockDatabase,
+) -> Result<(), Error> {
+    let importer = Importer::new(Default::default(), underlying_db(), (), ());
+    let sealed_header = SealedBlockHeader {
+        entity: sealed_block.entity.header().clone(),
+        consensus: sealed_block.consensus,
+    };
+
+    let mut imported_blocks = importer.subscribe();
+    let result = importer.commit_header(sealed_header.clone()).await;
+
+    if result.is_ok() {
+        // The header is broadcast as the block without transactions.
+        let imported = imported_blocks.try_recv().expect("The header is broadcast");
+        assert_eq!(imported.sealed_block.entity.header(), &sealed_header.entity);
+        assert!(imported.sealed_block.entity.transactions().is_empty());
+        assert!(imported.tx_status.is_empty());
+    } else {
+        assert!(matches!(
+            imported_blocks.try_recv(),
+            Err(TryRecvError::Empty)
+        ));
+    }
+    result
+}
+
+#[tokio::test]
+async fn commit_header_with_state_fails_when_state_import_fails() {
+    // given
+    let importer = Importer::new(
+        Default::default(),
+        header_db(Some(112), ok(Bytes32::zeroed()), ok(true), 0)(),
+        (),
+        (),
+    );
+    let sealed_block = poa_block(113);
+    let sealed_header = SealedBlockHeader {
+        entity: sealed_block.entity.header().clone(),
+        consensus: sealed_block.consensus,
+    };
+
+    // when
+    let result = importer
+        .commit_header_with_state(sealed_header, |_| Err(anyhow!("Invalid state")))
+        .await;
+
+    // then
+    assert_eq!(
+        result,
+        Err(Error::FailedStateImport(anyhow!("Invalid state")))
+    );
+}
+
+#[tokio::test]
+async fn commit_header_fail_when_locked() {
+    let importer = Importer::new(Default::default(), MockDatabase::default(), (), ());
+
+    let _guard = importer.lock();
+    assert_eq!(
+        importer.commit_header(Default::default()).await,
+        Err(Error::SemaphoreError(TryAcquireError::NoPermits))
+    );
+}
+
+fn body_db(header: Option<BlockHeader>, commits: usize) -> MockDatabase {
+    let mut db_tx = MockDatabase::default();
+    db_tx
+        .expect_bodiless_block_header()
+        .returning(move |_| Ok(header.clone()));
+    db_tx
+        .expect_store_block_body()
+        .times(commits)
+        .returning(|_, _, _| Ok(()));
+    db_tx.expect_commit().times(commits).returning(|| Ok(()));
+
+    let mut db = MockDatabase::default();
+    db.expect_transaction()
+        .return_once(move || StorageTransaction::new(db_tx));
+    db
+}
+
+#[test]
+fn commit_block_body_stores_transactions_matching_the_header() {
+    // given
+    let transactions = vec![Transaction::default_test_tx()];
+    let header = PartialBlockHeader::default().generate(&transactions, &[]);
+    let importer = Importer::new(Default::default(), body_db(Some(header), 1), (), ());
+
+    // when
+    let result = importer.commit_block_body(&113u32.into(), &transactions);
+
+    // then
+    assert_eq!(result, Ok(()));
+}
+
+#[test]
+fn commit_block_body_fails_when_transactions_do_not_match_the_header() {
+    // given
+    let header = PartialBlockHeader::default().generate(&[], &[]);
+    let importer = Importer::new(Default::default(), body_db(Some(header), 0), (), ());
+
+    // when
+    let result =
+        importer.commit_block_body(&113u32.into(), &[Transaction::default_test_tx()]);
+
+    // then
+    assert_eq!(result, Err(Error::InvalidBlockBody(113u32.into())));
+}
+
+#[test]
+fn commit_block_body_fails_when_block_has_body() {
+    // given
+    let importer = Importer::new(Default::default(), body_db(None, 0), (), ());
+
+    // when
+    let result = importer.commit_block_body(&113u32.into(), &[]);
+
+    // then
+    assert_eq!(result, Err(Error::NotBodilessBlock(113u32.into())));
+}
+
 #[test]
 fn one_lock_at_the_same_time() {
     let importer = Importer::new(
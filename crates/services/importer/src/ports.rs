@@ -6,10 +6,14 @@ use fuel_core_types::{
     blockchain::{
         block::Block,
         consensus::Consensus,
+        header::BlockHeader,
         SealedBlock,
+        SealedBlockHeader,
     },
+    fuel_tx::Transaction,
     fuel_types::{
         BlockHeight,
+        Bytes32,
         ChainId,
     },
     services::executor::{
@@ -52,6 +56,33 @@ pub trait ExecutorDatabase: ImporterDatabase {
     ) -> StorageResult<bool>;
 }
 
+/// The port for the database used to import headers without execution of blocks.
+pub trait HeaderDatabase: ImporterDatabase {
+    /// Returns the root of the block header merkle tree at the `height`.
+    fn block_header_merkle_root(&self, height: &BlockHeight) -> StorageResult<Bytes32>;
+
+    /// Inserts the `SealedBlockHeader` without transactions of the block
+    /// and marks the block as imported by the header only.
+    ///
+    /// The method returns `true` if the header is a new, otherwise `false`.
+    fn store_new_header(&mut self, header: &SealedBlockHeader) -> StorageResult<bool>;
+
+    /// Returns the header of the block imported by the header only,
+    /// if the body of the block is not stored yet.
+    fn bodiless_block_header(
+        &self,
+        height: &BlockHeight,
+    ) -> StorageResult<Option<BlockHeader>>;
+
+    /// Stores the `transactions` of the block imported by the header only.
+    fn store_block_body(
+        &mut self,
+        chain_id: &ChainId,
+        height: &BlockHeight,
+        transactions: &[Transaction],
+    ) -> StorageResult<()>;
+}
+
 #[cfg_attr(test, mockall::automock)]
 /// The verifier of the block.
 pub trait BlockVerifier: Send + Sync {
@@ -3,7 +3,10 @@ use crate::{
         postcard::PostcardCodec,
         NetworkCodec,
     },
-    config::Config,
+    config::{
+        fuel_upgrade::Checksum,
+        Config,
+    },
     discovery,
     gossipsub::{
         config::build_gossipsub_behaviour,
@@ -37,6 +40,25 @@ use libp2p::{
     PeerId,
 };
 
+/// The protocol version reported by the identify protocol.
+/// It is followed by the checksum of the genesis and chain id of the node.
+const IDENTIFY_PROTOCOL_VERSION: &str = "/fuel/1.0";
+
+/// Returns the protocol version that advertises the chain with the `checksum`.
+pub(crate) fn identify_protocol_version(checksum: &Checksum) -> String {
+    format!("{IDENTIFY_PROTOCOL_VERSION}/{}", hex::encode(checksum))
+}
+
+/// Returns `true` if the `protocol_version` reported by the peer advertises another
+/// chain than the one with the `checksum`. Peers that don't advertise their chain
+/// are not judged.
+pub(crate) fn is_another_chain(protocol_version: &str, checksum: &Checksum) -> bool {
+    protocol_version
+        .strip_prefix(IDENTIFY_PROTOCOL_VERSION)
+        .and_then(|suffix| suffix.strip_prefix('/'))
+        .is_some_and(|peer_checksum| peer_checksum != hex::encode(checksum))
+}
+
 /// Handles all p2p protocols needed for Fuel.
 #[derive(NetworkBehaviour)]
 pub struct FuelBehaviour {
@@ -97,7 +119,7 @@ impl FuelBehaviour {
 
         let identify = {
             let identify_config = identify::Config::new(
-                "/fuel/1.0".to_string(),
+                identify_protocol_version(&p2p_config.checksum),
                 p2p_config.keypair.public(),
             );
             if let Some(interval) = p2p_config.identify_interval {
@@ -213,4 +235,8 @@ impl FuelBehaviour {
     pub fn block_peer(&mut self, peer_id: PeerId) {
         self.blocked_peer.block_peer(peer_id)
     }
+
+    pub fn unblock_peer(&mut self, peer_id: PeerId) {
+        self.blocked_peer.unblock_peer(peer_id)
+    }
 }
@@ -39,6 +39,11 @@ fn serialize<D: Serialize>(data: &D) -> Result<Vec<u8>, io::Error> {
         .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))
 }
 
+/// The error returned when the response exceeds the maximum allowed size.
+#[derive(Debug, thiserror::Error)]
+#[error("The response exceeds the maximum allowed size of {0} bytes")]
+pub struct ResponseTooLarge(pub usize);
+
 #[derive(Debug, Clone)]
 pub struct PostcardCodec {
     /// Used for `max_size` parameter when reading Response Message
@@ -98,11 +103,19 @@ impl request_response::Codec for PostcardCodec {
         T: AsyncRead + Unpin + Send,
     {
         let mut response = Vec::new();
+        // Read one byte more than allowed to detect oversized responses
         socket
-            .take(self.max_response_size as u64)
+            .take((self.max_response_size as u64).saturating_add(1))
             .read_to_end(&mut response)
             .await?;
 
+        if response.len() > self.max_response_size {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                ResponseTooLarge(self.max_response_size),
+            ))
+        }
+
         deserialize(&response)
     }
 
@@ -177,6 +190,8 @@ impl AsRef<str> for MessageExchangePostcardProtocol {
 
 #[cfg(test)]
 mod tests {
+    #![allow(non_snake_case)]
+
     use super::*;
     use crate::request_response::messages::MAX_REQUEST_SIZE;
 
@@ -186,4 +201,29 @@ mod tests {
         let m = RequestMessage::Transactions(arbitrary_range);
         assert!(postcard::to_stdvec(&m).unwrap().len() <= MAX_REQUEST_SIZE);
     }
+
+    #[tokio::test]
+    async fn read_response__fails_with_response_too_large() {
+        // given
+        let max_response_size = 8;
+        let mut codec = PostcardCodec::new(max_response_size);
+        let response = ResponseMessage::Transactions(None);
+        let mut encoded = postcard::to_stdvec(&response).unwrap();
+        encoded.resize(max_response_size + 1, 0);
+        let mut socket = futures::io::Cursor::new(encoded);
+
+        // when
+        let result = request_response::Codec::read_response(
+            &mut codec,
+            &MessageExchangePostcardProtocol,
+            &mut socket,
+        )
+        .await;
+
+        // then
+        let error = result.expect_err("Should fail to read the oversized response");
+        assert!(error
+            .get_ref()
+            .is_some_and(|error| error.is::<ResponseTooLarge>()));
+    }
 }
@@ -4,7 +4,10 @@ use crate::{
     peer_manager::ConnectionState,
     TryPeerId,
 };
-use fuel_core_types::blockchain::consensus::Genesis;
+use fuel_core_types::{
+    blockchain::consensus::Genesis,
+    services::p2p::peer_reputation::ReputationConfig,
+};
 
 use libp2p::{
     core::{
@@ -117,6 +120,10 @@ pub struct Config<State = Initialized> {
     /// The duration between the last successful outbound or inbound ping
     /// and the next outbound ping
     pub info_interval: Option<Duration>,
+    /// How long a peer stays banned after its reputation drops below the allowed threshold
+    pub peer_ban_duration: Duration,
+    /// The score deltas of reputation events and the limits of the peer score
+    pub reputation_config: ReputationConfig,
 
     // `Gossipsub` config
     pub gossipsub_config: gossipsub::Config,
@@ -177,6 +184,8 @@ impl Config<NotInitialized> {
             reserved_nodes_only_mode: self.reserved_nodes_only_mode,
             identify_interval: self.identify_interval,
             info_interval: self.info_interval,
+            peer_ban_duration: self.peer_ban_duration,
+            reputation_config: self.reputation_config,
             gossipsub_config: self.gossipsub_config,
             heartbeat_config: self.heartbeat_config,
             set_request_timeout: self.set_request_timeout,
@@ -232,6 +241,8 @@ impl Config<NotInitialized> {
             heartbeat_max_time_since_last: Duration::from_secs(40),
             info_interval: Some(Duration::from_secs(3)),
             identify_interval: Some(Duration::from_secs(5)),
+            peer_ban_duration: Duration::from_secs(24 * 60 * 60),
+            reputation_config: ReputationConfig::default(),
             metrics: false,
             state: NotInitialized,
         }
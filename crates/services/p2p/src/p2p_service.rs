@@ -1,14 +1,19 @@
 use crate::{
     behavior::{
+        is_another_chain,
         FuelBehaviour,
         FuelBehaviourEvent,
     },
     codecs::{
-        postcard::PostcardCodec,
+        postcard::{
+            PostcardCodec,
+            ResponseTooLarge,
+        },
         GossipsubCodec,
     },
     config::{
         build_transport_function,
+        fuel_upgrade::Checksum,
         Config,
     },
     gossipsub::{
@@ -37,7 +42,11 @@ use crate::{
 use fuel_core_metrics::p2p_metrics::p2p_metrics;
 use fuel_core_types::{
     fuel_types::BlockHeight,
-    services::p2p::peer_reputation::AppScore,
+    services::p2p::peer_reputation::{
+        AppScore,
+        ReputationConfig,
+        ReputationEvent,
+    },
 };
 use futures::prelude::*;
 use libp2p::{
@@ -65,7 +74,10 @@ use libp2p::{
 use rand::seq::IteratorRandom;
 use std::{
     collections::HashMap,
-    time::Duration,
+    time::{
+        Duration,
+        SystemTime,
+    },
 };
 use tokio::sync::broadcast;
 use tracing::{
@@ -80,6 +92,10 @@ impl Punisher for Swarm<FuelBehaviour> {
     fn ban_peer(&mut self, peer_id: PeerId) {
         self.behaviour_mut().block_peer(peer_id)
     }
+
+    fn unban_peer(&mut self, peer_id: PeerId) {
+        self.behaviour_mut().unblock_peer(peer_id)
+    }
 }
 
 /// Listens to the events on the p2p network
@@ -122,6 +138,12 @@ pub struct FuelP2PService {
 
     /// Holds peers' information, and manages existing connections
     peer_manager: PeerManager,
+
+    /// The score deltas of the reputation events
+    reputation_config: ReputationConfig,
+
+    /// The checksum of the genesis and chain id, advertised by the peers of the same chain
+    checksum: Checksum,
 }
 
 #[derive(Debug)]
@@ -220,7 +242,11 @@ impl FuelP2PService {
                 reserved_peers,
                 connection_state,
                 config.max_peers_connected as usize,
+                config.peer_ban_duration,
+                &config.reputation_config,
             ),
+            reputation_config: config.reputation_config,
+            checksum: config.checksum,
         }
     }
 
@@ -407,6 +433,22 @@ impl FuelP2PService {
         );
     }
 
+    /// Report the reputation event of the peer.
+    /// The score of the event is taken from the [`ReputationConfig`].
+    pub fn report_peer_event(
+        &mut self,
+        peer_id: PeerId,
+        event: ReputationEvent,
+        reporting_service: &str,
+    ) {
+        if self.metrics {
+            p2p_metrics().peer_report(event.as_str());
+        }
+        let app_score = self.reputation_config.score(event);
+        debug!(target: "fuel-p2p", "{reporting_service} reported {peer_id} for {event}");
+        self.report_peer(peer_id, app_score, reporting_service);
+    }
+
     #[tracing::instrument(skip_all,
         level = "debug",
         fields(
@@ -446,6 +488,24 @@ impl FuelP2PService {
         &self.peer_manager
     }
 
+    /// Bans the peer until the `banned_until` time.
+    /// All connections with the banned peer are closed.
+    pub fn ban_peer(&mut self, peer_id: PeerId, banned_until: SystemTime) {
+        self.peer_manager
+            .ban_peer_until(peer_id, banned_until, &mut self.swarm);
+    }
+
+    /// Removes the ban of the peer.
+    /// Returns `true` if the peer was banned.
+    pub fn unban_peer(&mut self, peer_id: &PeerId) -> bool {
+        self.peer_manager.unban_peer(peer_id, &mut self.swarm)
+    }
+
+    /// Removes all bans that expired before `now`.
+    pub fn unban_expired_peers(&mut self, now: SystemTime) -> Vec<PeerId> {
+        self.peer_manager.unban_expired_peers(now, &mut self.swarm)
+    }
+
     fn handle_behaviour_event(
         &mut self,
         event: FuelBehaviourEvent,
@@ -601,6 +661,18 @@ impl FuelP2PService {
                                 c.send((peer, Err(ResponseError::TypeMismatch))).is_ok()
                             }
                         },
+                        ResponseSender::StateChunk(c) => match response {
+                            ResponseMessage::StateChunk(v) => {
+                                c.send((peer, Ok(v))).is_ok()
+                            }
+                            _ => {
+                                warn!(
+                                    "Invalid response type received for request {:?}",
+                                    request_id
+                                );
+                                c.send((peer, Err(ResponseError::TypeMismatch))).is_ok()
+                            }
+                        },
                     };
 
                     if !send_ok {
@@ -625,6 +697,10 @@ impl FuelP2PService {
             } => {
                 tracing::error!("RequestResponse outbound error for peer: {:?} with id: {:?} and error: {:?}", peer, request_id, error);
 
+                if let Some(event) = outbound_failure_reputation_event(&error) {
+                    self.report_peer_event(peer, event, "P2P");
+                }
+
                 if let Some(channel) = self.outbound_requests_table.remove(&request_id) {
                     match channel {
                         ResponseSender::SealedHeaders(c) => {
@@ -633,6 +709,9 @@ impl FuelP2PService {
                         ResponseSender::Transactions(c) => {
                             let _ = c.send((peer, Err(ResponseError::P2P(error))));
                         }
+                        ResponseSender::StateChunk(c) => {
+                            let _ = c.send((peer, Err(ResponseError::P2P(error))));
+                        }
                     };
                 }
             }
@@ -644,6 +723,14 @@ impl FuelP2PService {
     fn handle_identify_event(&mut self, event: identify::Event) -> Option<FuelP2PEvent> {
         match event {
             identify::Event::Received { peer_id, info } => {
+                if is_another_chain(&info.protocol_version, &self.checksum) {
+                    // The peer of another chain can't serve anything useful,
+                    // so it is banned regardless of its score.
+                    self.report_peer_event(peer_id, ReputationEvent::WrongChain, "P2P");
+                    self.peer_manager.ban_peer(peer_id, &mut self.swarm);
+                    return None
+                }
+
                 if self.metrics {
                     p2p_metrics().unique_peers.inc();
                 }
@@ -698,6 +785,25 @@ impl FuelP2PService {
     }
 }
 
+/// Returns the reputation event caused by the outbound request failure, if any.
+fn outbound_failure_reputation_event(
+    error: &request_response::OutboundFailure,
+) -> Option<ReputationEvent> {
+    match error {
+        request_response::OutboundFailure::Timeout => {
+            Some(ReputationEvent::RequestTimeout)
+        }
+        request_response::OutboundFailure::Io(error)
+            if error
+                .get_ref()
+                .is_some_and(|error| error.is::<ResponseTooLarge>()) =>
+        {
+            Some(ReputationEvent::OversizedResponse)
+        }
+        _ => None,
+    }
+}
+
 #[allow(clippy::cast_possible_truncation)]
 #[cfg(test)]
 mod tests {
@@ -728,6 +834,13 @@ mod tests {
         },
         service::to_message_acceptance,
     };
+    use fuel_core_chain_config::{
+        CoinConfig,
+        StateChunk,
+        StateChunkEntries,
+        StateChunkKind,
+        StateChunkRequest,
+    };
     use fuel_core_types::{
         blockchain::{
             consensus::{
@@ -1149,6 +1262,58 @@ mod tests {
         }
     }
 
+    // Simulates 2 connected p2p nodes, where Node B identifies itself
+    // with the checksum of another chain, so Node A bans it
+    #[tokio::test]
+    #[instrument]
+    async fn peer_identified_with_another_chain_is_banned() {
+        use crate::behavior::identify_protocol_version;
+        use libp2p::identify;
+
+        // Node A
+        let mut p2p_config =
+            Config::default_initialized("peer_identified_with_another_chain_is_banned");
+        let mut node_a = build_service_from_config(p2p_config.clone()).await;
+
+        // Node B
+        p2p_config.bootstrap_nodes = node_a.multiaddrs();
+        let mut node_b = build_service_from_config(p2p_config).await;
+        let node_b_id = node_b.local_peer_id;
+
+        loop {
+            tokio::select! {
+                node_a_event = node_a.next_event() => {
+                    if let Some(FuelP2PEvent::PeerConnected(peer_id)) = node_a_event {
+                        if peer_id == node_b_id {
+                            break
+                        }
+                    }
+                    tracing::info!("Node A Event: {:?}", node_a_event);
+                },
+                node_b_event = node_b.next_event() => {
+                    tracing::info!("Node B Event: {:?}", node_b_event);
+                },
+            };
+        }
+
+        // When
+        let info = identify::Info {
+            public_key: Keypair::generate_secp256k1().public(),
+            protocol_version: identify_protocol_version(&[1u8; 32].into()),
+            agent_version: "fuel-core".to_string(),
+            listen_addrs: vec![],
+            protocols: vec![],
+            observed_addr: Multiaddr::empty(),
+        };
+        node_a.handle_identify_event(identify::Event::Received {
+            peer_id: node_b_id,
+            info,
+        });
+
+        // Then
+        assert!(node_a.peer_manager().is_banned(&node_b_id));
+    }
+
     // Simulates 3 p2p nodes, Node B & Node C are bootstrapped with Node A
     // Using Identify Protocol Node C should be able to identify and connect to Node B
     #[tokio::test]
@@ -1572,6 +1737,23 @@ mod tests {
                                             }
                                         });
                                     }
+                                    RequestMessage::StateChunk(request) => {
+                                        let (tx_orchestrator, rx_orchestrator) = oneshot::channel();
+                                        assert!(node_a.send_request_msg(None, request_msg.clone(), ResponseSender::StateChunk(tx_orchestrator)).is_ok());
+                                        let tx_test_end = tx_test_end.clone();
+
+                                        tokio::spawn(async move {
+                                            let response_message = rx_orchestrator.await;
+
+                                            if let Ok((_, Ok(Some(chunk)))) = response_message {
+                                                let check = chunk.is_response_to(&request) && chunk.entries.len() == 5;
+                                                let _ = tx_test_end.send(check).await;
+                                            } else {
+                                                tracing::error!("Orchestrator failed to receive a message: {:?}", response_message);
+                                                let _ = tx_test_end.send(false).await;
+                                            }
+                                        });
+                                    }
                                 }
                             }
                         }
@@ -1593,6 +1775,16 @@ mod tests {
                                 let transactions = vec![Transactions(txs)];
                                 let _ = node_b.send_response_msg(*request_id, ResponseMessage::Transactions(Some(transactions)));
                             }
+                            RequestMessage::StateChunk(request) => {
+                                let coins = (0..5).map(|_| CoinConfig::default()).collect();
+                                let chunk = StateChunk {
+                                    height: request.height,
+                                    index: request.index,
+                                    last: true,
+                                    entries: StateChunkEntries::Coins(coins),
+                                };
+                                let _ = node_b.send_response_msg(*request_id, ResponseMessage::StateChunk(Some(chunk)));
+                            }
                         }
                     }
 
@@ -1602,6 +1794,17 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    #[instrument]
+    async fn request_response_works_with_state_chunk() {
+        let request = StateChunkRequest {
+            height: 13u32.into(),
+            kind: StateChunkKind::Coins,
+            index: 2,
+        };
+        request_response_works_with(RequestMessage::StateChunk(request)).await
+    }
+
     #[tokio::test]
     #[instrument]
     async fn request_response_works_with_transactions() {
@@ -2,10 +2,8 @@ use fuel_core_types::{
     fuel_types::BlockHeight,
     services::p2p::peer_reputation::{
         AppScore,
-        DECAY_APP_SCORE,
+        ReputationConfig,
         DEFAULT_APP_SCORE,
-        MAX_APP_SCORE,
-        MIN_APP_SCORE,
     },
 };
 use libp2p::{
@@ -22,6 +20,10 @@ use std::{
         Arc,
         RwLock,
     },
+    time::{
+        Duration,
+        SystemTime,
+    },
 };
 use tracing::{
     debug,
@@ -38,6 +40,17 @@ pub mod heartbeat_data;
 /// At this point we better just ban the peer
 const MIN_GOSSIPSUB_SCORE_BEFORE_BAN: AppScore = GRAYLIST_THRESHOLD;
 
+/// The longest ban. Longer bans are shortened to it, so the expiration time
+/// is always representable.
+pub const MAX_BAN_DURATION: Duration = Duration::from_secs(100 * 365 * 24 * 60 * 60);
+
+/// Returns the time when the ban for the `duration` started at `now` expires.
+/// The `duration` saturates at [`MAX_BAN_DURATION`].
+pub fn ban_expiration(now: SystemTime, duration: Duration) -> SystemTime {
+    now.checked_add(duration.min(MAX_BAN_DURATION))
+        .expect("The `MAX_BAN_DURATION` is representable by `SystemTime`")
+}
+
 // Info about a single Peer that we're connected to
 #[derive(Debug, Clone)]
 pub struct PeerInfo {
@@ -68,6 +81,9 @@ pub struct PeerManager {
     connection_state: Arc<RwLock<ConnectionState>>,
     max_non_reserved_peers: usize,
     reserved_peers_updates: tokio::sync::broadcast::Sender<usize>,
+    /// Banned peers along with the time when the ban expires.
+    banned_peers: HashMap<PeerId, SystemTime>,
+    ban_duration: Duration,
 }
 
 impl PeerManager {
@@ -76,15 +92,19 @@ impl PeerManager {
         reserved_peers: HashSet<PeerId>,
         connection_state: Arc<RwLock<ConnectionState>>,
         max_non_reserved_peers: usize,
+        ban_duration: Duration,
+        reputation_config: &ReputationConfig,
     ) -> Self {
         Self {
-            score_config: ScoreConfig::default(),
+            score_config: ScoreConfig::new(reputation_config),
             non_reserved_connected_peers: HashMap::with_capacity(max_non_reserved_peers),
             reserved_connected_peers: HashMap::with_capacity(reserved_peers.len()),
             reserved_peers,
             connection_state,
             max_non_reserved_peers,
             reserved_peers_updates,
+            banned_peers: HashMap::new(),
+            ban_duration,
         }
     }
 
@@ -97,7 +117,7 @@ impl PeerManager {
     }
 
     pub fn handle_gossip_score_update<T: Punisher>(
-        &self,
+        &mut self,
         peer_id: PeerId,
         gossip_score: f64,
         punisher: &mut T,
@@ -105,7 +125,7 @@ impl PeerManager {
         if gossip_score < self.score_config.min_gossip_score_allowed
             && !self.reserved_peers.contains(&peer_id)
         {
-            punisher.ban_peer(peer_id);
+            self.ban_peer(peer_id, punisher);
         }
     }
 
@@ -151,7 +171,7 @@ impl PeerManager {
 
     pub fn batch_update_score_with_decay(&mut self) {
         for peer_info in self.non_reserved_connected_peers.values_mut() {
-            peer_info.score *= DECAY_APP_SCORE;
+            peer_info.score *= self.score_config.decay_app_score;
         }
     }
 
@@ -170,13 +190,76 @@ impl PeerManager {
             info!(target: "fuel-p2p", "{reporting_service} updated {peer_id} with new score {score}");
 
             if new_score < self.score_config.min_app_score_allowed {
-                punisher.ban_peer(peer_id);
+                self.ban_peer(peer_id, punisher);
             }
         } else {
             log_missing_peer(&peer_id);
         }
     }
 
+    /// Bans the peer for the configured ban duration.
+    pub fn ban_peer<T: Punisher>(&mut self, peer_id: PeerId, punisher: &mut T) {
+        let banned_until = ban_expiration(SystemTime::now(), self.ban_duration);
+        self.ban_peer_until(peer_id, banned_until, punisher);
+    }
+
+    /// Bans the peer until the `banned_until` time.
+    /// If the peer is already banned, the latest expiration time is used.
+    pub fn ban_peer_until<T: Punisher>(
+        &mut self,
+        peer_id: PeerId,
+        banned_until: SystemTime,
+        punisher: &mut T,
+    ) {
+        let expiration = self.banned_peers.entry(peer_id).or_insert(banned_until);
+        *expiration = (*expiration).max(banned_until);
+        punisher.ban_peer(peer_id);
+        info!(target: "fuel-p2p", "Banned {peer_id} until {banned_until:?}");
+    }
+
+    /// Removes the ban of the peer.
+    /// Returns `true` if the peer was banned.
+    pub fn unban_peer<T: Punisher>(
+        &mut self,
+        peer_id: &PeerId,
+        punisher: &mut T,
+    ) -> bool {
+        let was_banned = self.banned_peers.remove(peer_id).is_some();
+        if was_banned {
+            punisher.unban_peer(*peer_id);
+            info!(target: "fuel-p2p", "Unbanned {peer_id}");
+        }
+        was_banned
+    }
+
+    /// Removes bans that expired before `now`.
+    /// Returns the list of unbanned peers.
+    pub fn unban_expired_peers<T: Punisher>(
+        &mut self,
+        now: SystemTime,
+        punisher: &mut T,
+    ) -> Vec<PeerId> {
+        let expired: Vec<_> = self
+            .banned_peers
+            .iter()
+            .filter(|(_, banned_until)| **banned_until <= now)
+            .map(|(peer_id, _)| *peer_id)
+            .collect();
+
+        for peer_id in &expired {
+            self.unban_peer(peer_id, punisher);
+        }
+        expired
+    }
+
+    pub fn get_banned_peers(&self) -> impl Iterator<Item = (&PeerId, &SystemTime)> {
+        self.banned_peers.iter()
+    }
+
+    pub fn is_banned(&self, peer_id: &PeerId) -> bool {
+        self.banned_peers.contains_key(peer_id)
+    }
+
     pub fn total_peers_connected(&self) -> usize {
         self.reserved_connected_peers
             .len()
@@ -377,20 +460,16 @@ fn log_missing_peer(peer_id: &PeerId) {
 struct ScoreConfig {
     max_app_score: AppScore,
     min_app_score_allowed: AppScore,
+    decay_app_score: AppScore,
     min_gossip_score_allowed: f64,
 }
 
-impl Default for ScoreConfig {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
 impl ScoreConfig {
-    pub fn new() -> Self {
+    pub fn new(reputation_config: &ReputationConfig) -> Self {
         Self {
-            max_app_score: MAX_APP_SCORE,
-            min_app_score_allowed: MIN_APP_SCORE,
+            max_app_score: reputation_config.max_app_score,
+            min_app_score_allowed: reputation_config.min_app_score,
+            decay_app_score: reputation_config.decay_app_score,
             min_gossip_score_allowed: MIN_GOSSIPSUB_SCORE_BEFORE_BAN,
         }
     }
@@ -398,11 +477,16 @@ impl ScoreConfig {
 
 pub trait Punisher {
     fn ban_peer(&mut self, peer_id: PeerId);
+
+    fn unban_peer(&mut self, peer_id: PeerId);
 }
 
 #[cfg(test)]
 mod tests {
+    #![allow(non_snake_case)]
+
     use super::*;
+    use fuel_core_types::services::p2p::peer_reputation::MIN_APP_SCORE;
 
     fn get_random_peers(size: usize) -> Vec<PeerId> {
         (0..size).map(|_| PeerId::random()).collect()
@@ -411,6 +495,18 @@ mod tests {
     fn initialize_peer_manager(
         reserved_peers: Vec<PeerId>,
         max_non_reserved_peers: usize,
+    ) -> PeerManager {
+        initialize_peer_manager_with_reputation(
+            reserved_peers,
+            max_non_reserved_peers,
+            ReputationConfig::default(),
+        )
+    }
+
+    fn initialize_peer_manager_with_reputation(
+        reserved_peers: Vec<PeerId>,
+        max_non_reserved_peers: usize,
+        reputation_config: ReputationConfig,
     ) -> PeerManager {
         let connection_state = ConnectionState::new();
         let (sender, _) =
@@ -421,9 +517,26 @@ mod tests {
             reserved_peers.into_iter().collect(),
             connection_state,
             max_non_reserved_peers,
+            Duration::from_secs(60),
+            &reputation_config,
         )
     }
 
+    #[derive(Default)]
+    struct FakePunisher {
+        banned: HashSet<PeerId>,
+    }
+
+    impl Punisher for FakePunisher {
+        fn ban_peer(&mut self, peer_id: PeerId) {
+            self.banned.insert(peer_id);
+        }
+
+        fn unban_peer(&mut self, peer_id: PeerId) {
+            self.banned.remove(&peer_id);
+        }
+    }
+
     #[test]
     fn only_allowed_number_of_non_reserved_peers_is_connected() {
         let max_non_reserved_peers = 5;
@@ -499,4 +612,99 @@ mod tests {
             reserved_peers.len() + max_non_reserved_peers
         );
     }
+
+    #[test]
+    fn peer_with_low_app_score_is_banned() {
+        let mut peer_manager = initialize_peer_manager(vec![], 5);
+        let mut punisher = FakePunisher::default();
+        let peer_id = PeerId::random();
+        peer_manager.handle_initial_connection(&peer_id);
+
+        let penalty = MIN_APP_SCORE - DEFAULT_APP_SCORE - 1.;
+        peer_manager.update_app_score(peer_id, penalty, "test", &mut punisher);
+
+        assert!(peer_manager.is_banned(&peer_id));
+        assert!(punisher.banned.contains(&peer_id));
+    }
+
+    #[test]
+    fn peer_above_configured_min_app_score_is_not_banned() {
+        // given
+        let reputation_config = ReputationConfig {
+            min_app_score: -200.,
+            ..Default::default()
+        };
+        let mut peer_manager =
+            initialize_peer_manager_with_reputation(vec![], 5, reputation_config);
+        let mut punisher = FakePunisher::default();
+        let peer_id = PeerId::random();
+        peer_manager.handle_initial_connection(&peer_id);
+
+        // when
+        peer_manager.update_app_score(peer_id, -100., "test", &mut punisher);
+
+        // then
+        assert!(!peer_manager.is_banned(&peer_id));
+    }
+
+    #[test]
+    fn batch_update_score_with_decay__uses_configured_decay() {
+        // given
+        let reputation_config = ReputationConfig {
+            decay_app_score: 0.5,
+            ..Default::default()
+        };
+        let mut peer_manager =
+            initialize_peer_manager_with_reputation(vec![], 5, reputation_config);
+        let mut punisher = FakePunisher::default();
+        let peer_id = PeerId::random();
+        peer_manager.handle_initial_connection(&peer_id);
+        peer_manager.update_app_score(peer_id, 10., "test", &mut punisher);
+
+        // when
+        peer_manager.batch_update_score_with_decay();
+
+        // then
+        let score = peer_manager.get_peer_info(&peer_id).unwrap().score;
+        assert_eq!(score, 5.);
+    }
+
+    #[test]
+    fn expired_bans_are_removed() {
+        let mut peer_manager = initialize_peer_manager(vec![], 5);
+        let mut punisher = FakePunisher::default();
+        let expired_peer = PeerId::random();
+        let banned_peer = PeerId::random();
+        let now = SystemTime::now();
+
+        peer_manager.ban_peer_until(
+            expired_peer,
+            now.checked_sub(Duration::from_secs(1)).unwrap(),
+            &mut punisher,
+        );
+        peer_manager.ban_peer_until(
+            banned_peer,
+            now.checked_add(Duration::from_secs(100)).unwrap(),
+            &mut punisher,
+        );
+        let unbanned = peer_manager.unban_expired_peers(now, &mut punisher);
+
+        assert_eq!(unbanned, vec![expired_peer]);
+        assert!(!peer_manager.is_banned(&expired_peer));
+        assert!(!punisher.banned.contains(&expired_peer));
+        assert!(peer_manager.is_banned(&banned_peer));
+        assert!(punisher.banned.contains(&banned_peer));
+    }
+
+    #[test]
+    fn ban_expiration__saturates_overflowing_duration() {
+        // given
+        let now = SystemTime::now();
+
+        // when
+        let banned_until = ban_expiration(now, Duration::MAX);
+
+        // then
+        assert_eq!(Some(banned_until), now.checked_add(MAX_BAN_DURATION));
+    }
 }
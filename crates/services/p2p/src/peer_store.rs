@@ -0,0 +1,110 @@
+//! The module provides definition of the tables used to persist the knowledge
+//! about peers between restarts of the node.
+
+use fuel_core_storage::{
+    blueprint::plain::Plain,
+    codec::{
+        postcard::Postcard,
+        raw::Raw,
+    },
+    kv_store::StorageColumn,
+    structured_storage::TableWithBlueprint,
+    Mappable,
+};
+use fuel_core_types::services::p2p::peer_reputation::AppScore;
+use libp2p::Multiaddr;
+use std::time::SystemTime;
+
+/// The peer store tables column ids to the corresponding [`fuel_core_storage::Mappable`] table.
+#[repr(u32)]
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    strum_macros::EnumCount,
+    strum_macros::IntoStaticStr,
+    PartialEq,
+    Eq,
+    enum_iterator::Sequence,
+    Hash,
+)]
+pub enum Column {
+    /// The column id of metadata about the peer store.
+    Metadata = 0,
+    /// The column of the table that stores peers seen by the node.
+    KnownPeers = 1,
+    /// The column of the table that stores banned peers.
+    BannedPeers = 2,
+}
+
+impl Column {
+    /// The total count of variants in the enum.
+    pub const COUNT: usize = <Self as strum::EnumCount>::COUNT;
+
+    /// Returns the `usize` representation of the `Column`.
+    pub fn as_u32(&self) -> u32 {
+        *self as u32
+    }
+}
+
+impl StorageColumn for Column {
+    fn name(&self) -> &'static str {
+        self.into()
+    }
+
+    fn id(&self) -> u32 {
+        self.as_u32()
+    }
+}
+
+/// The last known state of the peer.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct KnownPeer {
+    /// The addresses reported by the peer during identification.
+    pub addresses: Vec<Multiaddr>,
+    /// The last time the peer was connected to the node.
+    pub last_seen: SystemTime,
+    /// The application score of the peer at `last_seen`.
+    pub score: AppScore,
+}
+
+/// The table contains peers that the node was connected to.
+pub struct KnownPeers;
+
+impl Mappable for KnownPeers {
+    /// The key is the bytes representation of the `PeerId`.
+    type Key = [u8];
+    type OwnedKey = Vec<u8>;
+    type Value = Self::OwnedValue;
+    type OwnedValue = KnownPeer;
+}
+
+impl TableWithBlueprint for KnownPeers {
+    type Blueprint = Plain<Raw, Postcard>;
+    type Column = Column;
+
+    fn column() -> Column {
+        Column::KnownPeers
+    }
+}
+
+/// The table contains banned peers.
+pub struct BannedPeers;
+
+impl Mappable for BannedPeers {
+    /// The key is the bytes representation of the `PeerId`.
+    type Key = [u8];
+    type OwnedKey = Vec<u8>;
+    /// The value is the time when the ban expires.
+    type Value = Self::OwnedValue;
+    type OwnedValue = SystemTime;
+}
+
+impl TableWithBlueprint for BannedPeers {
+    type Blueprint = Plain<Raw, Postcard>;
+    type Column = Column;
+
+    fn column() -> Column {
+        Column::BannedPeers
+    }
+}
@@ -1,3 +1,8 @@
+use crate::peer_store::KnownPeer;
+use fuel_core_chain_config::{
+    StateChunk,
+    StateChunkRequest,
+};
 use fuel_core_services::stream::BoxStream;
 use fuel_core_storage::Result as StorageResult;
 use fuel_core_types::{
@@ -8,7 +13,11 @@ use fuel_core_types::{
     fuel_types::BlockHeight,
     services::p2p::Transactions,
 };
-use std::ops::Range;
+use libp2p::PeerId;
+use std::{
+    ops::Range,
+    time::SystemTime,
+};
 
 pub trait P2pDb: Send + Sync {
     fn get_sealed_headers(
@@ -22,9 +31,45 @@ pub trait P2pDb: Send + Sync {
     ) -> StorageResult<Option<Vec<Transactions>>>;
 
     fn get_genesis(&self) -> StorageResult<Genesis>;
+
+    /// Returns the chunk of the on-chain state at the requested height.
+    /// Returns `None` if the state at this height is not available.
+    fn get_state_chunk(
+        &self,
+        request: &StateChunkRequest,
+    ) -> StorageResult<Option<StateChunk>>;
 }
 
 pub trait BlockHeightImporter: Send + Sync {
     /// Creates a stream of next block heights
     fn next_block_height(&self) -> BoxStream<BlockHeight>;
 }
+
+/// The storage of peers and bans that survives restarts of the node.
+pub trait PeerStore: Send + Sync {
+    /// Returns all peers that the node was connected to before.
+    fn known_peers(&self) -> StorageResult<Vec<(PeerId, KnownPeer)>>;
+
+    /// Inserts or updates the state of the peer.
+    fn insert_known_peer(
+        &mut self,
+        peer_id: &PeerId,
+        peer: &KnownPeer,
+    ) -> StorageResult<()>;
+
+    /// Forgets the peer.
+    fn remove_known_peer(&mut self, peer_id: &PeerId) -> StorageResult<()>;
+
+    /// Returns all banned peers along with the time when the ban expires.
+    fn banned_peers(&self) -> StorageResult<Vec<(PeerId, SystemTime)>>;
+
+    /// Bans the peer until the `banned_until` time.
+    fn insert_banned_peer(
+        &mut self,
+        peer_id: &PeerId,
+        banned_until: SystemTime,
+    ) -> StorageResult<()>;
+
+    /// Removes the ban of the peer.
+    fn remove_banned_peer(&mut self, peer_id: &PeerId) -> StorageResult<()>;
+}
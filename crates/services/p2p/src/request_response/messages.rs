@@ -1,3 +1,7 @@
+use fuel_core_chain_config::{
+    StateChunk,
+    StateChunkRequest,
+};
 use fuel_core_types::{
     blockchain::SealedBlockHeader,
     services::p2p::Transactions,
@@ -24,12 +28,14 @@ pub(crate) const MAX_REQUEST_SIZE: usize = core::mem::size_of::<RequestMessage>(
 pub enum RequestMessage {
     SealedHeaders(Range<u32>),
     Transactions(Range<u32>),
+    StateChunk(StateChunkRequest),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ResponseMessage {
     SealedHeaders(Option<Vec<SealedBlockHeader>>),
     Transactions(Option<Vec<Transactions>>),
+    StateChunk(Option<StateChunk>),
 }
 
 pub type OnResponse<T> = oneshot::Sender<(PeerId, Result<T, ResponseError>)>;
@@ -38,6 +44,7 @@ pub type OnResponse<T> = oneshot::Sender<(PeerId, Result<T, ResponseError>)>;
 pub enum ResponseSender {
     SealedHeaders(OnResponse<Option<Vec<SealedBlockHeader>>>),
     Transactions(OnResponse<Option<Vec<Transactions>>>),
+    StateChunk(OnResponse<Option<StateChunk>>),
 }
 
 #[derive(Debug, Error)]
@@ -12,10 +12,15 @@ use crate::{
         FuelP2PEvent,
         FuelP2PService,
     },
-    peer_manager::PeerInfo,
+    peer_manager::{
+        ban_expiration,
+        PeerInfo,
+    },
+    peer_store::KnownPeer,
     ports::{
         BlockHeightImporter,
         P2pDb,
+        PeerStore,
     },
     request_response::messages::{
         OnResponse,
@@ -23,8 +28,13 @@ use crate::{
         ResponseMessage,
         ResponseSender,
     },
+    TryPeerId,
 };
 use anyhow::anyhow;
+use fuel_core_chain_config::{
+    StateChunk,
+    StateChunkRequest,
+};
 use fuel_core_services::{
     stream::BoxStream,
     RunnableService,
@@ -47,6 +57,7 @@ use fuel_core_types::{
         peer_reputation::{
             AppScore,
             PeerReport,
+            ReputationEvent,
         },
         BlockHeightHeartbeatData,
         GossipData,
@@ -59,17 +70,25 @@ use fuel_core_types::{
 };
 use futures::{
     future::BoxFuture,
+    stream::FuturesUnordered,
     StreamExt,
 };
 use libp2p::{
     gossipsub::MessageAcceptance,
+    multiaddr::Protocol,
     request_response::InboundRequestId,
+    Multiaddr,
     PeerId,
 };
 use std::{
+    collections::{
+        HashMap,
+        HashSet,
+    },
     fmt::Debug,
     ops::Range,
     sync::Arc,
+    time::SystemTime,
 };
 use tokio::{
     sync::{
@@ -77,6 +96,7 @@ use tokio::{
         mpsc,
         oneshot,
     },
+    task::JoinHandle,
     time::{
         Duration,
         Instant,
@@ -84,7 +104,19 @@ use tokio::{
 };
 use tracing::warn;
 
-pub type Service<V> = ServiceRunner<UninitializedTask<V, SharedState>>;
+pub type Service<V, S> = ServiceRunner<UninitializedTask<V, SharedState, S>>;
+
+/// The maximum number of peers kept in the peer store.
+/// The peers with the lowest score are forgotten first.
+const MAX_KNOWN_PEERS: usize = 1000;
+/// The peers not seen for this long are removed from the peer store.
+const KNOWN_PEER_EXPIRATION: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+/// The state of the connected peer is written to the peer store only if it changed
+/// or was written longer than this ago.
+const KNOWN_PEER_REFRESH_INTERVAL: Duration = Duration::from_secs(10 * 60);
+/// The maximum number of state chunks read at the same time for other peers.
+/// Requests above the limit are rejected.
+const MAX_PENDING_STATE_CHUNKS: usize = 4;
 
 enum TaskRequest {
     // Broadcast requests to p2p network
@@ -95,6 +127,21 @@ enum TaskRequest {
     GetAllPeerInfo {
         channel: oneshot::Sender<Vec<(PeerId, PeerInfo)>>,
     },
+    // Request to get all banned peers along with the ban expiration time
+    GetBannedPeers {
+        channel: oneshot::Sender<Vec<(PeerId, SystemTime)>>,
+    },
+    // Bans the peer for the `duration` or for the default ban duration
+    BanPeer {
+        peer_id: PeerId,
+        duration: Option<Duration>,
+        channel: oneshot::Sender<anyhow::Result<()>>,
+    },
+    // Removes the ban of the peer and replies whether the peer was banned
+    UnbanPeer {
+        peer_id: PeerId,
+        channel: oneshot::Sender<anyhow::Result<bool>>,
+    },
     GetSealedHeaders {
         block_height_range: Range<u32>,
         channel: OnResponse<Option<Vec<SealedBlockHeader>>>,
@@ -104,6 +151,11 @@ enum TaskRequest {
         from_peer: PeerId,
         channel: OnResponse<Option<Vec<Transactions>>>,
     },
+    GetStateChunk {
+        request: StateChunkRequest,
+        from_peer: Option<PeerId>,
+        channel: OnResponse<Option<StateChunk>>,
+    },
     // Responds back to the p2p network
     RespondWithGossipsubMessageReport((GossipsubMessageInfo, GossipsubMessageAcceptance)),
     RespondWithPeerReport {
@@ -111,6 +163,11 @@ enum TaskRequest {
         score: AppScore,
         reporting_service: &'static str,
     },
+    RespondWithReputationEvent {
+        peer_id: PeerId,
+        event: ReputationEvent,
+        reporting_service: &'static str,
+    },
 }
 
 impl Debug for TaskRequest {
@@ -128,15 +185,30 @@ impl Debug for TaskRequest {
             TaskRequest::GetTransactions { .. } => {
                 write!(f, "TaskRequest::GetTransactions")
             }
+            TaskRequest::GetStateChunk { .. } => {
+                write!(f, "TaskRequest::GetStateChunk")
+            }
             TaskRequest::RespondWithGossipsubMessageReport(_) => {
                 write!(f, "TaskRequest::RespondWithGossipsubMessageReport")
             }
             TaskRequest::RespondWithPeerReport { .. } => {
                 write!(f, "TaskRequest::RespondWithPeerReport")
             }
+            TaskRequest::RespondWithReputationEvent { .. } => {
+                write!(f, "TaskRequest::RespondWithReputationEvent")
+            }
             TaskRequest::GetAllPeerInfo { .. } => {
                 write!(f, "TaskRequest::GetPeerInfo")
             }
+            TaskRequest::GetBannedPeers { .. } => {
+                write!(f, "TaskRequest::GetBannedPeers")
+            }
+            TaskRequest::BanPeer { .. } => {
+                write!(f, "TaskRequest::BanPeer")
+            }
+            TaskRequest::UnbanPeer { .. } => {
+                write!(f, "TaskRequest::UnbanPeer")
+            }
         }
     }
 }
@@ -151,6 +223,7 @@ pub trait TaskP2PService: Send {
     fn get_peer_ids(&self) -> Vec<PeerId>;
     fn get_all_peer_info(&self) -> Vec<(&PeerId, &PeerInfo)>;
     fn get_peer_id_with_height(&self, height: &BlockHeight) -> Option<PeerId>;
+    fn get_banned_peers(&self) -> Vec<(PeerId, SystemTime)>;
 
     fn next_event(&mut self) -> BoxFuture<'_, Option<FuelP2PEvent>>;
 
@@ -185,7 +258,22 @@ pub trait TaskP2PService: Send {
         reporting_service: &str,
     ) -> anyhow::Result<()>;
 
+    fn report_peer_event(
+        &mut self,
+        peer_id: PeerId,
+        event: ReputationEvent,
+        reporting_service: &str,
+    ) -> anyhow::Result<()>;
+
     fn update_block_height(&mut self, height: BlockHeight) -> anyhow::Result<()>;
+
+    fn ban_peer(&mut self, peer_id: PeerId, banned_until: SystemTime);
+
+    fn unban_peer(&mut self, peer_id: &PeerId) -> bool;
+
+    fn unban_expired_peers(&mut self, now: SystemTime) -> Vec<PeerId>;
+
+    fn is_reserved_peer(&self, peer_id: &PeerId) -> bool;
 }
 
 impl TaskP2PService for FuelP2PService {
@@ -201,6 +289,13 @@ impl TaskP2PService for FuelP2PService {
         self.peer_manager().get_peer_id_with_height(height)
     }
 
+    fn get_banned_peers(&self) -> Vec<(PeerId, SystemTime)> {
+        self.peer_manager()
+            .get_banned_peers()
+            .map(|(peer_id, banned_until)| (*peer_id, *banned_until))
+            .collect()
+    }
+
     fn next_event(&mut self) -> BoxFuture<'_, Option<FuelP2PEvent>> {
         Box::pin(self.next_event())
     }
@@ -251,10 +346,36 @@ impl TaskP2PService for FuelP2PService {
         Ok(())
     }
 
+    fn report_peer_event(
+        &mut self,
+        peer_id: PeerId,
+        event: ReputationEvent,
+        reporting_service: &str,
+    ) -> anyhow::Result<()> {
+        self.report_peer_event(peer_id, event, reporting_service);
+        Ok(())
+    }
+
     fn update_block_height(&mut self, height: BlockHeight) -> anyhow::Result<()> {
         self.update_block_height(height);
         Ok(())
     }
+
+    fn ban_peer(&mut self, peer_id: PeerId, banned_until: SystemTime) {
+        self.ban_peer(peer_id, banned_until)
+    }
+
+    fn unban_peer(&mut self, peer_id: &PeerId) -> bool {
+        self.unban_peer(peer_id)
+    }
+
+    fn unban_expired_peers(&mut self, now: SystemTime) -> Vec<PeerId> {
+        self.unban_expired_peers(now)
+    }
+
+    fn is_reserved_peer(&self, peer_id: &PeerId) -> bool {
+        self.peer_manager().is_reserved(peer_id)
+    }
 }
 
 pub trait Broadcast: Send {
@@ -298,9 +419,10 @@ impl Broadcast for SharedState {
 }
 
 /// Uninitialized task for the p2p that can be upgraded later into [`Task`].
-pub struct UninitializedTask<V, B> {
+pub struct UninitializedTask<V, B, S> {
     chain_id: ChainId,
     view_provider: V,
+    peer_store: S,
     next_block_height: BoxStream<BlockHeight>,
     /// Receive internal Task Requests
     request_receiver: mpsc::Receiver<TaskRequest>,
@@ -310,10 +432,15 @@ pub struct UninitializedTask<V, B> {
 
 /// Orchestrates various p2p-related events between the inner `P2pService`
 /// and the top level `NetworkService`.
-pub struct Task<P, V, B> {
+pub struct Task<P, V, B, S> {
     chain_id: ChainId,
     p2p_service: P,
     view_provider: V,
+    peer_store: S,
+    /// The state of peers written to the `peer_store`.
+    known_peers: HashMap<PeerId, KnownPeer>,
+    /// The bans written to the `peer_store`.
+    persisted_bans: HashMap<PeerId, SystemTime>,
     next_block_height: BoxStream<BlockHeight>,
     /// Receive internal Task Requests
     request_receiver: mpsc::Receiver<TaskRequest>,
@@ -325,6 +452,10 @@ pub struct Task<P, V, B> {
     heartbeat_max_time_since_last: Duration,
     next_check_time: Instant,
     heartbeat_peer_reputation_config: HeartbeatPeerReputationConfig,
+    peer_ban_duration: Duration,
+    /// The state chunks that are being read for other peers.
+    pending_state_chunks:
+        FuturesUnordered<JoinHandle<(InboundRequestId, Option<StateChunk>)>>,
 }
 
 #[derive(Clone)]
@@ -333,11 +464,12 @@ pub struct HeartbeatPeerReputationConfig {
     low_heartbeat_frequency_penalty: AppScore,
 }
 
-impl<V> UninitializedTask<V, SharedState> {
+impl<V, S> UninitializedTask<V, SharedState, S> {
     pub fn new<B: BlockHeightImporter>(
         chain_id: ChainId,
         config: Config<NotInitialized>,
         view_provider: V,
+        peer_store: S,
         block_importer: B,
     ) -> Self {
         let (request_sender, request_receiver) = mpsc::channel(1024 * 10);
@@ -356,6 +488,7 @@ impl<V> UninitializedTask<V, SharedState> {
         Self {
             chain_id,
             view_provider,
+            peer_store,
             next_block_height,
             request_receiver,
             broadcast: SharedState {
@@ -369,7 +502,7 @@ impl<V> UninitializedTask<V, SharedState> {
     }
 }
 
-impl<P: TaskP2PService, V, B: Broadcast> Task<P, V, B> {
+impl<P: TaskP2PService, V, B: Broadcast, S: PeerStore> Task<P, V, B, S> {
     fn peer_heartbeat_reputation_checks(&self) -> anyhow::Result<()> {
         for (peer_id, peer_info) in self.p2p_service.get_all_peer_info() {
             if peer_info.heartbeat_data.duration_since_last_heartbeat()
@@ -410,6 +543,130 @@ impl<P: TaskP2PService, V, B: Broadcast> Task<P, V, B> {
             .report_peer(peer_id, app_score, reporting_service)?;
         Ok(())
     }
+
+    /// Writes the changed state of connected peers and bans into the peer store,
+    /// lifts bans that have expired and forgets stale peers.
+    fn sync_peer_store(&mut self) -> anyhow::Result<()> {
+        let now = SystemTime::now();
+
+        for peer_id in self.p2p_service.unban_expired_peers(now) {
+            self.peer_store.remove_banned_peer(&peer_id)?;
+            self.persisted_bans.remove(&peer_id);
+        }
+
+        for (peer_id, peer_info) in self.p2p_service.get_all_peer_info() {
+            let known_peer = KnownPeer {
+                addresses: peer_info.peer_addresses.iter().cloned().collect(),
+                last_seen: now,
+                score: peer_info.score,
+            };
+            let up_to_date = self.known_peers.get(peer_id).is_some_and(|stored| {
+                stored.addresses == known_peer.addresses
+                    && stored.score == known_peer.score
+                    && now
+                        .duration_since(stored.last_seen)
+                        .is_ok_and(|age| age < KNOWN_PEER_REFRESH_INTERVAL)
+            });
+            if !up_to_date {
+                self.peer_store.insert_known_peer(peer_id, &known_peer)?;
+                self.known_peers.insert(*peer_id, known_peer);
+            }
+        }
+
+        for peer_id in stale_known_peers(&self.known_peers, now, MAX_KNOWN_PEERS) {
+            self.peer_store.remove_known_peer(&peer_id)?;
+            self.known_peers.remove(&peer_id);
+        }
+
+        for (peer_id, banned_until) in self.p2p_service.get_banned_peers() {
+            if self.persisted_bans.get(&peer_id) != Some(&banned_until) {
+                self.peer_store.insert_banned_peer(&peer_id, banned_until)?;
+                self.persisted_bans.insert(peer_id, banned_until);
+            }
+        }
+        Ok(())
+    }
+
+    fn ban_peer(
+        &mut self,
+        peer_id: PeerId,
+        duration: Option<Duration>,
+    ) -> anyhow::Result<()> {
+        if self.p2p_service.is_reserved_peer(&peer_id) {
+            return Err(anyhow!(
+                "The peer {peer_id} is reserved, remove it from the reserved nodes to ban it"
+            ))
+        }
+        let banned_until = ban_expiration(
+            SystemTime::now(),
+            duration.unwrap_or(self.peer_ban_duration),
+        );
+        self.p2p_service.ban_peer(peer_id, banned_until);
+        self.peer_store.insert_banned_peer(&peer_id, banned_until)?;
+        self.persisted_bans.insert(peer_id, banned_until);
+        Ok(())
+    }
+
+    fn unban_peer(&mut self, peer_id: &PeerId) -> anyhow::Result<bool> {
+        let was_banned = self.p2p_service.unban_peer(peer_id);
+        self.peer_store.remove_banned_peer(peer_id)?;
+        self.persisted_bans.remove(peer_id);
+        Ok(was_banned)
+    }
+}
+
+/// Returns the peers that should be removed from the peer store: the peers
+/// not seen for [`KNOWN_PEER_EXPIRATION`] and the peers with the lowest score
+/// above the `limit`.
+fn stale_known_peers(
+    known_peers: &HashMap<PeerId, KnownPeer>,
+    now: SystemTime,
+    limit: usize,
+) -> Vec<PeerId> {
+    let (expired, mut alive): (Vec<_>, Vec<_>) =
+        known_peers.iter().partition(|(_, peer)| {
+            now.duration_since(peer.last_seen)
+                .is_ok_and(|age| age > KNOWN_PEER_EXPIRATION)
+        });
+    alive.sort_by(|(_, a), (_, b)| {
+        b.score
+            .total_cmp(&a.score)
+            .then_with(|| b.last_seen.cmp(&a.last_seen))
+    });
+    expired
+        .into_iter()
+        .chain(alive.into_iter().skip(limit))
+        .map(|(peer_id, _)| *peer_id)
+        .collect()
+}
+
+/// Returns the addresses of the peers from the previous runs that can be used
+/// to seed the discovery. Peers with the best score and the most recent
+/// connection come first, banned peers are skipped.
+fn known_peers_addresses(
+    mut known_peers: Vec<(PeerId, KnownPeer)>,
+    banned_peers: &HashSet<PeerId>,
+    limit: usize,
+) -> Vec<Multiaddr> {
+    known_peers.retain(|(peer_id, _)| !banned_peers.contains(peer_id));
+    known_peers.sort_by(|(_, a), (_, b)| {
+        b.score
+            .total_cmp(&a.score)
+            .then_with(|| b.last_seen.cmp(&a.last_seen))
+    });
+
+    known_peers
+        .into_iter()
+        .take(limit)
+        .flat_map(|(peer_id, known_peer)| {
+            known_peer.addresses.into_iter().map(move |mut address| {
+                if address.try_to_peer_id().is_none() {
+                    address.push(Protocol::P2p(peer_id));
+                }
+                address
+            })
+        })
+        .collect()
 }
 
 fn convert_peer_id(peer_id: &PeerId) -> anyhow::Result<FuelPeerId> {
@@ -418,15 +675,16 @@ fn convert_peer_id(peer_id: &PeerId) -> anyhow::Result<FuelPeerId> {
 }
 
 #[async_trait::async_trait]
-impl<V> RunnableService for UninitializedTask<V, SharedState>
+impl<V, S> RunnableService for UninitializedTask<V, SharedState, S>
 where
     V: AtomicView + 'static,
-    V::View: P2pDb,
+    V::View: P2pDb + 'static,
+    S: PeerStore + 'static,
 {
     const NAME: &'static str = "P2P";
 
     type SharedData = SharedState;
-    type Task = Task<FuelP2PService, V, SharedState>;
+    type Task = Task<FuelP2PService, V, SharedState, S>;
     type TaskParams = ();
 
     fn shared_data(&self) -> Self::SharedData {
@@ -441,6 +699,7 @@ where
         let Self {
             chain_id,
             view_provider,
+            mut peer_store,
             next_block_height,
             request_receiver,
             broadcast,
@@ -449,16 +708,45 @@ where
 
         let view = view_provider.latest_view();
         let genesis = view.get_genesis()?;
-        let config = config.init(genesis)?;
+        let mut config = config.init(genesis)?;
         let Config {
             max_block_size,
             max_headers_per_request,
             heartbeat_check_interval,
             heartbeat_max_avg_interval,
             heartbeat_max_time_since_last,
+            peer_ban_duration,
             ..
         } = config;
 
+        let now = SystemTime::now();
+        let mut banned_peers = vec![];
+        for (peer_id, banned_until) in peer_store.banned_peers()? {
+            if banned_until > now {
+                banned_peers.push((peer_id, banned_until));
+            } else {
+                peer_store.remove_banned_peer(&peer_id)?;
+            }
+        }
+        let mut known_peers: HashMap<_, _> =
+            peer_store.known_peers()?.into_iter().collect();
+        for peer_id in stale_known_peers(&known_peers, now, MAX_KNOWN_PEERS) {
+            peer_store.remove_known_peer(&peer_id)?;
+            known_peers.remove(&peer_id);
+        }
+        let banned_peer_ids = banned_peers.iter().map(|(peer_id, _)| *peer_id).collect();
+        let known_addresses = known_peers_addresses(
+            known_peers.clone().into_iter().collect(),
+            &banned_peer_ids,
+            config.max_peers_connected as usize,
+        );
+        tracing::info!(
+            "Loaded {} addresses of known peers and {} banned peers from the peer store",
+            known_addresses.len(),
+            banned_peers.len()
+        );
+        config.bootstrap_nodes.extend(known_addresses);
+
         // Hardcoded for now, but left here to be configurable in the future.
         // TODO: https://github.com/FuelLabs/fuel-core/issues/1340
         let heartbeat_peer_reputation_config = HeartbeatPeerReputationConfig {
@@ -473,6 +761,10 @@ where
         );
         p2p_service.start().await?;
 
+        for (peer_id, banned_until) in &banned_peers {
+            p2p_service.ban_peer(*peer_id, *banned_until);
+        }
+
         let next_check_time =
             Instant::now().checked_add(heartbeat_check_interval).expect(
                 "The heartbeat check interval should be small enough to do frequently",
@@ -482,6 +774,9 @@ where
             chain_id,
             p2p_service,
             view_provider,
+            peer_store,
+            known_peers,
+            persisted_bans: banned_peers.into_iter().collect(),
             request_receiver,
             next_block_height,
             broadcast,
@@ -491,6 +786,8 @@ where
             heartbeat_max_time_since_last,
             next_check_time,
             heartbeat_peer_reputation_config,
+            peer_ban_duration,
+            pending_state_chunks: FuturesUnordered::new(),
         };
         Ok(task)
     }
@@ -498,12 +795,13 @@ where
 
 // TODO: Add tests https://github.com/FuelLabs/fuel-core/issues/1275
 #[async_trait::async_trait]
-impl<P, V, B> RunnableTask for Task<P, V, B>
+impl<P, V, B, S> RunnableTask for Task<P, V, B, S>
 where
     P: TaskP2PService + 'static,
     V: AtomicView + 'static,
-    V::View: P2pDb,
+    V::View: P2pDb + 'static,
     B: Broadcast + 'static,
+    S: PeerStore + 'static,
 {
     async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
         tracing::debug!("P2P task is running");
@@ -548,6 +846,14 @@ where
                         let request_msg = RequestMessage::Transactions(block_height_range);
                         self.p2p_service.send_request_msg(Some(from_peer), request_msg, channel).expect("We always a peer here, so send has a target");
                     }
+                    Some(TaskRequest::GetStateChunk { request, from_peer, channel }) => {
+                        let channel = ResponseSender::StateChunk(channel);
+                        let request_msg = RequestMessage::StateChunk(request);
+                        let peer = from_peer.or_else(|| self.p2p_service.get_peer_id_with_height(&request.height));
+                        if self.p2p_service.send_request_msg(peer, request_msg, channel).is_err() {
+                            tracing::warn!("No peers found for the state at height {:?}", request.height);
+                        }
+                    }
                     Some(TaskRequest::RespondWithGossipsubMessageReport((message, acceptance))) => {
                         // report_message(&mut self.p2p_service, message, acceptance);
                         self.p2p_service.report_message(message, acceptance)?;
@@ -555,6 +861,9 @@ where
                     Some(TaskRequest::RespondWithPeerReport { peer_id, score, reporting_service }) => {
                         let _ = self.p2p_service.report_peer(peer_id, score, reporting_service);
                     }
+                    Some(TaskRequest::RespondWithReputationEvent { peer_id, event, reporting_service }) => {
+                        let _ = self.p2p_service.report_peer_event(peer_id, event, reporting_service);
+                    }
                     Some(TaskRequest::GetAllPeerInfo { channel }) => {
                         let peers = self.p2p_service.get_all_peer_info()
                             .into_iter()
@@ -562,6 +871,23 @@ where
                             .collect::<Vec<_>>();
                         let _ = channel.send(peers);
                     }
+                    Some(TaskRequest::GetBannedPeers { channel }) => {
+                        let _ = channel.send(self.p2p_service.get_banned_peers());
+                    }
+                    Some(TaskRequest::BanPeer { peer_id, duration, channel }) => {
+                        let result = self.ban_peer(peer_id, duration);
+                        if let Err(e) = &result {
+                            tracing::error!("Failed to ban peer {}: {:?}", peer_id, e);
+                        }
+                        let _ = channel.send(result);
+                    }
+                    Some(TaskRequest::UnbanPeer { peer_id, channel }) => {
+                        let result = self.unban_peer(&peer_id);
+                        if let Err(e) = &result {
+                            tracing::error!("Failed to unban peer {}: {:?}", peer_id, e);
+                        }
+                        let _ = channel.send(result);
+                    }
                     None => {
                         unreachable!("The `Task` is holder of the `Sender`, so it should not be possible");
                     }
@@ -628,11 +954,40 @@ where
                                     }
                                 };
                             }
+                            RequestMessage::StateChunk(request) => {
+                                if self.pending_state_chunks.len() >= MAX_PENDING_STATE_CHUNKS {
+                                    tracing::warn!("Too many pending state chunks, rejecting the request {:?}", request);
+                                    let response = None;
+                                    let _ = self.p2p_service.send_response_msg(request_id, ResponseMessage::StateChunk(response));
+                                } else {
+                                    // Reading of the state is slow, so it is done outside of the P2P loop.
+                                    let view = self.view_provider.latest_view();
+                                    let task = tokio::task::spawn_blocking(move || {
+                                        let response = view.get_state_chunk(&request).unwrap_or_else(|e| {
+                                            tracing::error!("Failed to get the state chunk {:?}: {:?}", request, e);
+                                            None
+                                        });
+                                        (request_id, response)
+                                    });
+                                    self.pending_state_chunks.push(task);
+                                }
+                            }
                         }
                     },
                     _ => (),
                 }
             },
+            Some(result) = self.pending_state_chunks.next(), if !self.pending_state_chunks.is_empty() => {
+                should_continue = true;
+                match result {
+                    Ok((request_id, response)) => {
+                        let _ = self.p2p_service.send_response_msg(request_id, ResponseMessage::StateChunk(response));
+                    }
+                    Err(e) => {
+                        tracing::error!("The task reading the state chunk failed: {:?}", e);
+                    }
+                }
+            },
             _  = tokio::time::sleep_until(self.next_check_time) => {
                 should_continue = true;
                 let res = self.peer_heartbeat_reputation_checks();
@@ -642,6 +997,9 @@ where
                         tracing::error!("Failed to perform peer heartbeat reputation checks: {:?}", e);
                     }
                 }
+                if let Err(e) = self.sync_peer_store() {
+                    tracing::error!("Failed to update the peer store: {:?}", e);
+                }
                 self.next_check_time += self.heartbeat_check_interval;
             },
             latest_block_height = self.next_block_height.next() => {
@@ -747,6 +1105,32 @@ impl SharedState {
         response.map_err(|e| anyhow!("Invalid response from peer {e:?}"))
     }
 
+    /// Requests the chunk of the state from the `from_peer` or
+    /// from any peer at the requested height if it is `None`.
+    pub async fn get_state_chunk(
+        &self,
+        from_peer: Option<Vec<u8>>,
+        request: StateChunkRequest,
+    ) -> anyhow::Result<(Vec<u8>, Option<StateChunk>)> {
+        let (sender, receiver) = oneshot::channel();
+        let from_peer = from_peer
+            .map(|peer_id| PeerId::from_bytes(&peer_id))
+            .transpose()?;
+
+        self.request_sender
+            .send(TaskRequest::GetStateChunk {
+                request,
+                from_peer,
+                channel: sender,
+            })
+            .await?;
+
+        let (peer_id, response) = receiver.await.map_err(|e| anyhow!("{e}"))?;
+
+        let data = response.map_err(|e| anyhow!("Invalid response from peer {e:?}"))?;
+        Ok((peer_id.to_bytes(), data))
+    }
+
     pub fn broadcast_transaction(
         &self,
         transaction: Arc<Transaction>,
@@ -776,6 +1160,50 @@ impl SharedState {
         receiver.await.map_err(|e| anyhow!("{}", e))
     }
 
+    pub async fn get_banned_peers(&self) -> anyhow::Result<Vec<(PeerId, SystemTime)>> {
+        let (sender, receiver) = oneshot::channel();
+
+        self.request_sender
+            .send(TaskRequest::GetBannedPeers { channel: sender })
+            .await?;
+
+        receiver.await.map_err(|e| anyhow!("{}", e))
+    }
+
+    /// Bans the peer for the `duration`.
+    /// If the `duration` is not specified, the configured ban duration is used.
+    pub async fn ban_peer(
+        &self,
+        peer_id: PeerId,
+        duration: Option<Duration>,
+    ) -> anyhow::Result<()> {
+        let (sender, receiver) = oneshot::channel();
+
+        self.request_sender
+            .send(TaskRequest::BanPeer {
+                peer_id,
+                duration,
+                channel: sender,
+            })
+            .await?;
+
+        receiver.await.map_err(|e| anyhow!("{}", e))?
+    }
+
+    /// Removes the ban of the peer. Returns `true` if the peer was banned.
+    pub async fn unban_peer(&self, peer_id: PeerId) -> anyhow::Result<bool> {
+        let (sender, receiver) = oneshot::channel();
+
+        self.request_sender
+            .send(TaskRequest::UnbanPeer {
+                peer_id,
+                channel: sender,
+            })
+            .await?;
+
+        receiver.await.map_err(|e| anyhow!("{}", e))?
+    }
+
     pub fn subscribe_tx(&self) -> broadcast::Receiver<TransactionGossipData> {
         self.tx_broadcast.subscribe()
     }
@@ -815,21 +1243,55 @@ impl SharedState {
             }
         }
     }
+
+    /// Reports the reputation `event` of the peer. The score of the event
+    /// is defined by the [`Config::reputation_config`].
+    pub fn report_peer_event(
+        &self,
+        peer_id: FuelPeerId,
+        event: ReputationEvent,
+        reporting_service: &'static str,
+    ) -> anyhow::Result<()> {
+        match Vec::from(peer_id).try_into() {
+            Ok(peer_id) => {
+                self.request_sender.try_send(
+                    TaskRequest::RespondWithReputationEvent {
+                        peer_id,
+                        event,
+                        reporting_service,
+                    },
+                )?;
+
+                Ok(())
+            }
+            Err(e) => {
+                warn!(target: "fuel-p2p", "Failed to read PeerId from {e:?}");
+                Err(anyhow::anyhow!("Failed to read PeerId from {e:?}"))
+            }
+        }
+    }
 }
 
-pub fn new_service<V, B>(
+pub fn new_service<V, S, B>(
     chain_id: ChainId,
     p2p_config: Config<NotInitialized>,
     view_provider: V,
+    peer_store: S,
     block_importer: B,
-) -> Service<V>
+) -> Service<V, S>
 where
     V: AtomicView + 'static,
-    V::View: P2pDb,
+    V::View: P2pDb + 'static,
+    S: PeerStore + 'static,
     B: BlockHeightImporter,
 {
-    let task =
-        UninitializedTask::new(chain_id, p2p_config, view_provider, block_importer);
+    let task = UninitializedTask::new(
+        chain_id,
+        p2p_config,
+        view_provider,
+        peer_store,
+        block_importer,
+    );
     Service::new(task)
 }
 
@@ -926,6 +1388,59 @@ pub mod tests {
         fn get_genesis(&self) -> StorageResult<Genesis> {
             Ok(Default::default())
         }
+
+        fn get_state_chunk(
+            &self,
+            _: &StateChunkRequest,
+        ) -> StorageResult<Option<StateChunk>> {
+            unimplemented!()
+        }
+    }
+
+    #[derive(Clone, Debug, Default)]
+    struct FakePeerStore {
+        known_peers: Vec<(PeerId, KnownPeer)>,
+        banned_peers: Vec<(PeerId, SystemTime)>,
+    }
+
+    impl PeerStore for FakePeerStore {
+        fn known_peers(&self) -> StorageResult<Vec<(PeerId, KnownPeer)>> {
+            Ok(self.known_peers.clone())
+        }
+
+        fn insert_known_peer(
+            &mut self,
+            peer_id: &PeerId,
+            peer: &KnownPeer,
+        ) -> StorageResult<()> {
+            self.known_peers.retain(|(id, _)| id != peer_id);
+            self.known_peers.push((*peer_id, peer.clone()));
+            Ok(())
+        }
+
+        fn remove_known_peer(&mut self, peer_id: &PeerId) -> StorageResult<()> {
+            self.known_peers.retain(|(id, _)| id != peer_id);
+            Ok(())
+        }
+
+        fn banned_peers(&self) -> StorageResult<Vec<(PeerId, SystemTime)>> {
+            Ok(self.banned_peers.clone())
+        }
+
+        fn insert_banned_peer(
+            &mut self,
+            peer_id: &PeerId,
+            banned_until: SystemTime,
+        ) -> StorageResult<()> {
+            self.banned_peers.retain(|(id, _)| id != peer_id);
+            self.banned_peers.push((*peer_id, banned_until));
+            Ok(())
+        }
+
+        fn remove_banned_peer(&mut self, peer_id: &PeerId) -> StorageResult<()> {
+            self.banned_peers.retain(|(id, _)| id != peer_id);
+            Ok(())
+        }
     }
 
     #[derive(Clone, Debug)]
@@ -940,8 +1455,13 @@ pub mod tests {
     #[tokio::test]
     async fn start_and_stop_awaits_works() {
         let p2p_config = Config::<NotInitialized>::default("start_stop_works");
-        let service =
-            new_service(ChainId::default(), p2p_config, FakeDb, FakeBlockImporter);
+        let service = new_service(
+            ChainId::default(),
+            p2p_config,
+            FakeDb,
+            FakePeerStore::default(),
+            FakeBlockImporter,
+        );
 
         // Node with p2p service started
         assert!(service.start_and_await().await.unwrap().started());
@@ -966,6 +1486,10 @@ pub mod tests {
             todo!()
         }
 
+        fn get_banned_peers(&self) -> Vec<(PeerId, SystemTime)> {
+            vec![]
+        }
+
         fn next_event(&mut self) -> BoxFuture<'_, Option<FuelP2PEvent>> {
             std::future::pending().boxed()
         }
@@ -1011,9 +1535,34 @@ pub mod tests {
             todo!()
         }
 
+        fn report_peer_event(
+            &mut self,
+            _peer_id: PeerId,
+            _event: ReputationEvent,
+            _reporting_service: &str,
+        ) -> anyhow::Result<()> {
+            todo!()
+        }
+
         fn update_block_height(&mut self, _height: BlockHeight) -> anyhow::Result<()> {
             todo!()
         }
+
+        fn ban_peer(&mut self, _peer_id: PeerId, _banned_until: SystemTime) {
+            todo!()
+        }
+
+        fn unban_peer(&mut self, _peer_id: &PeerId) -> bool {
+            todo!()
+        }
+
+        fn unban_expired_peers(&mut self, _now: SystemTime) -> Vec<PeerId> {
+            vec![]
+        }
+
+        fn is_reserved_peer(&self, _peer_id: &PeerId) -> bool {
+            false
+        }
     }
 
     #[derive(Clone)]
@@ -1055,6 +1604,13 @@ pub mod tests {
         fn get_genesis(&self) -> StorageResult<Genesis> {
             todo!()
         }
+
+        fn get_state_chunk(
+            &self,
+            _: &StateChunkRequest,
+        ) -> StorageResult<Option<StateChunk>> {
+            todo!()
+        }
     }
 
     struct FakeBroadcast {
@@ -1137,6 +1693,9 @@ pub mod tests {
             chain_id: Default::default(),
             p2p_service,
             view_provider: FakeDB,
+            peer_store: FakePeerStore::default(),
+            known_peers: Default::default(),
+            persisted_bans: Default::default(),
             next_block_height: FakeBlockImporter.next_block_height(),
             request_receiver,
             broadcast,
@@ -1146,6 +1705,8 @@ pub mod tests {
             heartbeat_max_time_since_last,
             next_check_time: Instant::now(),
             heartbeat_peer_reputation_config: heartbeat_peer_reputation_config.clone(),
+            peer_ban_duration: Duration::from_secs(0),
+            pending_state_chunks: FuturesUnordered::new(),
         };
         let (watch_sender, watch_receiver) = tokio::sync::watch::channel(State::Started);
         let mut watcher = StateWatcher::from(watch_receiver);
@@ -1218,6 +1779,9 @@ pub mod tests {
             chain_id: Default::default(),
             p2p_service,
             view_provider: FakeDB,
+            peer_store: FakePeerStore::default(),
+            known_peers: Default::default(),
+            persisted_bans: Default::default(),
             next_block_height: FakeBlockImporter.next_block_height(),
             request_receiver,
             broadcast,
@@ -1227,6 +1791,8 @@ pub mod tests {
             heartbeat_max_time_since_last,
             next_check_time: Instant::now(),
             heartbeat_peer_reputation_config: heartbeat_peer_reputation_config.clone(),
+            peer_ban_duration: Duration::from_secs(0),
+            pending_state_chunks: FuturesUnordered::new(),
         };
         let (watch_sender, watch_receiver) = tokio::sync::watch::channel(State::Started);
         let mut watcher = StateWatcher::from(watch_receiver);
@@ -1250,4 +1816,74 @@ pub mod tests {
         );
         assert_eq!(reporting_service, "p2p");
     }
+
+    #[test]
+    fn known_peers_addresses__skips_banned_and_orders_by_score() {
+        // given
+        let address: Multiaddr = "/ip4/127.0.0.1/tcp/4001".parse().unwrap();
+        let known_peer = |score| KnownPeer {
+            addresses: vec![address.clone()],
+            last_seen: SystemTime::now(),
+            score,
+        };
+        let good_peer = PeerId::random();
+        let average_peer = PeerId::random();
+        let banned_peer = PeerId::random();
+        let known_peers = vec![
+            (average_peer, known_peer(50.)),
+            (banned_peer, known_peer(100.)),
+            (good_peer, known_peer(80.)),
+        ];
+        let banned_peers = [banned_peer].into_iter().collect();
+
+        // when
+        let addresses = known_peers_addresses(known_peers, &banned_peers, 10);
+
+        // then
+        let peer_ids: Vec<_> = addresses
+            .iter()
+            .map(|address| address.try_to_peer_id().unwrap())
+            .collect();
+        assert_eq!(peer_ids, vec![good_peer, average_peer]);
+    }
+
+    #[test]
+    fn stale_known_peers__removes_expired_and_lowest_scored_peers() {
+        // given
+        let now = SystemTime::now();
+        let known_peer = |score, age| KnownPeer {
+            addresses: vec![],
+            last_seen: now.checked_sub(age).unwrap(),
+            score,
+        };
+        let expired_peer = PeerId::random();
+        let good_peer = PeerId::random();
+        let average_peer = PeerId::random();
+        let bad_peer = PeerId::random();
+        let known_peers = [
+            (
+                expired_peer,
+                known_peer(
+                    100.,
+                    KNOWN_PEER_EXPIRATION
+                        .checked_add(Duration::from_secs(1))
+                        .unwrap(),
+                ),
+            ),
+            (good_peer, known_peer(80., Duration::from_secs(1))),
+            (average_peer, known_peer(50., Duration::from_secs(1))),
+            (bad_peer, known_peer(10., Duration::from_secs(1))),
+        ]
+        .into_iter()
+        .collect();
+
+        // when
+        let mut stale = stale_known_peers(&known_peers, now, 2);
+
+        // then
+        stale.sort();
+        let mut expected = vec![expired_peer, bad_peer];
+        expected.sort();
+        assert_eq!(stale, expected);
+    }
 }
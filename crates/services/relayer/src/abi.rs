@@ -10,4 +10,11 @@ pub mod bridge {
             event MessageSent(bytes32 indexed sender, bytes32 indexed recipient, uint256 indexed nonce, uint64 amount, bytes data)
         ]"#,
     );
+
+    super::abigen!(
+        TransactionSubmitted,
+        r#"[
+            event TransactionSubmitted(uint256 indexed nonce, uint64 max_gas, bytes canonically_serialized_tx)
+        ]"#,
+    );
 }
@@ -12,6 +12,8 @@ use std::{
 
 pub(crate) static ETH_LOG_MESSAGE: Lazy<H256> =
     Lazy::new(crate::abi::bridge::MessageSentFilter::signature);
+pub(crate) static ETH_FORCED_TX: Lazy<H256> =
+    Lazy::new(crate::abi::bridge::TransactionSubmittedFilter::signature);
 
 // TODO: Move settlement fields into `ChainConfig` because it is part of the consensus.
 #[derive(Clone, Debug)]
@@ -19,8 +21,16 @@ pub(crate) static ETH_LOG_MESSAGE: Lazy<H256> =
 pub struct Config {
     /// The da block to which the contract was deployed.
     pub da_deploy_height: DaBlockHeight,
-    /// Uri address to ethereum client.
-    pub relayer: Option<url::Url>,
+    /// Uri addresses to ethereum clients. Endpoints are used in the order of the list,
+    /// the next endpoint is used when the previous ones are unhealthy.
+    pub relayer: Option<Vec<url::Url>>,
+    /// The number of endpoints that must return the same response to accept it.
+    /// The quorum `1` means that the first successful response is accepted.
+    pub quorum: usize,
+    /// The number of consecutive failures after which the endpoint is considered unhealthy.
+    pub endpoint_max_failures: u32,
+    /// How long the unhealthy endpoint is skipped before it is tried again.
+    pub endpoint_cooldown: Duration,
     // TODO: Create `EthAddress` into `fuel_core_types`.
     /// Ethereum contract address.
     pub eth_v2_listening_contracts: Vec<H160>,
@@ -48,6 +58,9 @@ impl Config {
     pub const DEFAULT_SYNC_MINIMUM_DURATION: Duration = Duration::from_secs(5);
     pub const DEFAULT_SYNCING_CALL_FREQ: Duration = Duration::from_secs(5);
     pub const DEFAULT_SYNCING_LOG_FREQ: Duration = Duration::from_secs(60);
+    pub const DEFAULT_QUORUM: usize = 1;
+    pub const DEFAULT_ENDPOINT_MAX_FAILURES: u32 = 3;
+    pub const DEFAULT_ENDPOINT_COOLDOWN: Duration = Duration::from_secs(30);
 }
 
 impl Default for Config {
@@ -55,6 +68,9 @@ impl Default for Config {
         Self {
             da_deploy_height: DaBlockHeight::from(Self::DEFAULT_DA_DEPLOY_HEIGHT),
             relayer: None,
+            quorum: Self::DEFAULT_QUORUM,
+            endpoint_max_failures: Self::DEFAULT_ENDPOINT_MAX_FAILURES,
+            endpoint_cooldown: Self::DEFAULT_ENDPOINT_COOLDOWN,
             eth_v2_listening_contracts: vec![H160::from_str(
                 "0x03E4538018285e1c03CCce2F92C9538c87606911",
             )
@@ -12,6 +12,7 @@ pub(crate) mod config;
 pub(crate) mod log;
 
 mod service;
+mod transport;
 
 #[cfg(any(test, feature = "test-helpers"))]
 pub mod mock_db;
@@ -32,9 +33,13 @@ pub use ethers_core::types::{
 };
 pub use service::{
     new_service,
+    repair_events,
+    verify_events,
+    EventsMismatch,
     Service,
     SharedState,
 };
+pub use transport::MultiEndpointTransport;
 
 #[cfg(test)]
 fuel_core_trace::enable_tracing!();
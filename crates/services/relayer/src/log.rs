@@ -10,9 +10,15 @@ use ethers_core::{
 };
 use fuel_core_types::{
     blockchain::primitives::DaBlockHeight,
-    entities::message::{
-        Message,
-        MessageV1,
+    entities::{
+        message::{
+            Message,
+            MessageV1,
+        },
+        relayer::transaction::{
+            RelayedTransaction,
+            RelayedTransactionV1,
+        },
     },
     fuel_types::{
         Address,
@@ -46,10 +52,33 @@ impl From<&MessageLog> for Message {
     }
 }
 
+/// Transaction send from da to fuel network for forced inclusion.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct TransactionLog {
+    pub nonce: Nonce,
+    pub max_gas: Word,
+    pub serialized_transaction: Vec<u8>,
+    pub da_height: DaBlockHeight,
+}
+
+impl From<TransactionLog> for RelayedTransaction {
+    fn from(transaction: TransactionLog) -> Self {
+        RelayedTransactionV1 {
+            nonce: transaction.nonce,
+            max_gas: transaction.max_gas,
+            serialized_transaction: transaction.serialized_transaction,
+            da_height: transaction.da_height,
+        }
+        .into()
+    }
+}
+
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum EthEventLog {
     // Bridge message from da side
     Message(MessageLog),
+    // Forced transaction from da side
+    Transaction(TransactionLog),
     Ignored,
 }
 
@@ -97,6 +126,35 @@ impl TryFrom<&Log> for EthEventLog {
                     ),
                 })
             }
+            n if n == *config::ETH_FORCED_TX => {
+                if log.topics.len() != 2 {
+                    return Err(anyhow!("Malformed topics for forced Transaction"))
+                }
+
+                let raw_log = RawLog {
+                    topics: log.topics.clone(),
+                    data: log.data.to_vec(),
+                };
+
+                let event = abi::bridge::TransactionSubmittedFilter::decode_log(&raw_log)
+                    .map_err(anyhow::Error::msg)?;
+                let mut nonce = Nonce::zeroed();
+                event.nonce.to_big_endian(nonce.as_mut());
+
+                Self::Transaction(TransactionLog {
+                    nonce,
+                    max_gas: event.max_gas,
+                    serialized_transaction: event.canonically_serialized_tx.to_vec(),
+                    // Safety: logs without block numbers are rejected by
+                    // FinalizationQueue::append_eth_log before the conversion to EthEventLog happens.
+                    // If block_number is none, that means the log is pending.
+                    da_height: DaBlockHeight::from(
+                        log.block_number
+                            .ok_or(anyhow!("Log missing block height"))?
+                            .as_u64(),
+                    ),
+                })
+            }
             _ => Self::Ignored,
         };
 
@@ -7,7 +7,10 @@ use fuel_core_storage::{
 };
 use fuel_core_types::{
     blockchain::primitives::DaBlockHeight,
-    entities::message::Message,
+    entities::{
+        message::Message,
+        relayer::transaction::RelayedTransaction,
+    },
     fuel_types::Nonce,
     services::relayer::Event,
 };
@@ -25,6 +28,7 @@ use std::{
 #[derive(Default)]
 pub struct Data {
     pub messages: BTreeMap<DaBlockHeight, HashMap<Nonce, Message>>,
+    pub transactions: BTreeMap<DaBlockHeight, HashMap<Nonce, RelayedTransaction>>,
     pub finalized_da_height: Option<DaBlockHeight>,
 }
 
@@ -46,6 +50,15 @@ impl MockDb {
             .iter()
             .find_map(|(_, map)| map.get(id).cloned())
     }
+
+    pub fn get_transaction(&self, nonce: &Nonce) -> Option<RelayedTransaction> {
+        self.data
+            .lock()
+            .unwrap()
+            .transactions
+            .iter()
+            .find_map(|(_, map)| map.get(nonce).cloned())
+    }
 }
 
 impl RelayerDb for MockDb {
@@ -63,6 +76,12 @@ impl RelayerDb for MockDb {
                         .or_default()
                         .insert(*message.id(), message.clone());
                 }
+                Event::Transaction(transaction) => {
+                    m.transactions
+                        .entry(transaction.da_height())
+                        .or_default()
+                        .insert(*transaction.nonce(), transaction.clone());
+                }
             }
         }
         let max = m.finalized_da_height.get_or_insert(0u64.into());
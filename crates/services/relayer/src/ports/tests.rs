@@ -1,6 +1,8 @@
 use crate::{
     ports::RelayerDb,
     storage::{
+        overwrite_events,
+        reset_finalized_da_height,
         DaHeightTable,
         EventsHistory,
     },
@@ -153,3 +155,80 @@ fn set_raises_da_height_monotonically(
     db.set_finalized_da_height_to_at_least(&new_height.into())
         .unwrap();
 }
+
+#[test]
+fn reset_removes_events_above_new_da_height() {
+    // Given
+    let current_height = 15u64;
+    let new_height = 10u64;
+    let mut db = MockStorage::default();
+    db.expect_get::<DaHeightTable>()
+        .once()
+        .returning(move |_| Ok(Some(Cow::Owned(current_height.into()))));
+    db.expect_remove::<EventsHistory>()
+        .times(5)
+        .withf(move |k| **k > new_height && **k <= current_height)
+        .returning(|_| Ok(None));
+    db.expect_insert::<DaHeightTable>()
+        .once()
+        .withf(move |_, v| **v == new_height)
+        .returning(|_, _| Ok(None));
+    db.expect_commit().once().returning(|| Ok(()));
+
+    // When
+    let mut db = db.into_transactional();
+    let result = reset_finalized_da_height(&mut db, &new_height.into(), &0u64.into());
+
+    // Then
+    assert!(result.is_ok());
+}
+
+#[test]
+fn reset_fails_above_current_da_height() {
+    // Given
+    let mut db = MockStorage::default();
+    db.expect_get::<DaHeightTable>()
+        .once()
+        .returning(|_| Ok(Some(Cow::Owned(10u64.into()))));
+
+    // When
+    let mut db = db.into_transactional();
+    let result = reset_finalized_da_height(&mut db, &11u64.into(), &0u64.into());
+
+    // Then
+    let err = result.expect_err("Should fail to raise the da height");
+    assert!(err.to_string().contains("is above the current one"));
+}
+
+#[test]
+fn reset_fails_below_da_deploy_height() {
+    // Given
+    let db = MockStorage::default();
+
+    // When
+    let mut db = db.into_transactional();
+    let result = reset_finalized_da_height(&mut db, &4u64.into(), &5u64.into());
+
+    // Then
+    let err = result.expect_err("Should fail to reset below the deploy height");
+    assert!(err.to_string().contains("is below the da deploy height"));
+}
+
+#[test]
+fn overwrite_with_empty_events_removes_da_height() {
+    // Given
+    let height = 10u64;
+    let mut db = MockStorage::default();
+    db.expect_remove::<EventsHistory>()
+        .once()
+        .withf(move |k| **k == height)
+        .returning(|_| Ok(None));
+    db.expect_commit().once().returning(|| Ok(()));
+
+    // When
+    let mut db = db.into_transactional();
+    let result = overwrite_events(&mut db, &height.into(), &[]);
+
+    // Then
+    assert!(result.is_ok());
+}
@@ -5,6 +5,7 @@ use crate::{
     ports::RelayerDb,
     service::state::EthLocal,
     Config,
+    MultiEndpointTransport,
 };
 use async_trait::async_trait;
 use core::time::Duration;
@@ -16,7 +17,6 @@ use ethers_core::types::{
     H160,
 };
 use ethers_providers::{
-    Http,
     Middleware,
     Provider,
     ProviderError,
@@ -29,7 +29,10 @@ use fuel_core_services::{
 };
 use fuel_core_types::{
     blockchain::primitives::DaBlockHeight,
-    entities::message::Message,
+    entities::{
+        message::Message,
+        relayer::transaction::RelayedTransaction,
+    },
 };
 use futures::StreamExt;
 use std::{
@@ -49,6 +52,13 @@ mod run;
 mod state;
 mod synced;
 mod syncing;
+mod verify;
+
+pub use verify::{
+    repair_events,
+    verify_events,
+    EventsMismatch,
+};
 
 #[cfg(test)]
 mod test;
@@ -57,7 +67,7 @@ type Synced = watch::Receiver<Option<DaBlockHeight>>;
 type NotifySynced = watch::Sender<Option<DaBlockHeight>>;
 
 /// The alias of runnable relayer service.
-pub type Service<D> = CustomizableService<Provider<Http>, D>;
+pub type Service<D> = CustomizableService<Provider<MultiEndpointTransport>, D>;
 type CustomizableService<P, D> = ServiceRunner<NotInitializedTask<P, D>>;
 
 /// The shared state of the relayer task.
@@ -323,14 +333,8 @@ pub fn new_service<D>(database: D, config: Config) -> anyhow::Result<Service<D>>
 where
     D: RelayerDb + Clone + 'static,
 {
-    let url = config.relayer.clone().ok_or_else(|| {
-        anyhow::anyhow!(
-            "Tried to start Relayer without setting an eth_client in the config"
-        )
-    })?;
-    // TODO: Does this handle https?
-    let http = Http::new(url);
-    let eth_node = Provider::new(http);
+    let transport = MultiEndpointTransport::new(&config)?;
+    let eth_node = Provider::new(transport);
     Ok(new_service_internal(eth_node, database, config))
 }
 
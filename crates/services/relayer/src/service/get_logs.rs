@@ -30,7 +30,10 @@ where
                             .from_block(page.oldest())
                             .to_block(page.latest())
                             .address(ValueOrArray::Array(contracts))
-                            .topic0(*crate::config::ETH_LOG_MESSAGE);
+                            .topic0(ValueOrArray::Array(vec![
+                                Some(*crate::config::ETH_LOG_MESSAGE),
+                                Some(*crate::config::ETH_FORCED_TX),
+                            ]));
 
                         tracing::info!(
                             "Downloading logs for block range: {}..={}",
@@ -64,28 +67,7 @@ where
     tokio::pin!(logs);
     while let Some((last_height, events)) = logs.try_next().await? {
         let last_height = last_height.into();
-        let mut ordered_events = BTreeMap::<DaBlockHeight, Vec<Event>>::new();
-        let fuel_events =
-            events
-                .into_iter()
-                .filter_map(|event| match EthEventLog::try_from(&event) {
-                    Ok(event) => {
-                        match event {
-                            EthEventLog::Message(m) => {
-                                Some(Ok(Event::Message(Message::from(&m))))
-                            }
-                            // TODO: Log out ignored messages.
-                            EthEventLog::Ignored => None,
-                        }
-                    }
-                    Err(e) => Some(Err(e)),
-                });
-
-        for event in fuel_events {
-            let event = event?;
-            let height = event.da_height();
-            ordered_events.entry(height).or_default().push(event);
-        }
+        let ordered_events = logs_to_events(events)?;
 
         let mut inserted_last_height = false;
         for (height, events) in ordered_events {
@@ -101,3 +83,33 @@ where
     }
     Ok(())
 }
+
+/// Converts the logs into the events ordered by the DA height.
+pub(crate) fn logs_to_events(
+    logs: Vec<Log>,
+) -> anyhow::Result<BTreeMap<DaBlockHeight, Vec<Event>>> {
+    let mut ordered_events = BTreeMap::<DaBlockHeight, Vec<Event>>::new();
+    let fuel_events =
+        logs.into_iter()
+            .filter_map(|event| match EthEventLog::try_from(&event) {
+                Ok(event) => match event {
+                    EthEventLog::Message(m) => {
+                        Some(Ok(Event::Message(Message::from(&m))))
+                    }
+                    EthEventLog::Transaction(tx) => {
+                        Some(Ok(Event::Transaction(RelayedTransaction::from(tx))))
+                    }
+                    // TODO: Log out ignored messages.
+                    EthEventLog::Ignored => None,
+                },
+                Err(e) => Some(Err(e)),
+            });
+
+    for event in fuel_events {
+        let event = event?;
+        let height = event.da_height();
+        ordered_events.entry(height).or_default().push(event);
+    }
+
+    Ok(ordered_events)
+}
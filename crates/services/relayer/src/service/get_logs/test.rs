@@ -1,5 +1,6 @@
 #![allow(clippy::arithmetic_side_effects)]
 use ethers_core::types::U256;
+use fuel_core_types::fuel_types::Nonce;
 use std::{
     ops::RangeInclusive,
     sync::atomic::{
@@ -9,7 +10,10 @@ use std::{
 };
 
 use crate::{
-    abi::bridge::MessageSentFilter,
+    abi::bridge::{
+        MessageSentFilter,
+        TransactionSubmittedFilter,
+    },
     service::state::EthSyncGap,
     test_helpers::{
         middleware::{
@@ -181,3 +185,34 @@ async fn test_da_height_updates(
 
     *mock_db.get_finalized_da_height().unwrap()
 }
+
+#[tokio::test]
+async fn write_logs_stores_forced_transactions_alongside_messages() {
+    // given
+    let mut mock_db = crate::mock_db::MockDb::default();
+    let transaction = TransactionSubmittedFilter {
+        nonce: U256::from(7),
+        max_gas: 1000,
+        canonically_serialized_tx: vec![1, 2, 3].into(),
+    };
+    let mut transaction_log = transaction.into_log();
+    transaction_log.block_number = Some(2u64.into());
+    let logs =
+        futures::stream::iter(vec![Ok((2, vec![message(1, 2, 0), transaction_log]))]);
+
+    // when
+    write_logs(&mut mock_db, logs).await.unwrap();
+
+    // then
+    let mut nonce = Nonce::zeroed();
+    U256::from(7).to_big_endian(nonce.as_mut());
+    let transaction = mock_db
+        .get_transaction(&nonce)
+        .expect("The forced transaction should be stored");
+    assert_eq!(transaction.max_gas(), 1000);
+    assert_eq!(transaction.serialized_transaction(), &[1, 2, 3]);
+    assert_eq!(transaction.da_height(), 2u64.into());
+    let mut message_nonce = Nonce::zeroed();
+    U256::from(1).to_big_endian(message_nonce.as_mut());
+    assert!(mock_db.get_message(&message_nonce).is_some());
+}
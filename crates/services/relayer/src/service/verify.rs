@@ -0,0 +1,177 @@
+//! Verification of the relayer storage against the DA layer.
+
+use super::*;
+use crate::storage::{
+    overwrite_events,
+    DaHeightTable,
+    EventsHistory,
+};
+use fuel_core_storage::{
+    transactional::Transactional,
+    Error as StorageError,
+    StorageAsRef,
+    StorageInspect,
+    StorageMutate,
+};
+use fuel_core_types::services::relayer::Event;
+use futures::TryStreamExt;
+use std::ops::RangeInclusive;
+
+#[cfg(test)]
+mod test;
+
+/// The difference between the events stored in the relayer database
+/// and the events emitted by the DA layer at the same DA height.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventsMismatch {
+    /// The DA height of the events.
+    pub da_height: DaBlockHeight,
+    /// The events stored in the relayer database.
+    pub stored: Vec<Event>,
+    /// The events emitted by the DA layer.
+    pub expected: Vec<Event>,
+}
+
+impl EventsMismatch {
+    /// Returns events emitted by the DA layer that are absent in the database
+    /// or stored at another position.
+    pub fn missing(&self) -> Vec<&Event> {
+        difference(&self.expected, &self.stored)
+    }
+
+    /// Returns events stored in the database that the DA layer didn't emit
+    /// or emitted at another position.
+    pub fn extra(&self) -> Vec<&Event> {
+        difference(&self.stored, &self.expected)
+    }
+}
+
+/// Returns the events from `left` that are not part of the longest common
+/// subsequence of `left` and `right`. The order of events matters, so
+/// reordered events are reported along with absent and duplicated ones.
+fn difference<'a>(left: &'a [Event], right: &[Event]) -> Vec<&'a Event> {
+    // `common[i][j]` is the length of the longest common subsequence
+    // of `left[i..]` and `right[j..]`.
+    let mut common =
+        vec![vec![0usize; right.len().saturating_add(1)]; left.len().saturating_add(1)];
+    for (i, l) in left.iter().enumerate().rev() {
+        for (j, r) in right.iter().enumerate().rev() {
+            let (next_i, next_j) = (i.saturating_add(1), j.saturating_add(1));
+            common[i][j] = if l == r {
+                common[next_i][next_j].saturating_add(1)
+            } else {
+                common[next_i][j].max(common[i][next_j])
+            };
+        }
+    }
+
+    let mut result = vec![];
+    let (mut i, mut j) = (0, 0);
+    while let Some(l) = left.get(i) {
+        let (next_i, next_j) = (i.saturating_add(1), j.saturating_add(1));
+        match right.get(j) {
+            Some(r) if l == r => (i, j) = (next_i, next_j),
+            Some(_) if common[i][next_j] >= common[next_i][j] => j = next_j,
+            _ => {
+                result.push(l);
+                i = next_i;
+            }
+        }
+    }
+    result
+}
+
+/// Re-downloads the logs for the `range` of DA heights from the DA layer
+/// and compares them with the events in the `database`.
+/// Returns mismatches ordered by the DA height.
+///
+/// The `range` should not exceed the finalized DA height of the `database`.
+pub async fn verify_events<S>(
+    config: &Config,
+    database: &S,
+    range: RangeInclusive<u64>,
+) -> anyhow::Result<Vec<EventsMismatch>>
+where
+    S: StorageInspect<EventsHistory, Error = StorageError>
+        + StorageInspect<DaHeightTable, Error = StorageError>,
+{
+    let transport = MultiEndpointTransport::new(config)?;
+    let eth_node = Provider::new(transport);
+    verify_events_internal(&eth_node, config, database, range).await
+}
+
+pub(crate) async fn verify_events_internal<P, S>(
+    eth_node: &P,
+    config: &Config,
+    database: &S,
+    range: RangeInclusive<u64>,
+) -> anyhow::Result<Vec<EventsMismatch>>
+where
+    P: Middleware<Error = ProviderError> + 'static,
+    S: StorageInspect<EventsHistory, Error = StorageError>
+        + StorageInspect<DaHeightTable, Error = StorageError>,
+{
+    let (start, end) = range.clone().into_inner();
+    if start > end {
+        return Err(anyhow::anyhow!(
+            "The start of the range {start} is above the end {end}"
+        ))
+    }
+    let finalized = StorageAsRef::storage::<DaHeightTable>(database)
+        .get(&())?
+        .map(|height| height.as_u64())
+        .unwrap_or_default();
+    if end > finalized {
+        return Err(anyhow::anyhow!(
+            "The end of the range {end} is above the finalized da height {finalized}"
+        ))
+    }
+
+    let logs: Vec<_> = download_logs(
+        &state::EthSyncGap::new(start, end),
+        config.eth_v2_listening_contracts.clone(),
+        eth_node,
+        config.log_page_size,
+    )
+    .map_ok(|(_, logs)| logs)
+    .try_concat()
+    .await?;
+    let mut expected = logs_to_events(logs)?;
+
+    let mut mismatches = vec![];
+    for da_height in range {
+        let da_height: DaBlockHeight = da_height.into();
+        let expected = expected.remove(&da_height).unwrap_or_default();
+        let stored = StorageAsRef::storage::<EventsHistory>(database)
+            .get(&da_height)?
+            .map(|events| events.into_owned())
+            .unwrap_or_default();
+
+        let mismatch = EventsMismatch {
+            da_height,
+            stored,
+            expected,
+        };
+        if mismatch.stored != mismatch.expected {
+            mismatches.push(mismatch);
+        }
+    }
+
+    Ok(mismatches)
+}
+
+/// Replaces the stored events with the events emitted by the DA layer
+/// for each of the `mismatches`.
+pub fn repair_events<T, Storage>(
+    database: &mut T,
+    mismatches: &[EventsMismatch],
+) -> anyhow::Result<()>
+where
+    T: Transactional<Storage = Storage>,
+    Storage: StorageMutate<EventsHistory, Error = StorageError>,
+{
+    for mismatch in mismatches {
+        overwrite_events(database, &mismatch.da_height, &mismatch.expected)?;
+    }
+    Ok(())
+}
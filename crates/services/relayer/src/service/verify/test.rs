@@ -0,0 +1,167 @@
+#![allow(non_snake_case)]
+
+use super::*;
+use crate::{
+    abi::bridge::MessageSentFilter,
+    storage::Column,
+    test_helpers::{
+        middleware::MockMiddleware,
+        EvtToLog,
+        LogTestHelper,
+    },
+};
+use ethers_core::types::U256;
+use fuel_core_storage::{
+    structured_storage::{
+        test::InMemoryStorage,
+        StructuredStorage,
+    },
+    StorageAsMut,
+};
+use std::collections::BTreeMap;
+
+type TestStorage = StructuredStorage<InMemoryStorage<Column>>;
+
+fn message(nonce: u64, block_number: u64, contract: H160) -> Log {
+    let message = MessageSentFilter {
+        nonce: U256::from(nonce),
+        ..Default::default()
+    };
+    let mut log = message.into_log();
+    log.address = contract;
+    log.block_number = Some(block_number.into());
+    log
+}
+
+fn storage(finalized: u64, events: &[Event]) -> TestStorage {
+    let mut storage = StructuredStorage::new(InMemoryStorage::default());
+    let mut ordered_events = BTreeMap::<DaBlockHeight, Vec<Event>>::new();
+    for event in events {
+        ordered_events
+            .entry(event.da_height())
+            .or_default()
+            .push(event.clone());
+    }
+    for (height, events) in ordered_events {
+        storage
+            .storage_as_mut::<EventsHistory>()
+            .insert(&height, &events)
+            .unwrap();
+    }
+    storage
+        .storage_as_mut::<DaHeightTable>()
+        .insert(&(), &finalized.into())
+        .unwrap();
+    storage
+}
+
+fn eth_node(logs: Vec<Log>) -> MockMiddleware {
+    let eth_node = MockMiddleware::default();
+    eth_node.update_data(|data| data.logs_batch = vec![logs]);
+    eth_node
+}
+
+#[tokio::test]
+async fn verify_events__returns_nothing_when_storage_matches_da() {
+    // Given
+    let config = Config::default();
+    let contract = config.eth_v2_listening_contracts[0];
+    let logs = vec![message(1, 2, contract), message(2, 2, contract)];
+    let events: Vec<Event> = logs.iter().map(|log| log.to_msg().into()).collect();
+    let database = storage(5, &events);
+    let eth_node = eth_node(logs);
+
+    // When
+    let mismatches = verify_events_internal(&eth_node, &config, &database, 0..=5)
+        .await
+        .unwrap();
+
+    // Then
+    assert_eq!(mismatches, vec![]);
+}
+
+#[tokio::test]
+async fn verify_events__reports_missing_and_extra_events() {
+    // Given
+    let config = Config::default();
+    let contract = config.eth_v2_listening_contracts[0];
+    let da_logs = vec![message(1, 2, contract), message(2, 4, contract)];
+    let stale_log = message(3, 3, contract);
+    let stored: Vec<Event> = vec![da_logs[0].to_msg().into(), stale_log.to_msg().into()];
+    let database = storage(5, &stored);
+    let eth_node = eth_node(da_logs.clone());
+
+    // When
+    let mismatches = verify_events_internal(&eth_node, &config, &database, 0..=5)
+        .await
+        .unwrap();
+
+    // Then
+    let extra_event: Event = stale_log.to_msg().into();
+    let missing_event: Event = da_logs[1].to_msg().into();
+    assert_eq!(mismatches.len(), 2);
+    assert_eq!(mismatches[0].da_height, 3u64.into());
+    assert_eq!(mismatches[0].extra(), vec![&extra_event]);
+    assert!(mismatches[0].missing().is_empty());
+    assert_eq!(mismatches[1].da_height, 4u64.into());
+    assert_eq!(mismatches[1].missing(), vec![&missing_event]);
+    assert!(mismatches[1].extra().is_empty());
+}
+
+#[tokio::test]
+async fn verify_events__reports_duplicated_events() {
+    // Given
+    let config = Config::default();
+    let contract = config.eth_v2_listening_contracts[0];
+    let logs = vec![message(1, 2, contract)];
+    let event: Event = logs[0].to_msg().into();
+    let database = storage(5, &[event.clone(), event.clone()]);
+    let eth_node = eth_node(logs);
+
+    // When
+    let mismatches = verify_events_internal(&eth_node, &config, &database, 0..=5)
+        .await
+        .unwrap();
+
+    // Then
+    assert_eq!(mismatches.len(), 1);
+    assert_eq!(mismatches[0].extra(), vec![&event]);
+}
+
+#[tokio::test]
+async fn verify_events__reports_reordered_events() {
+    // Given
+    let config = Config::default();
+    let contract = config.eth_v2_listening_contracts[0];
+    let logs = vec![message(1, 2, contract), message(2, 2, contract)];
+    let first: Event = logs[0].to_msg().into();
+    let second: Event = logs[1].to_msg().into();
+    let database = storage(5, &[second.clone(), first.clone()]);
+    let eth_node = eth_node(logs);
+
+    // When
+    let mismatches = verify_events_internal(&eth_node, &config, &database, 0..=5)
+        .await
+        .unwrap();
+
+    // Then
+    assert_eq!(mismatches.len(), 1);
+    assert_eq!(mismatches[0].da_height, 2u64.into());
+    assert_eq!(mismatches[0].missing(), vec![&second]);
+    assert_eq!(mismatches[0].extra(), vec![&first]);
+}
+
+#[tokio::test]
+async fn verify_events__fails_above_finalized_da_height() {
+    // Given
+    let config = Config::default();
+    let database = storage(5, &[]);
+    let eth_node = eth_node(vec![]);
+
+    // When
+    let result = verify_events_internal(&eth_node, &config, &database, 0..=6).await;
+
+    // Then
+    let err = result.expect_err("Should fail because the range is not finalized");
+    assert!(err.to_string().contains("finalized da height"));
+}
@@ -166,6 +166,82 @@ where
     }
 }
 
+/// Overwrites the events at the DA height with `events`.
+/// If `events` are empty, removes the entry for the DA height.
+/// Doesn't change the finalized DA height.
+pub fn overwrite_events<T, Storage>(
+    database: &mut T,
+    da_height: &DaBlockHeight,
+    events: &[Event],
+) -> StorageResult<()>
+where
+    T: Transactional<Storage = Storage>,
+    Storage: StorageMutate<EventsHistory, Error = StorageError>,
+{
+    if events.iter().any(|event| &event.da_height() != da_height) {
+        return Err(anyhow::anyhow!("Invalid da height").into())
+    }
+
+    let mut db_tx = database.transaction();
+    let db = db_tx.as_mut();
+    if events.is_empty() {
+        db.storage::<EventsHistory>().remove(da_height)?;
+    } else {
+        db.storage::<EventsHistory>().insert(da_height, events)?;
+    }
+    db_tx.commit()?;
+    Ok(())
+}
+
+/// Lowers the finalized DA height to `height` and removes all events above it
+/// up to the current finalized DA height, so the relayer downloads them again.
+/// Both actions happen atomically. Fails if `height` is above the current
+/// finalized DA height or below the `da_deploy_height`, because the relayer
+/// raises the finalized DA height to the `da_deploy_height` on the start.
+pub fn reset_finalized_da_height<T, Storage>(
+    database: &mut T,
+    height: &DaBlockHeight,
+    da_deploy_height: &DaBlockHeight,
+) -> StorageResult<()>
+where
+    T: Transactional<Storage = Storage>,
+    Storage: StorageMutate<EventsHistory, Error = StorageError>
+        + StorageMutate<DaHeightTable, Error = StorageError>,
+{
+    if height < da_deploy_height {
+        return Err(anyhow::anyhow!(
+            "The new finalized da height {} is below the da deploy height {}",
+            height.as_u64(),
+            da_deploy_height.as_u64()
+        )
+        .into())
+    }
+
+    let mut db_tx = database.transaction();
+    let db = db_tx.as_mut();
+    let current = StorageAsRef::storage::<DaHeightTable>(&db)
+        .get(&METADATA_KEY)?
+        .map(|cow| cow.as_u64())
+        .unwrap_or_default();
+
+    if height.as_u64() > current {
+        return Err(anyhow::anyhow!(
+            "The new finalized da height {} is above the current one {}",
+            height.as_u64(),
+            current
+        )
+        .into())
+    }
+
+    for da_height in height.as_u64().saturating_add(1)..=current {
+        db.storage::<EventsHistory>().remove(&da_height.into())?;
+    }
+    db.storage::<DaHeightTable>()
+        .insert(&METADATA_KEY, height)?;
+    db_tx.commit()?;
+    Ok(())
+}
+
 fn grow_monotonically<Storage>(
     s: &mut Storage,
     height: &DaBlockHeight,
@@ -52,6 +52,12 @@ impl EvtToLog for crate::abi::bridge::MessageSentFilter {
     }
 }
 
+impl EvtToLog for crate::abi::bridge::TransactionSubmittedFilter {
+    fn into_log(self) -> Log {
+        event_to_log(self, &crate::abi::bridge::TRANSACTIONSUBMITTED_ABI)
+    }
+}
+
 pub fn event_to_log<E>(event: E, abi: &ethers_core::abi::Abi) -> Log
 where
     E: EthEvent,
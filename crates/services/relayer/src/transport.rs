@@ -0,0 +1,403 @@
+//! The JSON-RPC transport that spreads requests to the DA layer over several endpoints.
+
+use crate::Config;
+use async_trait::async_trait;
+use ethers_core::types::{
+    Block,
+    Log,
+    TxHash,
+    U64,
+};
+use ethers_providers::{
+    Http,
+    JsonRpcClient,
+    ProviderError,
+};
+use fuel_core_metrics::relayer_metrics::relayer_metrics;
+use serde::{
+    de::DeserializeOwned,
+    Serialize,
+};
+use serde_json::Value;
+use std::{
+    collections::BTreeSet,
+    fmt::Debug,
+    sync::Mutex,
+};
+use tokio::time::{
+    Duration,
+    Instant,
+};
+
+#[cfg(test)]
+mod test;
+
+/// The transport that sends JSON-RPC requests to several DA endpoints.
+///
+/// Each endpoint tracks its health. An endpoint becomes unhealthy after
+/// `endpoint_max_failures` consecutive failures and is skipped for the
+/// `endpoint_cooldown` duration. After the cooldown, the endpoint gets a new
+/// chance to serve a request.
+///
+/// If the quorum is `1`, the request is sent to endpoints one by one, starting
+/// from healthy ones in the order of the configuration, until one of them succeeds.
+/// Otherwise, the request is sent to all healthy endpoints concurrently.
+/// Endpoints reach new blocks at different times, so for the queries of the latest
+/// height, see [`is_height_query`], the response with the `quorum`-th highest height
+/// is accepted, i.e. the height that at least `quorum` endpoints have reached.
+/// For other queries, the response is accepted only if at least `quorum` endpoints
+/// returned the same response. Responses are compared by the values that
+/// the relayer uses, see [`quorum_key`].
+#[derive(Debug)]
+pub struct MultiEndpointTransport<C = Http> {
+    endpoints: Vec<Endpoint<C>>,
+    quorum: usize,
+    max_failures: u32,
+    cooldown: Duration,
+    metrics: bool,
+}
+
+#[derive(Debug)]
+struct Endpoint<C> {
+    /// The label of the endpoint for logs and metrics. It is the position of
+    /// the endpoint in the config along with its origin, so it doesn't contain
+    /// credentials and path, but is still unique for endpoints on the same host.
+    label: String,
+    client: C,
+    health: Mutex<Health>,
+}
+
+#[derive(Debug, Default)]
+struct Health {
+    consecutive_failures: u32,
+    unhealthy_since: Option<Instant>,
+}
+
+impl MultiEndpointTransport<Http> {
+    /// Creates the transport from the list of endpoints in the config.
+    pub fn new(config: &Config) -> anyhow::Result<Self> {
+        let urls = config.relayer.clone().unwrap_or_default();
+        let clients = urls
+            .into_iter()
+            .enumerate()
+            .map(|(index, url)| {
+                let label = format!("{index}:{}", url.origin().ascii_serialization());
+                (label, Http::new(url))
+            })
+            .collect();
+        Self::with_clients(clients, config)
+    }
+}
+
+impl<C> MultiEndpointTransport<C> {
+    pub(crate) fn with_clients(
+        clients: Vec<(String, C)>,
+        config: &Config,
+    ) -> anyhow::Result<Self> {
+        if clients.is_empty() {
+            return Err(anyhow::anyhow!(
+                "Tried to start Relayer without setting an eth_client in the config"
+            ))
+        }
+        if config.quorum == 0 || config.quorum > clients.len() {
+            return Err(anyhow::anyhow!(
+                "The relayer quorum {} should be in the range 1..={}",
+                config.quorum,
+                clients.len()
+            ))
+        }
+
+        let endpoints = clients
+            .into_iter()
+            .map(|(label, client)| Endpoint {
+                label,
+                client,
+                health: Mutex::new(Health::default()),
+            })
+            .collect::<Vec<_>>();
+
+        if config.metrics {
+            for endpoint in endpoints.iter() {
+                relayer_metrics().set_healthy(&endpoint.label, true);
+            }
+        }
+
+        Ok(Self {
+            endpoints,
+            quorum: config.quorum,
+            max_failures: config.endpoint_max_failures,
+            cooldown: config.endpoint_cooldown,
+            metrics: config.metrics,
+        })
+    }
+
+    /// Returns healthy endpoints first and unhealthy endpoints after them.
+    /// Endpoints preserve the order from the config inside each group.
+    fn endpoints_by_health(&self) -> (Vec<&Endpoint<C>>, Vec<&Endpoint<C>>) {
+        let now = Instant::now();
+        self.endpoints.iter().partition(|endpoint| {
+            let health = endpoint.health.lock().expect("poisoned");
+            health.consecutive_failures < self.max_failures
+                || health
+                    .unhealthy_since
+                    .map_or(true, |since| now.duration_since(since) >= self.cooldown)
+        })
+    }
+
+    fn record_result(&self, endpoint: &Endpoint<C>, success: bool, duration: Duration) {
+        let mut health = endpoint.health.lock().expect("poisoned");
+        if success {
+            health.consecutive_failures = 0;
+            health.unhealthy_since = None;
+        } else {
+            health.consecutive_failures = health.consecutive_failures.saturating_add(1);
+            if health.consecutive_failures >= self.max_failures {
+                if health.unhealthy_since.is_none() {
+                    tracing::warn!(
+                        "The DA endpoint {} is unhealthy after {} consecutive failures",
+                        endpoint.label,
+                        health.consecutive_failures
+                    );
+                }
+                health.unhealthy_since = Some(Instant::now());
+            }
+        }
+
+        if self.metrics {
+            let metrics = relayer_metrics();
+            metrics.observe_request(&endpoint.label, duration.as_secs_f64(), success);
+            metrics.set_healthy(&endpoint.label, health.unhealthy_since.is_none());
+        }
+    }
+}
+
+impl<C> MultiEndpointTransport<C>
+where
+    C: JsonRpcClient,
+{
+    async fn request_endpoint(
+        &self,
+        endpoint: &Endpoint<C>,
+        method: &str,
+        params: &Value,
+    ) -> Result<Value, ProviderError> {
+        let start = Instant::now();
+        let result = endpoint
+            .client
+            .request::<_, Value>(method, params)
+            .await
+            .map_err(Into::into);
+        self.record_result(endpoint, result.is_ok(), start.elapsed());
+
+        if let Err(err) = &result {
+            tracing::debug!(
+                "The DA endpoint {} failed the `{method}` request: {err}",
+                endpoint.label
+            );
+        }
+        result
+    }
+
+    async fn request_with_failover(
+        &self,
+        method: &str,
+        params: &Value,
+    ) -> Result<Value, ProviderError> {
+        let (healthy, unhealthy) = self.endpoints_by_health();
+        let mut last_error = None;
+        // Unhealthy endpoints are the last resort if all healthy endpoints failed.
+        for endpoint in healthy.into_iter().chain(unhealthy) {
+            match self.request_endpoint(endpoint, method, params).await {
+                Ok(value) => return Ok(value),
+                Err(err) => last_error = Some(err),
+            }
+        }
+
+        Err(last_error.unwrap_or_else(|| {
+            ProviderError::CustomError("No DA endpoints are available".to_string())
+        }))
+    }
+
+    async fn request_with_quorum(
+        &self,
+        method: &str,
+        params: &Value,
+    ) -> Result<Value, ProviderError> {
+        let (mut endpoints, unhealthy) = self.endpoints_by_health();
+        if endpoints.len() < self.quorum {
+            endpoints.extend(unhealthy);
+        }
+
+        let responses = futures::future::join_all(
+            endpoints
+                .into_iter()
+                .map(|endpoint| self.request_endpoint(endpoint, method, params)),
+        )
+        .await;
+
+        if is_height_query(method, params) {
+            return self.height_quorum(method, responses)
+        }
+
+        let mut last_error = None;
+        // The first response with the key is returned if the key wins.
+        let mut votes: Vec<(Value, Value, usize)> = vec![];
+        for response in responses {
+            match response {
+                Ok(value) => {
+                    let key = quorum_key(method, &value);
+                    match votes.iter_mut().find(|(voted, _, _)| voted == &key) {
+                        Some((_, _, count)) => *count = count.saturating_add(1),
+                        None => votes.push((key, value, 1)),
+                    }
+                }
+                Err(err) => last_error = Some(err),
+            }
+        }
+
+        let best = votes.into_iter().max_by_key(|(_, _, count)| *count);
+        match best {
+            Some((_, value, count)) if count >= self.quorum => Ok(value),
+            Some((_, _, count)) => Err(ProviderError::CustomError(format!(
+                "Only {count} DA endpoints agreed on the `{method}` response, \
+                but the quorum is {}",
+                self.quorum
+            ))),
+            None => Err(last_error.unwrap_or_else(|| {
+                ProviderError::CustomError("No DA endpoints are available".to_string())
+            })),
+        }
+    }
+
+    /// Returns the response with the `quorum`-th highest height among the `responses`
+    /// to the height query, so at least `quorum` endpoints have reached this height.
+    fn height_quorum(
+        &self,
+        method: &str,
+        responses: Vec<Result<Value, ProviderError>>,
+    ) -> Result<Value, ProviderError> {
+        let mut last_error = None;
+        let mut reported = vec![];
+        for response in responses {
+            match response {
+                Ok(value) => match reported_height(method, &value) {
+                    Some(height) => reported.push((height, value)),
+                    None => {
+                        last_error = Some(ProviderError::CustomError(format!(
+                            "The DA endpoint didn't report the height in the `{method}` \
+                            response"
+                        )))
+                    }
+                },
+                Err(err) => last_error = Some(err),
+            }
+        }
+
+        let count = reported.len();
+        reported.sort_by(|(height, _), (other, _)| other.cmp(height));
+        match reported.into_iter().nth(self.quorum.saturating_sub(1)) {
+            Some((_, value)) => Ok(value),
+            None if count > 0 => Err(ProviderError::CustomError(format!(
+                "Only {count} DA endpoints reported the height for `{method}`, \
+                but the quorum is {}",
+                self.quorum
+            ))),
+            None => Err(last_error.unwrap_or_else(|| {
+                ProviderError::CustomError("No DA endpoints are available".to_string())
+            })),
+        }
+    }
+}
+
+/// Returns `true` if the `method` with the `params` asks for the latest height
+/// of the DA layer: `eth_blockNumber` and `eth_getBlockByNumber("latest")`.
+fn is_height_query(method: &str, params: &Value) -> bool {
+    match method {
+        "eth_blockNumber" => true,
+        "eth_getBlockByNumber" => params.get(0).and_then(Value::as_str) == Some("latest"),
+        _ => false,
+    }
+}
+
+/// Returns the height reported in the `response` to the height query.
+fn reported_height(method: &str, response: &Value) -> Option<u64> {
+    let height = match method {
+        "eth_blockNumber" => serde_json::from_value::<U64>(response.clone()).ok(),
+        _ => {
+            let block: Option<Block<TxHash>> =
+                serde_json::from_value(response.clone()).ok()?;
+            block?.number
+        }
+    };
+    height.map(|number| number.as_u64())
+}
+
+/// Returns the part of the `response` to the `method` that endpoints must agree on.
+///
+/// Endpoints may encode the same data differently or return additional fields,
+/// so the responses for the methods used by the relayer are decoded and reduced to:
+/// - the number and the hash of the block for `eth_getBlockBy*`;
+/// - the set of logs for `eth_getLogs`, regardless of their order.
+///
+/// The raw response is used for other methods or if it can't be decoded.
+fn quorum_key(method: &str, response: &Value) -> Value {
+    fn decode<T: DeserializeOwned>(response: &Value) -> Option<T> {
+        serde_json::from_value(response.clone()).ok()
+    }
+
+    let key = match method {
+        "eth_getBlockByNumber" | "eth_getBlockByHash" => {
+            decode::<Option<Block<TxHash>>>(response).and_then(|block| {
+                let block = block.map(|block| (block.number, block.hash));
+                serde_json::to_value(block).ok()
+            })
+        }
+        "eth_getLogs" => decode::<Vec<Log>>(response).and_then(|logs| {
+            let logs = logs
+                .into_iter()
+                .map(|log| {
+                    serde_json::to_string(&(
+                        log.block_hash,
+                        log.transaction_hash,
+                        log.log_index,
+                        log.address,
+                        log.topics,
+                        log.data,
+                    ))
+                })
+                .collect::<Result<BTreeSet<_>, _>>()
+                .ok()?;
+            serde_json::to_value(logs).ok()
+        }),
+        _ => None,
+    };
+    key.unwrap_or_else(|| response.clone())
+}
+
+#[async_trait]
+impl<C> JsonRpcClient for MultiEndpointTransport<C>
+where
+    C: JsonRpcClient,
+{
+    type Error = ProviderError;
+
+    async fn request<T, R>(&self, method: &str, params: T) -> Result<R, Self::Error>
+    where
+        T: Debug + Serialize + Send + Sync,
+        R: DeserializeOwned,
+    {
+        let params = match serde_json::to_value(params)? {
+            // Requests without parameters should send an empty list instead of `null`.
+            Value::Null => Value::Array(vec![]),
+            params => params,
+        };
+
+        let value = if self.quorum > 1 {
+            self.request_with_quorum(method, &params).await?
+        } else {
+            self.request_with_failover(method, &params).await?
+        };
+
+        Ok(serde_json::from_value(value)?)
+    }
+}
@@ -0,0 +1,304 @@
+#![allow(non_snake_case)]
+
+use super::*;
+use ethers_core::types::H256;
+use std::sync::{
+    atomic::{
+        AtomicUsize,
+        Ordering,
+    },
+    Arc,
+};
+
+#[derive(Debug, Clone, Default)]
+struct TestClient {
+    /// The response of the endpoint, `None` means that the endpoint is down.
+    response: Arc<Mutex<Option<Value>>>,
+    calls: Arc<AtomicUsize>,
+}
+
+impl TestClient {
+    fn responding(value: u64) -> Self {
+        let client = Self::default();
+        client.set_response(Some(value));
+        client
+    }
+
+    fn responding_with(value: Value) -> Self {
+        let client = Self::default();
+        *client.response.lock().unwrap() = Some(value);
+        client
+    }
+
+    fn failing() -> Self {
+        Self::default()
+    }
+
+    fn set_response(&self, value: Option<u64>) {
+        *self.response.lock().unwrap() =
+            value.map(|value| serde_json::to_value(U64::from(value)).unwrap());
+    }
+
+    fn calls(&self) -> usize {
+        self.calls.load(Ordering::SeqCst)
+    }
+}
+
+#[async_trait]
+impl JsonRpcClient for TestClient {
+    type Error = ProviderError;
+
+    async fn request<T, R>(&self, _: &str, _: T) -> Result<R, Self::Error>
+    where
+        T: Debug + Serialize + Send + Sync,
+        R: DeserializeOwned,
+    {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        let response = self.response.lock().unwrap().clone();
+        match response {
+            Some(value) => Ok(serde_json::from_value(value)?),
+            None => Err(ProviderError::CustomError("Endpoint is down".to_string())),
+        }
+    }
+}
+
+fn transport(
+    clients: &[TestClient],
+    quorum: usize,
+) -> MultiEndpointTransport<TestClient> {
+    let config = Config {
+        quorum,
+        endpoint_max_failures: 2,
+        endpoint_cooldown: Duration::from_secs(10),
+        ..Default::default()
+    };
+    let clients = clients
+        .iter()
+        .enumerate()
+        .map(|(i, client)| (format!("endpoint_{i}"), client.clone()))
+        .collect();
+    MultiEndpointTransport::with_clients(clients, &config).unwrap()
+}
+
+async fn block_number<C: JsonRpcClient>(
+    transport: &MultiEndpointTransport<C>,
+) -> Result<u64, ProviderError> {
+    let number: U64 = transport.request("eth_blockNumber", ()).await?;
+    Ok(number.as_u64())
+}
+
+fn block(number: u64) -> Value {
+    let block = Block::<TxHash> {
+        number: Some(number.into()),
+        hash: Some(H256::from_low_u64_be(number)),
+        ..Default::default()
+    };
+    serde_json::to_value(block).unwrap()
+}
+
+#[tokio::test]
+async fn request__fails_over_to_the_next_endpoint() {
+    // given
+    let first = TestClient::failing();
+    let second = TestClient::responding(10);
+    let transport = transport(&[first.clone(), second.clone()], 1);
+
+    // when
+    let result = block_number(&transport).await;
+
+    // then
+    assert_eq!(result.unwrap(), 10);
+    assert_eq!(first.calls(), 1);
+    assert_eq!(second.calls(), 1);
+}
+
+#[tokio::test]
+async fn request__fails_when_all_endpoints_fail() {
+    // given
+    let transport = transport(&[TestClient::failing(), TestClient::failing()], 1);
+
+    // when
+    let result = block_number(&transport).await;
+
+    // then
+    assert!(result.is_err());
+}
+
+#[tokio::test(start_paused = true)]
+async fn request__skips_unhealthy_endpoint_until_cooldown_passes() {
+    // given
+    let first = TestClient::failing();
+    let second = TestClient::responding(10);
+    let transport = transport(&[first.clone(), second.clone()], 1);
+    block_number(&transport).await.unwrap();
+    block_number(&transport).await.unwrap();
+    assert_eq!(first.calls(), 2);
+    first.set_response(Some(20));
+
+    // when
+    let while_unhealthy = block_number(&transport).await.unwrap();
+    tokio::time::advance(Duration::from_secs(10)).await;
+    let after_cooldown = block_number(&transport).await.unwrap();
+
+    // then
+    assert_eq!(while_unhealthy, 10);
+    assert_eq!(after_cooldown, 20);
+    assert_eq!(first.calls(), 3);
+}
+
+#[tokio::test]
+async fn request__with_quorum_returns_response_agreed_by_quorum() {
+    // given
+    let clients = [
+        TestClient::responding(10),
+        TestClient::responding(11),
+        TestClient::responding(10),
+    ];
+    let transport = transport(&clients, 2);
+
+    // when
+    let result = block_number(&transport).await;
+
+    // then
+    assert_eq!(result.unwrap(), 10);
+    assert!(clients.iter().all(|client| client.calls() == 1));
+}
+
+#[tokio::test]
+async fn request__with_quorum_returns_height_reached_by_quorum() {
+    // given
+    let clients = [
+        TestClient::responding(10),
+        TestClient::responding(12),
+        TestClient::responding(11),
+    ];
+    let transport = transport(&clients, 2);
+
+    // when
+    let result = block_number(&transport).await;
+
+    // then
+    assert_eq!(result.unwrap(), 11);
+}
+
+#[tokio::test]
+async fn request__with_quorum_fails_when_height_is_reported_below_quorum() {
+    // given
+    let clients = [
+        TestClient::responding(10),
+        TestClient::failing(),
+        TestClient::failing(),
+    ];
+    let transport = transport(&clients, 2);
+
+    // when
+    let result = block_number(&transport).await;
+
+    // then
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn request__with_quorum_returns_latest_block_reached_by_quorum() {
+    // given
+    let clients = [
+        TestClient::responding_with(block(12)),
+        TestClient::responding_with(block(10)),
+        TestClient::responding_with(block(11)),
+    ];
+    let transport = transport(&clients, 2);
+
+    // when
+    let result: Result<Block<TxHash>, _> = transport
+        .request("eth_getBlockByNumber", ("latest", false))
+        .await;
+
+    // then
+    assert_eq!(result.unwrap().number, Some(11.into()));
+}
+
+#[tokio::test]
+async fn request__with_quorum_fails_when_endpoints_disagree_on_content() {
+    // given
+    let clients = [
+        TestClient::responding_with(block(10)),
+        TestClient::responding_with(block(11)),
+        TestClient::failing(),
+    ];
+    let transport = transport(&clients, 2);
+
+    // when
+    let result: Result<Block<TxHash>, _> = transport
+        .request("eth_getBlockByNumber", ("0xa", false))
+        .await;
+
+    // then
+    assert!(result.is_err());
+}
+
+fn logs() -> (Log, Log) {
+    let first = Log {
+        block_hash: Some(H256::repeat_byte(1)),
+        log_index: Some(0.into()),
+        ..Default::default()
+    };
+    let second = Log {
+        log_index: Some(1.into()),
+        ..first.clone()
+    };
+    (first, second)
+}
+
+#[test]
+fn quorum_key__ignores_order_of_logs_and_unknown_fields() {
+    // given
+    let (first, second) = logs();
+    let response = serde_json::to_value([&first, &second]).unwrap();
+    let mut other_response = serde_json::to_value([&second, &first]).unwrap();
+    other_response[0]["blockTimestamp"] = Value::from("0x1");
+
+    // when
+    let key = quorum_key("eth_getLogs", &response);
+    let other_key = quorum_key("eth_getLogs", &other_response);
+
+    // then
+    assert_eq!(key, other_key);
+}
+
+#[test]
+fn quorum_key__detects_different_logs() {
+    // given
+    let (first, second) = logs();
+    let modified = Log {
+        data: vec![1].into(),
+        ..second.clone()
+    };
+    let response = serde_json::to_value([&first, &second]).unwrap();
+    let other_response = serde_json::to_value([&first, &modified]).unwrap();
+
+    // when
+    let key = quorum_key("eth_getLogs", &response);
+    let other_key = quorum_key("eth_getLogs", &other_response);
+
+    // then
+    assert_ne!(key, other_key);
+}
+
+#[test]
+fn with_clients__rejects_quorum_bigger_than_number_of_endpoints() {
+    // given
+    let config = Config {
+        quorum: 3,
+        ..Default::default()
+    };
+    let clients = vec![
+        ("first".to_string(), TestClient::default()),
+        ("second".to_string(), TestClient::default()),
+    ];
+
+    // when
+    let result = MultiEndpointTransport::with_clients(clients, &config);
+
+    // then
+    assert!(result.is_err());
+}
@@ -2,6 +2,12 @@
 //! This module contains the import task which is responsible for
 //! importing blocks from the network into the local blockchain.
 
+use fuel_core_chain_config::{
+    StateCheckpoint,
+    StateChunkKind,
+    StateChunkRequest,
+    StateConfig,
+};
 use fuel_core_services::{
     SharedMutex,
     StateWatcher,
@@ -21,7 +27,11 @@ use fuel_core_types::{
     },
 };
 use futures::{
-    stream::StreamExt,
+    future::BoxFuture,
+    stream::{
+        BoxStream,
+        StreamExt,
+    },
     FutureExt,
     Stream,
 };
@@ -58,6 +68,17 @@ mod tests;
 #[cfg(test)]
 mod back_pressure_tests;
 
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+/// The mode of the synchronization.
+pub enum SyncMode {
+    /// Download and execute full blocks.
+    #[default]
+    Full,
+    /// Download only sealed block headers and verify the seal and
+    /// the chain of headers without execution of blocks.
+    Light,
+}
+
 #[derive(Clone, Copy, Debug)]
 /// Parameters for the import task.
 pub struct Config {
@@ -65,6 +86,18 @@ pub struct Config {
     pub block_stream_buffer_size: usize,
     /// The maximum number of headers to request in a single batch.
     pub header_batch_size: usize,
+    /// The mode of the synchronization.
+    pub mode: SyncMode,
+    /// The trusted checkpoint. If the node is behind it, the node imports only
+    /// headers up to the checkpoint and downloads the state at the checkpoint
+    /// from peers instead of executing blocks.
+    pub checkpoint: Option<StateCheckpoint>,
+    /// The maximum number of chunks of one kind of the state at the checkpoint.
+    /// The peer that doesn't finish the state in this number of chunks is reported.
+    pub max_state_chunks: u32,
+    /// The maximum size of the state at the checkpoint, in bytes. The state is
+    /// verified and committed at once, so it is kept in memory until the commit.
+    pub max_state_size: u64,
 }
 
 impl Default for Config {
@@ -72,6 +105,10 @@ impl Default for Config {
         Self {
             block_stream_buffer_size: 10,
             header_batch_size: 100,
+            mode: SyncMode::Full,
+            checkpoint: None,
+            max_state_chunks: 1_000_000,
+            max_state_size: 512 * 1024 * 1024,
         }
     }
 }
@@ -139,10 +176,35 @@ impl<T> Batch<T> {
     pub fn is_err(&self) -> bool {
         self.results.len() < self.range.len()
     }
+
+    pub fn map<R>(self, f: impl FnMut(T) -> R) -> Batch<R> {
+        let Batch {
+            peer,
+            range,
+            results,
+        } = self;
+        Batch::new(peer, range, results.into_iter().map(f).collect())
+    }
 }
 
 type SealedHeaderBatch = Batch<SealedBlockHeader>;
 type SealedBlockBatch = Batch<SealedBlock>;
+type ImportableBatch = Batch<Importable>;
+
+/// The data received from the network that is ready to be imported.
+#[derive(Debug)]
+enum Importable {
+    /// The full block that should be executed.
+    Block(SealedBlock),
+    /// The sealed header of the block, used in the light mode.
+    Header(SealedBlockHeader),
+    /// The sealed header of the block at the checkpoint with the state at this block.
+    Checkpoint {
+        checkpoint: StateCheckpoint,
+        header: SealedBlockHeader,
+        state: StateConfig,
+    },
+}
 
 impl<P, E, C> Import<P, E, C>
 where
@@ -208,8 +270,58 @@ where
         let (shutdown_guard, mut shutdown_guard_recv) =
             tokio::sync::mpsc::channel::<()>(1);
 
-        let block_stream =
-            get_block_stream(range.clone(), params, p2p.clone(), consensus.clone());
+        let block_stream = match params.checkpoint {
+            // The node is behind the checkpoint, so it doesn't have the state to execute
+            // blocks. Import only headers up to the checkpoint and the state at it.
+            Some(checkpoint) if *range.start() <= *checkpoint.height => {
+                let checkpoint_height = *checkpoint.height;
+                if *range.end() < checkpoint_height {
+                    get_importable_stream(
+                        range.clone(),
+                        SyncMode::Light,
+                        params,
+                        p2p.clone(),
+                        consensus.clone(),
+                    )
+                } else {
+                    let headers_range =
+                        *range.start()..=checkpoint_height.saturating_sub(1);
+                    let rest_range = checkpoint_height.saturating_add(1)..=*range.end();
+                    let checkpoint_batch = get_checkpoint(
+                        checkpoint,
+                        params,
+                        p2p.clone(),
+                        consensus.clone(),
+                    )
+                    .boxed();
+                    get_importable_stream(
+                        headers_range,
+                        SyncMode::Light,
+                        params,
+                        p2p.clone(),
+                        consensus.clone(),
+                    )
+                    .chain(futures::stream::once(futures::future::ready(
+                        checkpoint_batch,
+                    )))
+                    .chain(get_importable_stream(
+                        rest_range,
+                        params.mode,
+                        params,
+                        p2p.clone(),
+                        consensus.clone(),
+                    ))
+                    .boxed()
+                }
+            }
+            _ => get_importable_stream(
+                range.clone(),
+                params.mode,
+                params,
+                p2p.clone(),
+                consensus.clone(),
+            ),
+        };
         let result = block_stream
             .map(move |stream_block_batch| {
                 let shutdown_guard = shutdown_guard.clone();
@@ -252,8 +364,8 @@ where
                     } = batch;
 
                     let mut done = vec![];
-                    for sealed_block in results {
-                        let res = execute_and_commit(executor.as_ref(), state, sealed_block).await;
+                    for importable in results {
+                        let res = import_and_commit(executor.as_ref(), state, importable).await;
 
                         match &res {
                             Ok(_) => {
@@ -295,6 +407,28 @@ where
     }
 }
 
+/// Returns the stream of batches ready to be imported in the `mode`.
+fn get_importable_stream<'a, P, C>(
+    range: RangeInclusive<u32>,
+    mode: SyncMode,
+    params: &'a Config,
+    p2p: Arc<P>,
+    consensus: Arc<C>,
+) -> BoxStream<'a, BoxFuture<'static, ImportableBatch>>
+where
+    P: PeerToPeerPort + Send + Sync + 'static,
+    C: ConsensusPort + Send + Sync + 'static,
+{
+    match mode {
+        SyncMode::Full => get_block_stream(range, params, p2p, consensus)
+            .map(|future| future.map(|batch| batch.map(Importable::Block)).boxed())
+            .boxed(),
+        SyncMode::Light => get_header_stream(range, params, p2p, consensus)
+            .map(|batch| futures::future::ready(batch).boxed())
+            .boxed(),
+    }
+}
+
 fn get_block_stream<
     P: PeerToPeerPort + Send + Sync + 'static,
     C: ConsensusPort + Send + Sync + 'static,
@@ -352,6 +486,189 @@ fn get_block_stream<
         })
 }
 
+/// Returns the stream of sealed headers with verified seals.
+/// Used in the light mode, where bodies of blocks are not downloaded.
+fn get_header_stream<
+    P: PeerToPeerPort + Send + Sync + 'static,
+    C: ConsensusPort + Send + Sync + 'static,
+>(
+    range: RangeInclusive<u32>,
+    params: &Config,
+    p2p: Arc<P>,
+    consensus: Arc<C>,
+) -> impl Stream<Item = ImportableBatch> + '_ {
+    get_header_batch_stream(range, params, p2p.clone()).map(
+        move |header_batch: SealedHeaderBatch| {
+            let Batch {
+                peer,
+                range,
+                results,
+            } = header_batch;
+            let checked_headers = results
+                .into_iter()
+                .take_while(|header| {
+                    check_sealed_header(header, peer.clone(), &p2p, &consensus)
+                })
+                .map(Importable::Header)
+                .collect::<Vec<_>>();
+            Batch::new(peer, range, checked_headers)
+        },
+    )
+}
+
+/// Downloads the sealed header at the checkpoint and the state at this header.
+/// Returns an empty batch if the header doesn't match the checkpoint
+/// or the state is unavailable.
+async fn get_checkpoint<P, C>(
+    checkpoint: StateCheckpoint,
+    params: &Config,
+    p2p: Arc<P>,
+    consensus: Arc<C>,
+) -> ImportableBatch
+where
+    P: PeerToPeerPort + Send + Sync + 'static,
+    C: ConsensusPort + Send + Sync + 'static,
+{
+    let height = *checkpoint.height;
+    let Batch {
+        peer,
+        range,
+        results,
+    } = get_headers_batch(height..height.saturating_add(1), &p2p).await;
+    let Some(header) = results.into_iter().next() else {
+        return Batch::new(peer, range, vec![])
+    };
+
+    if !check_sealed_header(&header, peer.clone(), &p2p, &consensus) {
+        return Batch::new(peer, range, vec![])
+    }
+
+    if header.entity.id() != checkpoint.block_id {
+        tracing::error!(
+            "The block at the height {} doesn't match the checkpoint",
+            height
+        );
+        report_peer(&p2p, peer.clone(), PeerReportReason::BadBlockHeader);
+        return Batch::new(peer, range, vec![])
+    }
+
+    let Some(state) = get_state(&checkpoint, params, &p2p, &consensus).await else {
+        return Batch::new(peer, range, vec![])
+    };
+
+    let checkpoint = Importable::Checkpoint {
+        checkpoint,
+        header,
+        state,
+    };
+    Batch::new(peer, range, vec![checkpoint])
+}
+
+/// Downloads all chunks of the state at the checkpoint.
+/// All chunks of one kind are requested from the same peer, so the peer
+/// can be reported if the assembled entries don't match the root of the checkpoint.
+/// Each kind is verified as soon as its last chunk arrives. The download stops if
+/// the state exceeds [`Config::max_state_chunks`] chunks of one kind
+/// or [`Config::max_state_size`].
+async fn get_state<P, C>(
+    checkpoint: &StateCheckpoint,
+    params: &Config,
+    p2p: &Arc<P>,
+    consensus: &Arc<C>,
+) -> Option<StateConfig>
+where
+    P: PeerToPeerPort + Send + Sync + 'static,
+    C: ConsensusPort + Send + Sync + 'static,
+{
+    let mut state = StateConfig::default();
+    let mut size = 0u64;
+    for kind in StateChunkKind::ALL {
+        let mut source_peer: Option<PeerId> = None;
+        let mut index = 0u32;
+        loop {
+            if index >= params.max_state_chunks {
+                tracing::error!(
+                    "The {:?} state at the height {} exceeds {} chunks",
+                    kind,
+                    checkpoint.height,
+                    params.max_state_chunks
+                );
+                if let Some(peer_id) = source_peer {
+                    report_peer(p2p, peer_id, PeerReportReason::InvalidStateChunk);
+                }
+                return None
+            }
+            let request = StateChunkRequest {
+                height: checkpoint.height,
+                kind,
+                index,
+            };
+            let SourcePeer {
+                peer_id,
+                data: chunk,
+            } = p2p
+                .get_state_chunk(source_peer.clone(), request)
+                .await
+                .trace_err("Failed to get the state chunk")
+                .ok()?;
+            source_peer = Some(peer_id.clone());
+
+            let chunk = match chunk {
+                Some(chunk) if chunk.is_response_to(&request) => chunk,
+                Some(_) => {
+                    report_peer(p2p, peer_id, PeerReportReason::InvalidStateChunk);
+                    return None
+                }
+                // The peer could move forward and doesn't have the state anymore.
+                None => {
+                    tracing::warn!(
+                        "The state chunk {:?} is unavailable at the peer {:?}",
+                        request,
+                        peer_id
+                    );
+                    return None
+                }
+            };
+
+            let chunk_size = u64::try_from(chunk.entries.size()).unwrap_or(u64::MAX);
+            size = size.saturating_add(chunk_size);
+            if size > params.max_state_size {
+                tracing::error!(
+                    "The state at the height {} exceeds {} bytes",
+                    checkpoint.height,
+                    params.max_state_size
+                );
+                report_peer(p2p, peer_id, PeerReportReason::InvalidStateChunk);
+                return None
+            }
+
+            let last = chunk.last;
+            state.extend_with_chunk(chunk);
+            if last {
+                break
+            }
+            index = index.saturating_add(1);
+        }
+
+        let valid = consensus
+            .check_state_root(checkpoint, kind, &state)
+            .trace_err("Failed to check the root of the state")
+            .ok()?;
+        if !valid {
+            tracing::error!(
+                "The {:?} state at the height {} doesn't match the checkpoint",
+                kind,
+                checkpoint.height
+            );
+            if let Some(peer_id) = source_peer {
+                report_peer(p2p, peer_id, PeerReportReason::InvalidStateChunk);
+            }
+            return None
+        }
+    }
+    Some(state)
+}
+
 fn get_header_batch_stream<P: PeerToPeerPort + Send + Sync + 'static>(
     range: RangeInclusive<u32>,
     params: &Config,
@@ -394,7 +711,7 @@ fn check_sealed_header<
         .trace_err("Failed to check consensus on header")
         .unwrap_or(false);
     if !validity {
-        report_peer(p2p, peer_id.clone(), PeerReportReason::BadBlockHeader);
+        report_peer(p2p, peer_id.clone(), PeerReportReason::BadSeal);
     }
     validity
 }
@@ -577,6 +894,102 @@ where
     r
 }
 
+#[tracing::instrument(
+    skip_all,
+    fields(
+        height = **header.entity.height(),
+        id = %header.entity.id()
+    ),
+    err
+)]
+async fn import_header_and_commit<E>(
+    executor: &E,
+    state: &SharedMutex<State>,
+    header: SealedBlockHeader,
+) -> anyhow::Result<()>
+where
+    E: BlockImporterPort + Send + Sync + 'static,
+{
+    // Verify the chain of headers and commit the header.
+    let height = *header.entity.height();
+    let r = executor.import_header(header).await;
+
+    // If the header was imported successfully, mark it as committed.
+    if r.is_ok() {
+        state.apply(|s| s.commit(*height));
+    } else {
+        tracing::error!("Import of the header at height {} failed: {:?}", *height, r);
+    }
+    r
+}
+
+#[tracing::instrument(
+    skip_all,
+    fields(
+        height = **header.entity.height(),
+        id = %header.entity.id()
+    ),
+    err
+)]
+async fn import_checkpoint_and_commit<E>(
+    executor: &E,
+    state: &SharedMutex<State>,
+    checkpoint: StateCheckpoint,
+    header: SealedBlockHeader,
+    state_config: StateConfig,
+) -> anyhow::Result<()>
+where
+    E: BlockImporterPort + Send + Sync + 'static,
+{
+    // Verify the state against the checkpoint and commit it with the header.
+    let height = *header.entity.height();
+    let r = executor
+        .import_checkpoint(checkpoint, header, state_config)
+        .await;
+
+    // If the checkpoint was imported successfully, mark it as committed.
+    if r.is_ok() {
+        state.apply(|s| s.commit(*height));
+    } else {
+        tracing::error!(
+            "Import of the checkpoint at height {} failed: {:?}",
+            *height,
+            r
+        );
+    }
+    r
+}
+
+async fn import_and_commit<E>(
+    executor: &E,
+    state: &SharedMutex<State>,
+    importable: Importable,
+) -> anyhow::Result<()>
+where
+    E: BlockImporterPort + Send + Sync + 'static,
+{
+    match importable {
+        Importable::Block(block) => execute_and_commit(executor, state, block).await,
+        Importable::Header(header) => {
+            import_header_and_commit(executor, state, header).await
+        }
+        Importable::Checkpoint {
+            checkpoint,
+            header,
+            state: state_config,
+        } => {
+            import_checkpoint_and_commit(
+                executor,
+                state,
+                checkpoint,
+                header,
+                state_config,
+            )
+            .await
+        }
+    }
+}
+
 /// Extra stream utilities.
 trait StreamUtil: Sized {
     /// Scan the stream for `None`.
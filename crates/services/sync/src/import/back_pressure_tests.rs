@@ -24,6 +24,9 @@ struct Input {
     Config{
         block_stream_buffer_size: 1,
         header_batch_size: 1,
+        mode: SyncMode::Full,
+        checkpoint: None,
+        ..Default::default()
     }
     => Count::default() ; "Empty sanity test"
 )]
@@ -36,6 +39,9 @@ struct Input {
     Config{
         block_stream_buffer_size: 1,
         header_batch_size: 1,
+        mode: SyncMode::Full,
+        checkpoint: None,
+        ..Default::default()
     }
     => is less_or_equal_than Count{ headers: 1, consensus: 1, transactions: 1, executes: 1, blocks: 1 }
     ; "Single with slow headers"
@@ -49,6 +55,9 @@ struct Input {
     Config{
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        mode: SyncMode::Full,
+        checkpoint: None,
+        ..Default::default()
     }
     => is less_or_equal_than Count{ headers: 10, consensus: 10, transactions: 10, executes: 1, blocks: 21 }
     ; "100 headers with max 10 with slow headers"
@@ -62,6 +71,9 @@ struct Input {
     Config{
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        mode: SyncMode::Full,
+        checkpoint: None,
+        ..Default::default()
     }
     => is less_or_equal_than Count{ headers: 10, consensus: 10, transactions: 10, executes: 1, blocks: 21 }
     ; "100 headers with max 10 with slow transactions"
@@ -75,6 +87,9 @@ struct Input {
     Config{
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        mode: SyncMode::Full,
+        checkpoint: None,
+        ..Default::default()
     }
     => is less_or_equal_than Count{ headers: 10, consensus: 10, transactions: 10, executes: 1, blocks: 21 }
     ; "50 headers with max 10 with slow executes"
@@ -88,6 +103,9 @@ struct Input {
     Config{
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        mode: SyncMode::Full,
+        checkpoint: None,
+        ..Default::default()
     }
     => is less_or_equal_than Count{ headers: 10, consensus: 10, transactions: 10, executes: 1, blocks: 21 }
     ; "50 headers with max 10 size and max 10 requests"
@@ -5,9 +5,16 @@ use crate::{
         MockBlockImporterPort,
     },
 };
+use fuel_core_chain_config::{
+    StateCheckpoint,
+    StateConfig,
+};
 use fuel_core_services::stream::BoxStream;
 use fuel_core_types::{
-    blockchain::SealedBlock,
+    blockchain::{
+        SealedBlock,
+        SealedBlockHeader,
+    },
     fuel_types::BlockHeight,
 };
 use std::time::Duration;
@@ -29,6 +36,19 @@ impl BlockImporterPort for PressureBlockImporter {
         });
         self.0.execute_and_commit(block).await
     }
+
+    async fn import_header(&self, header: SealedBlockHeader) -> anyhow::Result<()> {
+        self.0.import_header(header).await
+    }
+
+    async fn import_checkpoint(
+        &self,
+        checkpoint: StateCheckpoint,
+        header: SealedBlockHeader,
+        state: StateConfig,
+    ) -> anyhow::Result<()> {
+        self.0.import_checkpoint(checkpoint, header, state).await
+    }
 }
 
 impl PressureBlockImporter {
@@ -10,6 +10,10 @@ use crate::{
         PeerToPeerPort,
     },
 };
+use fuel_core_chain_config::{
+    StateChunk,
+    StateChunkRequest,
+};
 use fuel_core_services::stream::BoxStream;
 use fuel_core_types::{
     blockchain::SealedBlockHeader,
@@ -63,6 +67,14 @@ impl PeerToPeerPort for PressurePeerToPeer {
         self.p2p.get_transactions(block_ids).await
     }
 
+    async fn get_state_chunk(
+        &self,
+        peer_id: Option<PeerId>,
+        request: StateChunkRequest,
+    ) -> anyhow::Result<SourcePeer<Option<StateChunk>>> {
+        self.p2p.get_state_chunk(peer_id, request).await
+    }
+
     fn report_peer(
         &self,
         _peer: PeerId,
@@ -13,6 +13,12 @@ use crate::{
         PeerReportReason,
     },
 };
+use fuel_core_chain_config::{
+    CoinConfig,
+    ContractConfig,
+    StateChunk,
+    StateChunkEntries,
+};
 use fuel_core_types::services::p2p::Transactions;
 
 use super::*;
@@ -53,6 +59,9 @@ async fn test_import_0_to_5() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        mode: SyncMode::Full,
+        checkpoint: None,
+        ..Default::default()
     };
     let mocks = Mocks {
         consensus_port,
@@ -99,6 +108,9 @@ async fn test_import_3_to_5() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        mode: SyncMode::Full,
+        checkpoint: None,
+        ..Default::default()
     };
     let mocks = Mocks {
         consensus_port,
@@ -113,6 +125,500 @@ async fn test_import_3_to_5() {
     assert_eq!(v, expected);
 }
 
+#[tokio::test]
+async fn import__light_mode_imports_headers_without_transactions() {
+    // given
+    let mut consensus_port = MockConsensusPort::default();
+    consensus_port
+        .expect_check_sealed_header()
+        .times(6)
+        .returning(|_| Ok(true));
+    consensus_port.expect_await_da_height().times(0);
+
+    let mut p2p = MockPeerToPeerPort::default();
+    p2p.expect_get_sealed_block_headers()
+        .times(1)
+        .returning(|range| {
+            let peer = random_peer();
+            let headers = Some(range.map(empty_header).collect());
+            let headers = peer.bind(headers);
+            Ok(headers)
+        });
+    p2p.expect_get_transactions().times(0);
+
+    let mut executor = MockBlockImporterPort::default();
+    executor.expect_execute_and_commit().times(0);
+    executor
+        .expect_import_header()
+        .times(6)
+        .returning(|_| Ok(()));
+
+    let params = Config {
+        block_stream_buffer_size: 10,
+        header_batch_size: 10,
+        mode: SyncMode::Light,
+        checkpoint: None,
+        ..Default::default()
+    };
+    let mocks = Mocks {
+        consensus_port,
+        p2p,
+        executor,
+    };
+    let state = State::new(None, 5);
+    let state = SharedMutex::new(state);
+
+    // when
+    let res = test_import_inner(state, mocks, None, params).await;
+
+    // then
+    assert_eq!((State::new(5, None), true), res);
+}
+
+#[tokio::test]
+async fn import__light_mode_stops_on_invalid_header_chain() {
+    // given
+    let mut consensus_port = MockConsensusPort::default();
+    consensus_port
+        .expect_check_sealed_header()
+        .times(3)
+        .returning(|_| Ok(true));
+
+    let mut p2p = MockPeerToPeerPort::default();
+    p2p.expect_get_sealed_block_headers()
+        .times(1)
+        .returning(|range| {
+            let peer = random_peer();
+            let headers = Some(range.map(empty_header).collect());
+            let headers = peer.bind(headers);
+            Ok(headers)
+        });
+
+    let mut executor = MockBlockImporterPort::default();
+    executor
+        .expect_import_header()
+        .times(2)
+        .returning(|header| {
+            if **header.entity.height() == 4 {
+                Err(anyhow::anyhow!("Invalid previous root"))
+            } else {
+                Ok(())
+            }
+        });
+
+    let params = Config {
+        block_stream_buffer_size: 10,
+        header_batch_size: 10,
+        mode: SyncMode::Light,
+        checkpoint: None,
+        ..Default::default()
+    };
+    let mocks = Mocks {
+        consensus_port,
+        p2p,
+        executor,
+    };
+    let state = State::new(2, 5);
+    let state = SharedMutex::new(state);
+
+    // when
+    let res = test_import_inner(state, mocks, None, params).await;
+
+    // then
+    assert_eq!((State::new(3, None), false), res);
+}
+
+fn checkpoint_at(height: u32) -> StateCheckpoint {
+    StateCheckpoint {
+        height: height.into(),
+        block_id: empty_header(height).entity.id(),
+        coins_root: Default::default(),
+        contracts_root: Default::default(),
+        messages_root: Default::default(),
+    }
+}
+
+#[tokio::test]
+async fn import__checkpoint_imports_state_and_executes_blocks_after_it() {
+    // given
+    let mut consensus_port = MockConsensusPort::default();
+    consensus_port
+        .expect_check_sealed_header()
+        .times(6)
+        .returning(|_| Ok(true));
+    consensus_port
+        .expect_await_da_height()
+        .times(1)
+        .returning(|_| Ok(()));
+    consensus_port
+        .expect_check_state_root()
+        .times(3)
+        .returning(|_, _, _| Ok(true));
+
+    let mut p2p = MockPeerToPeerPort::default();
+    p2p.expect_get_sealed_block_headers()
+        .times(3)
+        .returning(|range| {
+            let peer = random_peer();
+            let headers = Some(range.map(empty_header).collect());
+            let headers = peer.bind(headers);
+            Ok(headers)
+        });
+    p2p.expect_get_transactions()
+        .times(1)
+        .returning(|block_ids| {
+            let data = block_ids.data;
+            let v = data.into_iter().map(|_| Transactions::default()).collect();
+            Ok(Some(v))
+        });
+    let coins_peer = random_peer();
+    p2p.expect_get_state_chunk()
+        .times(4)
+        .returning(move |peer_id, request| {
+            // The second chunk of coins is requested from the peer of the first one.
+            if request.kind == StateChunkKind::Coins && request.index == 1 {
+                assert_eq!(peer_id, Some(coins_peer.clone()));
+            }
+            let peer = peer_id.unwrap_or_else(|| coins_peer.clone());
+            let entries = match request.kind {
+                StateChunkKind::Coins if request.index == 0 => {
+                    StateChunkEntries::Coins(vec![CoinConfig::default()])
+                }
+                StateChunkKind::Coins => StateChunkEntries::Coins(vec![]),
+                StateChunkKind::Contracts => StateChunkEntries::Contracts(vec![]),
+                StateChunkKind::Messages => StateChunkEntries::Messages(vec![]),
+            };
+            let chunk = StateChunk {
+                height: request.height,
+                index: request.index,
+                last: !(request.kind == StateChunkKind::Coins && request.index == 0),
+                entries,
+            };
+            Ok(peer.bind(Some(chunk)))
+        });
+
+    let mut executor = MockBlockImporterPort::default();
+    executor
+        .expect_import_header()
+        .times(3)
+        .returning(|_| Ok(()));
+    executor.expect_import_checkpoint().times(1).returning(
+        |checkpoint, header, state| {
+            assert_eq!(*header.entity.height(), checkpoint.height);
+            assert_eq!(state.coins.map(|coins| coins.len()), Some(1));
+            Ok(())
+        },
+    );
+    executor
+        .expect_execute_and_commit()
+        .times(2)
+        .returning(|_| Ok(()));
+
+    let params = Config {
+        block_stream_buffer_size: 10,
+        header_batch_size: 10,
+        mode: SyncMode::Full,
+        checkpoint: Some(checkpoint_at(3)),
+        ..Default::default()
+    };
+    let mocks = Mocks {
+        consensus_port,
+        p2p,
+        executor,
+    };
+    let state = State::new(None, 5);
+    let state = SharedMutex::new(state);
+
+    // when
+    let res = test_import_inner(state, mocks, None, params).await;
+
+    // then
+    assert_eq!((State::new(5, None), true), res);
+}
+
+#[tokio::test]
+async fn import__checkpoint_fails_when_state_does_not_match_checkpoint() {
+    // given
+    let mut consensus_port = MockConsensusPort::default();
+    consensus_port
+        .expect_check_sealed_header()
+        .returning(|_| Ok(true));
+    consensus_port
+        .expect_await_da_height()
+        .returning(|_| Ok(()));
+    consensus_port
+        .expect_check_state_root()
+        .times(1)
+        .returning(|_, _, _| Ok(false));
+
+    let mut p2p = MockPeerToPeerPort::default();
+    p2p.expect_get_sealed_block_headers().returning(|range| {
+        let peer = random_peer();
+        let headers = Some(range.map(empty_header).collect());
+        let headers = peer.bind(headers);
+        Ok(headers)
+    });
+    p2p.expect_get_transactions().returning(|block_ids| {
+        let data = block_ids.data;
+        let v = data.into_iter().map(|_| Transactions::default()).collect();
+        Ok(Some(v))
+    });
+    p2p.expect_get_state_chunk()
+        .times(1)
+        .returning(|_, request| {
+            let chunk = StateChunk {
+                height: request.height,
+                index: request.index,
+                last: true,
+                entries: StateChunkEntries::Coins(vec![CoinConfig::default()]),
+            };
+            Ok(random_peer().bind(Some(chunk)))
+        });
+
+    let mut executor = MockBlockImporterPort::default();
+    executor
+        .expect_import_header()
+        .times(3)
+        .returning(|_| Ok(()));
+    executor.expect_import_checkpoint().times(0);
+    executor.expect_execute_and_commit().times(0);
+
+    let params = Config {
+        block_stream_buffer_size: 10,
+        header_batch_size: 10,
+        mode: SyncMode::Full,
+        checkpoint: Some(checkpoint_at(3)),
+        ..Default::default()
+    };
+    let mocks = Mocks {
+        consensus_port,
+        p2p,
+        executor,
+    };
+    let state = State::new(None, 5);
+    let state = SharedMutex::new(state);
+
+    // when
+    let res = test_import_inner(state, mocks, None, params).await;
+
+    // then
+    assert_eq!((State::new(2, None), false), res);
+}
+
+#[tokio::test]
+async fn import__checkpoint_fails_when_header_does_not_match_checkpoint() {
+    // given
+    // Blocks after the checkpoint may be requested in advance,
+    // so only the import is verified.
+    let mut consensus_port = MockConsensusPort::default();
+    consensus_port
+        .expect_check_sealed_header()
+        .returning(|_| Ok(true));
+    consensus_port
+        .expect_await_da_height()
+        .returning(|_| Ok(()));
+
+    let mut p2p = MockPeerToPeerPort::default();
+    p2p.expect_get_sealed_block_headers().returning(|range| {
+        let peer = random_peer();
+        let headers = Some(range.map(empty_header).collect());
+        let headers = peer.bind(headers);
+        Ok(headers)
+    });
+    p2p.expect_get_transactions().returning(|block_ids| {
+        let data = block_ids.data;
+        let v = data.into_iter().map(|_| Transactions::default()).collect();
+        Ok(Some(v))
+    });
+    p2p.expect_get_state_chunk().times(0);
+
+    let mut executor = MockBlockImporterPort::default();
+    executor
+        .expect_import_header()
+        .times(3)
+        .returning(|_| Ok(()));
+    executor.expect_import_checkpoint().times(0);
+    executor.expect_execute_and_commit().times(0);
+
+    let mut checkpoint = checkpoint_at(3);
+    checkpoint.block_id = Default::default();
+    let params = Config {
+        block_stream_buffer_size: 10,
+        header_batch_size: 10,
+        mode: SyncMode::Full,
+        checkpoint: Some(checkpoint),
+        ..Default::default()
+    };
+    let mocks = Mocks {
+        consensus_port,
+        p2p,
+        executor,
+    };
+    let state = State::new(None, 5);
+    let state = SharedMutex::new(state);
+
+    // when
+    let res = test_import_inner(state, mocks, None, params).await;
+
+    // then
+    assert_eq!((State::new(2, None), false), res);
+}
+
+#[tokio::test]
+async fn import__checkpoint_fails_when_state_exceeds_max_chunks() {
+    // given
+    let mut consensus_port = MockConsensusPort::default();
+    consensus_port
+        .expect_check_sealed_header()
+        .returning(|_| Ok(true));
+    consensus_port
+        .expect_await_da_height()
+        .returning(|_| Ok(()));
+    consensus_port.expect_check_state_root().times(0);
+
+    let mut p2p = MockPeerToPeerPort::default();
+    p2p.expect_get_sealed_block_headers().returning(|range| {
+        let peer = random_peer();
+        let headers = Some(range.map(empty_header).collect());
+        let headers = peer.bind(headers);
+        Ok(headers)
+    });
+    p2p.expect_get_transactions().returning(|block_ids| {
+        let data = block_ids.data;
+        let v = data.into_iter().map(|_| Transactions::default()).collect();
+        Ok(Some(v))
+    });
+    // The peer never sends the last chunk.
+    p2p.expect_get_state_chunk()
+        .times(4)
+        .returning(|_, request| {
+            let chunk = StateChunk {
+                height: request.height,
+                index: request.index,
+                last: false,
+                entries: StateChunkEntries::Coins(vec![CoinConfig::default()]),
+            };
+            Ok(random_peer().bind(Some(chunk)))
+        });
+
+    let mut executor = MockBlockImporterPort::default();
+    executor
+        .expect_import_header()
+        .times(3)
+        .returning(|_| Ok(()));
+    executor.expect_import_checkpoint().times(0);
+    executor.expect_execute_and_commit().times(0);
+
+    let params = Config {
+        block_stream_buffer_size: 10,
+        header_batch_size: 10,
+        mode: SyncMode::Full,
+        checkpoint: Some(checkpoint_at(3)),
+        max_state_chunks: 4,
+        ..Default::default()
+    };
+    let mocks = Mocks {
+        consensus_port,
+        p2p,
+        executor,
+    };
+    let state = State::new(None, 5);
+    let state = SharedMutex::new(state);
+
+    // when
+    let res = test_import_inner(state, mocks, None, params).await;
+
+    // then
+    assert_eq!((State::new(2, None), false), res);
+}
+
+#[tokio::test]
+async fn import__checkpoint_fails_when_state_exceeds_max_size() {
+    // given
+    let mut consensus_port = MockConsensusPort::default();
+    consensus_port
+        .expect_check_sealed_header()
+        .returning(|_| Ok(true));
+    consensus_port
+        .expect_await_da_height()
+        .returning(|_| Ok(()));
+    // Only the coins are verified.
+    consensus_port
+        .expect_check_state_root()
+        .times(1)
+        .returning(|_, _, _| Ok(true));
+
+    let mut p2p = MockPeerToPeerPort::default();
+    p2p.expect_get_sealed_block_headers().returning(|range| {
+        let peer = random_peer();
+        let headers = Some(range.map(empty_header).collect());
+        let headers = peer.bind(headers);
+        Ok(headers)
+    });
+    p2p.expect_get_transactions().returning(|block_ids| {
+        let data = block_ids.data;
+        let v = data.into_iter().map(|_| Transactions::default()).collect();
+        Ok(Some(v))
+    });
+    p2p.expect_get_state_chunk()
+        .times(2)
+        .returning(|_, request| {
+            let entries = match request.kind {
+                StateChunkKind::Contracts => {
+                    StateChunkEntries::Contracts(vec![ContractConfig {
+                        contract_id: Default::default(),
+                        code: vec![0; 64 * 1024],
+                        salt: Default::default(),
+                        state: None,
+                        balances: None,
+                        tx_id: None,
+                        output_index: None,
+                        tx_pointer_block_height: None,
+                        tx_pointer_tx_idx: None,
+                    }])
+                }
+                _ => StateChunkEntries::Coins(vec![]),
+            };
+            let chunk = StateChunk {
+                height: request.height,
+                index: request.index,
+                last: true,
+                entries,
+            };
+            Ok(random_peer().bind(Some(chunk)))
+        });
+
+    let mut executor = MockBlockImporterPort::default();
+    executor
+        .expect_import_header()
+        .times(3)
+        .returning(|_| Ok(()));
+    executor.expect_import_checkpoint().times(0);
+    executor.expect_execute_and_commit().times(0);
+
+    let params = Config {
+        block_stream_buffer_size: 10,
+        header_batch_size: 10,
+        mode: SyncMode::Full,
+        checkpoint: Some(checkpoint_at(3)),
+        max_state_size: 64 * 1024,
+        ..Default::default()
+    };
+    let mocks = Mocks {
+        consensus_port,
+        p2p,
+        executor,
+    };
+    let state = State::new(None, 5);
+    let state = SharedMutex::new(state);
+
+    // when
+    let res = test_import_inner(state, mocks, None, params).await;
+
+    // then
+    assert_eq!((State::new(2, None), false), res);
+}
+
 #[tokio::test]
 async fn test_import_0_to_499() {
     // The observed block height
@@ -217,6 +723,9 @@ async fn import__signature_fails_on_header_5_only() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        mode: SyncMode::Full,
+        checkpoint: None,
+        ..Default::default()
     };
 
     // when
@@ -265,6 +774,9 @@ async fn import__signature_fails_on_header_4_only() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        mode: SyncMode::Full,
+        checkpoint: None,
+        ..Default::default()
     };
 
     // when
@@ -296,6 +808,9 @@ async fn import__header_not_found() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        mode: SyncMode::Full,
+        checkpoint: None,
+        ..Default::default()
     };
 
     // when
@@ -327,6 +842,9 @@ async fn import__header_response_incomplete() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        mode: SyncMode::Full,
+        checkpoint: None,
+        ..Default::default()
     };
 
     // when
@@ -366,6 +884,9 @@ async fn import__header_5_not_found() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        mode: SyncMode::Full,
+        checkpoint: None,
+        ..Default::default()
     };
 
     // when
@@ -398,6 +919,9 @@ async fn import__header_4_not_found() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        mode: SyncMode::Full,
+        checkpoint: None,
+        ..Default::default()
     };
 
     // when
@@ -442,6 +966,9 @@ async fn import__transactions_not_found() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        mode: SyncMode::Full,
+        checkpoint: None,
+        ..Default::default()
     };
 
     // when
@@ -496,6 +1023,9 @@ async fn import__transactions_not_found_for_header_4() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        mode: SyncMode::Full,
+        checkpoint: None,
+        ..Default::default()
     };
 
     // when
@@ -541,6 +1071,9 @@ async fn import__transactions_not_found_for_header_5() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        mode: SyncMode::Full,
+        checkpoint: None,
+        ..Default::default()
     };
 
     // when
@@ -568,6 +1101,9 @@ async fn import__p2p_error() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        mode: SyncMode::Full,
+        checkpoint: None,
+        ..Default::default()
     };
 
     // when
@@ -612,6 +1148,9 @@ async fn import__p2p_error_on_4_transactions() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        mode: SyncMode::Full,
+        checkpoint: None,
+        ..Default::default()
     };
 
     // when
@@ -660,6 +1199,9 @@ async fn import__consensus_error_on_4() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        mode: SyncMode::Full,
+        checkpoint: None,
+        ..Default::default()
     };
 
     // when
@@ -714,6 +1256,9 @@ async fn import__consensus_error_on_5() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        mode: SyncMode::Full,
+        checkpoint: None,
+        ..Default::default()
     };
 
     // when
@@ -774,6 +1319,9 @@ async fn import__execution_error_on_header_4() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        mode: SyncMode::Full,
+        checkpoint: None,
+        ..Default::default()
     };
 
     // when
@@ -834,6 +1382,9 @@ async fn import__execution_error_on_header_5() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        mode: SyncMode::Full,
+        checkpoint: None,
+        ..Default::default()
     };
 
     // when
@@ -862,6 +1413,9 @@ async fn signature_always_fails() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        mode: SyncMode::Full,
+        checkpoint: None,
+        ..Default::default()
     };
 
     // when
@@ -914,6 +1468,9 @@ async fn import__can_work_in_two_loops() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        mode: SyncMode::Full,
+        checkpoint: None,
+        ..Default::default()
     };
 
     // when
@@ -1005,13 +1562,13 @@ async fn import__missing_headers_sends_peer_report() {
 }
 
 #[tokio::test]
-async fn import__bad_block_header_sends_peer_report() {
+async fn import__bad_seal_sends_peer_report() {
     // Given
     PeerReportTestBuilder::new()
         // When
         .with_check_sealed_header(false)
         // Then
-        .run_with_expected_reports([PeerReportReason::BadBlockHeader])
+        .run_with_expected_reports([PeerReportReason::BadSeal])
         .await;
 }
 
@@ -1101,6 +1658,9 @@ impl PeerReportTestBuilder {
         let params = Config {
             block_stream_buffer_size: 10,
             header_batch_size: 10,
+            mode: SyncMode::Full,
+            checkpoint: None,
+            ..Default::default()
         };
 
         let import = Import {
@@ -13,7 +13,10 @@ pub mod state;
 pub mod sync;
 mod tracing_helpers;
 
-pub use import::Config;
+pub use import::{
+    Config,
+    SyncMode,
+};
 
 use rand as _;
 
@@ -1,5 +1,12 @@
 //! Ports this services requires to function.
 
+use fuel_core_chain_config::{
+    StateCheckpoint,
+    StateChunk,
+    StateChunkKind,
+    StateChunkRequest,
+    StateConfig,
+};
 use fuel_core_services::stream::BoxStream;
 use fuel_core_types::{
     blockchain::{
@@ -28,10 +35,14 @@ pub enum PeerReportReason {
     MissingBlockHeaders,
     /// Report a peer for sending a bad block header
     BadBlockHeader,
+    /// Report a peer for sending a block header with an invalid consensus seal
+    BadSeal,
     /// Did not receive advertised transactions
     MissingTransactions,
     /// Received invalid transactions
     InvalidTransactions,
+    /// Did not receive the requested chunk of the state or received an invalid one
+    InvalidStateChunk,
 }
 
 #[cfg_attr(any(test, feature = "benchmarking"), mockall::automock)]
@@ -54,6 +65,14 @@ pub trait PeerToPeerPort {
         block_ids: SourcePeer<Range<u32>>,
     ) -> anyhow::Result<Option<Vec<Transactions>>>;
 
+    /// Request a chunk of the on-chain state at the checkpoint from the network.
+    /// If the `peer_id` is specified, the chunk is requested from this peer.
+    async fn get_state_chunk(
+        &self,
+        peer_id: Option<PeerId>,
+        request: StateChunkRequest,
+    ) -> anyhow::Result<SourcePeer<Option<StateChunk>>>;
+
     /// Report a peer for some reason to modify their reputation.
     fn report_peer(&self, peer: PeerId, report: PeerReportReason) -> anyhow::Result<()>;
 }
@@ -66,6 +85,13 @@ pub trait ConsensusPort {
     fn check_sealed_header(&self, header: &SealedBlockHeader) -> anyhow::Result<bool>;
     /// await for this DA height to be sync'd.
     async fn await_da_height(&self, da_height: &DaBlockHeight) -> anyhow::Result<()>;
+    /// Check if the entries of the `kind` in the `state` match the root from the `checkpoint`.
+    fn check_state_root(
+        &self,
+        checkpoint: &StateCheckpoint,
+        kind: StateChunkKind,
+        state: &StateConfig,
+    ) -> anyhow::Result<bool>;
 }
 
 #[cfg_attr(any(test, feature = "benchmarking"), mockall::automock)]
@@ -78,4 +104,19 @@ pub trait BlockImporterPort {
     /// Execute the given sealed block
     /// and commit it to the database.
     async fn execute_and_commit(&self, block: SealedBlock) -> anyhow::Result<()>;
+
+    /// Verify that the given sealed header follows the chain of
+    /// previous headers and commit it to the database without execution.
+    /// Used in the light mode, where bodies of blocks are not downloaded.
+    async fn import_header(&self, header: SealedBlockHeader) -> anyhow::Result<()>;
+
+    /// Verify the sealed header of the checkpoint in the same way as `import_header`
+    /// and commit it to the database along with the state at the checkpoint.
+    /// The state must match the roots from the checkpoint.
+    async fn import_checkpoint(
+        &self,
+        checkpoint: StateCheckpoint,
+        header: SealedBlockHeader,
+        state: StateConfig,
+    ) -> anyhow::Result<()>;
 }
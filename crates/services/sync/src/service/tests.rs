@@ -9,9 +9,12 @@ use futures::{
 };
 
 use crate::{
-    import::test_helpers::{
-        empty_header,
-        random_peer,
+    import::{
+        test_helpers::{
+            empty_header,
+            random_peer,
+        },
+        SyncMode,
     },
     ports::{
         MockBlockImporterPort,
@@ -66,6 +69,9 @@ async fn test_new_service() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        mode: SyncMode::Full,
+        checkpoint: None,
+        ..Default::default()
     };
     let s = new_service(4u32.into(), p2p, importer, consensus, params).unwrap();
 
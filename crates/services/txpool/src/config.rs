@@ -19,6 +19,9 @@ pub struct Config {
     pub transaction_ttl: Duration,
     /// The number of allowed active transaction status subscriptions.
     pub number_of_active_subscription: usize,
+    /// The minimum percentage by which the gas price of a transaction must exceed
+    /// the gas price of the colliding transactions to replace them.
+    pub min_gas_price_bump_percentage: u64,
 }
 
 impl Default for Config {
@@ -31,6 +34,7 @@ impl Default for Config {
         // 5 minute TTL
         let transaction_ttl = Duration::from_secs(60 * 5);
         let number_of_active_subscription = max_tx;
+        let min_gas_price_bump_percentage = 10;
         Self::new(
             max_tx,
             max_depth,
@@ -40,6 +44,7 @@ impl Default for Config {
             metrics,
             transaction_ttl,
             number_of_active_subscription,
+            min_gas_price_bump_percentage,
         )
     }
 }
@@ -55,6 +60,7 @@ impl Config {
         metrics: bool,
         transaction_ttl: Duration,
         number_of_active_subscription: usize,
+        min_gas_price_bump_percentage: u64,
     ) -> Self {
         // # Dev-note: If you add a new field, be sure that this field is propagated correctly
         //  in all places where `new` is used.
@@ -67,6 +73,7 @@ impl Config {
             metrics,
             transaction_ttl,
             number_of_active_subscription,
+            min_gas_price_bump_percentage,
         }
     }
 }
@@ -46,6 +46,8 @@ pub struct Dependency {
     max_depth: usize,
     /// utxo-validation feature flag
     utxo_validation: bool,
+    /// The minimum percentage of the gas price bump to replace a transaction.
+    min_gas_price_bump_percentage: u64,
 }
 
 #[derive(Debug, Clone)]
@@ -91,16 +93,113 @@ pub struct MessageState {
 }
 
 impl Dependency {
-    pub fn new(max_depth: usize, utxo_validation: bool) -> Self {
+    pub fn new(
+        max_depth: usize,
+        utxo_validation: bool,
+        min_gas_price_bump_percentage: u64,
+    ) -> Self {
         Self {
             coins: HashMap::new(),
             contracts: HashMap::new(),
             messages: HashMap::new(),
             max_depth,
             utxo_validation,
+            min_gas_price_bump_percentage,
         }
     }
 
+    /// Returns the minimum gas price required to replace a transaction
+    /// with the `gas_price`. The replacement must always be priced higher.
+    fn replacement_gas_price(&self, gas_price: GasPrice) -> GasPrice {
+        let bump = u128::from(gas_price)
+            .saturating_mul(u128::from(self.min_gas_price_bump_percentage))
+            / 100;
+        let bump = GasPrice::try_from(bump).unwrap_or(GasPrice::MAX).max(1);
+        gas_price.saturating_add(bump)
+    }
+
+    /// Returns `true` if the transaction with the `new_gas_price` can replace
+    /// the transaction with the `gas_price`.
+    fn can_replace(&self, gas_price: GasPrice, new_gas_price: GasPrice) -> bool {
+        new_gas_price >= self.replacement_gas_price(gas_price)
+    }
+
+    /// Checks that the transaction with the `new_gas_price` can replace the `collided`
+    /// transaction with the `gas_price`. Returns the `collision` error if the new
+    /// transaction is not priced higher at all.
+    fn check_replacement(
+        &self,
+        collided: TxId,
+        gas_price: GasPrice,
+        new_gas_price: GasPrice,
+        collision: impl FnOnce() -> Error,
+    ) -> Result<(), Error> {
+        if new_gas_price <= gas_price {
+            return Err(collision())
+        }
+        if !self.can_replace(gas_price, new_gas_price) {
+            return Err(Error::NotInsertedGasPriceBumpTooLow(
+                collided,
+                self.replacement_gas_price(gas_price),
+            ))
+        }
+        Ok(())
+    }
+
+    /// Finds all transactions inside txpool that spend outputs of the `tx`,
+    /// directly or through other transactions.
+    fn find_descendants(
+        &self,
+        tx: &ArcPoolTx,
+        seen: &mut HashMap<TxId, ArcPoolTx>,
+        txs: &HashMap<TxId, TxInfo>,
+    ) -> Result<(), Error> {
+        let mut check = vec![tx.clone()];
+        while let Some(parent) = check.pop() {
+            for (index, output) in parent.outputs().iter().enumerate() {
+                let children: Vec<TxId> = match output {
+                    Output::Coin { .. }
+                    | Output::Change { .. }
+                    | Output::Variable { .. } => {
+                        let Ok(index) = u8::try_from(index) else {
+                            continue
+                        };
+                        let utxo_id = UtxoId::new(parent.id(), index);
+                        self.coins
+                            .get(&utxo_id)
+                            .and_then(|state| state.is_spend_by)
+                            .into_iter()
+                            .collect()
+                    }
+                    Output::ContractCreated { contract_id, .. } => self
+                        .contracts
+                        .get(contract_id)
+                        .map(|state| state.used_by.iter().copied().collect())
+                        .unwrap_or_default(),
+                    Output::Contract(_) => vec![],
+                };
+
+                for child in children {
+                    if seen.contains_key(&child) {
+                        continue
+                    }
+                    let child = txs
+                        .get(&child)
+                        .ok_or_else(|| {
+                            Error::Other(format!(
+                                "The dependent tx {child:#x} is missing in the txpool"
+                            ))
+                        })?
+                        .tx()
+                        .clone();
+                    seen.insert(child.id(), child.clone());
+                    check.push(child);
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// find all dependent Transactions that are inside txpool.
     /// Does not check db. They can be sorted by gasPrice to get order of dependency
     pub(crate) fn find_dependent(
@@ -289,43 +388,41 @@ impl Dependency {
                                 .get(spend_by)
                                 .expect("Tx should be always present in txpool");
                             // compare if tx has better price
-                            if txpool_tx.price() > tx.price() {
-                                return Err(Error::NotInsertedCollision(
-                                    *spend_by, *utxo_id,
-                                ))
-                            } else {
-                                if state.is_in_database() {
-                                    // this means it is loaded from db. Get tx to compare output.
-                                    if self.utxo_validation {
-                                        let coin = db
-                                            .utxo(utxo_id)
-                                            .map_err(|e| {
-                                                Error::Database(format!("{:?}", e))
-                                            })?
-                                            .ok_or(
-                                                Error::NotInsertedInputUtxoIdNotDoesNotExist(
-                                                    *utxo_id,
-                                                ),
-                                            )?;
-                                        if !coin
-                                            .matches_input(input)
-                                            .expect("The input is coin above")
-                                        {
-                                            return Err(Error::NotInsertedIoCoinMismatch)
-                                        }
+                            self.check_replacement(
+                                *spend_by,
+                                txpool_tx.price(),
+                                tx.price(),
+                                || Error::NotInsertedCollision(*spend_by, *utxo_id),
+                            )?;
+                            if state.is_in_database() {
+                                // this means it is loaded from db. Get tx to compare output.
+                                if self.utxo_validation {
+                                    let coin = db
+                                        .utxo(utxo_id)
+                                        .map_err(|e| Error::Database(format!("{:?}", e)))?
+                                        .ok_or(
+                                            Error::NotInsertedInputUtxoIdNotDoesNotExist(
+                                                *utxo_id,
+                                            ),
+                                        )?;
+                                    if !coin
+                                        .matches_input(input)
+                                        .expect("The input is coin above")
+                                    {
+                                        return Err(Error::NotInsertedIoCoinMismatch)
                                     }
-                                } else {
-                                    // tx output is in pool
-                                    let output_tx = txs.get(utxo_id.tx_id()).unwrap();
-                                    let output = &output_tx.outputs()
-                                        [utxo_id.output_index() as usize];
-                                    Self::check_if_coin_input_can_spend_output(
-                                        output, input, false,
-                                    )?;
-                                };
-
-                                collided.push(*spend_by);
-                            }
+                                }
+                            } else {
+                                // tx output is in pool
+                                let output_tx = txs.get(utxo_id.tx_id()).unwrap();
+                                let output =
+                                    &output_tx.outputs()[utxo_id.output_index() as usize];
+                                Self::check_if_coin_input_can_spend_output(
+                                    output, input, false,
+                                )?;
+                            };
+
+                            collided.push(*spend_by);
                         }
                         // if coin is not spend, it will be spend later down the line
                     } else {
@@ -390,14 +487,18 @@ impl Dependency {
 
                     if let Some(state) = self.messages.get(nonce) {
                         // some other is already attempting to spend this message, compare gas price
-                        if state.gas_price >= tx.price() {
-                            return Err(Error::NotInsertedCollisionMessageId(
-                                state.spent_by,
-                                *nonce,
-                            ))
-                        } else {
-                            collided.push(state.spent_by);
-                        }
+                        self.check_replacement(
+                            state.spent_by,
+                            state.gas_price,
+                            tx.price(),
+                            || {
+                                Error::NotInsertedCollisionMessageId(
+                                    state.spent_by,
+                                    *nonce,
+                                )
+                            },
+                        )?;
+                        collided.push(state.spent_by);
                     }
                     db_messages.insert(
                         *nonce,
@@ -458,15 +559,17 @@ impl Dependency {
                     if contract.is_in_database() {
                         return Err(Error::NotInsertedContractIdAlreadyTaken(*contract_id))
                     }
-                    // check who is priced more
-                    if contract.gas_price > tx.price() {
-                        // new tx is priced less then current tx
-                        return Err(Error::NotInsertedCollisionContractId(*contract_id))
-                    }
                     // if we are prices more, mark current contract origin for removal.
                     let origin = contract.origin.expect(
                         "Only contract without origin are the ones that are inside DB. And we check depth for that, so we are okay to just unwrap"
                         );
+                    // check who is priced more
+                    self.check_replacement(
+                        *origin.tx_id(),
+                        contract.gas_price,
+                        tx.price(),
+                        || Error::NotInsertedCollisionContractId(*contract_id),
+                    )?;
                     collided.push(*origin.tx_id());
                 }
             }
@@ -477,28 +580,58 @@ impl Dependency {
     }
 
     /// insert tx inside dependency
-    /// return list of transactions that are removed from txpool
+    /// return list of transactions replaced by the tx, each of them with the list
+    /// of transactions that depend on it. All of them are removed from txpool
     pub(crate) fn insert<'a, DB>(
         &'a mut self,
         txs: &'a HashMap<TxId, TxInfo>,
         db: &DB,
         tx: &'a ArcPoolTx,
-    ) -> Result<Vec<ArcPoolTx>, Error>
+    ) -> Result<Vec<(ArcPoolTx, Vec<ArcPoolTx>)>, Error>
     where
         DB: TxPoolDb,
     {
         let (max_depth, db_coins, db_contracts, db_messages, collided) =
             self.check_for_collision(txs, db, tx)?;
 
+        // The replacement removes the whole chain of transactions that depend on the
+        // collided ones, so it should be priced higher than each of them.
+        for collided in collided.iter() {
+            let collided = txs
+                .get(collided)
+                .expect("Collided should be present in txpool")
+                .tx();
+            let mut chain = HashMap::new();
+            self.find_descendants(collided, &mut chain, txs)?;
+            let max_gas_price = chain
+                .values()
+                .map(|tx| tx.price())
+                .fold(collided.price(), core::cmp::max);
+            if !self.can_replace(max_gas_price, tx.price()) {
+                return Err(Error::NotInsertedReplacementUnderpriced(
+                    collided.id(),
+                    self.replacement_gas_price(max_gas_price),
+                ))
+            }
+        }
+
         // now we are sure that transaction can be included. remove all collided transactions
         let mut removed_tx = Vec::new();
+        let mut removed_ids = HashSet::new();
         for collided in collided.into_iter() {
+            // The collided transaction is already removed as a dependent of another one.
+            if removed_ids.contains(&collided) {
+                continue
+            }
             let collided = txs
                 .get(&collided)
                 .expect("Collided should be present in txpool");
-            removed_tx.extend(
-                self.recursively_remove_all_dependencies(txs, collided.tx().clone()),
-            );
+            let mut removed =
+                self.recursively_remove_all_dependencies(txs, collided.tx().clone());
+            // The first removed transaction is the collided one.
+            let dependents = removed.split_off(1);
+            removed_ids.extend(removed.iter().chain(dependents.iter()).map(|tx| tx.id()));
+            removed_tx.push((collided.tx().clone(), dependents));
         }
 
         // iterate over all inputs and spend parent coins/contracts
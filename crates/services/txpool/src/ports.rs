@@ -16,9 +16,11 @@ use fuel_core_types::{
     services::{
         block_importer::SharedImportResult,
         p2p::{
+            peer_reputation::ReputationEvent,
             GossipsubMessageAcceptance,
             GossipsubMessageInfo,
             NetworkData,
+            PeerId,
         },
     },
 };
@@ -39,6 +41,9 @@ pub trait PeerToPeer: Send + Sync {
         message_info: GossipsubMessageInfo,
         validity: GossipsubMessageAcceptance,
     ) -> anyhow::Result<()>;
+
+    // Report the reputation event of the peer that gossiped a transaction.
+    fn report_peer(&self, peer_id: PeerId, event: ReputationEvent) -> anyhow::Result<()>;
 }
 
 pub trait BlockImporter: Send + Sync {
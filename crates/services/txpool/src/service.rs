@@ -35,6 +35,7 @@ use fuel_core_types::{
     },
     services::{
         p2p::{
+            peer_reputation::ReputationEvent,
             GossipData,
             GossipsubMessageAcceptance,
             GossipsubMessageInfo,
@@ -234,7 +235,7 @@ where
                     // verify tx
                     let checked_tx = check_single_tx(tx, current_height, &self.shared.config).await;
 
-                    let acceptance = match checked_tx {
+                    let (acceptance, reputation_event) = match checked_tx {
                         Ok(tx) => {
                             let txs = vec![tx];
 
@@ -249,21 +250,28 @@ where
 
                             match result.pop() {
                                 Some(Ok(_)) => {
-                                    GossipsubMessageAcceptance::Accept
+                                    (GossipsubMessageAcceptance::Accept, None)
                                 },
                                 // Use similar p2p punishment rules as bitcoin
                                 // https://github.com/bitcoin/bitcoin/blob/6ff0aa089c01ff3e610ecb47814ed739d685a14c/src/net_processing.cpp#L1856
                                 Some(Err(Error::ConsensusValidity(_))) | Some(Err(Error::MintIsDisallowed)) => {
-                                    GossipsubMessageAcceptance::Reject
+                                    (GossipsubMessageAcceptance::Reject, Some(ReputationEvent::InvalidGossipedTransaction))
                                 },
-                                _ => GossipsubMessageAcceptance::Ignore
+                                Some(Err(Error::NotInsertedTxKnown)) => {
+                                    (GossipsubMessageAcceptance::Ignore, Some(ReputationEvent::DuplicateGossipedTransaction))
+                                },
+                                _ => (GossipsubMessageAcceptance::Ignore, None)
                             }
                         }
                         Err(_) => {
-                            GossipsubMessageAcceptance::Reject
+                            (GossipsubMessageAcceptance::Reject, Some(ReputationEvent::InvalidGossipedTransaction))
                         }
                     };
 
+                    if let Some(event) = reputation_event {
+                        let _ = self.shared.p2p.report_peer(peer_id.clone(), event);
+                    }
+
                     // notify p2p layer about whether this tx was accepted
                     let message_info = GossipsubMessageInfo {
                         message_id,
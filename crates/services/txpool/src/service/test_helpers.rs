@@ -2,6 +2,7 @@ use super::*;
 use crate::{
     mock_db::MockDBProvider,
     ports::BlockImporter,
+    test_helpers::UnsetInput,
     MockDb,
 };
 use fuel_core_services::{
@@ -18,13 +19,18 @@ use fuel_core_types::{
     fuel_tx::{
         Cacheable,
         Input,
+        Output,
         Transaction,
         TransactionBuilder,
         Word,
     },
     services::{
         block_importer::ImportResult,
-        p2p::GossipsubMessageAcceptance,
+        p2p::{
+            peer_reputation::ReputationEvent,
+            GossipsubMessageAcceptance,
+            PeerId,
+        },
     },
 };
 use std::cell::RefCell;
@@ -62,6 +68,10 @@ impl TestContext {
     pub fn setup_coin(&self) -> (Coin, Input) {
         crate::test_helpers::setup_coin(&mut self.rng.borrow_mut(), Some(&self.mock_db))
     }
+
+    pub fn create_output_and_input(&self, amount: Word) -> (Output, UnsetInput) {
+        crate::test_helpers::create_output_and_input(&mut self.rng.borrow_mut(), amount)
+    }
 }
 
 mockall::mock! {
@@ -79,6 +89,8 @@ mockall::mock! {
             message_info: GossipsubMessageInfo,
             validity: GossipsubMessageAcceptance,
         ) -> anyhow::Result<()>;
+
+        fn report_peer(&self, peer_id: PeerId, event: ReputationEvent) -> anyhow::Result<()>;
     }
 }
 
@@ -5,7 +5,12 @@ use crate::service::test_helpers::{
 };
 use fuel_core_services::Service as ServiceTrait;
 use fuel_core_types::{
-    fuel_tx::UniqueIdentifier,
+    fuel_tx::{
+        Cacheable,
+        TransactionBuilder,
+        UniqueIdentifier,
+        UtxoId,
+    },
     fuel_types::ChainId,
 };
 use std::time::Duration;
@@ -267,3 +272,107 @@ async fn simple_insert_removal_subscription() {
 
     service.stop_and_await().await.unwrap();
 }
+
+#[tokio::test]
+async fn replaced_tx_is_squeezed_out_with_replaced_by_higher_gas_reason() {
+    // given
+    let ctx = TestContextBuilder::new().build_and_start().await;
+    let (_, gas_coin) = ctx.setup_coin();
+    let build_tx = |gas_price| {
+        let mut tx = TransactionBuilder::script(vec![], vec![])
+            .gas_price(gas_price)
+            .script_gas_limit(1000)
+            .add_input(gas_coin.clone())
+            .finalize_as_transaction();
+        tx.precompute(&Default::default())
+            .expect("Should be able to cache");
+        Arc::new(tx)
+    };
+    let tx1 = build_tx(10);
+    let tx2 = build_tx(20);
+    let service = ctx.service();
+
+    let mut tx1_subscribe_updates = service
+        .shared
+        .tx_update_subscribe(tx1.cached_id().unwrap())
+        .unwrap();
+    let out = service.shared.insert(vec![tx1.clone()]).await;
+    assert!(out[0].is_ok(), "Tx1 should be OK, got err:{out:?}");
+    let update = tx1_subscribe_updates.next().await.unwrap();
+    assert!(matches!(
+        update,
+        TxStatusMessage::Status(TransactionStatus::Submitted { .. })
+    ));
+
+    // when
+    let out = service.shared.insert(vec![tx2.clone()]).await;
+
+    // then
+    assert!(out[0].is_ok(), "Tx2 should be OK, got err:{out:?}");
+    let update = tx1_subscribe_updates.next().await.unwrap();
+    assert_eq!(
+        update,
+        TxStatusMessage::Status(TransactionStatus::SqueezedOut {
+            reason: Error::ReplacedByHigherGas(tx2.cached_id().unwrap()).to_string()
+        }),
+    );
+
+    service.stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn dependent_of_replaced_tx_is_squeezed_out_with_dependency_removed_reason() {
+    // given
+    let ctx = TestContextBuilder::new().build_and_start().await;
+    let (_, gas_coin) = ctx.setup_coin();
+    let (output, unset_input) = ctx.create_output_and_input(10);
+    let mut tx1 = TransactionBuilder::script(vec![], vec![])
+        .gas_price(10)
+        .script_gas_limit(1000)
+        .add_input(gas_coin.clone())
+        .add_output(output)
+        .finalize_as_transaction();
+    tx1.precompute(&Default::default())
+        .expect("Should be able to cache");
+    let tx1_id = tx1.cached_id().unwrap();
+    let mut tx2 = TransactionBuilder::script(vec![], vec![])
+        .gas_price(10)
+        .script_gas_limit(1000)
+        .add_input(unset_input.into_input(UtxoId::new(tx1_id, 0)))
+        .finalize_as_transaction();
+    tx2.precompute(&Default::default())
+        .expect("Should be able to cache");
+    let mut tx3 = TransactionBuilder::script(vec![], vec![])
+        .gas_price(20)
+        .script_gas_limit(1000)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+    tx3.precompute(&Default::default())
+        .expect("Should be able to cache");
+    let service = ctx.service();
+
+    let out = service
+        .shared
+        .insert(vec![Arc::new(tx1), Arc::new(tx2.clone())])
+        .await;
+    assert!(out.iter().all(|result| result.is_ok()), "got err:{out:?}");
+    let mut tx2_subscribe_updates = service
+        .shared
+        .tx_update_subscribe(tx2.cached_id().unwrap())
+        .unwrap();
+
+    // when
+    let out = service.shared.insert(vec![Arc::new(tx3)]).await;
+
+    // then
+    assert!(out[0].is_ok(), "Tx3 should be OK, got err:{out:?}");
+    let update = tx2_subscribe_updates.next().await.unwrap();
+    assert_eq!(
+        update,
+        TxStatusMessage::Status(TransactionStatus::SqueezedOut {
+            reason: Error::DependencyRemoved(tx1_id).to_string()
+        }),
+    );
+
+    service.stop_and_await().await.unwrap();
+}
@@ -168,6 +168,11 @@ async fn test_gossipped_transaction_with_check_error_rejected() {
             send.send(()).unwrap();
             Ok(())
         });
+    p2p.expect_report_peer().returning(|_, event| {
+        // Expect the peer to be punished for the invalid transaction
+        assert_eq!(event, ReputationEvent::InvalidGossipedTransaction);
+        Ok(())
+    });
     ctx_builder.with_p2p(p2p);
 
     // build and start the txpool service
@@ -208,6 +213,11 @@ async fn test_gossipped_mint_rejected() {
             send.send(()).unwrap();
             Ok(())
         });
+    p2p.expect_report_peer().returning(|_, event| {
+        // Expect the peer to be punished for the invalid transaction
+        assert_eq!(event, ReputationEvent::InvalidGossipedTransaction);
+        Ok(())
+    });
     // setup test context
     let mut ctx_builder = TestContextBuilder::new();
     ctx_builder.with_p2p(p2p);
@@ -88,9 +88,7 @@ impl TextContext {
         &mut self,
         amount: Word,
     ) -> (Output, UnsetInput) {
-        let input = self.random_predicate(AssetId::BASE, amount, None);
-        let output = Output::coin(*input.input_owner().unwrap(), amount, AssetId::BASE);
-        (output, UnsetInput(input))
+        create_output_and_input(&mut self.rng, amount)
     }
 
     pub(crate) fn random_predicate(
@@ -129,6 +127,15 @@ pub(crate) fn setup_coin(rng: &mut StdRng, mock_db: Option<&MockDb>) -> (Coin, I
     add_coin_to_state(input, mock_db)
 }
 
+pub(crate) fn create_output_and_input(
+    rng: &mut StdRng,
+    amount: Word,
+) -> (Output, UnsetInput) {
+    let input = random_predicate(rng, AssetId::BASE, amount, None);
+    let output = Output::coin(*input.input_owner().unwrap(), amount, AssetId::BASE);
+    (output, UnsetInput(input))
+}
+
 pub(crate) fn add_coin_to_state(input: Input, mock_db: Option<&MockDb>) -> (Coin, Input) {
     let mut coin = CompressedCoin::default();
     coin.set_owner(*input.input_owner().unwrap());
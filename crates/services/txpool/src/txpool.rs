@@ -72,7 +72,11 @@ impl<ViewProvider> TxPool<ViewProvider> {
             by_hash: HashMap::new(),
             by_gas_price: PriceSort::default(),
             by_time: TimeSort::default(),
-            by_dependency: Dependency::new(max_depth, config.utxo_validation),
+            by_dependency: Dependency::new(
+                max_depth,
+                config.utxo_validation,
+                config.min_gas_price_bump_percentage,
+            ),
             config,
             database,
         }
@@ -319,36 +323,41 @@ where
                 .observe(tx.metered_bytes_size() as f64);
         }
         // check and insert dependency
-        let rem = self.by_dependency.insert(&self.by_hash, view, &tx)?;
+        let replacements = self.by_dependency.insert(&self.by_hash, view, &tx)?;
         let info = TxInfo::new(tx.clone());
         let submitted_time = info.submitted_time();
         self.by_gas_price.insert(&info);
         self.by_time.insert(&info);
         self.by_hash.insert(tx.id(), info);
 
-        // if some transaction were removed so we don't need to check limit
-        let removed = if rem.is_empty() {
-            if max_limit_hit {
-                // remove last tx from sort
-                let rem_tx = self.by_gas_price.lowest_tx().unwrap(); // safe to unwrap limit is hit
-                self.remove_inner(&rem_tx);
-                vec![rem_tx]
-            } else {
-                Vec::new()
-            }
+        // if some transaction were replaced so we don't need to check limit
+        let removed = if replacements.is_empty() && max_limit_hit {
+            // remove last tx from sort
+            let rem_tx = self.by_gas_price.lowest_tx().unwrap(); // safe to unwrap limit is hit
+            self.remove_inner(&rem_tx);
+            vec![rem_tx]
         } else {
-            // remove ret from by_hash and from by_price
-            for rem in rem.iter() {
-                self.remove_tx(&rem.id());
-            }
-
-            rem
+            Vec::new()
         };
 
+        // remove replaced transactions and their dependents from by_hash and from by_price
+        let mut replaced = Vec::with_capacity(replacements.len());
+        let mut removed_dependents = Vec::new();
+        for (replaced_tx, dependents) in replacements {
+            self.remove_tx(&replaced_tx.id());
+            for dependent in dependents {
+                self.remove_tx(&dependent.id());
+                removed_dependents.push((dependent, replaced_tx.id()));
+            }
+            replaced.push(replaced_tx);
+        }
+
         Ok(InsertionResult {
             inserted: tx,
             submitted_time,
             removed,
+            replaced,
+            removed_dependents,
         })
     }
 
@@ -373,14 +382,26 @@ where
             match ret {
                 Ok(InsertionResult {
                     removed,
+                    replaced,
+                    removed_dependents,
                     inserted,
                     submitted_time,
                 }) => {
                     for removed in removed {
-                        // small todo there is possibility to have removal reason (ReplacedByHigherGas, DependencyRemoved)
-                        // but for now it is okay to just use Error::Removed.
                         tx_status_sender.send_squeezed_out(removed.id(), Error::Removed);
                     }
+                    for replaced in replaced {
+                        tx_status_sender.send_squeezed_out(
+                            replaced.id(),
+                            Error::ReplacedByHigherGas(inserted.id()),
+                        );
+                    }
+                    for (dependent, replaced_id) in removed_dependents {
+                        tx_status_sender.send_squeezed_out(
+                            dependent.id(),
+                            Error::DependencyRemoved(*replaced_id),
+                        );
+                    }
                     tx_status_sender.send_submitted(
                         inserted.id(),
                         Tai64::from_unix(submitted_time.as_secs() as i64),
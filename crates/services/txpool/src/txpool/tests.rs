@@ -283,7 +283,7 @@ async fn higher_priced_tx_removes_lower_priced_tx() {
     let vec = txpool
         .insert_single(tx2)
         .expect("Tx2 should be Ok, got Err");
-    assert_eq!(vec.removed[0].id(), tx1_id, "Tx1 id should be removed");
+    assert_eq!(vec.replaced[0].id(), tx1_id, "Tx1 id should be removed");
 }
 
 #[tokio::test]
@@ -466,13 +466,16 @@ async fn more_priced_tx3_removes_tx1_and_dependent_tx2() {
     let vec = txpool
         .insert_single(tx3)
         .expect("Tx3 should be OK, got Err");
+    assert_eq!(vec.replaced.len(), 1, "Tx1 should be replaced:{vec:?}");
+    assert_eq!(vec.replaced[0].id(), tx1_id, "Tx1 id should be replaced");
     assert_eq!(
-        vec.removed.len(),
-        2,
-        "Tx1 and Tx2 should be removed:{vec:?}",
+        vec.removed_dependents.len(),
+        1,
+        "Tx2 should be removed as a dependent:{vec:?}",
     );
-    assert_eq!(vec.removed[0].id(), tx1_id, "Tx1 id should be removed");
-    assert_eq!(vec.removed[1].id(), tx2_id, "Tx2 id should be removed");
+    let (dependent, replaced_id) = &vec.removed_dependents[0];
+    assert_eq!(dependent.id(), tx2_id, "Tx2 id should be removed");
+    assert_eq!(*replaced_id, tx1_id, "Tx2 should depend on Tx1");
 }
 
 #[tokio::test]
@@ -510,17 +513,125 @@ async fn more_priced_tx2_removes_tx1_and_more_priced_tx3_removes_tx2() {
     let squeezed = txpool
         .insert_single(tx2)
         .expect("Tx2 should be OK, got Err");
-    assert_eq!(squeezed.removed.len(), 1);
+    assert_eq!(squeezed.replaced.len(), 1);
     let squeezed = txpool
         .insert_single(tx3)
         .expect("Tx3 should be OK, got Err");
     assert_eq!(
-        squeezed.removed.len(),
+        squeezed.replaced.len(),
         1,
         "Tx2 should be removed:{squeezed:?}"
     );
 }
 
+#[tokio::test]
+async fn tx_not_replaced_when_gas_price_bump_is_too_low() {
+    // given
+    let mut context = TextContext::default().config(Config {
+        min_gas_price_bump_percentage: 50,
+        ..Default::default()
+    });
+
+    let (_, gas_coin) = context.setup_coin();
+
+    let tx1 = TransactionBuilder::script(vec![], vec![])
+        .gas_price(10)
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(gas_coin.clone())
+        .finalize_as_transaction();
+
+    let tx2 = TransactionBuilder::script(vec![], vec![])
+        .gas_price(14)
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(gas_coin.clone())
+        .finalize_as_transaction();
+
+    let tx3 = TransactionBuilder::script(vec![], vec![])
+        .gas_price(15)
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+
+    let tx1_id = tx1.id(&ChainId::default());
+    let mut txpool = context.build();
+    let tx1 = check_unwrap_tx(tx1, &txpool.config).await;
+    let tx2 = check_unwrap_tx(tx2, &txpool.config).await;
+    let tx3 = check_unwrap_tx(tx3, &txpool.config).await;
+    txpool
+        .insert_single(tx1)
+        .expect("Tx1 should be OK, got Err");
+
+    // when
+    let err = txpool
+        .insert_single(tx2)
+        .expect_err("Tx2 should be Err, got Ok");
+    let result = txpool
+        .insert_single(tx3)
+        .expect("Tx3 should be OK, got Err");
+
+    // then
+    assert!(matches!(
+        err,
+        Error::NotInsertedGasPriceBumpTooLow(id, 15) if id == tx1_id
+    ));
+    assert_eq!(result.replaced.len(), 1);
+    assert_eq!(result.replaced[0].id(), tx1_id);
+}
+
+#[tokio::test]
+async fn tx_not_replaced_when_dependent_tx_is_priced_higher() {
+    // given
+    let mut context = TextContext::default();
+
+    let (_, gas_coin) = context.setup_coin();
+
+    let (output, unset_input) = context.create_output_and_input(10);
+    let tx1 = TransactionBuilder::script(vec![], vec![])
+        .gas_price(10)
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(gas_coin.clone())
+        .add_output(output)
+        .finalize_as_transaction();
+
+    let input = unset_input.into_input(UtxoId::new(tx1.id(&Default::default()), 0));
+
+    let tx2 = TransactionBuilder::script(vec![], vec![])
+        .gas_price(20)
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(input)
+        .finalize_as_transaction();
+
+    let tx3 = TransactionBuilder::script(vec![], vec![])
+        .gas_price(12)
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+
+    let tx1_id = tx1.id(&ChainId::default());
+    let mut txpool = context.build();
+    let tx1 = check_unwrap_tx(tx1, &txpool.config).await;
+    let tx2 = check_unwrap_tx(tx2, &txpool.config).await;
+    let tx3 = check_unwrap_tx(tx3, &txpool.config).await;
+    txpool
+        .insert_single(tx1)
+        .expect("Tx1 should be OK, got Err");
+    txpool
+        .insert_single(tx2)
+        .expect("Tx2 should be OK, got Err");
+
+    // when
+    let err = txpool
+        .insert_single(tx3)
+        .expect_err("Tx3 should be Err, got Ok");
+
+    // then
+    assert!(matches!(
+        err,
+        Error::NotInsertedReplacementUnderpriced(id, 22) if id == tx1_id
+    ));
+    assert_eq!(txpool.txs().len(), 2);
+}
+
 #[tokio::test]
 async fn tx_limit_hit() {
     let mut context = TextContext::default().config(Config {
@@ -909,8 +1020,8 @@ async fn higher_priced_tx_squeezes_out_lower_priced_tx_with_same_message_id() {
     let tx_high = check_unwrap_tx(tx_high, &txpool.config).await;
     let squeezed_out_txs = txpool.insert_single(tx_high).expect("should succeed");
 
-    assert_eq!(squeezed_out_txs.removed.len(), 1);
-    assert_eq!(squeezed_out_txs.removed[0].id(), tx_low_id,);
+    assert_eq!(squeezed_out_txs.replaced.len(), 1);
+    assert_eq!(squeezed_out_txs.replaced[0].id(), tx_low_id,);
 }
 
 #[tokio::test]
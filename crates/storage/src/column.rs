@@ -64,6 +64,10 @@ pub enum Column {
     Metadata = 17,
     /// See [`SealedBlockConsensus`](crate::tables::SealedBlockConsensus)
     FuelBlockConsensus = 18,
+    /// The reverse changes of each block used to roll back the state.
+    ModificationsHistory = 19,
+    /// See [`HeaderOnlyBlocks`](crate::tables::HeaderOnlyBlocks)
+    HeaderOnlyBlocks = 20,
 }
 
 impl Column {
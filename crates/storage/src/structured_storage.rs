@@ -27,6 +27,7 @@ pub mod balances;
 pub mod blocks;
 pub mod coins;
 pub mod contracts;
+pub mod header_only_blocks;
 pub mod merkle_data;
 pub mod messages;
 pub mod sealed_block;
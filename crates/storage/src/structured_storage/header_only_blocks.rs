@@ -0,0 +1,28 @@
+//! The module contains implementations and tests for the `HeaderOnlyBlocks` table.
+
+use crate::{
+    blueprint::plain::Plain,
+    codec::{
+        postcard::Postcard,
+        primitive::Primitive,
+    },
+    column::Column,
+    structured_storage::TableWithBlueprint,
+    tables::HeaderOnlyBlocks,
+};
+
+impl TableWithBlueprint for HeaderOnlyBlocks {
+    type Blueprint = Plain<Primitive<4>, Postcard>;
+    type Column = Column;
+
+    fn column() -> Column {
+        Column::HeaderOnlyBlocks
+    }
+}
+
+#[cfg(test)]
+crate::basic_storage_tests!(
+    HeaderOnlyBlocks,
+    <HeaderOnlyBlocks as crate::Mappable>::Key::default(),
+    Some(vec![fuel_core_types::fuel_tx::TxId::zeroed(); 2])
+);
@@ -77,6 +77,19 @@ impl Mappable for SealedBlockConsensus {
     type OwnedValue = Consensus;
 }
 
+/// The table of blocks imported without execution, by the header only.
+/// The [`FuelBlocks`] table contains the header of such blocks without transactions.
+/// The value is the list of ids of the transactions of the block, if the body
+/// of the block was fetched from peers later.
+pub struct HeaderOnlyBlocks;
+
+impl Mappable for HeaderOnlyBlocks {
+    type Key = Self::OwnedKey;
+    type OwnedKey = BlockHeight;
+    type Value = Self::OwnedValue;
+    type OwnedValue = Option<Vec<TxId>>;
+}
+
 /// The storage table of coins. Each [`CompressedCoin`]
 /// is represented by unique `UtxoId`.
 pub struct Coins;
@@ -181,3 +181,268 @@ where
         MockStorageMethods::root::<Key, M>(self, key)
     }
 }
+
+/// The macro that generates tests of the [`KeyValueStore`](crate::kv_store::KeyValueStore),
+/// [`BatchOperations`](crate::kv_store::BatchOperations), and
+/// [`IteratorableStore`](crate::iter::IteratorableStore) implementations.
+/// The `$storage` expression should create a new empty storage or a type that
+/// dereferences to it. The `$column` is the column of the storage used by tests.
+#[macro_export]
+macro_rules! kv_store_tests {
+    ($storage:expr, $column:expr) => {
+        #[cfg(test)]
+        #[allow(unused_imports)]
+        mod kv_store_tests {
+            use super::*;
+            use $crate::{
+                iter::{
+                    IterDirection,
+                    IteratorableStore,
+                },
+                kv_store::{
+                    BatchOperations,
+                    KeyValueStore,
+                    WriteOperation,
+                },
+            };
+            use std::sync::Arc;
+
+            /// Keys with multi-byte prefixes ending in `0xFF`, which can't be
+            /// incremented to get the upper bound of the prefix.
+            fn keys() -> Vec<Vec<u8>> {
+                vec![
+                    vec![],
+                    vec![0x00],
+                    vec![0x01, 0x00],
+                    vec![0x01, 0x01],
+                    vec![0x01, 0xFF],
+                    vec![0x01, 0xFF, 0x00],
+                    vec![0x01, 0xFF, 0xFF],
+                    vec![0x01, 0xFF, 0xFF, 0x01],
+                    vec![0x02],
+                    vec![0x02, 0x00],
+                    vec![0xFF],
+                    vec![0xFF, 0xFF],
+                    vec![0xFF, 0xFF, 0x00],
+                ]
+            }
+
+            /// The sorted `keys` with the same filtering as the `iter_all` should do.
+            fn expected_keys(
+                keys: &[Vec<u8>],
+                prefix: Option<&[u8]>,
+                start: Option<&[u8]>,
+                direction: IterDirection,
+            ) -> Vec<Vec<u8>> {
+                if let (Some(prefix), Some(start)) = (prefix, start) {
+                    if !start.starts_with(prefix) {
+                        return vec![]
+                    }
+                }
+                let mut keys = keys
+                    .iter()
+                    .filter(|key| prefix.map_or(true, |prefix| key.starts_with(prefix)))
+                    .filter(|key| match (start, direction) {
+                        (None, _) => true,
+                        (Some(start), IterDirection::Forward) => key.as_slice() >= start,
+                        (Some(start), IterDirection::Reverse) => key.as_slice() <= start,
+                    })
+                    .cloned()
+                    .collect::<Vec<_>>();
+                keys.sort();
+                if direction == IterDirection::Reverse {
+                    keys.reverse();
+                }
+                keys
+            }
+
+            fn value_of(key: &[u8]) -> Arc<Vec<u8>> {
+                Arc::new([key, &[0xAA]].concat())
+            }
+
+            #[test]
+            fn put_and_get() {
+                // Given
+                let storage = $storage;
+                let key = vec![0xA, 0xB, 0xC];
+                let value = Arc::new(vec![1, 2, 3]);
+
+                // When
+                storage.put(&key, $column, value.clone()).unwrap();
+
+                // Then
+                assert_eq!(storage.get(&key, $column).unwrap(), Some(value));
+            }
+
+            #[test]
+            fn replace_returns_previous_value() {
+                // Given
+                let storage = $storage;
+                let key = vec![0xA, 0xB, 0xC];
+                let first = Arc::new(vec![1, 2, 3]);
+                let second = Arc::new(vec![4, 5, 6]);
+                storage.put(&key, $column, first.clone()).unwrap();
+
+                // When
+                let previous = storage.replace(&key, $column, second.clone()).unwrap();
+
+                // Then
+                assert_eq!(previous, Some(first));
+                assert_eq!(storage.get(&key, $column).unwrap(), Some(second));
+            }
+
+            #[test]
+            fn take_removes_value() {
+                // Given
+                let storage = $storage;
+                let key = vec![0xA, 0xB, 0xC];
+                let value = Arc::new(vec![1, 2, 3]);
+                storage.put(&key, $column, value.clone()).unwrap();
+
+                // When
+                let taken = storage.take(&key, $column).unwrap();
+
+                // Then
+                assert_eq!(taken, Some(value));
+                assert!(!storage.exists(&key, $column).unwrap());
+            }
+
+            #[test]
+            fn delete_removes_value() {
+                // Given
+                let storage = $storage;
+                let key = vec![0xA, 0xB, 0xC];
+                storage.put(&key, $column, Arc::new(vec![1, 2, 3])).unwrap();
+                assert!(storage.exists(&key, $column).unwrap());
+
+                // When
+                storage.delete(&key, $column).unwrap();
+
+                // Then
+                assert!(!storage.exists(&key, $column).unwrap());
+                assert_eq!(storage.get(&key, $column).unwrap(), None);
+            }
+
+            #[test]
+            fn size_of_value_and_read() {
+                // Given
+                let storage = $storage;
+                let key = vec![0xA, 0xB, 0xC];
+                storage.put(&key, $column, Arc::new(vec![1, 2, 3])).unwrap();
+
+                // When
+                let size = storage.size_of_value(&key, $column).unwrap();
+                let mut buf = [0u8; 3];
+                let read = storage.read(&key, $column, &mut buf).unwrap();
+
+                // Then
+                assert_eq!(size, Some(3));
+                assert_eq!(read, Some(3));
+                assert_eq!(buf, [1, 2, 3]);
+            }
+
+            #[test]
+            fn can_use_empty_key_and_value() {
+                // Given
+                let storage = $storage;
+                let value = Arc::new(vec![]);
+
+                // When
+                storage.put(&[], $column, value.clone()).unwrap();
+
+                // Then
+                assert_eq!(storage.get(&[], $column).unwrap(), Some(value));
+            }
+
+            #[test]
+            fn batch_write_inserts_and_removes() {
+                // Given
+                let storage = $storage;
+                let removed = vec![0x01];
+                let inserted = vec![0x02];
+                storage.put(&removed, $column, value_of(&removed)).unwrap();
+
+                // When
+                let mut entries = vec![
+                    (removed.clone(), $column, WriteOperation::Remove),
+                    (
+                        inserted.clone(),
+                        $column,
+                        WriteOperation::Insert(value_of(&inserted)),
+                    ),
+                ]
+                .into_iter();
+                storage.batch_write(&mut entries).unwrap();
+
+                // Then
+                assert_eq!(storage.get(&removed, $column).unwrap(), None);
+                assert_eq!(
+                    storage.get(&inserted, $column).unwrap(),
+                    Some(value_of(&inserted))
+                );
+            }
+
+            #[test]
+            fn iter_all_respects_prefix_start_and_direction() {
+                // Given
+                let storage = $storage;
+                let keys = keys();
+                for key in keys.iter() {
+                    storage.put(key, $column, value_of(key)).unwrap();
+                }
+                let prefixes = [
+                    None,
+                    Some(vec![0x01]),
+                    Some(vec![0x01, 0xFF]),
+                    Some(vec![0x01, 0xFF, 0xFF]),
+                    Some(vec![0xFF]),
+                    Some(vec![0xFF, 0xFF]),
+                    Some(vec![0x03]),
+                ];
+                let starts = [
+                    None,
+                    Some(vec![0x00]),
+                    Some(vec![0x01, 0x01]),
+                    Some(vec![0x01, 0xFF, 0x00]),
+                    Some(vec![0x01, 0xFF, 0xFF, 0x02]),
+                    Some(vec![0xFF, 0xFF]),
+                ];
+
+                for prefix in prefixes.iter() {
+                    for start in starts.iter() {
+                        for direction in [IterDirection::Forward, IterDirection::Reverse] {
+                            // When
+                            let actual = storage
+                                .iter_all(
+                                    $column,
+                                    prefix.as_deref(),
+                                    start.as_deref(),
+                                    direction,
+                                )
+                                .collect::<Result<Vec<_>, _>>()
+                                .unwrap();
+
+                            // Then
+                            let expected = expected_keys(
+                                &keys,
+                                prefix.as_deref(),
+                                start.as_deref(),
+                                direction,
+                            )
+                            .into_iter()
+                            .map(|key| {
+                                let value = value_of(&key);
+                                (key, value)
+                            })
+                            .collect::<Vec<_>>();
+                            assert_eq!(
+                                actual, expected,
+                                "prefix: {prefix:?}, start: {start:?}, direction: {direction:?}"
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    };
+}
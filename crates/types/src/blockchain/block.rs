@@ -129,6 +129,15 @@ impl Block<Transaction> {
 }
 
 impl<T> Block<T> {
+    /// Creates a block that only contains the header, without transactions.
+    /// It is used by the light client to store headers without bodies.
+    pub fn header_only(header: BlockHeader) -> Self {
+        Block::V1(BlockV1 {
+            header,
+            transactions: vec![],
+        })
+    }
+
     /// Destructure into the inner types.
     pub fn into_inner(self) -> (BlockHeader, Vec<T>) {
         match self {
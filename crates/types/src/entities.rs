@@ -7,6 +7,7 @@ use message::Message;
 pub mod coins;
 pub mod contract;
 pub mod message;
+pub mod relayer;
 
 impl TryFrom<Message> for MessageCoin {
     type Error = anyhow::Error;
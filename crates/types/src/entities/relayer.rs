@@ -0,0 +1,3 @@
+//! Relayed entities
+
+pub mod transaction;
@@ -0,0 +1,110 @@
+//! Relayed (forced) transactions
+
+use crate::{
+    blockchain::primitives::DaBlockHeight,
+    fuel_types::{
+        Nonce,
+        Word,
+    },
+};
+
+/// Transaction sent from the DA layer to fuel by the relayer. The sequencer must
+/// include it at the start of the block for the corresponding DA height.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RelayedTransaction {
+    /// Relayed Transaction Version 1
+    V1(RelayedTransactionV1),
+}
+
+#[cfg(any(test, feature = "test-helpers"))]
+impl Default for RelayedTransaction {
+    fn default() -> Self {
+        Self::V1(Default::default())
+    }
+}
+
+/// The V1 version of the relayed transaction.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct RelayedTransactionV1 {
+    /// Nonce must be unique. It's used to prevent replay attacks
+    pub nonce: Nonce,
+    /// The max gas that this transaction can consume
+    pub max_gas: Word,
+    /// The serialized transaction transmitted from the bridge
+    pub serialized_transaction: Vec<u8>,
+    /// The block height from the parent da layer that originated this transaction
+    pub da_height: DaBlockHeight,
+}
+
+impl From<RelayedTransactionV1> for RelayedTransaction {
+    fn from(value: RelayedTransactionV1) -> Self {
+        Self::V1(value)
+    }
+}
+
+impl RelayedTransaction {
+    /// Get the relayed transaction nonce
+    pub fn nonce(&self) -> &Nonce {
+        match self {
+            RelayedTransaction::V1(transaction) => &transaction.nonce,
+        }
+    }
+
+    /// Set the relayed transaction nonce
+    #[cfg(any(test, feature = "test-helpers"))]
+    pub fn set_nonce(&mut self, nonce: Nonce) {
+        match self {
+            RelayedTransaction::V1(transaction) => transaction.nonce = nonce,
+        }
+    }
+
+    /// Get the max gas of the relayed transaction
+    pub fn max_gas(&self) -> Word {
+        match self {
+            RelayedTransaction::V1(transaction) => transaction.max_gas,
+        }
+    }
+
+    /// Set the max gas of the relayed transaction
+    #[cfg(any(test, feature = "test-helpers"))]
+    pub fn set_max_gas(&mut self, max_gas: Word) {
+        match self {
+            RelayedTransaction::V1(transaction) => transaction.max_gas = max_gas,
+        }
+    }
+
+    /// Get the canonically serialized transaction
+    pub fn serialized_transaction(&self) -> &[u8] {
+        match self {
+            RelayedTransaction::V1(transaction) => &transaction.serialized_transaction,
+        }
+    }
+
+    /// Set the serialized transaction bytes
+    #[cfg(any(test, feature = "test-helpers"))]
+    pub fn set_serialized_transaction(&mut self, serialized_bytes: Vec<u8>) {
+        match self {
+            RelayedTransaction::V1(transaction) => {
+                transaction.serialized_transaction = serialized_bytes
+            }
+        }
+    }
+
+    /// Get the DA height that originated this transaction
+    pub fn da_height(&self) -> DaBlockHeight {
+        match self {
+            RelayedTransaction::V1(transaction) => transaction.da_height,
+        }
+    }
+
+    /// Set the da height
+    #[cfg(any(test, feature = "test-helpers"))]
+    pub fn set_da_height(&mut self, height: DaBlockHeight) {
+        match self {
+            RelayedTransaction::V1(transaction) => transaction.da_height = height,
+        }
+    }
+}
@@ -19,6 +19,7 @@ use crate::{
         ValidityError,
     },
     fuel_types::{
+        BlockHeight,
         Bytes32,
         ContractId,
         Nonce,
@@ -64,6 +65,15 @@ pub enum Event {
     CoinCreated(Coin),
     /// The coin was consumed by the transaction.
     CoinConsumed(Coin),
+    /// The forced transaction relayed from the DA layer was not included into the block.
+    ForcedTransactionFailed {
+        /// The nonce of the relayed transaction.
+        nonce: Nonce,
+        /// The height of the block where the transaction should have been included.
+        block_height: BlockHeight,
+        /// The reason why the transaction was not included.
+        failure: String,
+    },
 }
 
 /// The status of a transaction after it is executed.
@@ -353,6 +363,10 @@ pub enum Error {
     PreviousBlockIsNotFound,
     #[display(fmt = "The relayer gives incorrect messages for the requested da height")]
     RelayerGivesIncorrectMessages,
+    #[display(
+        fmt = "The valid forced transaction {_0:#x} is missing at the start of the block"
+    )]
+    ForcedTransactionIsMissing(Bytes32),
 }
 
 impl From<Error> for anyhow::Error {
@@ -21,3 +21,18 @@ pub type AddressBalance = Balance<Address>;
 
 /// The alias for the `Balance` of the contract.
 pub type ContractBalance = Balance<ContractId>;
+
+/// The keys and values of the `extensions` of errors returned by the GraphQL API.
+/// They allow clients to handle errors without matching the error messages.
+pub mod error_extensions {
+    /// The key of the extension with the code of the error.
+    pub const CODE: &str = "code";
+    /// The key of the extension with the [`ErrorKind`](crate::services::txpool::ErrorKind)
+    /// of the transaction rejected by the TxPool.
+    pub const TXPOOL_ERROR: &str = "txpool_error";
+
+    /// The code of the error about the missing entity.
+    pub const NOT_FOUND: &str = "NOT_FOUND";
+    /// The code of the error about the transaction rejected by the TxPool.
+    pub const TXPOOL: &str = "TXPOOL";
+}
@@ -183,6 +183,14 @@ pub struct PeerInfo {
     pub app_score: f64,
 }
 
+/// Contains metadata about a banned peer
+pub struct BannedPeerInfo {
+    /// The libp2p peer id
+    pub id: PeerId,
+    /// The time when the ban expires
+    pub banned_until: SystemTime,
+}
+
 /// Contains information from the most recent heartbeat received by the peer
 pub struct HeartbeatData {
     /// The currently reported block height of the peer
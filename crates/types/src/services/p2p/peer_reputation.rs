@@ -21,3 +21,155 @@ impl PeerReport for AppScore {
         *self
     }
 }
+
+/// The node-wide set of events that affect the reputation of a peer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ReputationEvent {
+    // Good
+    /// The block received from the peer was imported successfully
+    SuccessfulBlockImport,
+
+    // Bad
+    /// The peer didn't return the advertised block headers
+    MissingBlockHeaders,
+    /// The peer returned a malformed block header
+    BadBlockHeader,
+    /// The peer returned a block header with an invalid consensus seal
+    BadSeal,
+    /// The peer didn't return the advertised transactions
+    MissingTransactions,
+    /// The peer returned transactions that don't match the block header
+    InvalidTransactions,
+    /// The peer returned a chunk of the state that doesn't match the request
+    InvalidStateChunk,
+    /// The peer gossiped a transaction that failed validation
+    InvalidGossipedTransaction,
+    /// The peer gossiped a transaction that is already known to the node
+    DuplicateGossipedTransaction,
+    /// The peer returned a response exceeding the maximum allowed size
+    OversizedResponse,
+    /// The peer didn't respond to the request in time
+    RequestTimeout,
+    /// The peer follows a different chain
+    WrongChain,
+}
+
+impl ReputationEvent {
+    /// All variants of the event.
+    pub const ALL: [ReputationEvent; 12] = [
+        ReputationEvent::SuccessfulBlockImport,
+        ReputationEvent::MissingBlockHeaders,
+        ReputationEvent::BadBlockHeader,
+        ReputationEvent::BadSeal,
+        ReputationEvent::MissingTransactions,
+        ReputationEvent::InvalidTransactions,
+        ReputationEvent::InvalidStateChunk,
+        ReputationEvent::InvalidGossipedTransaction,
+        ReputationEvent::DuplicateGossipedTransaction,
+        ReputationEvent::OversizedResponse,
+        ReputationEvent::RequestTimeout,
+        ReputationEvent::WrongChain,
+    ];
+
+    /// Returns the snake case name of the event, used for configuration and metrics.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            ReputationEvent::SuccessfulBlockImport => "successful_block_import",
+            ReputationEvent::MissingBlockHeaders => "missing_block_headers",
+            ReputationEvent::BadBlockHeader => "bad_block_header",
+            ReputationEvent::BadSeal => "bad_seal",
+            ReputationEvent::MissingTransactions => "missing_transactions",
+            ReputationEvent::InvalidTransactions => "invalid_transactions",
+            ReputationEvent::InvalidStateChunk => "invalid_state_chunk",
+            ReputationEvent::InvalidGossipedTransaction => "invalid_gossiped_transaction",
+            ReputationEvent::DuplicateGossipedTransaction => {
+                "duplicate_gossiped_transaction"
+            }
+            ReputationEvent::OversizedResponse => "oversized_response",
+            ReputationEvent::RequestTimeout => "request_timeout",
+            ReputationEvent::WrongChain => "wrong_chain",
+        }
+    }
+
+    /// Returns the position of the event in [`ReputationEvent::ALL`].
+    fn index(&self) -> usize {
+        Self::ALL
+            .iter()
+            .position(|event| event == self)
+            .expect("`ReputationEvent::ALL` contains all events")
+    }
+}
+
+impl core::fmt::Display for ReputationEvent {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl core::str::FromStr for ReputationEvent {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|event| event.as_str() == s)
+            .ok_or_else(|| format!("Unknown reputation event `{s}`"))
+    }
+}
+
+/// The configuration of the peer reputation.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReputationConfig {
+    /// Minimum allowed peer score before peer is banned
+    pub min_app_score: AppScore,
+    /// Maximum value a peer can reach with its score
+    pub max_app_score: AppScore,
+    /// The factor by which the score of active peers decays over time
+    pub decay_app_score: AppScore,
+    /// The score delta applied for each [`ReputationEvent`],
+    /// in the order of [`ReputationEvent::ALL`]
+    pub deltas: [AppScore; ReputationEvent::ALL.len()],
+}
+
+impl ReputationConfig {
+    /// Returns the score delta for the `event`.
+    pub fn score(&self, event: ReputationEvent) -> AppScore {
+        self.deltas[event.index()]
+    }
+
+    /// Overrides the score delta for the `event`.
+    pub fn set_score(&mut self, event: ReputationEvent, score: AppScore) {
+        self.deltas[event.index()] = score;
+    }
+}
+
+impl Default for ReputationConfig {
+    fn default() -> Self {
+        let mut config = Self {
+            min_app_score: MIN_APP_SCORE,
+            max_app_score: MAX_APP_SCORE,
+            decay_app_score: DECAY_APP_SCORE,
+            deltas: [0.; ReputationEvent::ALL.len()],
+        };
+
+        for event in ReputationEvent::ALL {
+            let score = match event {
+                ReputationEvent::SuccessfulBlockImport => 5.,
+                ReputationEvent::MissingBlockHeaders
+                | ReputationEvent::BadBlockHeader
+                | ReputationEvent::BadSeal
+                | ReputationEvent::MissingTransactions
+                | ReputationEvent::InvalidTransactions
+                | ReputationEvent::InvalidStateChunk
+                | ReputationEvent::WrongChain => -100.,
+                ReputationEvent::InvalidGossipedTransaction
+                | ReputationEvent::OversizedResponse => -20.,
+                ReputationEvent::RequestTimeout => -5.,
+                ReputationEvent::DuplicateGossipedTransaction => -1.,
+            };
+            config.set_score(event, score);
+        }
+
+        config
+    }
+}
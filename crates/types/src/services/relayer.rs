@@ -2,7 +2,10 @@
 
 use crate::{
     blockchain::primitives::DaBlockHeight,
-    entities::message::Message,
+    entities::{
+        message::Message,
+        relayer::transaction::RelayedTransaction,
+    },
 };
 
 /// The event that may come from the relayer.
@@ -11,6 +14,8 @@ use crate::{
 pub enum Event {
     /// The message event which was sent to the bridge.
     Message(Message),
+    /// The transaction event which was submitted to the bridge for forced inclusion.
+    Transaction(RelayedTransaction),
 }
 
 impl Event {
@@ -18,6 +23,7 @@ impl Event {
     pub fn da_height(&self) -> DaBlockHeight {
         match self {
             Event::Message(message) => message.da_height(),
+            Event::Transaction(transaction) => transaction.da_height(),
         }
     }
 }
@@ -27,3 +33,9 @@ impl From<Message> for Event {
         Event::Message(message)
     }
 }
+
+impl From<RelayedTransaction> for Event {
+    fn from(transaction: RelayedTransaction) -> Self {
+        Event::Transaction(transaction)
+    }
+}
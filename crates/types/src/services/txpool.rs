@@ -167,8 +167,13 @@ pub struct InsertionResult {
     pub inserted: ArcPoolTx,
     /// The time the transaction was inserted.
     pub submitted_time: Duration,
-    /// These were removed during the insertion
+    /// These were removed during the insertion because the pool limit was hit
     pub removed: Vec<ArcPoolTx>,
+    /// These were replaced by the inserted transaction with a higher gas price
+    pub replaced: Vec<ArcPoolTx>,
+    /// These were removed because they depend on the replaced transactions.
+    /// Each of them is paired with the id of the replaced transaction
+    pub removed_dependents: Vec<(ArcPoolTx, TxId)>,
 }
 
 /// The status of the transaction during its life from the tx pool until the block.
@@ -236,8 +241,22 @@ pub fn from_executor_to_status(
     }
 }
 
+/// The error of the TxPool.
+///
+/// The [`ErrorKind`] is the variant of the error without details. It can be converted
+/// to and parsed from the name of the variant, so it can be shared with other processes.
 #[allow(missing_docs)]
-#[derive(thiserror::Error, Debug, Clone)]
+#[derive(thiserror::Error, Debug, Clone, strum_macros::EnumDiscriminants)]
+#[strum_discriminants(
+    name(ErrorKind),
+    allow(missing_docs),
+    derive(
+        Hash,
+        strum_macros::Display,
+        strum_macros::EnumString,
+        strum_macros::IntoStaticStr
+    )
+)]
 #[non_exhaustive]
 pub enum Error {
     #[error("TxPool required that transaction contains metadata")]
@@ -262,6 +281,14 @@ pub enum Error {
         "Transaction is not inserted. A higher priced tx {0:#x} is already spending this message: {1:#x}"
     )]
     NotInsertedCollisionMessageId(TxId, Nonce),
+    #[error(
+        "Transaction is not inserted. Replacement of tx {0:#x} and its dependents requires the gas price at least {1}"
+    )]
+    NotInsertedReplacementUnderpriced(TxId, Word),
+    #[error(
+        "Transaction is not inserted. The gas price bump is too low to replace tx {0:#x}, the gas price should be at least {1}"
+    )]
+    NotInsertedGasPriceBumpTooLow(TxId, Word),
     #[error("Transaction is not inserted. UTXO input does not exist: {0:#x}")]
     NotInsertedOutputDoesNotExist(UtxoId),
     #[error("Transaction is not inserted. UTXO input contract does not exist or was already spent: {0:#x}")]
@@ -303,6 +330,10 @@ pub enum Error {
     // small todo for now it can pass but in future we should include better messages
     #[error("Transaction removed.")]
     Removed,
+    #[error("Transaction was replaced by the transaction {0:#x} with a higher gas price.")]
+    ReplacedByHigherGas(TxId),
+    #[error("Transaction removed because its dependency {0:#x} was removed.")]
+    DependencyRemoved(TxId),
     #[error("Transaction expired because it exceeded the configured time to live `tx-pool-ttl`.")]
     TTLReason,
     #[error("Transaction squeezed out because {0}")]
@@ -323,3 +354,10 @@ impl From<CheckError> for Error {
         Error::ConsensusValidity(e)
     }
 }
+
+impl Error {
+    /// Returns the kind of the error.
+    pub fn kind(&self) -> ErrorKind {
+        self.into()
+    }
+}
@@ -0,0 +1,55 @@
+use fuel_core::{
+    combined_database::CombinedDatabase,
+    service::{
+        config::DbType,
+        Config,
+        FuelService,
+    },
+};
+use fuel_core_client::client::FuelClient;
+use tempfile::TempDir;
+
+const DATABASE_CACHE_SIZE: usize = 10 * 1024 * 1024;
+
+#[tokio::test]
+async fn backup_and_restore_preserve_blocks_at_the_same_height() {
+    // Given
+    let db_dir = TempDir::new().unwrap();
+    let database = CombinedDatabase::open(db_dir.path(), DATABASE_CACHE_SIZE).unwrap();
+    let srv = FuelService::from_combined_database(database, Config::local_node())
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    client.produce_blocks(5, None).await.unwrap();
+    let backup_dir = TempDir::new().unwrap();
+    let backup_path = backup_dir.path().join("backup");
+    let restored_dir = TempDir::new().unwrap();
+    let restored_path = restored_dir.path().join("db");
+
+    // When
+    let height = srv
+        .shared
+        .database
+        .backup(&DbType::RocksDb, &backup_path)
+        .unwrap();
+    CombinedDatabase::restore(&DbType::RocksDb, &backup_path, &restored_path).unwrap();
+
+    // Then
+    assert!(*height <= 5);
+    let restored = CombinedDatabase::open(&restored_path, DATABASE_CACHE_SIZE).unwrap();
+    assert_eq!(restored.on_chain().latest_height().unwrap(), height);
+    assert_eq!(restored.off_chain().latest_height().unwrap(), height);
+    let block = restored
+        .on_chain()
+        .get_sealed_block_by_height(&height)
+        .unwrap()
+        .expect("The block should exist in the restored database");
+    let original_block = srv
+        .shared
+        .database
+        .on_chain()
+        .get_sealed_block_by_height(&height)
+        .unwrap()
+        .unwrap();
+    assert_eq!(block, original_block);
+}
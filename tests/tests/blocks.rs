@@ -8,6 +8,7 @@ use fuel_core::{
 use fuel_core_client::client::{
     pagination::{
         PageDirection,
+        PaginationConfig,
         PaginationRequest,
     },
     types::TransactionStatus,
@@ -32,6 +33,7 @@ use fuel_core_types::{
     secrecy::ExposeSecret,
     tai64::Tai64,
 };
+use futures::TryStreamExt;
 use itertools::{
     rev,
     Itertools,
@@ -305,6 +307,32 @@ async fn block_connection_5(
     };
 }
 
+#[tokio::test]
+async fn blocks_stream_follows_cursors_until_the_last_block() {
+    // Given
+    let srv = FuelService::from_database(Default::default(), Config::local_node())
+        .await
+        .unwrap();
+    let client = FuelClient::builder()
+        .endpoint(srv.bound_address.to_string())
+        .pagination(PaginationConfig {
+            page_size: 3,
+            max_concurrent_requests: Some(1),
+        })
+        .build()
+        .unwrap();
+    client.produce_blocks(9, None).await.unwrap();
+
+    // When
+    let blocks: Vec<_> = client.blocks_stream().try_collect().await.unwrap();
+
+    // Then
+    assert_eq!(
+        blocks.into_iter().map(|b| b.header.height).collect_vec(),
+        (0..10).collect_vec()
+    );
+}
+
 mod full_block {
     use super::*;
     use cynic::QueryBuilder;
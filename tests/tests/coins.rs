@@ -12,6 +12,10 @@ use fuel_core::{
     },
 };
 use fuel_core_client::client::{
+    error::{
+        ClientError,
+        GraphQlError,
+    },
     types::CoinType,
     FuelClient,
 };
@@ -229,7 +233,7 @@ mod coin {
                 asset_id: asset_id_a,
                 collected_amount: 0,
             }
-            .to_str_error_string()
+            .to_client_error_string()
         );
     }
 
@@ -256,7 +260,7 @@ mod coin {
                 asset_id: asset_id_a,
                 collected_amount: 300,
             }
-            .to_str_error_string()
+            .to_client_error_string()
         );
     }
 
@@ -275,7 +279,7 @@ mod coin {
         assert!(coins_per_asset.is_err());
         assert_eq!(
             coins_per_asset.unwrap_err().to_string(),
-            CoinsQueryError::MaxCoinsReached.to_str_error_string()
+            CoinsQueryError::MaxCoinsReached.to_client_error_string()
         );
     }
 }
@@ -445,7 +449,7 @@ mod message_coin {
                 asset_id: base_asset_id,
                 collected_amount: 0,
             }
-            .to_str_error_string()
+            .to_client_error_string()
         );
     }
 
@@ -464,7 +468,7 @@ mod message_coin {
                 asset_id: base_asset_id,
                 collected_amount: 300,
             }
-            .to_str_error_string()
+            .to_client_error_string()
         );
     }
 
@@ -479,7 +483,7 @@ mod message_coin {
         assert!(coins_per_asset.is_err());
         assert_eq!(
             coins_per_asset.unwrap_err().to_string(),
-            CoinsQueryError::MaxCoinsReached.to_str_error_string()
+            CoinsQueryError::MaxCoinsReached.to_client_error_string()
         );
     }
 }
@@ -656,7 +660,7 @@ mod all_coins {
                 asset_id: asset_id_a,
                 collected_amount: 0,
             }
-            .to_str_error_string()
+            .to_client_error_string()
         );
     }
 
@@ -679,7 +683,7 @@ mod all_coins {
                 asset_id: asset_id_a,
                 collected_amount: 300,
             }
-            .to_str_error_string()
+            .to_client_error_string()
         );
     }
 
@@ -698,7 +702,7 @@ mod all_coins {
         assert!(coins_per_asset.is_err());
         assert_eq!(
             coins_per_asset.unwrap_err().to_string(),
-            CoinsQueryError::MaxCoinsReached.to_str_error_string()
+            CoinsQueryError::MaxCoinsReached.to_client_error_string()
         );
     }
 }
@@ -766,18 +770,23 @@ async fn coins_to_spend_error_duplicate_asset_query(
     assert!(coins_per_asset.is_err());
     assert_eq!(
         coins_per_asset.unwrap_err().to_string(),
-        CoinsQueryError::DuplicateAssets(asset_id).to_str_error_string()
+        CoinsQueryError::DuplicateAssets(asset_id).to_client_error_string()
     );
 }
 
-trait ToStdErrorString {
-    fn to_str_error_string(self) -> String;
+trait ToClientErrorString {
+    fn to_client_error_string(self) -> String;
 }
 
-impl ToStdErrorString for CoinsQueryError {
-    fn to_str_error_string(self) -> String {
-        fuel_core_client::client::from_strings_errors_to_std_error(vec![self.to_string()])
-            .to_string()
+impl ToClientErrorString for CoinsQueryError {
+    fn to_client_error_string(self) -> String {
+        ClientError::GraphQl(vec![GraphQlError {
+            message: self.to_string(),
+            path: vec![],
+            code: None,
+            txpool_error: None,
+        }])
+        .to_string()
     }
 }
 
@@ -5,7 +5,15 @@ use fuel_core::{
         FuelService,
     },
 };
-use fuel_core_client::client::FuelClient;
+use fuel_core_client::client::{
+    builder::RetryConfig,
+    error::ClientError,
+    FuelClient,
+};
+use std::{
+    net::TcpListener,
+    time::Duration,
+};
 
 #[tokio::test]
 async fn health() {
@@ -41,3 +49,53 @@ async fn can_restart_node() {
             .unwrap();
     }
 }
+
+#[tokio::test]
+async fn client_fails_over_to_healthy_endpoint() {
+    // Given
+    let srv = FuelService::from_database(Database::default(), Config::local_node())
+        .await
+        .unwrap();
+    let unavailable_address = TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap();
+    let client = FuelClient::builder()
+        .endpoint(unavailable_address.to_string())
+        .endpoint(srv.bound_address.to_string())
+        .retry(RetryConfig {
+            max_retries: 1,
+            initial_backoff: Duration::from_millis(10),
+            ..Default::default()
+        })
+        .build()
+        .unwrap();
+
+    // When
+    let health = client.health().await;
+
+    // Then
+    assert!(health.unwrap());
+    assert_eq!(
+        client.url().port(),
+        Some(srv.bound_address.port()),
+        "The client should switch to the available endpoint"
+    );
+}
+
+#[tokio::test]
+async fn client_returns_timeout_error_if_node_does_not_respond() {
+    // Given
+    let unresponsive_node = TcpListener::bind("127.0.0.1:0").unwrap();
+    let client = FuelClient::builder()
+        .endpoint(unresponsive_node.local_addr().unwrap().to_string())
+        .timeout(Duration::from_millis(100))
+        .build()
+        .unwrap();
+
+    // When
+    let result = client.health().await;
+
+    // Then
+    assert!(matches!(result, Err(ClientError::Timeout)), "{result:?}");
+}
@@ -1,6 +1,8 @@
 #![deny(unused_must_use)]
 #![deny(warnings)]
 
+#[cfg(feature = "default")]
+mod backup;
 mod balances;
 mod blocks;
 mod chain;
@@ -19,6 +21,8 @@ mod messages;
 mod metrics;
 mod node_info;
 mod poa;
+#[cfg(feature = "default")]
+mod reindex;
 #[cfg(feature = "relayer")]
 mod relayer;
 mod snapshot;
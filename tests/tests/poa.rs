@@ -1,24 +1,44 @@
 use fuel_core::{
+    chain_config::ConsensusConfig,
     combined_database::CombinedDatabase,
+    remote_signer::{
+        DaemonAddress,
+        RemoteBlockSigner,
+        RemoteSignerConfig,
+        SigningDaemonConfig,
+    },
     service::{
         Config,
         FuelService,
+        ServiceTrait,
     },
 };
 use fuel_core_client::client::{
     types::TransactionStatus,
     FuelClient,
 };
+use fuel_core_poa::ports::BlockSigner;
 use fuel_core_types::{
-    blockchain::consensus::Consensus,
+    blockchain::{
+        block::Block,
+        consensus::Consensus,
+    },
     fuel_crypto::SecretKey,
-    fuel_tx::Transaction,
+    fuel_tx::{
+        Input,
+        Transaction,
+    },
     secrecy::Secret,
+    tai64::Tai64,
 };
 use rand::{
     rngs::StdRng,
     SeedableRng,
 };
+use std::{
+    path::Path,
+    time::Duration,
+};
 
 #[tokio::test]
 async fn can_get_sealed_block_from_poa_produced_block() {
@@ -82,21 +102,240 @@ async fn can_get_sealed_block_from_poa_produced_block() {
         .expect("failed to verify signature");
 }
 
+/// Starts the daemon persisting its slashing protection state in the `dir`.
+fn start_signing_daemon(
+    secret: SecretKey,
+    dir: &Path,
+) -> fuel_core::remote_signer::Service {
+    start_signing_daemon_at(
+        secret,
+        DaemonAddress::Tcp("127.0.0.1:0".parse().unwrap()),
+        dir,
+    )
+}
+
+fn start_signing_daemon_at(
+    secret: SecretKey,
+    addr: DaemonAddress,
+    dir: &Path,
+) -> fuel_core::remote_signer::Service {
+    let config = SigningDaemonConfig {
+        addr,
+        tls: None,
+        slashing_protection_file: dir.join("slashing_protection"),
+    };
+    fuel_core::remote_signer::new_service(Secret::new(secret.into()), config).unwrap()
+}
+
+fn remote_signer_config(
+    daemon: &fuel_core::remote_signer::Service,
+) -> RemoteSignerConfig {
+    RemoteSignerConfig {
+        url: daemon.shared.bound_address.url(false).unwrap(),
+        tls: None,
+        timeout: Duration::from_secs(5),
+    }
+}
+
+#[tokio::test]
+async fn can_produce_blocks_signed_by_remote_signer() {
+    // Given
+    let mut rng = StdRng::seed_from_u64(11);
+    let poa_secret = SecretKey::random(&mut rng);
+    let poa_public = poa_secret.public_key();
+    let dir = tempfile::tempdir().unwrap();
+    let daemon = start_signing_daemon(poa_secret, dir.path());
+    daemon.start_and_await().await.unwrap();
+
+    let db = CombinedDatabase::default();
+    let mut config = Config::local_node();
+    config.chain_conf.consensus = ConsensusConfig::PoA {
+        signing_key: Input::owner(&poa_public),
+    };
+    config.consensus_key = None;
+    config.remote_signer = Some(remote_signer_config(&daemon));
+    let srv = FuelService::from_combined_database(db.clone(), config)
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // When
+    let status = client
+        .submit_and_await_commit(&Transaction::default_test_tx())
+        .await
+        .unwrap();
+
+    // Then
+    let block_height = match status {
+        TransactionStatus::Success { block_height, .. } => block_height,
+        _ => {
+            panic!("unexpected result")
+        }
+    };
+    let sealed_block_header = db
+        .on_chain()
+        .get_sealed_block_header(&block_height)
+        .unwrap()
+        .expect("expected sealed header to be available");
+    let block_id = sealed_block_header.entity.id();
+    let signature = match sealed_block_header.consensus {
+        Consensus::PoA(poa) => poa.signature,
+        _ => panic!("Not expected consensus"),
+    };
+    signature
+        .verify(&poa_public, &block_id.into_message())
+        .expect("failed to verify signature");
+}
+
+#[tokio::test]
+async fn remote_signer_refuses_to_sign_another_block_at_the_same_height() {
+    // Given
+    let mut rng = StdRng::seed_from_u64(12);
+    let poa_secret = SecretKey::random(&mut rng);
+    let dir = tempfile::tempdir().unwrap();
+    let daemon = start_signing_daemon(poa_secret, dir.path());
+    daemon.start_and_await().await.unwrap();
+    let signer = RemoteBlockSigner::new(
+        &remote_signer_config(&daemon),
+        Input::owner(&poa_secret.public_key()),
+    )
+    .unwrap();
+
+    let mut first_block = Block::default();
+    first_block.header_mut().set_block_height(1u32.into());
+    first_block.header_mut().set_time(Tai64(1));
+    let mut second_block = first_block.clone();
+    second_block.header_mut().set_time(Tai64(2));
+    signer.seal_block(&first_block).await.unwrap();
+
+    // When
+    let same_block = signer.seal_block(&first_block).await;
+    let conflicting_block = signer.seal_block(&second_block).await;
+
+    // Then
+    assert!(same_block.is_ok());
+    assert!(conflicting_block.is_err());
+}
+
+#[tokio::test]
+async fn remote_signer_refuses_another_block_at_the_same_height_after_restart() {
+    // Given
+    let mut rng = StdRng::seed_from_u64(14);
+    let poa_secret = SecretKey::random(&mut rng);
+    let dir = tempfile::tempdir().unwrap();
+    let daemon = start_signing_daemon(poa_secret, dir.path());
+    daemon.start_and_await().await.unwrap();
+    let signer = RemoteBlockSigner::new(
+        &remote_signer_config(&daemon),
+        Input::owner(&poa_secret.public_key()),
+    )
+    .unwrap();
+
+    let mut first_block = Block::default();
+    first_block.header_mut().set_block_height(1u32.into());
+    first_block.header_mut().set_time(Tai64(1));
+    let mut second_block = first_block.clone();
+    second_block.header_mut().set_time(Tai64(2));
+    signer.seal_block(&first_block).await.unwrap();
+    daemon.stop_and_await().await.unwrap();
+    let daemon = start_signing_daemon(poa_secret, dir.path());
+    daemon.start_and_await().await.unwrap();
+    let signer = RemoteBlockSigner::new(
+        &remote_signer_config(&daemon),
+        Input::owner(&poa_secret.public_key()),
+    )
+    .unwrap();
+
+    // When
+    let result = signer.seal_block(&second_block).await;
+
+    // Then
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn remote_signer_is_not_available_when_the_daemon_is_stopped() {
+    // Given
+    let mut rng = StdRng::seed_from_u64(15);
+    let poa_secret = SecretKey::random(&mut rng);
+    let dir = tempfile::tempdir().unwrap();
+    let daemon = start_signing_daemon(poa_secret, dir.path());
+    daemon.start_and_await().await.unwrap();
+    let signer = RemoteBlockSigner::new(
+        &remote_signer_config(&daemon),
+        Input::owner(&poa_secret.public_key()),
+    )
+    .unwrap();
+    assert!(signer.is_available().await);
+
+    // When
+    daemon.stop_and_await().await.unwrap();
+
+    // Then
+    assert!(!signer.is_available().await);
+}
+
+#[test]
+fn signing_daemon_refuses_non_loopback_address_without_tls() {
+    // Given
+    let mut rng = StdRng::seed_from_u64(16);
+    let poa_secret = SecretKey::random(&mut rng);
+    let dir = tempfile::tempdir().unwrap();
+    let config = SigningDaemonConfig {
+        addr: DaemonAddress::Tcp("0.0.0.0:0".parse().unwrap()),
+        tls: None,
+        slashing_protection_file: dir.path().join("slashing_protection"),
+    };
+
+    // When
+    let result =
+        fuel_core::remote_signer::new_service(Secret::new(poa_secret.into()), config);
+
+    // Then
+    assert!(result.is_err());
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn remote_signer_signs_blocks_over_unix_socket() {
+    // Given
+    let mut rng = StdRng::seed_from_u64(13);
+    let poa_secret = SecretKey::random(&mut rng);
+    let poa_public = poa_secret.public_key();
+    let dir = tempfile::tempdir().unwrap();
+    let daemon = start_signing_daemon_at(
+        poa_secret,
+        DaemonAddress::Unix(dir.path().join("signer.sock")),
+        dir.path(),
+    );
+    daemon.start_and_await().await.unwrap();
+    let signer =
+        RemoteBlockSigner::new(&remote_signer_config(&daemon), Input::owner(&poa_public))
+            .unwrap();
+    let block = Block::default();
+
+    // When
+    let consensus = signer.seal_block(&block).await.unwrap();
+
+    // Then
+    let signature = match consensus {
+        Consensus::PoA(poa) => poa.signature,
+        _ => panic!("Not expected consensus"),
+    };
+    signature
+        .verify(&poa_public, &block.id().into_message())
+        .expect("failed to verify signature");
+}
+
 #[cfg(feature = "p2p")]
 mod p2p {
     use super::*;
-    use fuel_core::{
-        chain_config::ConsensusConfig,
-        p2p_test_helpers::{
-            make_config,
-            make_node,
-            Bootstrap,
-        },
-        service::ServiceTrait,
+    use fuel_core::p2p_test_helpers::{
+        make_config,
+        make_node,
+        Bootstrap,
     };
     use fuel_core_poa::Trigger;
-    use fuel_core_types::fuel_tx::Input;
-    use std::time::Duration;
 
     // Starts first_producer which creates some blocks
     // Then starts second_producer that uses the first one as a reserved peer.
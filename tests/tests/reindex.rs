@@ -0,0 +1,148 @@
+use fuel_core::{
+    combined_database::CombinedDatabase,
+    service::{
+        reindex::rebuild_off_chain,
+        Config,
+        FuelService,
+        ServiceTrait,
+    },
+};
+use fuel_core_client::client::FuelClient;
+use fuel_core_storage::{
+    tables::HeaderOnlyBlocks,
+    StorageAsMut,
+};
+use fuel_core_types::fuel_tx::{
+    Bytes32,
+    Transaction,
+    UniqueIdentifier,
+};
+use tempfile::TempDir;
+
+const DATABASE_CACHE_SIZE: usize = 10 * 1024 * 1024;
+
+async fn node_with_transactions(
+    db_dir: &TempDir,
+    config: &Config,
+) -> (CombinedDatabase, Vec<Bytes32>) {
+    let database = CombinedDatabase::open(db_dir.path(), DATABASE_CACHE_SIZE).unwrap();
+    let srv = FuelService::from_combined_database(database.clone(), config.clone())
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let mut tx_ids = vec![];
+    for _ in 0..3 {
+        let tx = Transaction::default_test_tx();
+        client.submit_and_await_commit(&tx).await.unwrap();
+        tx_ids.push(tx.id(&config.chain_conf.consensus_parameters.chain_id));
+    }
+    srv.stop_and_await().await.unwrap();
+    (database, tx_ids)
+}
+
+fn statuses(
+    database: &CombinedDatabase,
+    tx_ids: &[Bytes32],
+) -> Vec<Option<fuel_core_types::services::txpool::TransactionStatus>> {
+    tx_ids
+        .iter()
+        .map(|id| database.off_chain().get_tx_status(id).unwrap())
+        .collect()
+}
+
+#[tokio::test]
+async fn rebuild_off_chain_from_genesis_restores_transaction_statuses() {
+    // Given
+    let db_dir = TempDir::new().unwrap();
+    let config = Config::local_node();
+    let (database, tx_ids) = node_with_transactions(&db_dir, &config).await;
+    let expected_statuses = statuses(&database, &tx_ids);
+    let on_chain_height = database.on_chain().latest_height().unwrap();
+    database.off_chain().clear().unwrap();
+
+    // When
+    let height = rebuild_off_chain(&database, &config, None).unwrap();
+
+    // Then
+    assert_eq!(height, on_chain_height);
+    assert_eq!(
+        database.off_chain().latest_height().unwrap(),
+        on_chain_height
+    );
+    assert!(expected_statuses.iter().all(Option::is_some));
+    assert_eq!(statuses(&database, &tx_ids), expected_statuses);
+    assert_eq!(database.check_consistency().unwrap(), vec![]);
+}
+
+#[tokio::test]
+async fn rebuild_off_chain_from_height_replays_only_later_blocks() {
+    // Given
+    let db_dir = TempDir::new().unwrap();
+    let config = Config::local_node();
+    let (database, tx_ids) = node_with_transactions(&db_dir, &config).await;
+    let expected_statuses = statuses(&database, &tx_ids);
+    let on_chain_height = database.on_chain().latest_height().unwrap();
+
+    // When
+    let height = rebuild_off_chain(&database, &config, Some(2u32.into())).unwrap();
+
+    // Then
+    assert_eq!(height, on_chain_height);
+    assert_eq!(statuses(&database, &tx_ids), expected_statuses);
+    assert_eq!(database.check_consistency().unwrap(), vec![]);
+}
+
+#[tokio::test]
+async fn rebuild_off_chain_skips_execution_of_header_only_blocks() {
+    // Given
+    let db_dir = TempDir::new().unwrap();
+    let config = Config::local_node();
+    let (mut database, tx_ids) = node_with_transactions(&db_dir, &config).await;
+    let expected_statuses = statuses(&database, &tx_ids);
+    let on_chain_height = database.on_chain().latest_height().unwrap();
+    // Each transaction is included in its own block after the genesis block.
+    database
+        .on_chain_mut()
+        .storage_as_mut::<HeaderOnlyBlocks>()
+        .insert(&2u32.into(), &Some(vec![tx_ids[1]]))
+        .unwrap();
+    database.off_chain().clear().unwrap();
+
+    // When
+    let height = rebuild_off_chain(&database, &config, None).unwrap();
+
+    // Then
+    assert_eq!(height, on_chain_height);
+    assert_eq!(
+        statuses(&database, &tx_ids),
+        vec![
+            expected_statuses[0].clone(),
+            None,
+            expected_statuses[2].clone()
+        ]
+    );
+}
+
+#[tokio::test]
+async fn node_refuses_to_start_until_interrupted_rebuild_is_finished() {
+    // Given
+    let db_dir = TempDir::new().unwrap();
+    let config = Config::local_node();
+    let (database, _) = node_with_transactions(&db_dir, &config).await;
+    database.on_chain().record_reindex_in_progress().unwrap();
+    database.off_chain().clear().unwrap();
+    let result =
+        FuelService::from_combined_database(database.clone(), config.clone()).await;
+    assert!(result.is_err());
+
+    // When
+    rebuild_off_chain(&database, &config, None).unwrap();
+
+    // Then
+    assert!(!database.on_chain().reindex_in_progress().unwrap());
+    let srv = FuelService::from_combined_database(database, config)
+        .await
+        .unwrap();
+    srv.stop_and_await().await.unwrap();
+}
@@ -1,5 +1,9 @@
 use crate::helpers::TestContext;
 use fuel_core::{
+    chain_config::{
+        CoinConfig,
+        StateConfig,
+    },
     schema::tx::receipt::all_receipts,
     service::{
         Config,
@@ -11,6 +15,10 @@ use fuel_core_client::client::{
         PageDirection,
         PaginationRequest,
     },
+    transfer::{
+        Signer,
+        TransferOptions,
+    },
     types::TransactionStatus,
     FuelClient,
 };
@@ -27,7 +35,6 @@ use rand::{
     Rng,
     SeedableRng,
 };
-use std::io::ErrorKind::NotFound;
 
 mod predicates;
 mod tx_pointer;
@@ -102,7 +109,7 @@ async fn dry_run_script() {
         .transaction_status(&tx.id(&Default::default()))
         .await
         .unwrap_err();
-    assert_eq!(err.kind(), NotFound);
+    assert!(err.is_not_found());
 }
 
 #[tokio::test]
@@ -139,7 +146,7 @@ async fn dry_run_create() {
         .transaction_status(&tx.id(&Default::default()))
         .await
         .unwrap_err();
-    assert_eq!(err.kind(), NotFound);
+    assert!(err.is_not_found());
 }
 
 #[tokio::test]
@@ -180,6 +187,57 @@ async fn submit() {
     assert_eq!(tx.id(&ChainId::default()), ret_tx.id(&ChainId::default()));
 }
 
+#[tokio::test]
+async fn transfer_spends_coins_of_signer_and_returns_change() {
+    // Given
+    let mut rng = StdRng::seed_from_u64(2322);
+    let secret = SecretKey::random(&mut rng);
+    let owner = secret.address();
+    let recipient: Address = rng.gen();
+    let mut config = Config::local_node();
+    config.utxo_validation = true;
+    config.chain_conf.initial_state = Some(StateConfig {
+        coins: Some(
+            [100, 200, 300]
+                .into_iter()
+                .map(|amount| CoinConfig {
+                    tx_id: None,
+                    output_index: None,
+                    tx_pointer_block_height: None,
+                    tx_pointer_tx_idx: None,
+                    maturity: None,
+                    owner,
+                    amount,
+                    asset_id: AssetId::BASE,
+                })
+                .collect(),
+        ),
+        ..Default::default()
+    });
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // When
+    let status = client
+        .transfer(
+            &secret,
+            vec![Output::coin(recipient, 250, AssetId::BASE)],
+            TransferOptions::default(),
+        )
+        .await
+        .unwrap();
+
+    // Then
+    assert!(matches!(status, TransactionStatus::Success { .. }));
+    let recipient_balance = client
+        .balance(&recipient, Some(&AssetId::BASE))
+        .await
+        .unwrap();
+    let owner_balance = client.balance(&owner, Some(&AssetId::BASE)).await.unwrap();
+    assert_eq!(recipient_balance, 250);
+    assert_eq!(owner_balance, 350);
+}
+
 #[ignore]
 #[tokio::test]
 async fn transaction_status_submitted() {
@@ -16,6 +16,7 @@ use fuel_core_types::{
     fuel_crypto::*,
     fuel_tx::*,
     fuel_types::ChainId,
+    services::txpool::ErrorKind as TxPoolErrorKind,
 };
 use futures::future::join_all;
 use itertools::Itertools;
@@ -128,11 +129,59 @@ async fn submit_utxo_verified_tx_below_min_gas_price_fails() {
     let result = client.submit(&tx).await;
 
     assert!(result.is_err());
-    assert!(result
-        .err()
-        .unwrap()
-        .to_string()
-        .contains("The gas price is too low"));
+    let error = result.err().unwrap();
+    assert!(error.to_string().contains("The gas price is too low"));
+    assert_eq!(
+        error.txpool_error(),
+        Some(TxPoolErrorKind::NotInsertedGasPriceTooLow)
+    );
+}
+
+#[tokio::test]
+async fn submit_utxo_verified_tx_with_spent_coin_fails() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let secret = SecretKey::random(&mut rng);
+    let utxo_id = rng.gen();
+    let owner = rng.gen();
+    let transaction = |script_gas_limit| {
+        TransactionBuilder::script(
+            op::ret(RegId::ONE).to_bytes().into_iter().collect(),
+            vec![],
+        )
+        .script_gas_limit(script_gas_limit)
+        .add_unsigned_coin_input(
+            secret,
+            utxo_id,
+            1000,
+            Default::default(),
+            Default::default(),
+            Default::default(),
+        )
+        .add_output(Output::change(owner, 0, AssetId::default()))
+        .finalize()
+    };
+    let spend = transaction(10000);
+    let double_spend = transaction(20000);
+
+    let mut test_builder = TestSetupBuilder::new(2322);
+    test_builder.config_coin_inputs_from_transactions(&[&spend]);
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        ..
+    } = test_builder.finalize().await;
+    client
+        .submit_and_await_commit(&spend.into())
+        .await
+        .expect("the first spend should succeed");
+
+    let result = client.submit(&double_spend.into()).await;
+
+    // the coin is removed from the database after it is spent
+    assert_eq!(
+        result.err().unwrap().txpool_error(),
+        Some(TxPoolErrorKind::NotInsertedInputUtxoIdNotDoesNotExist)
+    );
 }
 
 // verify that dry run can disable utxo_validation by simulating a transaction with unsigned